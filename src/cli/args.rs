@@ -1,7 +1,7 @@
-use clap::{Parser, Subcommand, ValueEnum};
-
-// Re-export Shell from clap_complete for use in command definitions
-pub use clap_complete::Shell;
+use crate::cli::output::{OutputFormat, OutputLevel};
+use crate::cli::pager::PagerMode;
+use clap::{ArgAction, Parser, Subcommand, ValueEnum};
+use std::path::PathBuf;
 
 /// Claude Dev CLI - Unified development environment management tool
 #[derive(Parser, Debug)]
@@ -14,45 +14,217 @@ pub use clap_complete::Shell;
     propagate_version = true
 )]
 pub struct Cli {
-    /// Enable verbose output
-    #[arg(short, long, global = true)]
+    /// Increase verbosity (-v debug, -vv more debug detail, -vvv trace)
+    #[arg(short = 'v', action = ArgAction::Count, global = true)]
+    pub verbosity: u8,
+
+    /// Enable verbose output (same as -v)
+    #[arg(long, global = true)]
     pub verbose: bool,
 
-    /// Suppress non-error output
-    #[arg(short, long, global = true, conflicts_with = "verbose")]
+    /// Suppress non-error output (same as --log-level error)
+    #[arg(short, long, global = true, conflicts_with_all = ["verbose", "verbosity", "log_level"])]
     pub quiet: bool,
 
+    /// Set the output level (error/warn/info/debug/trace)
+    #[arg(long, global = true, value_enum, conflicts_with_all = ["verbose", "verbosity"])]
+    pub log_level: Option<LogLevel>,
+
+    /// Use this configuration file instead of the default location
+    #[arg(long, global = true, value_name = "PATH")]
+    pub config: Option<PathBuf>,
+
+    /// Work on the project in this directory instead of the current repository
+    #[arg(long, global = true, value_name = "PATH")]
+    pub project_root: Option<PathBuf>,
+
     /// Disable colored output
     #[arg(long, global = true)]
     pub no_color: bool,
 
-    /// Set language (en/ja)
-    #[arg(long, global = true, value_enum, default_value = "en")]
-    pub lang: Language,
+    /// Set language (auto/en/ja or an installed catalog code; defaults to general.language)
+    ///
+    /// `auto` detects the language from LC_ALL/LC_MESSAGES/LANG.
+    #[arg(long, global = true, value_name = "LANG", value_parser = Language::parse)]
+    pub lang: Option<Language>,
+
+    /// Message format: text, or json for one JSON object per line (for CI)
+    #[arg(long = "output", global = true, value_enum, default_value = "text")]
+    pub output_format: OutputFormat,
+
+    /// Print the external commands that would run instead of running them
+    #[arg(long, global = true)]
+    pub dry_run: bool,
+
+    /// Hide progress output unless the command fails
+    #[arg(long, global = true, conflicts_with = "quiet")]
+    pub quiet_on_success: bool,
+
+    /// Send a desktop notification when the command finishes
+    #[arg(long, global = true)]
+    pub notify: bool,
+
+    /// Always page long result lists through $PAGER (default: only when taller than the terminal)
+    #[arg(long, global = true, conflicts_with = "no_pager")]
+    pub pager: bool,
+
+    /// Print result lists directly without a pager
+    #[arg(long, global = true)]
+    pub no_pager: bool,
 
     #[command(subcommand)]
     pub command: Commands,
 }
 
-/// Language selection
+impl Cli {
+    /// Resolve the output level from `--quiet`, `--log-level`, `--verbose`, and `-v`
+    pub fn output_level(&self) -> OutputLevel {
+        if self.quiet {
+            OutputLevel::Quiet
+        } else if let Some(level) = self.log_level {
+            level.to_output_level()
+        } else if self.verbose || self.dev_debug_verbose() {
+            OutputLevel::Verbose
+        } else {
+            OutputLevel::from_verbosity(self.verbosity)
+        }
+    }
+
+    /// Whether `dev debug --verbose` was given
+    ///
+    /// `-v` is taken by the global verbosity count, which also enables
+    /// verbose debugging; `dev debug --verbose` is the same as `-v`.
+    fn dev_debug_verbose(&self) -> bool {
+        matches!(
+            self.command,
+            Commands::Dev(DevCommands::Debug { verbose: true, .. })
+        )
+    }
+
+    /// Resolve the pager mode from `--pager` and `--no-pager`
+    pub fn pager_mode(&self) -> PagerMode {
+        if self.no_pager {
+            PagerMode::Never
+        } else if self.pager {
+            PagerMode::Always
+        } else {
+            PagerMode::Auto
+        }
+    }
+}
+
+/// Output level selection for `--log-level`
 #[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum LogLevel {
+    /// Errors only
+    Error,
+    /// Warnings and errors
+    Warn,
+    /// Normal output
+    Info,
+    /// Debug details
+    Debug,
+    /// Everything
+    Trace,
+}
+
+impl LogLevel {
+    /// Convert to the output handler level
+    pub fn to_output_level(self) -> OutputLevel {
+        match self {
+            LogLevel::Error => OutputLevel::Quiet,
+            LogLevel::Warn => OutputLevel::Warn,
+            LogLevel::Info => OutputLevel::Normal,
+            LogLevel::Debug => OutputLevel::Verbose,
+            LogLevel::Trace => OutputLevel::Trace,
+        }
+    }
+}
+
+/// Language selection
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Language {
+    /// Detect from the OS locale (`LC_ALL` / `LC_MESSAGES` / `LANG`)
+    Auto,
     /// English
     En,
     /// Japanese
     Ja,
+    /// Language of an external catalog (`~/.config/cldev/i18n/{code}.toml`)
+    External(String),
 }
 
 impl Language {
+    /// Parse a `--lang` value or `general.language` setting
+    ///
+    /// Any code other than `auto`, `en` and `ja` selects the external catalog
+    /// of that name; it must look like a language code (e.g. `fr`, `pt-br`).
+    pub fn parse(value: &str) -> std::result::Result<Self, String> {
+        match value.to_lowercase().as_str() {
+            "auto" => Ok(Language::Auto),
+            "en" => Ok(Language::En),
+            "ja" => Ok(Language::Ja),
+            code if crate::core::i18n::is_valid_code(code) => {
+                Ok(Language::External(code.to_string()))
+            }
+            _ => Err(format!(
+                "invalid language '{}' (expected auto, en, ja, or a catalog code such as fr)",
+                value
+            )),
+        }
+    }
+
     /// Convert CLI language to i18n language
-    pub fn to_i18n(self) -> crate::core::i18n::Language {
+    pub fn to_i18n(&self) -> crate::core::i18n::Language {
         match self {
+            Language::Auto => crate::core::i18n::Language::detect(),
             Language::En => crate::core::i18n::Language::English,
             Language::Ja => crate::core::i18n::Language::Japanese,
+            Language::External(code) => crate::core::i18n::Language::External(code.clone()),
         }
     }
 }
 
+/// Shell to generate completions for
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+#[allow(clippy::enum_variant_names)]
+pub enum Shell {
+    Bash,
+    Zsh,
+    Fish,
+    #[value(name = "powershell")]
+    PowerShell,
+    Elvish,
+    #[value(alias = "nu")]
+    Nushell,
+}
+
+impl Shell {
+    /// Get the clap_complete generator for this shell
+    ///
+    /// Returns `None` for Nushell, which is generated by clap_complete_nushell.
+    pub fn clap_shell(self) -> Option<clap_complete::Shell> {
+        match self {
+            Shell::Bash => Some(clap_complete::Shell::Bash),
+            Shell::Zsh => Some(clap_complete::Shell::Zsh),
+            Shell::Fish => Some(clap_complete::Shell::Fish),
+            Shell::PowerShell => Some(clap_complete::Shell::PowerShell),
+            Shell::Elvish => Some(clap_complete::Shell::Elvish),
+            Shell::Nushell => None,
+        }
+    }
+}
+
+impl std::fmt::Display for Shell {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = self
+            .to_possible_value()
+            .map(|value| value.get_name().to_string())
+            .unwrap_or_default();
+        f.write_str(&name)
+    }
+}
+
 /// Top-level commands organized by category
 #[derive(Subcommand, Debug)]
 pub enum Commands {
@@ -96,6 +268,10 @@ pub enum Commands {
     #[command(subcommand, about = super::help::session_about())]
     Session(super::SessionCommand),
 
+    /// Check that the tools the detected project needs are installed
+    #[command(about = super::help::doctor_about())]
+    Doctor,
+
     /// Generate shell completions
     #[command(about = super::help::completions_about())]
     Completions {
@@ -147,6 +323,24 @@ pub enum ConfigCommands {
         target: ConfigTarget,
     },
 
+    #[command(about = super::help::config_get_about())]
+    Get {
+        #[arg(help = super::help::config_get_key_help())]
+        key: String,
+    },
+
+    #[command(about = super::help::config_set_about())]
+    Set {
+        #[arg(help = super::help::config_set_key_help())]
+        key: String,
+
+        #[arg(help = super::help::config_set_value_help())]
+        value: String,
+    },
+
+    #[command(about = super::help::config_diff_about())]
+    Diff,
+
     #[command(about = super::help::config_list_about())]
     List {
         #[arg(short, long, help = super::help::config_list_detailed_help())]
@@ -171,6 +365,18 @@ pub enum ConfigCommands {
         retention_days: Option<i64>,
     },
 
+    #[command(about = super::help::config_restore_about())]
+    Restore {
+        #[arg(help = super::help::config_restore_from_help())]
+        from: Option<String>,
+    },
+
+    #[command(about = super::help::config_migrate_about())]
+    Migrate {
+        #[arg(short, long, help = super::help::config_migrate_backup_help())]
+        backup: bool,
+    },
+
     #[command(about = super::help::config_update_docs_about())]
     UpdateDocs {
         #[arg(value_enum, help = super::help::config_update_docs_type_help())]
@@ -193,6 +399,8 @@ pub enum DocType {
     Implementation,
     Api,
     Architecture,
+    /// Markdown reference of every command, generated from the CLI definition
+    Commands,
 }
 
 // ============================================================================
@@ -224,7 +432,7 @@ pub enum DevCommands {
         #[arg(help = super::help::dev_debug_symptom_help())]
         symptom: String,
 
-        #[arg(short, long, help = super::help::dev_debug_verbose_help())]
+        #[arg(long, help = super::help::dev_debug_verbose_help())]
         verbose: bool,
     },
 
@@ -303,6 +511,15 @@ pub enum GitCommands {
 
         #[arg(long, help = super::help::git_commit_amend_help())]
         amend: bool,
+
+        #[arg(long, help = super::help::git_commit_skip_checks_help())]
+        skip_checks: bool,
+
+        #[arg(long, conflicts_with = "no_emoji", help = super::help::git_commit_emoji_help())]
+        emoji: bool,
+
+        #[arg(long, help = super::help::git_commit_no_emoji_help())]
+        no_emoji: bool,
     },
 
     #[command(about = super::help::git_branch_about())]
@@ -331,6 +548,30 @@ pub enum GitCommands {
         #[arg(short, long, help = super::help::git_status_detailed_help())]
         detailed: bool,
     },
+
+    #[command(about = super::help::git_sync_about())]
+    Sync {
+        #[arg(long, help = super::help::git_sync_autostash_help())]
+        autostash: bool,
+    },
+
+    #[command(about = super::help::git_install_hook_about())]
+    InstallHook {
+        #[arg(value_enum, default_value = "pre-commit", help = super::help::git_install_hook_kind_help())]
+        kind: HookKind,
+
+        #[arg(long, help = super::help::git_install_hook_command_help())]
+        command: Option<String>,
+
+        #[arg(short, long, help = super::help::git_install_hook_force_help())]
+        force: bool,
+    },
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum HookKind {
+    PreCommit,
+    PrePush,
 }
 
 #[derive(Debug, Clone, Copy, ValueEnum)]
@@ -354,6 +595,12 @@ pub enum QualityCommands {
         #[arg(short, long, help = super::help::quality_lint_fix_help())]
         fix: bool,
 
+        #[arg(long, help = super::help::quality_lint_all_help())]
+        all: bool,
+
+        #[arg(long, help = super::help::quality_json_help())]
+        json: bool,
+
         #[arg(help = super::help::quality_lint_paths_help())]
         paths: Vec<String>,
     },
@@ -363,6 +610,9 @@ pub enum QualityCommands {
         #[arg(short, long, help = super::help::quality_format_check_help())]
         check: bool,
 
+        #[arg(long, help = super::help::quality_json_help())]
+        json: bool,
+
         #[arg(help = super::help::quality_format_paths_help())]
         paths: Vec<String>,
     },
@@ -377,6 +627,15 @@ pub enum QualityCommands {
 
         #[arg(short, long, help = super::help::quality_test_watch_help())]
         watch: bool,
+
+        #[arg(long, help = super::help::quality_json_help())]
+        json: bool,
+    },
+
+    #[command(about = super::help::quality_fix_all_about())]
+    FixAll {
+        #[arg(long, help = super::help::quality_fix_all_skip_tests_help())]
+        skip_tests: bool,
     },
 }
 
@@ -396,6 +655,15 @@ pub enum TechCommands {
 
         #[arg(short, long, value_enum, default_value = "development", help = super::help::tech_start_env_help())]
         env: Environment,
+
+        #[arg(long, value_name = "URL", help = super::help::tech_start_health_url_help())]
+        health_url: Option<String>,
+    },
+
+    #[command(about = super::help::tech_list_about())]
+    List {
+        #[arg(short, long, help = super::help::tech_list_detailed_help())]
+        detailed: bool,
     },
 }
 
@@ -414,6 +682,17 @@ pub enum Environment {
     Production,
 }
 
+impl Environment {
+    /// Get the environment name, as used for `[environments.<name>]` config tables
+    pub fn name(self) -> &'static str {
+        match self {
+            Environment::Development => "development",
+            Environment::Staging => "staging",
+            Environment::Production => "production",
+        }
+    }
+}
+
 // ============================================================================
 // Operations Commands
 // ============================================================================
@@ -440,9 +719,13 @@ pub enum OpsCommands {
         #[arg(short = 'y', long, help = super::help::ops_deploy_yes_help())]
         yes: bool,
 
-        #[arg(short, long, help = super::help::ops_deploy_dry_run_help())]
+        /// Former `--dry-run` short flag of deploy, kept as an alias of the global `--dry-run`
+        #[arg(short = 'd', id = "deploy_dry_run", hide = true)]
         dry_run: bool,
     },
+
+    #[command(about = super::help::ops_status_about())]
+    Status,
 }
 
 // ============================================================================
@@ -461,6 +744,19 @@ pub enum AnalysisCommands {
 
         #[arg(short, long, help = super::help::analysis_analyze_detailed_help())]
         detailed: bool,
+
+        #[arg(long, default_value_t = 6, help = super::help::analysis_analyze_dup_window_help())]
+        dup_window: usize,
+
+        // Not `--lang`, which is the global message language
+        #[arg(long, value_name = "EXT", value_delimiter = ',', help = super::help::analysis_analyze_lang_help())]
+        langs: Vec<String>,
+
+        #[arg(long, help = super::help::analysis_analyze_no_gitignore_help())]
+        no_gitignore: bool,
+
+        #[arg(long, help = super::help::analysis_analyze_since_tag_help())]
+        since_tag: bool,
     },
 
     #[command(about = super::help::analysis_explain_about())]
@@ -497,6 +793,9 @@ pub enum AnalysisCommands {
 
         #[arg(help = super::help::analysis_serena_targets_help())]
         targets: Vec<String>,
+
+        #[arg(long, help = super::help::analysis_serena_plan_help())]
+        plan: bool,
     },
 }
 
@@ -514,6 +813,7 @@ pub enum AnalysisFormat {
     Text,
     Json,
     Html,
+    Markdown,
 }
 
 #[derive(Debug, Clone, Copy, ValueEnum)]
@@ -594,6 +894,57 @@ pub enum LrCommands {
         #[arg(short, long, default_value = "10", help = super::help::lr_similar_limit_help())]
         limit: Option<usize>,
     },
+
+    #[command(about = super::help::lr_lint_about())]
+    Lint,
+
+    #[command(about = super::help::lr_link_about())]
+    Link {
+        #[arg(help = super::help::lr_link_id_help())]
+        id: String,
+
+        #[arg(short, long, default_value = "5", help = super::help::lr_link_limit_help())]
+        limit: usize,
+    },
+
+    #[command(about = super::help::lr_export_about())]
+    Export {
+        #[arg(short, long, value_enum, default_value = "markdown", help = super::help::lr_export_format_help())]
+        format: LrExportFormat,
+
+        #[arg(help = super::help::lr_export_output_help())]
+        output: Option<PathBuf>,
+    },
+
+    #[command(about = super::help::lr_import_about())]
+    Import {
+        #[arg(help = super::help::lr_import_path_help())]
+        path: PathBuf,
+    },
+
+    #[command(about = super::help::lr_migrate_about())]
+    Migrate,
+
+    #[command(about = super::help::lr_hotspots_about())]
+    Hotspots {
+        #[arg(short, long, value_enum, default_value = "table", help = super::help::lr_hotspots_format_help())]
+        format: LrHotspotsFormat,
+
+        #[arg(short, long, default_value = "20", help = super::help::lr_hotspots_limit_help())]
+        limit: usize,
+    },
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum LrExportFormat {
+    Markdown,
+    Json,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum LrHotspotsFormat {
+    Table,
+    Csv,
 }
 
 #[derive(Debug, Clone, Copy, ValueEnum)]
@@ -630,6 +981,12 @@ pub enum TodoCommands {
     Add {
         #[arg(help = super::help::todo_add_description_help())]
         description: Option<String>,
+
+        #[arg(short, long, value_enum, help = super::help::todo_add_priority_help())]
+        priority: Option<TodoPriority>,
+
+        #[arg(short, long, help = super::help::todo_add_due_help())]
+        due: Option<String>,
     },
 
     /// List all todo items
@@ -648,11 +1005,24 @@ pub enum TodoCommands {
     #[command(about = super::help::todo_sync_about())]
     Sync,
 
+    /// Import TODO/FIXME/HACK comments from source files
+    #[command(about = super::help::todo_import_about())]
+    Import,
+
     /// Interactive todo management mode
     #[command(about = super::help::todo_interactive_about())]
     Interactive,
 }
 
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum TodoPriority {
+    Low,
+    #[value(alias = "med")]
+    Medium,
+    High,
+    Critical,
+}
+
 /// Extract language flag from command-line arguments before clap parsing
 /// This allows i18n help messages to be displayed in the correct language
 pub fn extract_language_from_args() -> crate::core::i18n::Language {
@@ -662,24 +1032,22 @@ pub fn extract_language_from_args() -> crate::core::i18n::Language {
     for i in 0..args.len() {
         if args[i] == "--lang" {
             if let Some(lang_str) = args.get(i + 1) {
-                return match lang_str.as_str() {
-                    "ja" => crate::core::i18n::Language::Japanese,
-                    _ => crate::core::i18n::Language::English,
-                };
+                return Language::parse(lang_str)
+                    .map(|lang| lang.to_i18n())
+                    .unwrap_or_default();
             }
         }
     }
 
     // Check environment variable CLDEV_LANG as fallback
     if let Ok(env_lang) = std::env::var("CLDEV_LANG") {
-        return match env_lang.as_str() {
-            "ja" => crate::core::i18n::Language::Japanese,
-            _ => crate::core::i18n::Language::English,
-        };
+        return Language::parse(&env_lang)
+            .map(|lang| lang.to_i18n())
+            .unwrap_or_default();
     }
 
-    // Default to English
-    crate::core::i18n::Language::English
+    // Fall back to the OS locale
+    crate::core::i18n::Language::detect()
 }
 
 #[cfg(test)]
@@ -695,9 +1063,130 @@ mod tests {
         Cli::command().debug_assert();
     }
 
+    #[test]
+    fn test_verbosity_count_levels() {
+        let parse = |args: &[&str]| {
+            Cli::try_parse_from(["cldev"].iter().chain(args).chain(&["config", "list"]))
+                .unwrap()
+                .output_level()
+        };
+
+        let level = parse(&["-vv"]);
+        assert!(level >= OutputLevel::Verbose);
+        assert!(level < OutputLevel::Trace);
+
+        assert_eq!(parse(&[]), OutputLevel::Normal);
+        assert_eq!(parse(&["-v"]), OutputLevel::Verbose);
+        assert_eq!(parse(&["-vvv"]), OutputLevel::Trace);
+        assert_eq!(parse(&["--verbose"]), OutputLevel::Verbose);
+        assert_eq!(parse(&["--quiet"]), OutputLevel::Quiet);
+        assert_eq!(parse(&["--log-level", "warn"]), OutputLevel::Warn);
+    }
+
+    #[test]
+    fn test_analyze_langs_do_not_clash_with_global_lang() {
+        super::super::help::init_help_i18n(crate::core::i18n::Language::English);
+
+        let cli = Cli::try_parse_from([
+            "cldev", "--lang", "ja", "analysis", "analyze", "--langs", "rb,java",
+        ])
+        .unwrap();
+
+        assert_eq!(cli.lang, Some(Language::Ja));
+        match cli.command {
+            Commands::Analysis(AnalysisCommands::Analyze { langs, .. }) => {
+                assert_eq!(langs, vec!["rb", "java"])
+            }
+            other => panic!("unexpected command: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_dev_debug_verbose_matches_short_v() {
+        super::super::help::init_help_i18n(crate::core::i18n::Language::English);
+
+        let cli = Cli::try_parse_from(["cldev", "dev", "debug", "slow queries", "-v"]).unwrap();
+        assert_eq!(cli.verbosity, 1);
+        assert_eq!(cli.output_level(), OutputLevel::Verbose);
+
+        let cli =
+            Cli::try_parse_from(["cldev", "dev", "debug", "slow queries", "--verbose"]).unwrap();
+        assert_eq!(cli.output_level(), OutputLevel::Verbose);
+    }
+
+    #[test]
+    fn test_quiet_conflicts_with_verbosity() {
+        assert!(Cli::try_parse_from(["cldev", "-q", "-v", "config", "list"]).is_err());
+    }
+
+    #[test]
+    fn test_output_format_flag() {
+        let cli = Cli::try_parse_from(["cldev", "config", "list"]).unwrap();
+        assert_eq!(cli.output_format, OutputFormat::Text);
+
+        let cli = Cli::try_parse_from(["cldev", "config", "list", "--output", "json"]).unwrap();
+        assert_eq!(cli.output_format, OutputFormat::Json);
+    }
+
+    #[test]
+    fn test_dry_run_is_global() {
+        let cli = Cli::try_parse_from(["cldev", "--dry-run", "git", "sync"]).unwrap();
+        assert!(cli.dry_run);
+
+        let cli = Cli::try_parse_from(["cldev", "ops", "deploy", "staging", "--dry-run"]).unwrap();
+        assert!(cli.dry_run);
+
+        let cli = Cli::try_parse_from(["cldev", "git", "sync"]).unwrap();
+        assert!(!cli.dry_run);
+    }
+
+    #[test]
+    fn test_deploy_keeps_short_dry_run_flag() {
+        let cli = Cli::try_parse_from(["cldev", "ops", "deploy", "staging", "-d"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Commands::Ops(OpsCommands::Deploy { dry_run: true, .. })
+        ));
+    }
+
     #[test]
     fn test_language_enum() {
         assert!(matches!(Language::En, Language::En));
         assert!(matches!(Language::Ja, Language::Ja));
     }
+
+    #[test]
+    fn test_language_explicit_codes_ignore_locale() {
+        use crate::core::i18n::Language as I18nLanguage;
+
+        assert_eq!(Language::En.to_i18n(), I18nLanguage::English);
+        assert_eq!(Language::Ja.to_i18n(), I18nLanguage::Japanese);
+    }
+
+    #[test]
+    fn test_language_defaults_to_config() {
+        super::super::help::init_help_i18n(crate::core::i18n::Language::English);
+
+        // Without --lang, general.language decides
+        let cli = Cli::try_parse_from(["cldev", "config", "list"]).unwrap();
+        assert_eq!(cli.lang, None);
+
+        let cli = Cli::try_parse_from(["cldev", "--lang", "ja", "config", "list"]).unwrap();
+        assert_eq!(cli.lang, Some(Language::Ja));
+    }
+
+    #[test]
+    fn test_language_selects_external_catalog() {
+        super::super::help::init_help_i18n(crate::core::i18n::Language::English);
+
+        let cli = Cli::try_parse_from(["cldev", "--lang", "pt-BR", "config", "list"]).unwrap();
+        assert_eq!(cli.lang, Some(Language::External("pt-br".to_string())));
+        assert_eq!(
+            Language::External("fr".to_string()).to_i18n(),
+            crate::core::i18n::Language::External("fr".to_string())
+        );
+
+        assert!(Cli::try_parse_from(["cldev", "--lang", "../fr", "config", "list"]).is_err());
+        assert_eq!(Language::parse("auto"), Ok(Language::Auto));
+    }
 }
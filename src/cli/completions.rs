@@ -1,37 +1,142 @@
 #![allow(dead_code)]
 
 use clap::CommandFactory;
-use clap_complete::{generate, Shell};
-use std::io;
+use clap_complete::generate;
+use clap_complete_nushell::Nushell;
+use std::ffi::OsString;
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
 
-use crate::cli::args::Cli;
+use crate::cli::args::{Cli, Shell};
 use crate::cli::output::OutputHandler;
+use crate::core::config::home_dir;
+use crate::core::error::{CldevError, Result};
+use crate::core::security::SecurePath;
 
 /// Generate shell completions for the specified shell
 ///
 /// # Arguments
 ///
-/// * `shell` - The target shell (Bash, Zsh, Fish, PowerShell)
+/// * `shell` - The target shell (Bash, Zsh, Fish, PowerShell, Elvish, Nushell)
 ///
 /// # Examples
 ///
 /// ```
+/// use cldev::cli::args::Shell;
 /// use cldev::cli::completions::generate_completions;
 /// use cldev::cli::help;
 /// use cldev::core::i18n::Language;
-/// use clap_complete::Shell;
 ///
 /// // Initialize help system before generating completions
 /// help::init_help_i18n(Language::English);
 ///
 /// // Generate Bash completions
-/// generate_completions(Shell::Bash);
+/// generate_completions(Shell::Bash).unwrap();
 /// ```
-pub fn generate_completions(shell: Shell) {
+pub fn generate_completions(shell: Shell) -> Result<()> {
+    write_completions(shell, &mut io::stdout())
+}
+
+/// Write completions for the specified shell to `out`
+///
+/// The script is generated in memory first, since the clap generators panic
+/// on write errors such as a closed pipe.
+pub fn write_completions(shell: Shell, out: &mut dyn Write) -> Result<()> {
     let mut cmd = Cli::command();
     let bin_name = cmd.get_name().to_string();
 
-    generate(shell, &mut cmd, bin_name, &mut io::stdout());
+    let mut script = Vec::new();
+    match shell.clap_shell() {
+        Some(generator) => generate(generator, &mut cmd, bin_name, &mut script),
+        None => generate(Nushell, &mut cmd, bin_name, &mut script),
+    }
+
+    out.write_all(&script)?;
+    out.flush()?;
+    Ok(())
+}
+
+/// Base directories completions are installed under
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompletionDirs {
+    /// `$XDG_DATA_HOME`, or `~/.local/share`
+    pub data: PathBuf,
+    /// `$XDG_CONFIG_HOME`, or `~/.config`
+    pub config: PathBuf,
+}
+
+impl CompletionDirs {
+    /// Resolve the base directories from the XDG variables, falling back to `home`
+    pub fn from_env(home: &Path) -> Self {
+        Self::resolve(home, |name| std::env::var_os(name))
+    }
+
+    /// Resolve the base directories, looking variables up with `var`
+    ///
+    /// Empty and relative values are ignored, as the XDG spec requires.
+    fn resolve(home: &Path, var: impl Fn(&str) -> Option<OsString>) -> Self {
+        let base = |name: &str, default: &str| {
+            var(name)
+                .map(PathBuf::from)
+                .filter(|path| path.is_absolute())
+                .unwrap_or_else(|| home.join(default))
+        };
+
+        Self {
+            data: base("XDG_DATA_HOME", ".local/share"),
+            config: base("XDG_CONFIG_HOME", ".config"),
+        }
+    }
+
+    /// Get the base directory completions for a shell go under, and their path below it
+    fn install_location(&self, shell: Shell) -> (&Path, &'static str) {
+        match shell {
+            Shell::Bash => (&self.data, "bash-completion/completions/cldev"),
+            // Not on zsh's default $fpath, which holds no per-user directory
+            Shell::Zsh => (&self.data, "zsh/site-functions/_cldev"),
+            Shell::Fish => (&self.config, "fish/completions/cldev.fish"),
+            // Must be dot-sourced from the PowerShell profile
+            Shell::PowerShell => (&self.config, "powershell/completions/cldev.ps1"),
+            // Loaded with `use cldev` from rc.elv
+            Shell::Elvish => (&self.config, "elvish/lib/cldev.elv"),
+            // Must be sourced from config.nu
+            Shell::Nushell => (&self.config, "nushell/completions/cldev.nu"),
+        }
+    }
+
+    /// Get where completions for a shell are installed
+    pub fn install_path(&self, shell: Shell) -> PathBuf {
+        let (base, relative) = self.install_location(shell);
+        base.join(relative)
+    }
+}
+
+/// Install completions for a shell into the user's XDG base directories
+///
+/// Creates the completion directory if needed and returns the written path.
+pub fn install_completions(shell: Shell) -> Result<PathBuf> {
+    install_completions_in(shell, &CompletionDirs::from_env(&home_dir()?))
+}
+
+/// Install completions for a shell under `dirs`
+fn install_completions_in(shell: Shell, dirs: &CompletionDirs) -> Result<PathBuf> {
+    let (base, relative) = dirs.install_location(shell);
+
+    // The base directory may itself be a symlink, such as a ~/.config kept in
+    // a dotfiles repository; only refuse to write anywhere outside of it
+    fs::create_dir_all(base)?;
+    let secure_path = SecurePath::within(base, Path::new(relative))
+        .map_err(|e| CldevError::security(format!("Invalid completion path: {}", e)))?;
+
+    if let Some(dir) = secure_path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+
+    let mut file = fs::File::create(&secure_path)?;
+    write_completions(shell, &mut file)?;
+
+    Ok(base.join(relative))
 }
 
 /// Generate completions for all supported shells to files
@@ -53,10 +158,7 @@ pub fn generate_completions(shell: Shell) {
 /// let output = OutputHandler::default();
 /// generate_all_completions(&output).expect("Failed to generate completions");
 /// ```
-pub fn generate_all_completions(output: &OutputHandler) -> io::Result<()> {
-    use std::fs;
-    use std::path::Path;
-
+pub fn generate_all_completions(output: &OutputHandler) -> Result<()> {
     let completions_dir = Path::new("completions");
 
     // Create completions directory if it doesn't exist
@@ -64,22 +166,21 @@ pub fn generate_all_completions(output: &OutputHandler) -> io::Result<()> {
         fs::create_dir(completions_dir)?;
     }
 
-    let mut cmd = Cli::command();
-    let bin_name = cmd.get_name().to_string();
-
     // Generate for each shell
     let shells = [
         (Shell::Bash, "cldev.bash"),
         (Shell::Zsh, "cldev.zsh"),
         (Shell::Fish, "cldev.fish"),
         (Shell::PowerShell, "_cldev.ps1"),
+        (Shell::Elvish, "cldev.elv"),
+        (Shell::Nushell, "cldev.nu"),
     ];
 
     for (shell, filename) in shells.iter() {
         let output_path = completions_dir.join(filename);
         let mut file = fs::File::create(&output_path)?;
 
-        generate(*shell, &mut cmd, bin_name.clone(), &mut file);
+        write_completions(*shell, &mut file)?;
 
         output.success(&format!(
             "Generated {} completion: {}",
@@ -98,7 +199,8 @@ fn shell_name(shell: Shell) -> &'static str {
         Shell::Zsh => "Zsh",
         Shell::Fish => "Fish",
         Shell::PowerShell => "PowerShell",
-        _ => "Unknown",
+        Shell::Elvish => "Elvish",
+        Shell::Nushell => "Nushell",
     }
 }
 
@@ -120,8 +222,9 @@ fn get_installation_instructions(shell: Shell) -> &'static str {
 Add to your ~/.bashrc:
     eval "$(cldev completions bash)"
 
-Or save to a file:
-    cldev completions bash > ~/.local/share/bash-completion/completions/cldev
+Or install to the bash-completion user directory:
+    cldev completions bash --install
+    # Writes ${XDG_DATA_HOME:-~/.local/share}/bash-completion/completions/cldev
 "#
         }
         Shell::Zsh => {
@@ -129,10 +232,11 @@ Or save to a file:
 Add to your ~/.zshrc:
     eval "$(cldev completions zsh)"
 
-Or save to a completion directory:
-    cldev completions zsh > ~/.zsh/completion/_cldev
-    # Then add to ~/.zshrc:
-    fpath=(~/.zsh/completion $fpath)
+Or install to a completion directory:
+    cldev completions zsh --install
+    # The directory is not on zsh's default $fpath, so add to ~/.zshrc
+    # before compinit:
+    fpath=(${XDG_DATA_HOME:-$HOME/.local/share}/zsh/site-functions $fpath)
     autoload -Uz compinit && compinit
 "#
         }
@@ -147,19 +251,47 @@ Save to Fish completions directory:
 Add to your PowerShell profile:
     cldev completions powershell | Out-String | Invoke-Expression
 
-Or save to a file and source it:
-    cldev completions powershell > cldev.ps1
+Or install to a file and dot-source it:
+    cldev completions powershell --install
     # Then add to your profile:
-    . path\to\cldev.ps1
+    . ~/.config/powershell/completions/cldev.ps1
+
+On Windows the profile is usually
+    ~\Documents\PowerShell\Microsoft.PowerShell_profile.ps1
+"#
+        }
+        Shell::Elvish => {
+            r#"
+Save to an Elvish module and load it from ~/.config/elvish/rc.elv:
+    cldev completions elvish > ~/.config/elvish/lib/cldev.elv
+    # Then add to rc.elv:
+    use cldev
+"#
+        }
+        Shell::Nushell => {
+            r#"
+Save to the Nushell completions directory:
+    cldev completions nushell | save -f ~/.config/nushell/completions/cldev.nu
+    # Then add to config.nu:
+    source ~/.config/nushell/completions/cldev.nu
 "#
         }
-        _ => "No installation instructions available for this shell.",
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use tempfile::TempDir;
+
+    const ALL_SHELLS: [Shell; 6] = [
+        Shell::Bash,
+        Shell::Zsh,
+        Shell::Fish,
+        Shell::PowerShell,
+        Shell::Elvish,
+        Shell::Nushell,
+    ];
 
     #[test]
     fn test_shell_name() {
@@ -167,13 +299,146 @@ mod tests {
         assert_eq!(shell_name(Shell::Zsh), "Zsh");
         assert_eq!(shell_name(Shell::Fish), "Fish");
         assert_eq!(shell_name(Shell::PowerShell), "PowerShell");
+        assert_eq!(shell_name(Shell::Nushell), "Nushell");
     }
 
     #[test]
-    fn test_installation_instructions_not_empty() {
-        let shells = [Shell::Bash, Shell::Zsh, Shell::Fish, Shell::PowerShell];
+    fn test_completions_generated_for_each_shell() {
+        for shell in ALL_SHELLS {
+            let mut out = Vec::new();
+            write_completions(shell, &mut out).unwrap();
 
-        for shell in shells.iter() {
+            let script = String::from_utf8(out).unwrap();
+            assert!(!script.trim().is_empty(), "{} completions are empty", shell);
+            assert!(script.contains("cldev"), "{} completions miss cldev", shell);
+        }
+    }
+
+    #[test]
+    fn test_nushell_completions() {
+        let mut out = Vec::new();
+        write_completions(Shell::Nushell, &mut out).unwrap();
+        let script = String::from_utf8(out).unwrap();
+
+        assert!(script.starts_with("module completions {"));
+        assert!(script.contains("  export extern cldev [\n"));
+        assert!(script.contains("  export extern \"cldev git commit\" [\n"));
+        assert!(script.contains("    --dry-run"));
+        assert!(script.contains("    message?: string"));
+        // Value enums get a completer listing their values
+        assert!(script.contains("shell: string@\"nu-complete cldev completions shell\""));
+        assert!(script.contains("\"powershell\" \"elvish\" \"nushell\""));
+        assert!(script.trim_end().ends_with("export use completions *"));
+    }
+
+    fn home_dirs(home: &Path) -> CompletionDirs {
+        CompletionDirs::resolve(home, |_| None)
+    }
+
+    #[test]
+    fn test_completion_install_path() {
+        let dirs = home_dirs(Path::new("/home/user"));
+
+        assert_eq!(
+            dirs.install_path(Shell::Bash),
+            PathBuf::from("/home/user/.local/share/bash-completion/completions/cldev")
+        );
+        assert_eq!(
+            dirs.install_path(Shell::PowerShell),
+            PathBuf::from("/home/user/.config/powershell/completions/cldev.ps1")
+        );
+    }
+
+    #[test]
+    fn test_completion_dirs_honor_xdg_variables() {
+        let dirs = CompletionDirs::resolve(Path::new("/home/user"), |name| match name {
+            "XDG_DATA_HOME" => Some("/data".into()),
+            // Relative values are ignored
+            "XDG_CONFIG_HOME" => Some("config".into()),
+            _ => None,
+        });
+
+        assert_eq!(
+            dirs.install_path(Shell::Zsh),
+            PathBuf::from("/data/zsh/site-functions/_cldev")
+        );
+        assert_eq!(
+            dirs.install_path(Shell::Fish),
+            PathBuf::from("/home/user/.config/fish/completions/cldev.fish")
+        );
+    }
+
+    #[test]
+    fn test_install_completions_per_shell() {
+        let cases = [
+            (
+                Shell::Bash,
+                ".local/share/bash-completion/completions/cldev",
+            ),
+            (Shell::Zsh, ".local/share/zsh/site-functions/_cldev"),
+            (Shell::Fish, ".config/fish/completions/cldev.fish"),
+            (
+                Shell::PowerShell,
+                ".config/powershell/completions/cldev.ps1",
+            ),
+            (Shell::Elvish, ".config/elvish/lib/cldev.elv"),
+            (Shell::Nushell, ".config/nushell/completions/cldev.nu"),
+        ];
+
+        for (shell, relative) in cases {
+            let home = TempDir::new().unwrap();
+
+            let path = install_completions_in(shell, &home_dirs(home.path())).unwrap();
+            assert_eq!(path, home.path().join(relative), "{:?}", shell);
+
+            let content = fs::read_to_string(&path).unwrap();
+            assert!(
+                content.contains("cldev"),
+                "{:?} completions are empty",
+                shell
+            );
+        }
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_install_completions_through_symlinked_config_dir() {
+        let temp_dir = TempDir::new().unwrap();
+        let home = temp_dir.path().join("home");
+        let dotfiles = temp_dir.path().join("dotfiles/config");
+        fs::create_dir_all(&home).unwrap();
+        fs::create_dir_all(&dotfiles).unwrap();
+        std::os::unix::fs::symlink(&dotfiles, home.join(".config")).unwrap();
+
+        let path = install_completions_in(Shell::Fish, &home_dirs(&home)).unwrap();
+        assert_eq!(path, home.join(".config/fish/completions/cldev.fish"));
+        assert!(dotfiles.join("fish/completions/cldev.fish").is_file());
+    }
+
+    /// Writer failing like stdout piped into a process that already exited
+    struct ClosedPipe;
+
+    impl Write for ClosedPipe {
+        fn write(&mut self, _: &[u8]) -> io::Result<usize> {
+            Err(io::Error::from(io::ErrorKind::BrokenPipe))
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_write_error_is_returned() {
+        for shell in ALL_SHELLS {
+            let err = write_completions(shell, &mut ClosedPipe).unwrap_err();
+            assert!(matches!(err, CldevError::Io(_)), "{:?}: {:?}", shell, err);
+        }
+    }
+
+    #[test]
+    fn test_installation_instructions_not_empty() {
+        for shell in ALL_SHELLS.iter() {
             let instructions = get_installation_instructions(*shell);
             assert!(
                 !instructions.is_empty(),
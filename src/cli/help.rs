@@ -94,6 +94,30 @@ pub fn config_edit_target_help() -> &'static str {
     help("opt-config-edit-target")
 }
 
+pub fn config_get_about() -> &'static str {
+    help("cmd-config-get-desc")
+}
+
+pub fn config_get_key_help() -> &'static str {
+    help("opt-config-get-key")
+}
+
+pub fn config_set_about() -> &'static str {
+    help("cmd-config-set-desc")
+}
+
+pub fn config_set_key_help() -> &'static str {
+    help("opt-config-set-key")
+}
+
+pub fn config_set_value_help() -> &'static str {
+    help("opt-config-set-value")
+}
+
+pub fn config_diff_about() -> &'static str {
+    help("cmd-config-diff-desc")
+}
+
 pub fn config_list_about() -> &'static str {
     help("cmd-config-list-desc")
 }
@@ -126,6 +150,22 @@ pub fn config_maintain_retention_days_help() -> &'static str {
     help("opt-config-maintain-retention-days")
 }
 
+pub fn config_restore_about() -> &'static str {
+    help("cmd-config-restore-desc")
+}
+
+pub fn config_restore_from_help() -> &'static str {
+    help("opt-config-restore-from")
+}
+
+pub fn config_migrate_about() -> &'static str {
+    help("cmd-config-migrate-desc")
+}
+
+pub fn config_migrate_backup_help() -> &'static str {
+    help("opt-config-migrate-backup")
+}
+
 pub fn config_update_docs_about() -> &'static str {
     help("cmd-config-update-docs-desc")
 }
@@ -254,6 +294,18 @@ pub fn git_commit_amend_help() -> &'static str {
     help("opt-git-commit-amend")
 }
 
+pub fn git_commit_skip_checks_help() -> &'static str {
+    help("opt-git-commit-skip-checks")
+}
+
+pub fn git_commit_emoji_help() -> &'static str {
+    help("opt-git-commit-emoji")
+}
+
+pub fn git_commit_no_emoji_help() -> &'static str {
+    help("opt-git-commit-no-emoji")
+}
+
 pub fn git_branch_about() -> &'static str {
     help("cmd-git-branch-desc")
 }
@@ -290,6 +342,30 @@ pub fn git_status_detailed_help() -> &'static str {
     help("opt-git-status-detailed")
 }
 
+pub fn git_sync_about() -> &'static str {
+    help("cmd-git-sync-desc")
+}
+
+pub fn git_sync_autostash_help() -> &'static str {
+    help("opt-git-sync-autostash")
+}
+
+pub fn git_install_hook_about() -> &'static str {
+    help("cmd-git-install-hook-desc")
+}
+
+pub fn git_install_hook_kind_help() -> &'static str {
+    help("arg-git-install-hook-kind")
+}
+
+pub fn git_install_hook_command_help() -> &'static str {
+    help("opt-git-install-hook-command")
+}
+
+pub fn git_install_hook_force_help() -> &'static str {
+    help("opt-git-install-hook-force")
+}
+
 // ============================================================================
 // Quality Commands
 // ============================================================================
@@ -306,6 +382,10 @@ pub fn quality_lint_fix_help() -> &'static str {
     help("opt-quality-lint-fix")
 }
 
+pub fn quality_lint_all_help() -> &'static str {
+    help("opt-quality-lint-all")
+}
+
 pub fn quality_lint_paths_help() -> &'static str {
     help("arg-quality-lint-paths")
 }
@@ -338,6 +418,18 @@ pub fn quality_test_watch_help() -> &'static str {
     help("opt-quality-test-watch")
 }
 
+pub fn quality_json_help() -> &'static str {
+    help("opt-quality-json")
+}
+
+pub fn quality_fix_all_about() -> &'static str {
+    help("cmd-quality-fix-all-desc")
+}
+
+pub fn quality_fix_all_skip_tests_help() -> &'static str {
+    help("opt-quality-fix-all-skip-tests")
+}
+
 // ============================================================================
 // Tech Stack Commands
 // ============================================================================
@@ -362,6 +454,18 @@ pub fn tech_start_env_help() -> &'static str {
     help("opt-tech-start-env")
 }
 
+pub fn tech_start_health_url_help() -> &'static str {
+    help("opt-tech-start-health-url")
+}
+
+pub fn tech_list_about() -> &'static str {
+    help("cmd-tech-list-desc")
+}
+
+pub fn tech_list_detailed_help() -> &'static str {
+    help("opt-tech-list-detailed")
+}
+
 // ============================================================================
 // Operations Commands
 // ============================================================================
@@ -398,8 +502,8 @@ pub fn ops_deploy_yes_help() -> &'static str {
     help("opt-ops-deploy-yes")
 }
 
-pub fn ops_deploy_dry_run_help() -> &'static str {
-    help("opt-ops-deploy-dry-run")
+pub fn ops_status_about() -> &'static str {
+    help("cmd-ops-status-desc")
 }
 
 // ============================================================================
@@ -426,6 +530,22 @@ pub fn analysis_analyze_detailed_help() -> &'static str {
     help("opt-analysis-analyze-detailed")
 }
 
+pub fn analysis_analyze_dup_window_help() -> &'static str {
+    help("opt-analysis-analyze-dup-window")
+}
+
+pub fn analysis_analyze_lang_help() -> &'static str {
+    help("opt-analysis-analyze-lang")
+}
+
+pub fn analysis_analyze_no_gitignore_help() -> &'static str {
+    help("opt-analysis-analyze-no-gitignore")
+}
+
+pub fn analysis_analyze_since_tag_help() -> &'static str {
+    help("opt-analysis-analyze-since-tag")
+}
+
 pub fn analysis_explain_about() -> &'static str {
     help("cmd-analysis-explain-desc")
 }
@@ -474,6 +594,10 @@ pub fn analysis_serena_targets_help() -> &'static str {
     help("arg-analysis-serena-targets")
 }
 
+pub fn analysis_serena_plan_help() -> &'static str {
+    help("opt-analysis-serena-plan")
+}
+
 // ============================================================================
 // Learning Record Commands
 // ============================================================================
@@ -570,6 +694,58 @@ pub fn lr_similar_limit_help() -> &'static str {
     help("opt-lr-similar-limit")
 }
 
+pub fn lr_lint_about() -> &'static str {
+    help("cmd-lr-lint-desc")
+}
+
+pub fn lr_link_about() -> &'static str {
+    help("cmd-lr-link-desc")
+}
+
+pub fn lr_link_id_help() -> &'static str {
+    help("arg-lr-link-id")
+}
+
+pub fn lr_link_limit_help() -> &'static str {
+    help("opt-lr-link-limit")
+}
+
+pub fn lr_export_about() -> &'static str {
+    help("cmd-lr-export-desc")
+}
+
+pub fn lr_export_format_help() -> &'static str {
+    help("opt-lr-export-format")
+}
+
+pub fn lr_export_output_help() -> &'static str {
+    help("opt-lr-export-output")
+}
+
+pub fn lr_import_about() -> &'static str {
+    help("cmd-lr-import-desc")
+}
+
+pub fn lr_import_path_help() -> &'static str {
+    help("arg-lr-import-path")
+}
+
+pub fn lr_migrate_about() -> &'static str {
+    help("cmd-lr-migrate-desc")
+}
+
+pub fn lr_hotspots_about() -> &'static str {
+    help("cmd-lr-hotspots-desc")
+}
+
+pub fn lr_hotspots_format_help() -> &'static str {
+    help("opt-lr-hotspots-format")
+}
+
+pub fn lr_hotspots_limit_help() -> &'static str {
+    help("opt-lr-hotspots-limit")
+}
+
 // ============================================================================
 // Todo Commands
 // ============================================================================
@@ -594,6 +770,14 @@ pub fn todo_add_description_help() -> &'static str {
     help("arg-todo-add-description")
 }
 
+pub fn todo_add_priority_help() -> &'static str {
+    help("opt-todo-add-priority")
+}
+
+pub fn todo_add_due_help() -> &'static str {
+    help("opt-todo-add-due")
+}
+
 pub fn todo_list_about() -> &'static str {
     help("cmd-todo-list-desc")
 }
@@ -610,6 +794,10 @@ pub fn todo_sync_about() -> &'static str {
     help("cmd-todo-sync-desc")
 }
 
+pub fn todo_import_about() -> &'static str {
+    help("cmd-todo-import-desc")
+}
+
 pub fn todo_interactive_about() -> &'static str {
     help("cmd-todo-interactive-desc")
 }
@@ -618,6 +806,10 @@ pub fn todo_interactive_about() -> &'static str {
 // Completions
 // ============================================================================
 
+pub fn doctor_about() -> &'static str {
+    help("cmd-doctor-desc")
+}
+
 pub fn completions_about() -> &'static str {
     help("cmd-completions-desc")
 }
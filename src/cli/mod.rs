@@ -2,7 +2,8 @@ pub mod args;
 pub mod completions;
 pub mod help;
 pub mod output;
+pub mod pager;
 pub mod session;
 
-pub use completions::{generate_completions, print_installation_instructions};
+pub use completions::{generate_completions, install_completions};
 pub use session::{handle_session, SessionCommand};
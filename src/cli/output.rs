@@ -1,19 +1,168 @@
 #![allow(dead_code)]
 
-use crate::core::i18n::I18n;
+use crate::core::i18n::{I18n, MessageSource};
 use colored::{ColoredString, Colorize};
+use indicatif::{ProgressBar, ProgressStyle};
+use std::cell::RefCell;
 use std::collections::HashMap;
 use std::io::{self, IsTerminal, Write};
 
 /// Output level for controlling verbosity
+///
+/// Ordered from least to most output: error, warn, info, debug, trace.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub enum OutputLevel {
     /// No output except errors
     Quiet = 0,
+    /// Warnings and errors only
+    Warn = 1,
     /// Normal output
-    Normal = 1,
-    /// Verbose output with details
-    Verbose = 2,
+    Normal = 2,
+    /// Verbose output with details (debug)
+    Verbose = 3,
+    /// Everything, including trace messages
+    Trace = 4,
+}
+
+impl OutputLevel {
+    /// Map a `-v` occurrence count to a level (-v debug, -vv more debug, -vvv trace)
+    ///
+    /// Without any `-v` the level stays at normal (info) output. `-v` and
+    /// `-vv` both select debug output; `-vv` additionally shows the deeper
+    /// messages of [`OutputHandler::debug_at`].
+    pub fn from_verbosity(count: u8) -> Self {
+        match count {
+            0 => Self::Normal,
+            1 | 2 => Self::Verbose,
+            _ => Self::Trace,
+        }
+    }
+
+    /// Smallest `-v` count that selects this level (0 below debug)
+    fn verbosity(self) -> u8 {
+        match self {
+            Self::Verbose => 1,
+            Self::Trace => 3,
+            _ => 0,
+        }
+    }
+}
+
+/// Format of messages printed by [`OutputHandler`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, clap::ValueEnum)]
+pub enum OutputFormat {
+    /// Human-readable, optionally colored text
+    #[default]
+    Text,
+    /// One JSON object per line: `{"level", "message", "ts"}`
+    Json,
+}
+
+/// Progress bar for long-running work
+///
+/// Hidden handles accept every call and draw nothing, so callers never need
+/// to check whether progress is being shown.
+#[derive(Debug, Clone, Default)]
+pub struct ProgressHandle(Option<ProgressBar>);
+
+impl ProgressHandle {
+    /// A handle that draws nothing
+    pub fn hidden() -> Self {
+        Self(None)
+    }
+
+    /// Whether the bar is drawn
+    pub fn is_visible(&self) -> bool {
+        self.0.is_some()
+    }
+
+    /// Advance the bar by `delta` steps
+    pub fn inc(&self, delta: u64) {
+        if let Some(bar) = &self.0 {
+            bar.inc(delta);
+        }
+    }
+
+    /// Set the current position
+    pub fn set_position(&self, position: u64) {
+        if let Some(bar) = &self.0 {
+            bar.set_position(position);
+        }
+    }
+
+    /// Set the total number of steps
+    pub fn set_length(&self, len: u64) {
+        if let Some(bar) = &self.0 {
+            bar.set_length(len);
+        }
+    }
+
+    /// Set the message shown next to the bar
+    pub fn set_message(&self, msg: &str) {
+        if let Some(bar) = &self.0 {
+            bar.set_message(msg.to_string());
+        }
+    }
+
+    /// Remove the bar from the terminal
+    pub fn finish(&self) {
+        if let Some(bar) = &self.0 {
+            bar.finish_and_clear();
+        }
+    }
+}
+
+/// Render a command line, single-quoting arguments the shell would split or expand
+fn format_command<S: AsRef<str>>(program: &str, args: &[S]) -> String {
+    let quote = |arg: &str| {
+        let plain = !arg.is_empty()
+            && arg
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || "-_./:=@%+,".contains(c));
+        if plain {
+            arg.to_string()
+        } else {
+            format!("'{}'", arg.replace('\'', "'\\''"))
+        }
+    };
+
+    std::iter::once(quote(program))
+        .chain(args.iter().map(|arg| quote(arg.as_ref())))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Whether a progress bar should be drawn
+///
+/// Bars are only shown for text output at normal verbosity or above, on a
+/// terminal, and when `[ui].progress_bar` is enabled.
+fn progress_visible(enabled: bool, level: OutputLevel, format: OutputFormat, is_tty: bool) -> bool {
+    enabled && level >= OutputLevel::Normal && format == OutputFormat::Text && is_tty
+}
+
+/// Decide whether output to a stream should be colored
+///
+/// Reads the `NO_COLOR` and `CLICOLOR_FORCE` environment variables; see
+/// [`color_choice`] for the precedence.
+fn use_color(no_color_flag: bool, is_tty: bool) -> bool {
+    color_choice(
+        no_color_flag,
+        std::env::var_os("NO_COLOR").is_some(),
+        std::env::var("CLICOLOR_FORCE").is_ok_and(|v| v == "1"),
+        is_tty,
+    )
+}
+
+/// Resolve color output from the flag, environment, and terminal
+///
+/// `--no-color` wins, then `NO_COLOR` (any value) disables color, then
+/// `CLICOLOR_FORCE=1` forces it on; otherwise color is used only on a terminal.
+fn color_choice(no_color_flag: bool, no_color_env: bool, force_env: bool, is_tty: bool) -> bool {
+    if no_color_flag || no_color_env {
+        false
+    } else {
+        force_env || is_tty
+    }
 }
 
 /// Handler for structured output with color support and i18n
@@ -21,24 +170,45 @@ pub struct OutputHandler {
     level: OutputLevel,
     use_color: bool,
     i18n: I18n,
+    /// Route human-readable output to stderr (stdout carries machine-readable output)
+    stderr_only: bool,
+    /// Progress lines held back until the command finishes (`--quiet-on-success`)
+    buffer: Option<RefCell<Vec<String>>>,
+    /// Text or JSON-lines output (`--output`)
+    format: OutputFormat,
+    /// Title of the most recent section, attached to JSON list items
+    section: RefCell<Option<String>>,
+    /// Destination replacing stdout, if set
+    stdout: Option<RefCell<Box<dyn Write>>>,
+    /// Destination replacing stderr, if set
+    stderr: Option<RefCell<Box<dyn Write>>>,
+    /// Whether progress bars may be shown (`[ui].progress_bar`)
+    progress_bar: bool,
+    /// Print external commands instead of running them (`--dry-run`)
+    dry_run: bool,
+    /// Number of `-v` flags, selecting how deep [`OutputHandler::debug_at`] traces go
+    verbosity: u8,
 }
 
 impl OutputHandler {
     /// Create a new output handler
     pub fn new(verbose: bool, quiet: bool, no_color: bool) -> Self {
-        let level = if quiet {
-            OutputLevel::Quiet
-        } else if verbose {
-            OutputLevel::Verbose
-        } else {
-            OutputLevel::Normal
-        };
+        Self::from_parts(
+            Self::level_from_flags(verbose, quiet),
+            no_color,
+            I18n::new(),
+        )
+    }
 
-        Self {
-            level,
-            use_color: !no_color && io::stdout().is_terminal(),
-            i18n: I18n::new(),
-        }
+    /// Create a new output handler with an explicit output level and language
+    pub fn with_level(
+        level: OutputLevel,
+        no_color: bool,
+        language: crate::core::i18n::Language,
+    ) -> Self {
+        let handler = Self::from_parts(level, no_color, I18n::with_language(language));
+        handler.warn_incomplete_catalog();
+        handler
     }
 
     /// Create a new output handler with a specific language
@@ -48,18 +218,35 @@ impl OutputHandler {
         no_color: bool,
         language: crate::core::i18n::Language,
     ) -> Self {
-        let level = if quiet {
+        Self::with_level(Self::level_from_flags(verbose, quiet), no_color, language)
+    }
+
+    /// Output level selected by the `verbose` and `quiet` flags; quiet wins
+    fn level_from_flags(verbose: bool, quiet: bool) -> OutputLevel {
+        if quiet {
             OutputLevel::Quiet
         } else if verbose {
             OutputLevel::Verbose
         } else {
             OutputLevel::Normal
-        };
+        }
+    }
 
+    /// Build a handler writing text to the standard streams
+    fn from_parts(level: OutputLevel, no_color: bool, i18n: I18n) -> Self {
         Self {
             level,
-            use_color: !no_color && io::stdout().is_terminal(),
-            i18n: I18n::with_language(language),
+            use_color: use_color(no_color, io::stdout().is_terminal()),
+            i18n,
+            stderr_only: false,
+            buffer: None,
+            format: OutputFormat::Text,
+            section: RefCell::new(None),
+            stdout: None,
+            stderr: None,
+            progress_bar: true,
+            dry_run: false,
+            verbosity: level.verbosity(),
         }
     }
 
@@ -70,54 +257,275 @@ impl OutputHandler {
 
     /// Get a localized message
     pub fn t(&self, key: &str) -> String {
+        self.debug_message_fallback(key);
         self.i18n.get(key)
     }
 
     /// Get a localized message with variable substitution
     pub fn t_with_vars(&self, key: &str, vars: &HashMap<&str, &str>) -> String {
+        self.debug_message_fallback(key);
         self.i18n.get_with_vars(key, vars)
     }
 
     /// Get a localized message with a single variable
     pub fn t_format(&self, key: &str, var_name: &str, var_value: &str) -> String {
+        self.debug_message_fallback(key);
         self.i18n.format(key, var_name, var_value)
     }
 
+    /// Get a localized message whose wording depends on a count
+    pub fn t_plural(&self, key: &str, count: usize, var_name: &str, var_value: &str) -> String {
+        self.debug_message_fallback(&self.i18n.plural_key(key, count));
+        self.i18n.t_plural(key, count, var_name, var_value)
+    }
+
+    /// Report a message that is not translated in the current language
+    fn debug_message_fallback(&self, key: &str) {
+        match self.i18n.source(key) {
+            MessageSource::Language => {}
+            MessageSource::English => self.debug(&format!(
+                "Message '{}' is missing for {}, using English",
+                key,
+                self.i18n.language()
+            )),
+            MessageSource::Key => {
+                self.debug(&format!("Message '{}' is missing in every language", key))
+            }
+        }
+    }
+
     /// Set the language for i18n
     pub fn set_language(&mut self, language: crate::core::i18n::Language) {
         self.i18n.set_language(language);
+        self.warn_incomplete_catalog();
+    }
+
+    /// Switch to the language of an external catalog file
+    pub fn load_catalog_file(&mut self, path: &std::path::Path) -> crate::core::Result<()> {
+        self.i18n.load_catalog_file(path)?;
+        self.warn_incomplete_catalog();
+        Ok(())
+    }
+
+    /// Warn when an external catalog could not be loaded or lacks translations
+    fn warn_incomplete_catalog(&self) {
+        let language = self.i18n.language();
+        if !matches!(language, crate::core::i18n::Language::External(_)) {
+            return;
+        }
+
+        if let Some(error) = self.i18n.catalog_error() {
+            let mut vars = HashMap::new();
+            vars.insert("language", language.code());
+            vars.insert("error", error);
+            self.warning(&self.t_with_vars("i18n-catalog-load-failed", &vars));
+            return;
+        }
+
+        let missing = self.i18n.missing_keys();
+        if !missing.is_empty() {
+            let count = missing.len().to_string();
+            let mut vars = HashMap::new();
+            vars.insert("language", language.code());
+            vars.insert("count", count.as_str());
+            self.warning(&self.t_with_vars("i18n-catalog-incomplete", &vars));
+        }
+    }
+
+    /// Route all human-readable output to stderr
+    ///
+    /// Used by commands with `--json` so that stdout only contains the JSON document.
+    pub fn set_stderr_only(&mut self, enabled: bool) {
+        self.stderr_only = enabled;
+        if enabled {
+            self.use_color = self.use_color && use_color(false, io::stderr().is_terminal());
+        }
+    }
+
+    /// Switch between text and JSON-lines output
+    pub fn set_format(&mut self, format: OutputFormat) {
+        self.format = format;
+    }
+
+    /// Get the output format
+    pub fn format(&self) -> OutputFormat {
+        self.format
+    }
+
+    /// Raise the debug depth to `count` `-v` flags
+    ///
+    /// The depth never drops below what the output level already implies,
+    /// so `--log-level debug` keeps showing depth-1 messages without `-v`.
+    pub fn set_verbosity(&mut self, count: u8) {
+        if self.level != OutputLevel::Quiet {
+            self.verbosity = self.verbosity.max(count);
+        }
+    }
+
+    /// Number of `-v` flags in effect
+    pub fn verbosity(&self) -> u8 {
+        self.verbosity
+    }
+
+    /// Print external commands instead of running them (`--dry-run`)
+    pub fn set_dry_run(&mut self, enabled: bool) {
+        self.dry_run = enabled;
+    }
+
+    /// Check if commands should only print what they would run
+    pub fn is_dry_run(&self) -> bool {
+        self.dry_run
+    }
+
+    /// Print an external command skipped by `--dry-run`
+    ///
+    /// Shown regardless of the output level, since it is the command's result.
+    pub fn dry_run_command<S: AsRef<str>>(&self, program: &str, args: &[S]) {
+        let command = format_command(program, args);
+        match self.format {
+            OutputFormat::Text => {
+                let line = self.t_format("dry-run-command", "command", &command);
+                self.println(&self.apply_color(&line, |s| s.yellow()));
+            }
+            OutputFormat::Json => self.println(&self.json_line("dry-run", &command)),
+        }
+    }
+
+    /// Enable or disable progress bars (`[ui].progress_bar`)
+    pub fn set_progress_bar(&mut self, enabled: bool) {
+        self.progress_bar = enabled;
+    }
+
+    /// Start a progress bar with `len` steps
+    ///
+    /// Returns a hidden handle when progress bars are disabled, output is
+    /// quiet, buffered or JSON, or stdout is not a terminal.
+    pub fn progress(&self, len: u64) -> ProgressHandle {
+        let visible = self.buffer.is_none()
+            && progress_visible(
+                self.progress_bar,
+                self.level,
+                self.format,
+                io::stdout().is_terminal(),
+            );
+        if !visible {
+            return ProgressHandle::hidden();
+        }
+
+        let bar = ProgressBar::new(len);
+        bar.set_style(
+            ProgressStyle::default_bar()
+                .template(
+                    "{spinner:.green} [{elapsed_precise}] [{bar:40.cyan/blue}] {pos}/{len} {msg}",
+                )
+                .expect("Invalid progress bar template")
+                .progress_chars("#>-"),
+        );
+        ProgressHandle(Some(bar))
+    }
+
+    /// Send output to the given writers instead of stdout and stderr
+    pub fn set_writers(&mut self, stdout: Box<dyn Write>, stderr: Box<dyn Write>) {
+        self.stdout = Some(RefCell::new(stdout));
+        self.stderr = Some(RefCell::new(stderr));
+    }
+
+    /// Buffer info, debug, and step output until the command finishes
+    ///
+    /// The buffer is flushed when an error is printed or when the command fails,
+    /// and discarded when it succeeds.
+    pub fn set_quiet_on_success(&mut self, enabled: bool) {
+        self.buffer = enabled.then(|| RefCell::new(Vec::new()));
+    }
+
+    /// Flush buffered output if the command failed, otherwise discard it
+    pub fn finish_quiet_on_success(&self, success: bool) {
+        for line in self.take_failure_output(success) {
+            self.println(&line);
+        }
+    }
+
+    /// Drain the buffer, returning the lines to show for the given outcome
+    fn take_failure_output(&self, success: bool) -> Vec<String> {
+        let lines = self
+            .buffer
+            .as_ref()
+            .map(|buffer| buffer.take())
+            .unwrap_or_default();
+
+        if success {
+            Vec::new()
+        } else {
+            lines
+        }
     }
 
     /// Print a success message
     pub fn success(&self, msg: &str) {
         if self.level >= OutputLevel::Normal {
-            self.println(&self.colorize("✓", msg, |s| s.green().bold()));
+            self.println(&self.message("success", "✓", msg, |s| s.green().bold()));
         }
     }
 
-    /// Print an error message
+    /// Print an error message to stderr
     pub fn error(&self, msg: &str) {
-        self.eprintln(&self.colorize("✗", msg, |s| s.red().bold()));
+        self.finish_quiet_on_success(false);
+        self.eprintln(&self.message("error", "✗", msg, |s| s.red().bold()));
     }
 
-    /// Print a warning message
+    /// Print a warning message to stderr
     pub fn warning(&self, msg: &str) {
-        if self.level >= OutputLevel::Normal {
-            self.println(&self.colorize("⚠", msg, |s| s.yellow().bold()));
+        if self.level >= OutputLevel::Warn {
+            self.eprintln(&self.message("warning", "⚠", msg, |s| s.yellow().bold()));
         }
     }
 
+    /// Warn that a transient failure is about to be retried
+    ///
+    /// Meant as the `on_retry` callback of [`crate::core::retry::with_backoff`].
+    pub fn warn_retry(
+        &self,
+        attempt: u32,
+        attempts: u32,
+        error: &crate::core::CldevError,
+        delay: std::time::Duration,
+    ) {
+        let (attempt, attempts) = (attempt.to_string(), attempts.to_string());
+        let (error, delay) = (error.to_string(), format!("{:?}", delay));
+        let mut vars = HashMap::new();
+        vars.insert("attempt", attempt.as_str());
+        vars.insert("attempts", attempts.as_str());
+        vars.insert("error", error.as_str());
+        vars.insert("delay", delay.as_str());
+        self.warning(&self.t_with_vars("retry-attempt-failed", &vars));
+    }
+
     /// Print an info message
     pub fn info(&self, msg: &str) {
         if self.level >= OutputLevel::Normal {
-            self.println(&self.colorize("ℹ", msg, |s| s.blue()));
+            self.println_buffered(&self.message("info", "ℹ", msg, |s| s.blue()));
         }
     }
 
     /// Print a verbose/debug message
     pub fn debug(&self, msg: &str) {
-        if self.level >= OutputLevel::Verbose {
-            self.println(&self.colorize("→", msg, |s| s.dimmed()));
+        self.debug_at(1, msg);
+    }
+
+    /// Print a debug message shown from `depth` `-v` flags on
+    ///
+    /// Depth 1 shows with `-v` (like [`debug`](Self::debug)), 2 with `-vv`,
+    /// and 3 with `-vvv`, so commands can emit deeper traces on request.
+    pub fn debug_at(&self, depth: u8, msg: &str) {
+        if self.verbosity >= depth.max(1) {
+            self.println_buffered(&self.message("debug", "→", msg, |s| s.dimmed()));
+        }
+    }
+
+    /// Print a trace message (most detailed level)
+    pub fn trace(&self, msg: &str) {
+        if self.level >= OutputLevel::Trace {
+            self.println_buffered(&self.message("trace", "…", msg, |s| s.dimmed()));
         }
     }
 
@@ -125,13 +533,14 @@ impl OutputHandler {
     pub fn step(&self, step: usize, total: usize, msg: &str) {
         if self.level >= OutputLevel::Normal {
             let prefix = format!("[{}/{}]", step, total);
-            self.println(&self.colorize(&prefix, msg, |s| s.cyan()));
+            self.println_buffered(&self.message("info", &prefix, msg, |s| s.cyan()));
         }
     }
 
     /// Print a header/section message
     pub fn header(&self, msg: &str) {
-        if self.level >= OutputLevel::Normal {
+        self.section.replace(Some(msg.to_string()));
+        if self.level >= OutputLevel::Normal && self.format == OutputFormat::Text {
             let separator = "=".repeat(msg.len() + 4);
             self.println(&self.apply_color(&separator, |s| s.bright_blue()));
             self.println(&self.apply_color(&format!("  {}  ", msg), |s| s.bright_blue().bold()));
@@ -141,7 +550,8 @@ impl OutputHandler {
 
     /// Print a subheader message
     pub fn subheader(&self, msg: &str) {
-        if self.level >= OutputLevel::Normal {
+        self.section.replace(Some(msg.to_string()));
+        if self.level >= OutputLevel::Normal && self.format == OutputFormat::Text {
             self.println(&self.apply_color(&format!("--- {} ---", msg), |s| s.cyan()));
         }
     }
@@ -159,51 +569,86 @@ impl OutputHandler {
     }
 
     /// Print to stdout (respects quiet mode)
+    ///
+    /// In JSON mode the text becomes a record with level `raw`.
     pub fn print(&self, msg: &str) {
         if self.level >= OutputLevel::Normal {
-            print!("{}", msg);
+            if self.format == OutputFormat::Json {
+                self.println(&self.json_line("raw", msg));
+            } else if self.stderr_only {
+                self.write_stderr(msg);
+            } else {
+                self.write_stdout(msg);
+            }
         }
     }
 
     /// Print to stdout with newline (respects quiet mode)
+    ///
+    /// In JSON mode the text becomes a record with level `raw`.
     pub fn println_raw(&self, msg: &str) {
         if self.level >= OutputLevel::Normal {
-            println!("{}", msg);
+            match self.format {
+                OutputFormat::Text => self.println(msg),
+                OutputFormat::Json => self.println(&self.json_line("raw", msg)),
+            }
+        }
+    }
+
+    /// Print a report document to stdout (respects quiet mode)
+    ///
+    /// Unlike `print`, this ignores `set_stderr_only`, which exists to keep
+    /// status messages out of the way of exactly this kind of document.
+    /// In JSON mode the text becomes a record with level `raw`.
+    pub fn report(&self, msg: &str) {
+        if self.level >= OutputLevel::Normal {
+            if self.format == OutputFormat::Json {
+                self.write_stdout(&format!("{}\n", self.json_line("raw", msg)));
+            } else {
+                self.write_stdout(msg);
+            }
         }
     }
 
     /// Print to stderr with newline (respects quiet mode for non-errors)
     pub fn eprintln_raw(&self, msg: &str) {
         if self.level >= OutputLevel::Normal {
-            eprintln!("{}", msg);
+            self.eprintln(msg);
         }
     }
 
-    /// Print empty line (respects quiet mode)
+    /// Print empty line (respects quiet mode; skipped in JSON mode)
     pub fn print_newline(&self) {
-        if self.level >= OutputLevel::Normal {
-            println!();
+        if self.level >= OutputLevel::Normal && self.format == OutputFormat::Text {
+            self.println("");
         }
     }
 
     /// Print a list item
     pub fn list_item(&self, msg: &str) {
         if self.level >= OutputLevel::Normal {
-            self.println(&self.colorize("•", msg, |s| s.normal()));
+            self.println(&self.message("item", "•", msg, |s| s.normal()));
         }
     }
 
     /// Print a key-value pair
     pub fn key_value(&self, key: &str, value: &str) {
-        if self.level >= OutputLevel::Normal {
+        if self.level >= OutputLevel::Normal && self.format == OutputFormat::Json {
+            self.println(&self.json_line("info", &format!("{}: {}", key, value)));
+        } else if self.level >= OutputLevel::Normal {
             let key_colored = self.apply_color(key, |s| s.cyan().bold());
             self.println(&format!("{}: {}", key_colored, value));
         }
     }
 
+    /// Emphasize a matched term inside other text (bold yellow when colored)
+    pub fn highlight(&self, text: &str) -> String {
+        self.apply_color(text, |s| s.yellow().bold())
+    }
+
     /// Start a spinner/progress indicator (returns a message to show)
     pub fn start_progress(&self, msg: &str) -> String {
-        if self.level >= OutputLevel::Normal {
+        if self.level >= OutputLevel::Normal && self.format == OutputFormat::Text {
             let formatted = self.colorize("⏳", msg, |s| s.yellow());
             eprint!("\r{}", formatted);
             io::stderr().flush().ok();
@@ -215,7 +660,13 @@ impl OutputHandler {
 
     /// Finish a progress indicator
     pub fn finish_progress(&self, msg: &str, success: bool) {
-        if self.level >= OutputLevel::Normal {
+        if self.format == OutputFormat::Json {
+            if success {
+                self.success(msg);
+            } else {
+                self.error(msg);
+            }
+        } else if self.level >= OutputLevel::Normal {
             let formatted = if success {
                 self.colorize("✓", msg, |s| s.green().bold())
             } else {
@@ -225,6 +676,35 @@ impl OutputHandler {
         }
     }
 
+    /// Format a message for the current output format
+    ///
+    /// Text output gets a colored prefix; JSON output becomes one JSON object.
+    /// List items carry the title of the section they appear under.
+    fn message<F>(&self, level: &str, prefix: &str, msg: &str, color_fn: F) -> String
+    where
+        F: Fn(ColoredString) -> ColoredString,
+    {
+        match self.format {
+            OutputFormat::Text => self.colorize(prefix, msg, color_fn),
+            OutputFormat::Json => self.json_line(level, msg),
+        }
+    }
+
+    /// Serialize a message as a JSON-lines record
+    fn json_line(&self, level: &str, msg: &str) -> String {
+        let mut record = serde_json::json!({
+            "level": level,
+            "message": msg,
+            "ts": chrono::Local::now().to_rfc3339(),
+        });
+        if level == "item" {
+            if let Some(section) = self.section.borrow().as_ref() {
+                record["section"] = serde_json::Value::from(section.as_str());
+            }
+        }
+        record.to_string()
+    }
+
     /// Create a formatted message with prefix and color
     fn colorize<F>(&self, prefix: &str, msg: &str, color_fn: F) -> String
     where
@@ -249,14 +729,46 @@ impl OutputHandler {
         }
     }
 
-    /// Print to stdout
+    /// Print to stdout (or stderr when routed there)
     fn println(&self, msg: &str) {
-        println!("{}", msg);
+        if self.stderr_only {
+            self.eprintln(msg);
+        } else {
+            self.write_stdout(&format!("{}\n", msg));
+        }
+    }
+
+    /// Print a progress line, holding it back when buffering is enabled
+    fn println_buffered(&self, msg: &str) {
+        match &self.buffer {
+            Some(buffer) => buffer.borrow_mut().push(msg.to_string()),
+            None => self.println(msg),
+        }
     }
 
     /// Print to stderr
     fn eprintln(&self, msg: &str) {
-        eprintln!("{}", msg);
+        self.write_stderr(&format!("{}\n", msg));
+    }
+
+    /// Write text to stdout or its replacement writer
+    fn write_stdout(&self, text: &str) {
+        match &self.stdout {
+            Some(writer) => {
+                let _ = writer.borrow_mut().write_all(text.as_bytes());
+            }
+            None => print!("{}", text),
+        }
+    }
+
+    /// Write text to stderr or its replacement writer
+    fn write_stderr(&self, text: &str) {
+        match &self.stderr {
+            Some(writer) => {
+                let _ = writer.borrow_mut().write_all(text.as_bytes());
+            }
+            None => eprint!("{}", text),
+        }
     }
 
     /// Check if verbose mode is enabled
@@ -264,6 +776,11 @@ impl OutputHandler {
         self.level >= OutputLevel::Verbose
     }
 
+    /// Check if trace output is enabled
+    pub fn is_trace(&self) -> bool {
+        self.level >= OutputLevel::Trace
+    }
+
     /// Check if quiet mode is enabled
     pub fn is_quiet(&self) -> bool {
         self.level == OutputLevel::Quiet
@@ -273,6 +790,11 @@ impl OutputHandler {
     pub fn level(&self) -> OutputLevel {
         self.level
     }
+
+    /// Check if human-readable output is routed to stderr
+    pub fn is_stderr_only(&self) -> bool {
+        self.stderr_only
+    }
 }
 
 impl Default for OutputHandler {
@@ -303,14 +825,104 @@ macro_rules! with_progress {
     }};
 }
 
+/// Output capture helpers for tests
+#[cfg(test)]
+pub(crate) mod test_support {
+    use super::OutputHandler;
+    use std::cell::RefCell;
+    use std::io::{self, Write};
+    use std::rc::Rc;
+
+    /// Writer that shares its buffer with the test
+    #[derive(Clone, Default)]
+    pub struct SharedBuffer(Rc<RefCell<Vec<u8>>>);
+
+    impl Write for SharedBuffer {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.borrow_mut().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl SharedBuffer {
+        pub fn text(&self) -> String {
+            String::from_utf8(self.0.borrow().clone()).unwrap()
+        }
+
+        pub fn records(&self) -> Vec<serde_json::Value> {
+            String::from_utf8(self.0.borrow().clone())
+                .unwrap()
+                .lines()
+                .map(|line| serde_json::from_str(line).unwrap())
+                .collect()
+        }
+    }
+
+    /// Redirect a handler's stdout and stderr into buffers
+    pub fn capture(handler: &mut OutputHandler) -> (SharedBuffer, SharedBuffer) {
+        let (stdout, stderr) = (SharedBuffer::default(), SharedBuffer::default());
+        handler.set_writers(Box::new(stdout.clone()), Box::new(stderr.clone()));
+        (stdout, stderr)
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use super::test_support::capture;
     use super::*;
 
     #[test]
     fn test_output_levels() {
+        assert!(OutputLevel::Trace > OutputLevel::Verbose);
         assert!(OutputLevel::Verbose > OutputLevel::Normal);
-        assert!(OutputLevel::Normal > OutputLevel::Quiet);
+        assert!(OutputLevel::Normal > OutputLevel::Warn);
+        assert!(OutputLevel::Warn > OutputLevel::Quiet);
+    }
+
+    #[test]
+    fn test_from_verbosity() {
+        assert_eq!(OutputLevel::from_verbosity(0), OutputLevel::Normal);
+        assert_eq!(OutputLevel::from_verbosity(1), OutputLevel::Verbose);
+        assert_eq!(OutputLevel::from_verbosity(2), OutputLevel::Verbose);
+        assert_eq!(OutputLevel::from_verbosity(3), OutputLevel::Trace);
+        assert_eq!(OutputLevel::from_verbosity(7), OutputLevel::Trace);
+    }
+
+    #[test]
+    fn test_debug_depth_follows_verbosity() {
+        for (count, shown) in [(1, false), (2, true)] {
+            let mut handler = OutputHandler::with_level(
+                OutputLevel::from_verbosity(count),
+                true,
+                Default::default(),
+            );
+            handler.set_verbosity(count);
+            let (stdout, _) = capture(&mut handler);
+
+            handler.debug_at(1, "depth one");
+            handler.debug_at(2, "depth two");
+
+            assert!(stdout.text().contains("depth one"), "-v x{}", count);
+            assert_eq!(stdout.text().contains("depth two"), shown, "-v x{}", count);
+        }
+    }
+
+    #[test]
+    fn test_log_level_implies_debug_depth() {
+        let mut handler = OutputHandler::with_level(OutputLevel::Verbose, true, Default::default());
+        handler.set_verbosity(0);
+        let (stdout, _) = capture(&mut handler);
+
+        handler.debug("plain debug");
+        handler.debug_at(2, "depth two");
+
+        assert_eq!(handler.verbosity(), 1);
+        assert!(stdout.text().contains("plain debug"));
+        assert!(!stdout.text().contains("depth two"));
     }
 
     #[test]
@@ -329,6 +941,32 @@ mod tests {
         assert!(quiet_handler.is_quiet());
     }
 
+    #[test]
+    fn test_quiet_on_success_buffers_progress() {
+        let mut handler = OutputHandler::new(true, false, true);
+        handler.set_quiet_on_success(true);
+
+        handler.info("detecting project");
+        handler.debug("running cargo");
+        handler.step(1, 2, "linting");
+        assert!(handler.take_failure_output(true).is_empty());
+
+        handler.info("detecting project");
+        handler.debug("running cargo");
+        assert_eq!(
+            handler.take_failure_output(false),
+            vec!["ℹ detecting project", "→ running cargo"]
+        );
+        assert!(handler.take_failure_output(false).is_empty());
+    }
+
+    #[test]
+    fn test_quiet_on_success_disabled_does_not_buffer() {
+        let handler = OutputHandler::new(false, false, true);
+        handler.info("printed immediately");
+        assert!(handler.take_failure_output(false).is_empty());
+    }
+
     #[test]
     fn test_color_application() {
         let handler_color = OutputHandler::new(false, false, false);
@@ -341,4 +979,272 @@ mod tests {
         assert!(with_color.contains("test"));
         assert!(without_color.contains("test"));
     }
+
+    #[test]
+    fn test_missing_translation_is_reported_at_debug_level() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("fr.toml");
+        std::fs::write(&path, "command-success = \"Terminé\"\n").unwrap();
+
+        let mut handler = OutputHandler::new(true, false, true);
+        handler.load_catalog_file(&path).unwrap();
+        let (stdout, _) = capture(&mut handler);
+
+        handler.t("command-success");
+        assert!(stdout.text().is_empty());
+
+        handler.t("command-failed");
+        handler.t("no-such-key");
+        let text = stdout.text();
+        assert!(text.contains("Message 'command-failed' is missing for fr, using English"));
+        assert!(text.contains("Message 'no-such-key' is missing in every language"));
+    }
+
+    #[test]
+    fn test_unloadable_catalog_is_warned() {
+        let mut handler = OutputHandler::new(false, false, true);
+        let (_, stderr) = capture(&mut handler);
+
+        handler.set_language(crate::core::i18n::Language::External("zz-none".to_string()));
+        assert!(stderr.text().contains("no catalog installed for 'zz-none'"));
+    }
+
+    #[test]
+    fn test_external_catalog_translates_t() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("fr.toml");
+        std::fs::write(
+            &path,
+            "command-success = \"Commande terminée avec succès\"\n",
+        )
+        .unwrap();
+
+        let mut handler = OutputHandler::new(false, true, true);
+        handler.load_catalog_file(&path).unwrap();
+
+        assert_eq!(
+            handler.i18n().language(),
+            &crate::core::i18n::Language::External("fr".to_string())
+        );
+        assert_eq!(
+            handler.t("command-success"),
+            "Commande terminée avec succès"
+        );
+        // Untranslated keys fall back to English
+        assert_eq!(
+            handler.t("command-failed"),
+            crate::core::i18n::I18n::with_language(crate::core::i18n::Language::English)
+                .get("command-failed")
+        );
+    }
+
+    #[test]
+    fn test_color_choice() {
+        // (flag, NO_COLOR, CLICOLOR_FORCE=1, tty) -> color
+        let cases = [
+            ((false, false, false, true), true),
+            ((false, false, false, false), false),
+            ((true, false, false, true), false),
+            ((true, false, true, true), false),
+            ((false, true, false, true), false),
+            ((false, true, true, true), false),
+            ((false, false, true, false), true),
+            ((false, false, true, true), true),
+        ];
+
+        for ((flag, no_color, force, tty), expected) in cases {
+            assert_eq!(
+                color_choice(flag, no_color, force, tty),
+                expected,
+                "flag={} NO_COLOR={} CLICOLOR_FORCE={} tty={}",
+                flag,
+                no_color,
+                force,
+                tty
+            );
+        }
+    }
+
+    #[test]
+    fn test_piped_output_is_uncolored() {
+        let handler = OutputHandler::new(false, false, false);
+        let piped = !io::stdout().is_terminal();
+        if piped && std::env::var("CLICOLOR_FORCE").as_deref() != Ok("1") {
+            assert!(!handler.use_color);
+        }
+        assert!(!OutputHandler::new(false, false, true).use_color);
+    }
+
+    fn levels(records: &[serde_json::Value]) -> Vec<&str> {
+        records
+            .iter()
+            .map(|r| r["level"].as_str().unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_text_streams() {
+        let mut handler = OutputHandler::new(true, false, true);
+        let (stdout, stderr) = capture(&mut handler);
+
+        handler.info("starting");
+        handler.warning("careful");
+        handler.error("broken");
+        handler.success("done");
+        handler.list_item("entry");
+
+        assert_eq!(stdout.text(), "ℹ starting\n✓ done\n• entry\n");
+        assert_eq!(stderr.text(), "⚠ careful\n✗ broken\n");
+    }
+
+    #[test]
+    fn test_stderr_only_routes_everything_to_stderr() {
+        let mut handler = OutputHandler::new(false, false, true);
+        let (stdout, stderr) = capture(&mut handler);
+        handler.set_stderr_only(true);
+
+        handler.info("starting");
+        handler.error("broken");
+
+        assert_eq!(stdout.text(), "");
+        assert_eq!(stderr.text(), "ℹ starting\n✗ broken\n");
+    }
+
+    #[test]
+    fn test_json_output_lines() {
+        let mut handler = OutputHandler::new(true, false, true);
+        handler.set_format(OutputFormat::Json);
+        let (stdout, stderr) = capture(&mut handler);
+
+        handler.info("starting");
+        handler.warning("careful");
+        handler.error("broken");
+        handler.success("done");
+        handler.debug("details");
+
+        let out = stdout.records();
+        let err = stderr.records();
+        assert_eq!(levels(&out), ["info", "success", "debug"]);
+        assert_eq!(levels(&err), ["warning", "error"]);
+        assert_eq!(err[1]["message"], "broken");
+        for record in out.iter().chain(&err) {
+            assert!(chrono::DateTime::parse_from_rfc3339(record["ts"].as_str().unwrap()).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_json_wraps_raw_text() {
+        let mut handler = OutputHandler::new(false, false, true);
+        handler.set_format(OutputFormat::Json);
+        let (stdout, _) = capture(&mut handler);
+
+        handler.print("partial");
+        handler.println_raw("linter output\nsecond line");
+        handler.print_newline();
+
+        let records = stdout.records();
+        assert_eq!(levels(&records), ["raw", "raw"]);
+        assert_eq!(records[0]["message"], "partial");
+        assert_eq!(records[1]["message"], "linter output\nsecond line");
+    }
+
+    #[test]
+    fn test_json_list_items_nest_under_section() {
+        let mut handler = OutputHandler::new(false, false, true);
+        handler.set_format(OutputFormat::Json);
+        let (buffer, _) = capture(&mut handler);
+
+        handler.list_item("orphan");
+        handler.section("Staged files");
+        handler.list_item("src/main.rs");
+        handler.list_item("src/lib.rs");
+
+        let records = buffer.records();
+        assert_eq!(records.len(), 3);
+        assert!(records[0].get("section").is_none());
+        for record in &records[1..] {
+            assert_eq!(record["level"], "item");
+            assert_eq!(record["section"], "Staged files");
+        }
+        assert_eq!(records[2]["message"], "src/lib.rs");
+    }
+
+    #[test]
+    fn test_json_respects_level() {
+        let mut handler = OutputHandler::new(false, true, true);
+        handler.set_format(OutputFormat::Json);
+        let (stdout, stderr) = capture(&mut handler);
+
+        handler.info("hidden");
+        handler.debug("hidden");
+        handler.error("shown");
+
+        assert!(stdout.records().is_empty());
+        assert_eq!(levels(&stderr.records()), ["error"]);
+    }
+
+    #[test]
+    fn test_progress_visible() {
+        use OutputFormat::{Json, Text};
+        use OutputLevel::{Normal, Quiet, Verbose};
+
+        assert!(progress_visible(true, Normal, Text, true));
+        assert!(progress_visible(true, Verbose, Text, true));
+        assert!(!progress_visible(false, Normal, Text, true));
+        assert!(!progress_visible(true, Quiet, Text, true));
+        assert!(!progress_visible(true, Normal, Json, true));
+        assert!(!progress_visible(true, Normal, Text, false));
+    }
+
+    #[test]
+    fn test_hidden_progress_is_noop() {
+        let progress = ProgressHandle::hidden();
+        assert!(!progress.is_visible());
+        progress.set_length(10);
+        progress.set_message("working");
+        progress.inc(3);
+        progress.set_position(5);
+        progress.finish();
+    }
+
+    #[test]
+    fn test_progress_in_quiet_mode() {
+        let handler = OutputHandler::new(false, true, true);
+        let progress = handler.progress(3);
+        assert!(!progress.is_visible());
+        for _ in 0..3 {
+            progress.inc(1);
+        }
+        progress.finish();
+
+        let mut handler = OutputHandler::new(false, false, true);
+        handler.set_progress_bar(false);
+        assert!(!handler.progress(3).is_visible());
+    }
+
+    #[test]
+    fn test_format_command() {
+        assert_eq!(
+            format_command("cargo", &["build", "--release"]),
+            "cargo build --release"
+        );
+        assert_eq!(
+            format_command("git", &["commit", "-m", "feat: add x"]),
+            "git commit -m 'feat: add x'"
+        );
+        assert_eq!(
+            format_command("git", &["commit", "-m", "it's done", ""]),
+            "git commit -m 'it'\\''s done' ''"
+        );
+    }
+
+    #[test]
+    fn test_dry_run_command_is_printed_when_quiet() {
+        let mut handler = OutputHandler::new(false, true, true);
+        handler.set_dry_run(true);
+        let (stdout, _) = capture(&mut handler);
+
+        handler.dry_run_command("git", &["checkout", "-b", "feature/x"]);
+        assert!(stdout.text().contains("git checkout -b feature/x"));
+    }
 }
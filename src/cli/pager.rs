@@ -0,0 +1,205 @@
+//! Paging for long command output
+//!
+//! Long result lists are piped through `$PAGER` (default `less -R`) when
+//! stdout is a terminal and the output does not fit on screen. Controlled by
+//! the global `--pager` / `--no-pager` flags.
+
+use crate::core::error::{CldevError, Result};
+use std::io::{self, IsTerminal, Write};
+use std::process::{Command, Stdio};
+
+/// Pager used when `$PAGER` is not set
+const DEFAULT_PAGER: &str = "less -R";
+
+/// When to send output through the pager
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PagerMode {
+    /// Page only when the output is taller than the terminal
+    #[default]
+    Auto,
+    /// Always page on a terminal (`--pager`)
+    Always,
+    /// Never page (`--no-pager`)
+    Never,
+}
+
+/// Where a block of output ends up
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PagerTarget {
+    /// Printed directly to stdout
+    Inline,
+    /// Piped through the pager
+    Pager,
+}
+
+/// Displays command output, paging it when appropriate
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Pager {
+    mode: PagerMode,
+}
+
+impl Pager {
+    /// Create a pager with the given mode
+    pub fn new(mode: PagerMode) -> Self {
+        Self { mode }
+    }
+
+    /// Decide where output goes
+    ///
+    /// Output is never paged when stdout is not a terminal. In auto mode it is
+    /// paged only when it has more lines than the terminal height.
+    pub fn target(&self, text: &str, is_tty: bool, height: Option<usize>) -> PagerTarget {
+        if !is_tty {
+            return PagerTarget::Inline;
+        }
+
+        let page = match self.mode {
+            PagerMode::Never => false,
+            PagerMode::Always => true,
+            PagerMode::Auto => height.is_some_and(|h| text.lines().count() > h),
+        };
+
+        if page {
+            PagerTarget::Pager
+        } else {
+            PagerTarget::Inline
+        }
+    }
+
+    /// Display output on stdout, through the pager if needed
+    pub fn display(&self, text: &str) -> Result<()> {
+        let stdout = io::stdout();
+        let target = self.target(text, stdout.is_terminal(), terminal_height());
+        self.write_to(target, text, &mut stdout.lock())
+    }
+
+    /// Write output to the selected target, falling back to `out` if the pager fails
+    fn write_to(&self, target: PagerTarget, text: &str, out: &mut dyn Write) -> Result<()> {
+        if target == PagerTarget::Pager && spawn_pager(text).is_ok() {
+            return Ok(());
+        }
+
+        out.write_all(text.as_bytes())?;
+        out.flush()?;
+        Ok(())
+    }
+}
+
+/// Get the terminal height in rows, if stdout is a terminal
+fn terminal_height() -> Option<usize> {
+    console::Term::stdout()
+        .size_checked()
+        .map(|(rows, _)| rows as usize)
+}
+
+/// Resolve the pager program and arguments from `$PAGER`
+///
+/// `-R` is added to `less` so that ANSI colors survive.
+fn pager_command(pager_env: Option<&str>) -> Option<(String, Vec<String>)> {
+    let pager = pager_env
+        .map(str::trim)
+        .filter(|p| !p.is_empty())
+        .unwrap_or(DEFAULT_PAGER);
+
+    let mut parts = pager.split_whitespace().map(String::from);
+    let program = parts.next()?;
+    let mut args: Vec<String> = parts.collect();
+
+    if program == "less" && !args.iter().any(|a| a == "-R" || a == "-r") {
+        args.push("-R".to_string());
+    }
+
+    Some((program, args))
+}
+
+/// Pipe text through the pager and wait for it to exit
+fn spawn_pager(text: &str) -> Result<()> {
+    let (program, args) = pager_command(std::env::var("PAGER").ok().as_deref())
+        .ok_or_else(|| CldevError::command("No pager configured"))?;
+
+    let mut child = Command::new(&program)
+        .args(&args)
+        .stdin(Stdio::piped())
+        .spawn()
+        .map_err(|e| CldevError::command(format!("Failed to start pager {}: {}", program, e)))?;
+
+    if let Some(mut stdin) = child.stdin.take() {
+        // The user may quit the pager before reading everything
+        let _ = stdin.write_all(text.as_bytes());
+    }
+
+    child
+        .wait()
+        .map_err(|e| CldevError::command(format!("Failed to wait for pager: {}", e)))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn long_output(lines: usize) -> String {
+        (0..lines).map(|i| format!("line {}\n", i)).collect()
+    }
+
+    #[test]
+    fn test_no_pager_prints_inline() {
+        let pager = Pager::new(PagerMode::Never);
+        let text = long_output(100);
+
+        let target = pager.target(&text, true, Some(24));
+        assert_eq!(target, PagerTarget::Inline);
+
+        let mut out = Vec::new();
+        pager.write_to(target, &text, &mut out).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), text);
+    }
+
+    #[test]
+    fn test_long_output_on_tty_selects_pager() {
+        let pager = Pager::new(PagerMode::Auto);
+
+        assert_eq!(
+            pager.target(&long_output(100), true, Some(24)),
+            PagerTarget::Pager
+        );
+        assert_eq!(
+            pager.target(&long_output(10), true, Some(24)),
+            PagerTarget::Inline
+        );
+        assert_eq!(
+            Pager::new(PagerMode::Always).target(&long_output(10), true, Some(24)),
+            PagerTarget::Pager
+        );
+    }
+
+    #[test]
+    fn test_non_tty_prints_inline() {
+        for mode in [PagerMode::Auto, PagerMode::Always] {
+            assert_eq!(
+                Pager::new(mode).target(&long_output(100), false, Some(24)),
+                PagerTarget::Inline
+            );
+        }
+    }
+
+    #[test]
+    fn test_pager_command() {
+        assert_eq!(
+            pager_command(None),
+            Some(("less".to_string(), vec!["-R".to_string()]))
+        );
+        assert_eq!(
+            pager_command(Some("less -F")),
+            Some(("less".to_string(), vec!["-F".to_string(), "-R".to_string()]))
+        );
+        assert_eq!(
+            pager_command(Some("more")),
+            Some(("more".to_string(), vec![]))
+        );
+        assert_eq!(
+            pager_command(Some("  ")),
+            Some(("less".to_string(), vec!["-R".to_string()]))
+        );
+    }
+}
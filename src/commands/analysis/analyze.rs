@@ -1,10 +1,24 @@
 use crate::cli::args::{AnalysisFormat, AnalysisTarget};
-use crate::cli::output::OutputHandler;
-use crate::core::error::Result;
+use crate::cli::output::{OutputHandler, ProgressHandle};
+use crate::core::config::Config;
+use crate::core::error::{CldevError, Result};
+use crate::core::git_utils::GitUtils;
+use crate::core::project_detector::{ProjectDetector, ProjectType};
+use ignore::gitignore::{Gitignore, GitignoreBuilder};
+use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write as _;
+use std::hash::{Hash, Hasher};
 use std::path::{Path, PathBuf};
 
+/// Base of the polynomial rolling hash used for duplicate detection
+const ROLLING_HASH_BASE: u64 = 1_000_003;
+
+/// Average line length above which a file is treated as minified or generated
+const MAX_AVG_LINE_LENGTH: usize = 500;
+
 /// Main analysis result structure
 #[derive(Debug, Serialize, Deserialize)]
 pub struct AnalysisResult {
@@ -21,6 +35,9 @@ pub struct AnalysisSummary {
     pub languages: HashMap<String, usize>,
     pub issues_found: usize,
     pub overall_score: f32,
+    /// Source files left out as binary, non-UTF-8 or minified
+    #[serde(default)]
+    pub skipped_files: usize,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -41,7 +58,7 @@ pub struct StructureAnalysis {
     pub circular_dependencies: Vec<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ModuleInfo {
     pub name: String,
     pub path: String,
@@ -50,7 +67,7 @@ pub struct ModuleInfo {
     pub imports: usize,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DependencyInfo {
     pub from: String,
     pub to: String,
@@ -64,7 +81,7 @@ pub struct PerformanceAnalysis {
     pub optimization_suggestions: Vec<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct HotSpot {
     pub file: String,
     pub line: usize,
@@ -95,7 +112,7 @@ pub struct ComplexityMetrics {
     pub maintainability_index: f32,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CodeSmell {
     pub file: String,
     pub line: usize,
@@ -115,6 +132,10 @@ pub struct DuplicationMetrics {
 pub struct DuplicatedBlock {
     pub file1: String,
     pub file2: String,
+    /// First line of the block in `file1` (1-based)
+    pub line1: usize,
+    /// First line of the block in `file2` (1-based)
+    pub line2: usize,
     pub lines: usize,
 }
 
@@ -125,9 +146,13 @@ pub struct DebtAnalysis {
     pub debt_by_category: HashMap<String, f32>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DebtItem {
     pub file: String,
+    /// 1-based line of the marker comment
+    pub line: usize,
+    /// Comment text following the marker
+    pub comment: String,
     pub category: String,
     pub description: String,
     pub effort_hours: f32,
@@ -168,30 +193,99 @@ pub struct DebtSummary {
     pub high_priority_items: usize,
 }
 
+/// Which files an analysis scans
+#[derive(Debug, Clone, Default)]
+pub struct ScanScope {
+    /// File extensions to scan (default: detected from the project type)
+    pub langs: Vec<String>,
+    /// Skip files matched by the project's `.gitignore`
+    pub use_gitignore: bool,
+    /// Only scan files changed since the latest tag
+    pub since_tag: bool,
+}
+
 /// Analyze project based on specified target
 pub fn analyze_project(
     target: AnalysisTarget,
     format: AnalysisFormat,
     detailed: bool,
+    duplication_window: usize,
+    scope: &ScanScope,
     output: &OutputHandler,
 ) -> Result<()> {
     output.info(&output.t_format("analyze-starting", "target", &format!("{:?}", target)));
 
-    let current_dir = std::env::current_dir()?;
+    // Analyze the whole repository even when run from a subdirectory
+    let root = Config::project_root()?;
+    let config = Config::load(None).ok();
+    let filter = SourceFilter::resolve(&scope.langs, &root, scope.use_gitignore)
+        .with_ignore_patterns(
+            &root,
+            config.as_ref().map_or(&[][..], |c| &c.analysis.ignore),
+        );
+    let jobs = config.as_ref().map(|c| c.performance.max_jobs).unwrap_or(1);
+    let mut files = walk(&root, &filter);
+    output.debug_at(
+        2,
+        &format!(
+            "Walked {}: {} source files, {} test files, depth {}",
+            root.display(),
+            files.source_files.len(),
+            files.test_files,
+            files.max_depth
+        ),
+    );
+    if scope.since_tag {
+        let git = GitUtils::discover(&root).ok();
+        match git.map(|git| restrict_to_tag(&mut files, &git, &root)) {
+            Some(Ok(Some(tag))) => {
+                let count = files.source_files.len().to_string();
+                let vars = HashMap::from([("count", count.as_str()), ("tag", tag.as_str())]);
+                output.info(&output.t_with_vars("analyze-since-tag", &vars));
+            }
+            Some(Err(e)) => return Err(e),
+            _ => output.warning(&output.t("analyze-since-tag-none")),
+        }
+    }
+    let progress = output.progress(files.source_files.len() as u64);
+    let scan = &scan_project(files, jobs, &progress)?;
+    progress.finish();
+    output.debug_at(
+        1,
+        &format!(
+            "Scanned {} files on {} threads ({} skipped)",
+            scan.files.len(),
+            jobs.max(1),
+            scan.skipped_files
+        ),
+    );
+    for file in &scan.files {
+        output.debug_at(
+            3,
+            &format!(
+                "{}: {} lines, {} imports, {} hot spots",
+                file.module.path,
+                file.module.lines,
+                file.module.imports,
+                file.hot_spots.len()
+            ),
+        );
+    }
 
     let result = match target {
-        AnalysisTarget::Structure => analyze_structure(&current_dir, detailed, output)?,
-        AnalysisTarget::Performance => analyze_performance(&current_dir, detailed, output)?,
-        AnalysisTarget::Quality => analyze_quality(&current_dir, detailed, output)?,
-        AnalysisTarget::Debt => analyze_debt(&current_dir, detailed, output)?,
-        AnalysisTarget::Overview => analyze_overview(&current_dir, detailed, output)?,
+        AnalysisTarget::Structure => analyze_structure(scan, detailed, output),
+        AnalysisTarget::Performance => analyze_performance(scan, detailed, output),
+        AnalysisTarget::Quality => analyze_quality(scan, detailed, duplication_window, output),
+        AnalysisTarget::Debt => analyze_debt(scan, detailed, output),
+        AnalysisTarget::Overview => analyze_overview(scan, detailed, output),
     };
 
     // Format and output results
     match format {
         AnalysisFormat::Text => output_text(&result, output),
         AnalysisFormat::Json => output_json(&result, output)?,
-        AnalysisFormat::Html => output_html(&result, output)?,
+        AnalysisFormat::Html => output_html(&result, output),
+        AnalysisFormat::Markdown => output_markdown(&result, output)?,
     }
 
     output.success(&output.t_format(
@@ -204,54 +298,60 @@ pub fn analyze_project(
 }
 
 fn analyze_structure(
-    path: &Path,
+    scan: &ProjectScan,
     _detailed: bool,
     output: &OutputHandler,
-) -> Result<AnalysisResult> {
+) -> AnalysisResult {
     output.debug(&output.t("analyze-structure"));
 
-    let modules = scan_modules(path)?;
-    let dependencies = analyze_dependencies(&modules)?;
+    let modules: Vec<ModuleInfo> = scan.files.iter().map(|f| f.module.clone()).collect();
+    let dependencies: Vec<DependencyInfo> = scan
+        .files
+        .iter()
+        .flat_map(|f| f.dependencies.iter().cloned())
+        .collect();
     let circular_deps = detect_circular_dependencies(&dependencies);
 
     let summary = AnalysisSummary {
-        total_files: modules.len(),
-        total_lines: modules.iter().map(|m| m.lines).sum(),
-        languages: count_languages(&modules),
+        total_files: scan.files.len(),
+        total_lines: scan.total_lines(),
+        languages: scan.languages(),
+        skipped_files: scan.skipped_files,
         issues_found: circular_deps.len(),
         overall_score: calculate_structure_score(&modules, &circular_deps),
     };
 
     let structure = StructureAnalysis {
-        depth: calculate_max_depth(path),
+        depth: scan.max_depth,
         modules,
         dependencies,
         circular_dependencies: circular_deps,
     };
 
-    Ok(AnalysisResult {
+    AnalysisResult {
         target: "Structure".to_string(),
         timestamp: chrono::Utc::now().to_rfc3339(),
         summary,
         details: AnalysisDetails::Structure(structure),
-    })
+    }
 }
 
 fn analyze_performance(
-    path: &Path,
+    scan: &ProjectScan,
     _detailed: bool,
     output: &OutputHandler,
-) -> Result<AnalysisResult> {
+) -> AnalysisResult {
     output.debug(&output.t("analyze-performance"));
 
-    let hot_spots = detect_performance_hotspots(path)?;
-    let memory_metrics = analyze_memory_usage(path)?;
+    let hot_spots = scan.hot_spots();
+    let memory_metrics = analyze_memory_usage(scan);
     let suggestions = generate_optimization_suggestions(&hot_spots, &memory_metrics);
 
     let summary = AnalysisSummary {
-        total_files: count_source_files(path),
-        total_lines: count_total_lines(path)?,
-        languages: detect_languages(path)?,
+        total_files: scan.files.len(),
+        total_lines: scan.total_lines(),
+        languages: scan.languages(),
+        skipped_files: scan.skipped_files,
         issues_found: hot_spots.len(),
         overall_score: calculate_performance_score(&hot_spots),
     };
@@ -262,26 +362,32 @@ fn analyze_performance(
         optimization_suggestions: suggestions,
     };
 
-    Ok(AnalysisResult {
+    AnalysisResult {
         target: "Performance".to_string(),
         timestamp: chrono::Utc::now().to_rfc3339(),
         summary,
         details: AnalysisDetails::Performance(performance),
-    })
+    }
 }
 
-fn analyze_quality(path: &Path, _detailed: bool, output: &OutputHandler) -> Result<AnalysisResult> {
+fn analyze_quality(
+    scan: &ProjectScan,
+    _detailed: bool,
+    duplication_window: usize,
+    output: &OutputHandler,
+) -> AnalysisResult {
     output.debug(&output.t("analyze-quality"));
 
-    let complexity = calculate_complexity_metrics(path)?;
-    let smells = detect_code_smells(path)?;
-    let coverage = estimate_test_coverage(path)?;
-    let duplication = analyze_duplication(path)?;
+    let complexity = calculate_complexity_metrics(scan);
+    let smells = scan.code_smells();
+    let coverage = estimate_test_coverage(scan);
+    let duplication = analyze_duplication(scan, duplication_window);
 
     let summary = AnalysisSummary {
-        total_files: count_source_files(path),
-        total_lines: count_total_lines(path)?,
-        languages: detect_languages(path)?,
+        total_files: scan.files.len(),
+        total_lines: scan.total_lines(),
+        languages: scan.languages(),
+        skipped_files: scan.skipped_files,
         issues_found: smells.len(),
         overall_score: calculate_quality_score(&complexity, &smells, coverage),
     };
@@ -293,25 +399,26 @@ fn analyze_quality(path: &Path, _detailed: bool, output: &OutputHandler) -> Resu
         duplication,
     };
 
-    Ok(AnalysisResult {
+    AnalysisResult {
         target: "Quality".to_string(),
         timestamp: chrono::Utc::now().to_rfc3339(),
         summary,
         details: AnalysisDetails::Quality(quality),
-    })
+    }
 }
 
-fn analyze_debt(path: &Path, _detailed: bool, output: &OutputHandler) -> Result<AnalysisResult> {
+fn analyze_debt(scan: &ProjectScan, _detailed: bool, output: &OutputHandler) -> AnalysisResult {
     output.debug(&output.t("analyze-debt"));
 
-    let debt_items = scan_technical_debt(path)?;
+    let debt_items = scan.debt_items();
     let debt_by_category = categorize_debt(&debt_items);
     let total_hours: f32 = debt_items.iter().map(|d| d.effort_hours).sum();
 
     let summary = AnalysisSummary {
-        total_files: count_source_files(path),
-        total_lines: count_total_lines(path)?,
-        languages: detect_languages(path)?,
+        total_files: scan.files.len(),
+        total_lines: scan.total_lines(),
+        languages: scan.languages(),
+        skipped_files: scan.skipped_files,
         issues_found: debt_items.len(),
         overall_score: calculate_debt_score(total_hours, debt_items.len()),
     };
@@ -322,31 +429,28 @@ fn analyze_debt(path: &Path, _detailed: bool, output: &OutputHandler) -> Result<
         debt_by_category,
     };
 
-    Ok(AnalysisResult {
+    AnalysisResult {
         target: "Debt".to_string(),
         timestamp: chrono::Utc::now().to_rfc3339(),
         summary,
         details: AnalysisDetails::Debt(debt),
-    })
+    }
 }
 
-fn analyze_overview(
-    path: &Path,
-    _detailed: bool,
-    output: &OutputHandler,
-) -> Result<AnalysisResult> {
+fn analyze_overview(scan: &ProjectScan, _detailed: bool, output: &OutputHandler) -> AnalysisResult {
     output.debug(&output.t("analyze-overview"));
 
     // Collect high-level metrics from each analysis type
-    let structure_summary = get_structure_summary(path)?;
-    let performance_summary = get_performance_summary(path)?;
-    let quality_summary = get_quality_summary(path)?;
-    let debt_summary = get_debt_summary(path)?;
+    let structure_summary = get_structure_summary(scan);
+    let performance_summary = get_performance_summary(scan);
+    let quality_summary = get_quality_summary(scan);
+    let debt_summary = get_debt_summary(scan);
 
     let summary = AnalysisSummary {
-        total_files: count_source_files(path),
-        total_lines: count_total_lines(path)?,
-        languages: detect_languages(path)?,
+        total_files: scan.files.len(),
+        total_lines: scan.total_lines(),
+        languages: scan.languages(),
+        skipped_files: scan.skipped_files,
         issues_found: structure_summary.circular_deps
             + performance_summary.hot_spots_count
             + quality_summary.code_smells_count,
@@ -365,155 +469,363 @@ fn analyze_overview(
         debt: debt_summary,
     };
 
-    Ok(AnalysisResult {
+    AnalysisResult {
         target: "Overview".to_string(),
         timestamp: chrono::Utc::now().to_rfc3339(),
         summary,
         details: AnalysisDetails::Overview(overview),
-    })
+    }
 }
 
-// Helper functions for module scanning
-fn scan_modules(path: &Path) -> Result<Vec<ModuleInfo>> {
-    let mut modules = Vec::new();
-
-    if let Ok(entries) = std::fs::read_dir(path) {
-        for entry in entries.flatten() {
-            let path = entry.path();
-            if path.is_file() && is_source_file(&path) {
-                if let Ok(content) = std::fs::read_to_string(&path) {
-                    modules.push(ModuleInfo {
-                        name: path.file_stem().unwrap().to_string_lossy().to_string(),
-                        path: path.to_string_lossy().to_string(),
-                        lines: content.lines().count(),
-                        exports: count_exports(&content),
-                        imports: count_imports(&content),
-                    });
-                }
-            } else if path.is_dir() && !is_ignored_dir(&path) {
-                modules.extend(scan_modules(&path)?);
-            }
+/// Findings for a single source file, merged into project-wide metrics
+#[derive(Debug, Clone, PartialEq)]
+struct FileScan {
+    module: ModuleInfo,
+    dependencies: Vec<DependencyInfo>,
+    hot_spots: Vec<HotSpot>,
+    large_allocations: Vec<String>,
+    code_smells: Vec<CodeSmell>,
+    debt_items: Vec<DebtItem>,
+    complexities: Vec<usize>,
+    halstead: HalsteadMetrics,
+    normalized: NormalizedFile,
+}
+
+impl FileScan {
+    /// Maintainability index of this file, from 0 (worst) to 100
+    fn maintainability_index(&self) -> f32 {
+        let complexity = self.complexities.iter().sum::<usize>().max(1);
+        maintainability_index(self.halstead.volume(), complexity, self.module.lines)
+    }
+
+    /// Read and analyze one source file
+    ///
+    /// Returns `Ok(None)` for files that are not worth scanning: content that
+    /// is not valid UTF-8, or minified code whose average line is longer than
+    /// [`MAX_AVG_LINE_LENGTH`].
+    fn load(path: &Path) -> std::io::Result<Option<Self>> {
+        let Ok(content) = String::from_utf8(std::fs::read(path)?) else {
+            return Ok(None);
+        };
+        if is_minified(&content) {
+            return Ok(None);
         }
+        let Some(stem) = path.file_stem() else {
+            return Ok(None);
+        };
+
+        let file = path.to_string_lossy().to_string();
+        let syntax = LexicalSyntax::for_path(path);
+        let module = ModuleInfo {
+            name: stem.to_string_lossy().to_string(),
+            path: file.clone(),
+            lines: content.lines().count(),
+            exports: count_exports(&content),
+            imports: count_imports(&content),
+        };
+
+        Ok(Some(Self {
+            dependencies: scan_dependencies(&module.name, &content),
+            hot_spots: detect_performance_hotspots(&file, &content),
+            large_allocations: detect_large_allocations(&file, &content),
+            code_smells: detect_code_smells(&file, &content),
+            debt_items: scan_technical_debt(&file, &content),
+            complexities: function_complexities(&content, syntax),
+            halstead: halstead_metrics(&content, syntax),
+            normalized: NormalizedFile::new(&file, &content),
+            module,
+        }))
     }
+}
 
-    Ok(modules)
+/// Whether a file looks minified or generated, judged by its average line length
+fn is_minified(content: &str) -> bool {
+    let lines = content.lines().count().max(1);
+    content.len() / lines > MAX_AVG_LINE_LENGTH
 }
 
-fn analyze_dependencies(modules: &[ModuleInfo]) -> Result<Vec<DependencyInfo>> {
-    let mut deps = Vec::new();
+/// Every source file under the project root, scanned once
+#[derive(Debug, Clone, PartialEq)]
+struct ProjectScan {
+    /// Per-file results in path order
+    files: Vec<FileScan>,
+    /// Files whose name marks them as tests, regardless of extension
+    test_files: usize,
+    /// Source files left out as binary, non-UTF-8 or minified
+    skipped_files: usize,
+    /// Deepest directory nesting below the root
+    max_depth: usize,
+}
 
-    for module in modules {
-        // Simple dependency detection based on imports
-        // In a real implementation, this would use a proper AST parser
-        if let Ok(content) = std::fs::read_to_string(&module.path) {
-            for line in content.lines() {
-                if line.contains("use ") || line.contains("import ") || line.contains("require(") {
-                    // Extract dependency information
-                    deps.push(DependencyInfo {
-                        from: module.name.clone(),
-                        to: extract_import_name(line),
-                        kind: "module".to_string(),
-                    });
-                }
+impl ProjectScan {
+    fn total_lines(&self) -> usize {
+        self.files.iter().map(|f| f.module.lines).sum()
+    }
+
+    fn languages(&self) -> HashMap<String, usize> {
+        let mut langs = HashMap::new();
+
+        for file in &self.files {
+            if let Some(ext) = Path::new(&file.module.path).extension() {
+                let ext = ext.to_string_lossy().to_string();
+                *langs.entry(ext).or_insert(0) += 1;
             }
         }
+
+        langs
+    }
+
+    fn hot_spots(&self) -> Vec<HotSpot> {
+        self.files
+            .iter()
+            .flat_map(|f| f.hot_spots.iter().cloned())
+            .collect()
+    }
+
+    fn code_smells(&self) -> Vec<CodeSmell> {
+        self.files
+            .iter()
+            .flat_map(|f| f.code_smells.iter().cloned())
+            .collect()
     }
 
-    Ok(deps)
+    fn debt_items(&self) -> Vec<DebtItem> {
+        self.files
+            .iter()
+            .flat_map(|f| f.debt_items.iter().cloned())
+            .collect()
+    }
 }
 
-fn detect_circular_dependencies(deps: &[DependencyInfo]) -> Vec<String> {
-    // Simple cycle detection - in production, use a proper graph algorithm
-    let mut circular = Vec::new();
-    let mut seen = HashMap::new();
+/// Analyze the walked source files on `jobs` threads, advancing `progress` per file
+fn scan_project(walk: Walk, jobs: usize, progress: &ProgressHandle) -> Result<ProjectScan> {
+    // Unreadable files are dropped; `None` marks a file skipped by `FileScan::load`
+    let load = |path: &PathBuf| {
+        let file = FileScan::load(path).ok();
+        progress.inc(1);
+        file
+    };
 
+    let loaded: Vec<Option<FileScan>> = if jobs <= 1 {
+        walk.source_files.iter().filter_map(load).collect()
+    } else {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(jobs)
+            .build()
+            .map_err(|e| CldevError::command(format!("Failed to start scan threads: {}", e)))?;
+        pool.install(|| walk.source_files.par_iter().filter_map(load).collect())
+    };
+
+    let skipped_files = loaded.iter().filter(|file| file.is_none()).count();
+    Ok(ProjectScan {
+        files: loaded.into_iter().flatten().collect(),
+        test_files: walk.test_files,
+        skipped_files,
+        max_depth: walk.max_depth,
+    })
+}
+
+/// Module-level imports of a file
+fn scan_dependencies(module: &str, content: &str) -> Vec<DependencyInfo> {
+    // Simple dependency detection based on imports
+    // In a real implementation, this would use a proper AST parser
+    content
+        .lines()
+        .filter(|line| {
+            line.contains("use ") || line.contains("import ") || line.contains("require(")
+        })
+        .map(|line| DependencyInfo {
+            from: module.to_string(),
+            to: extract_import_name(line),
+            kind: "module".to_string(),
+        })
+        .collect()
+}
+
+/// Report every dependency cycle as an ordered path, e.g. `a -> b -> c -> a`
+///
+/// Cycles are the strongly connected components (Tarjan's algorithm) with at
+/// least two modules; each is reported once, starting from its first module
+/// in name order.
+fn detect_circular_dependencies(deps: &[DependencyInfo]) -> Vec<String> {
+    let names: Vec<&str> = deps
+        .iter()
+        .flat_map(|dep| [dep.from.as_str(), dep.to.as_str()])
+        .collect::<std::collections::BTreeSet<_>>()
+        .into_iter()
+        .collect();
+    let ids: HashMap<&str, usize> = names.iter().enumerate().map(|(i, n)| (*n, i)).collect();
+
+    let mut adjacency = vec![Vec::new(); names.len()];
     for dep in deps {
-        let key = format!("{}->{}", dep.from, dep.to);
-        let reverse = format!("{}->{}", dep.to, dep.from);
+        adjacency[ids[dep.from.as_str()]].push(ids[dep.to.as_str()]);
+    }
+    for edges in &mut adjacency {
+        edges.sort_unstable();
+        edges.dedup();
+    }
 
-        if seen.contains_key(&reverse) {
-            circular.push(format!("{} <-> {}", dep.from, dep.to));
+    let mut cycles: Vec<String> = strongly_connected_components(&adjacency)
+        .into_iter()
+        .filter(|component| component.len() >= 2)
+        .filter_map(|component| cycle_path(&adjacency, &component))
+        .map(|path| {
+            path.iter()
+                .map(|&id| names[id])
+                .collect::<Vec<_>>()
+                .join(" -> ")
+        })
+        .collect();
+    cycles.sort();
+    cycles
+}
+
+/// Tarjan's strongly connected components, iterative to avoid deep recursion
+fn strongly_connected_components(adjacency: &[Vec<usize>]) -> Vec<Vec<usize>> {
+    const UNVISITED: usize = usize::MAX;
+
+    let mut index = vec![UNVISITED; adjacency.len()];
+    let mut lowlink = vec![0; adjacency.len()];
+    let mut on_stack = vec![false; adjacency.len()];
+    let mut stack = Vec::new();
+    let mut next_index = 0;
+    let mut components = Vec::new();
+
+    for root in 0..adjacency.len() {
+        if index[root] != UNVISITED {
+            continue;
         }
-        seen.insert(key, true);
-    }
 
-    circular
-}
+        // (node, position of the next edge to follow)
+        let mut call_stack = vec![(root, 0)];
+        index[root] = next_index;
+        lowlink[root] = next_index;
+        next_index += 1;
+        stack.push(root);
+        on_stack[root] = true;
+
+        while let Some(frame) = call_stack.last_mut() {
+            let (node, edge) = *frame;
+
+            if let Some(&next) = adjacency[node].get(edge) {
+                frame.1 += 1;
+                if index[next] == UNVISITED {
+                    index[next] = next_index;
+                    lowlink[next] = next_index;
+                    next_index += 1;
+                    stack.push(next);
+                    on_stack[next] = true;
+                    call_stack.push((next, 0));
+                } else if on_stack[next] {
+                    lowlink[node] = lowlink[node].min(index[next]);
+                }
+                continue;
+            }
 
-fn detect_performance_hotspots(path: &Path) -> Result<Vec<HotSpot>> {
-    let mut hotspots = Vec::new();
+            call_stack.pop();
+            if let Some(&(parent, _)) = call_stack.last() {
+                lowlink[parent] = lowlink[parent].min(lowlink[node]);
+            }
 
-    // Scan for common performance anti-patterns
-    if let Ok(entries) = std::fs::read_dir(path) {
-        for entry in entries.flatten() {
-            let file_path = entry.path();
-            if file_path.is_file() && is_source_file(&file_path) {
-                if let Ok(content) = std::fs::read_to_string(&file_path) {
-                    for (i, line) in content.lines().enumerate() {
-                        // Detect performance issues
-                        if line.contains("clone()") && line.contains(".clone().clone()") {
-                            hotspots.push(HotSpot {
-                                file: file_path.to_string_lossy().to_string(),
-                                line: i + 1,
-                                issue: "Multiple unnecessary clones".to_string(),
-                                severity: "high".to_string(),
-                            });
-                        }
-                        if line.contains("unwrap()")
-                            && (line.contains("iter") || line.contains("loop"))
-                        {
-                            hotspots.push(HotSpot {
-                                file: file_path.to_string_lossy().to_string(),
-                                line: i + 1,
-                                issue: "Unwrap in loop may cause panic".to_string(),
-                                severity: "medium".to_string(),
-                            });
-                        }
+            if lowlink[node] == index[node] {
+                let mut component = Vec::new();
+                while let Some(member) = stack.pop() {
+                    on_stack[member] = false;
+                    component.push(member);
+                    if member == node {
+                        break;
                     }
                 }
-            } else if file_path.is_dir() && !is_ignored_dir(&file_path) {
-                hotspots.extend(detect_performance_hotspots(&file_path)?);
+                components.push(component);
             }
         }
     }
 
-    Ok(hotspots)
+    components
 }
 
-fn analyze_memory_usage(path: &Path) -> Result<MemoryMetrics> {
-    let mut large_allocations = Vec::new();
+/// Shortest cycle through the lowest-numbered node of a component
+///
+/// Returns the path with the start node repeated at the end.
+fn cycle_path(adjacency: &[Vec<usize>], component: &[usize]) -> Option<Vec<usize>> {
+    let members: HashSet<usize> = component.iter().copied().collect();
+    let start = *component.iter().min()?;
 
-    // Estimate based on data structures
-    if let Ok(entries) = std::fs::read_dir(path) {
-        for entry in entries.flatten() {
-            let file_path = entry.path();
-            if file_path.is_file() && is_source_file(&file_path) {
-                if let Ok(content) = std::fs::read_to_string(&file_path) {
-                    for line in content.lines() {
-                        if line.contains("Vec::with_capacity") {
-                            if let Some(cap) = extract_capacity(line) {
-                                if cap > 10000 {
-                                    large_allocations.push(format!(
-                                        "{}: capacity {}",
-                                        file_path.to_string_lossy(),
-                                        cap
-                                    ));
-                                }
-                            }
-                        }
-                    }
+    let mut parent: HashMap<usize, usize> = HashMap::new();
+    let mut queue = std::collections::VecDeque::from([start]);
+
+    while let Some(node) = queue.pop_front() {
+        for &next in &adjacency[node] {
+            if next == start {
+                let mut path = vec![start];
+                let mut current = node;
+                while current != start {
+                    path.push(current);
+                    current = parent[&current];
                 }
-            } else if file_path.is_dir() && !is_ignored_dir(&file_path) {
-                large_allocations.extend(analyze_memory_usage(&file_path)?.large_allocations);
+                path[1..].reverse();
+                path.push(start);
+                return Some(path);
             }
+            if members.contains(&next) && !parent.contains_key(&next) {
+                parent.insert(next, node);
+                queue.push_back(next);
+            }
+        }
+    }
+
+    None
+}
+
+pub(super) fn detect_performance_hotspots(file: &str, content: &str) -> Vec<HotSpot> {
+    let mut hotspots = Vec::new();
+
+    // Scan for common performance anti-patterns
+    for (i, line) in content.lines().enumerate() {
+        if line.contains("clone()") && line.contains(".clone().clone()") {
+            hotspots.push(HotSpot {
+                file: file.to_string(),
+                line: i + 1,
+                issue: "Multiple unnecessary clones".to_string(),
+                severity: "high".to_string(),
+            });
+        }
+        if line.contains("unwrap()") && (line.contains("iter") || line.contains("loop")) {
+            hotspots.push(HotSpot {
+                file: file.to_string(),
+                line: i + 1,
+                issue: "Unwrap in loop may cause panic".to_string(),
+                severity: "medium".to_string(),
+            });
         }
     }
 
-    Ok(MemoryMetrics {
+    hotspots
+}
+
+fn detect_large_allocations(file: &str, content: &str) -> Vec<String> {
+    content
+        .lines()
+        .filter(|line| line.contains("Vec::with_capacity"))
+        .filter_map(extract_capacity)
+        .filter(|cap| *cap > 10000)
+        .map(|cap| format!("{}: capacity {}", file, cap))
+        .collect()
+}
+
+fn analyze_memory_usage(scan: &ProjectScan) -> MemoryMetrics {
+    // Estimate based on data structures
+    let large_allocations: Vec<String> = scan
+        .files
+        .iter()
+        .flat_map(|f| f.large_allocations.iter().cloned())
+        .collect();
+
+    MemoryMetrics {
         estimated_heap: large_allocations.len() * 1024, // Rough estimate
         stack_frames: 0,                                // Would need runtime analysis
         large_allocations,
-    })
+    }
 }
 
 fn generate_optimization_suggestions(hotspots: &[HotSpot], memory: &MemoryMetrics) -> Vec<String> {
@@ -536,165 +848,552 @@ fn generate_optimization_suggestions(hotspots: &[HotSpot], memory: &MemoryMetric
     suggestions
 }
 
-fn calculate_complexity_metrics(path: &Path) -> Result<ComplexityMetrics> {
-    let mut total_complexity = 0.0;
-    let mut max_complexity = 0;
-    let mut function_count = 0;
-
-    if let Ok(entries) = std::fs::read_dir(path) {
-        for entry in entries.flatten() {
-            let file_path = entry.path();
-            if file_path.is_file() && is_source_file(&file_path) {
-                if let Ok(content) = std::fs::read_to_string(&file_path) {
-                    for line in content.lines() {
-                        if line.contains("fn ") {
-                            function_count += 1;
-                            // Simple complexity estimate based on control flow
-                            let complexity = count_control_flow_keywords(line);
-                            total_complexity += complexity as f32;
-                            max_complexity = max_complexity.max(complexity);
-                        }
-                    }
-                }
-            } else if file_path.is_dir() && !is_ignored_dir(&file_path) {
-                let metrics = calculate_complexity_metrics(&file_path)?;
-                total_complexity += metrics.cyclomatic_avg * function_count as f32;
-                max_complexity = max_complexity.max(metrics.cyclomatic_max);
-            }
-        }
-    }
+fn calculate_complexity_metrics(scan: &ProjectScan) -> ComplexityMetrics {
+    let complexities: Vec<usize> = scan
+        .files
+        .iter()
+        .flat_map(|f| f.complexities.iter().copied())
+        .collect();
 
-    let avg_complexity = if function_count > 0 {
-        total_complexity / function_count as f32
-    } else {
+    let avg_complexity = if complexities.is_empty() {
         0.0
+    } else {
+        complexities.iter().sum::<usize>() as f32 / complexities.len() as f32
     };
 
-    Ok(ComplexityMetrics {
+    ComplexityMetrics {
         cyclomatic_avg: avg_complexity,
-        cyclomatic_max: max_complexity,
+        cyclomatic_max: complexities.iter().copied().max().unwrap_or(0),
         cognitive_avg: avg_complexity * 1.2, // Rough estimate
-        maintainability_index: calculate_maintainability_index(avg_complexity),
-    })
+        maintainability_index: average_maintainability_index(scan),
+    }
 }
 
-fn detect_code_smells(path: &Path) -> Result<Vec<CodeSmell>> {
-    let mut smells = Vec::new();
+/// Mean maintainability index over the scanned files (100 when there are none)
+fn average_maintainability_index(scan: &ProjectScan) -> f32 {
+    if scan.files.is_empty() {
+        return 100.0;
+    }
+    scan.files
+        .iter()
+        .map(FileScan::maintainability_index)
+        .sum::<f32>()
+        / scan.files.len() as f32
+}
+
+/// Halstead operator and operand counts for a piece of source code
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HalsteadMetrics {
+    /// Distinct operators (n1)
+    pub distinct_operators: usize,
+    /// Distinct operands (n2)
+    pub distinct_operands: usize,
+    /// Total operators (N1)
+    pub total_operators: usize,
+    /// Total operands (N2)
+    pub total_operands: usize,
+}
+
+impl HalsteadMetrics {
+    /// Program vocabulary, n = n1 + n2
+    pub fn vocabulary(&self) -> usize {
+        self.distinct_operators + self.distinct_operands
+    }
+
+    /// Program length, N = N1 + N2
+    pub fn length(&self) -> usize {
+        self.total_operators + self.total_operands
+    }
+
+    /// Program volume, V = N * log2(n)
+    pub fn volume(&self) -> f64 {
+        match self.vocabulary() {
+            0 | 1 => 0.0,
+            n => self.length() as f64 * (n as f64).log2(),
+        }
+    }
+}
+
+/// Keywords counted as Halstead operators rather than operands
+const OPERATOR_KEYWORDS: &[&str] = &[
+    "if", "else", "match", "loop", "while", "for", "in", "return", "break", "continue", "let",
+    "mut", "fn", "function", "def", "func", "as", "pub", "use", "import", "from", "struct", "enum",
+    "impl", "trait", "class", "const", "static", "where", "async", "await", "yield", "try",
+    "catch", "throw", "new", "and", "or", "not", "var",
+];
+
+/// Multi-character operators, longest first so they win over their prefixes
+const MULTI_CHAR_OPERATORS: &[&str] = &[
+    "<<=", ">>=", "...", "..=", "===", "!==", "::", "->", "=>", "==", "!=", "<=", ">=", "&&", "||",
+    "+=", "-=", "*=", "/=", "%=", "^=", "&=", "|=", "<<", ">>", "..", "**",
+];
+
+/// Count Halstead operators and operands in source code
+///
+/// Comments and string literals are skipped. Identifiers and numbers are
+/// operands, except keywords, which count as operators along with
+/// punctuation. Closing brackets are not counted separately from their
+/// opening bracket.
+pub fn halstead_metrics(content: &str, syntax: LexicalSyntax) -> HalsteadMetrics {
+    let mut scanner = CodeScanner::new(syntax);
+    let mut operators: HashMap<String, usize> = HashMap::new();
+    let mut operands: HashMap<String, usize> = HashMap::new();
+
+    for line in content.lines() {
+        let code = scanner.strip_line(line);
+        let mut rest = code.as_str();
+
+        while let Some(c) = rest.chars().next() {
+            if c.is_whitespace() || matches!(c, ')' | '}' | ']') {
+                rest = &rest[c.len_utf8()..];
+            } else if c.is_alphanumeric() || c == '_' {
+                let end = rest
+                    .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+                    .unwrap_or(rest.len());
+                let token = &rest[..end];
+                let counts = if OPERATOR_KEYWORDS.contains(&token) {
+                    &mut operators
+                } else {
+                    &mut operands
+                };
+                *counts.entry(token.to_string()).or_insert(0) += 1;
+                rest = &rest[end..];
+            } else {
+                let op = MULTI_CHAR_OPERATORS
+                    .iter()
+                    .find(|op| rest.starts_with(*op))
+                    .copied()
+                    .unwrap_or(&rest[..c.len_utf8()]);
+                *operators.entry(op.to_string()).or_insert(0) += 1;
+                rest = &rest[op.len()..];
+            }
+        }
+    }
+
+    HalsteadMetrics {
+        distinct_operators: operators.len(),
+        distinct_operands: operands.len(),
+        total_operators: operators.values().sum(),
+        total_operands: operands.values().sum(),
+    }
+}
+
+/// Cyclomatic complexity of each function in a source file
+///
+/// A function starts at its `fn`/`function`/`def`/`func` token and runs until
+/// the next one; its complexity is one plus the control-flow keywords in it.
+fn function_complexities(content: &str, syntax: LexicalSyntax) -> Vec<usize> {
+    let mut scanner = CodeScanner::new(syntax);
+    let mut complexities = Vec::new();
+
+    for line in content.lines() {
+        let code = scanner.strip_line(line);
+        if code_tokens(&code).any(|token| matches!(token, "fn" | "function" | "def" | "func")) {
+            complexities.push(1);
+        }
+        if let Some(current) = complexities.last_mut() {
+            *current += count_control_flow_keywords(&code);
+        }
+    }
+
+    complexities
+}
+
+/// Comment and literal syntax of a source language, as far as scanning needs it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct LexicalSyntax {
+    /// `//` line comments and `/* */` block comments
+    c_comments: bool,
+    /// `#` line comments
+    hash_comments: bool,
+    /// Characters that open and close a string literal
+    string_quotes: &'static [char],
+    /// `'x'` is a character literal, and a lone `'` a lifetime or label
+    char_literals: bool,
+}
+
+impl Default for LexicalSyntax {
+    /// C-family syntax (Rust, C, Go, Java, ...)
+    fn default() -> Self {
+        Self {
+            c_comments: true,
+            hash_comments: false,
+            string_quotes: &['"'],
+            char_literals: true,
+        }
+    }
+}
+
+impl LexicalSyntax {
+    /// Syntax for the language of `path`, judged by its extension
+    fn for_path(path: &Path) -> Self {
+        let ext = path
+            .extension()
+            .map(|e| e.to_string_lossy().to_lowercase())
+            .unwrap_or_default();
+
+        match ext.as_str() {
+            "py" | "rb" | "sh" | "bash" | "zsh" | "pl" | "r" | "ex" | "exs" => Self {
+                c_comments: false,
+                hash_comments: true,
+                string_quotes: &['"', '\''],
+                char_literals: false,
+            },
+            "php" => Self {
+                c_comments: true,
+                hash_comments: true,
+                string_quotes: &['"', '\''],
+                char_literals: false,
+            },
+            "js" | "jsx" | "mjs" | "cjs" | "ts" | "tsx" => Self {
+                c_comments: true,
+                hash_comments: false,
+                string_quotes: &['"', '\'', '`'],
+                char_literals: false,
+            },
+            _ => Self::default(),
+        }
+    }
+}
+
+/// Removes comments and string literals from source lines
+///
+/// Keeps state between lines so that multi-line `/* */` comments and string
+/// literals are skipped as a whole.
+#[derive(Debug, Default)]
+struct CodeScanner {
+    syntax: LexicalSyntax,
+    in_block_comment: bool,
+    /// Quote character of the string literal the previous line left open
+    in_string: Option<char>,
+}
+
+impl CodeScanner {
+    fn new(syntax: LexicalSyntax) -> Self {
+        Self {
+            syntax,
+            ..Self::default()
+        }
+    }
 
-    if let Ok(entries) = std::fs::read_dir(path) {
-        for entry in entries.flatten() {
-            let file_path = entry.path();
-            if file_path.is_file() && is_source_file(&file_path) {
-                if let Ok(content) = std::fs::read_to_string(&file_path) {
-                    for (i, line) in content.lines().enumerate() {
-                        // Detect various code smells
-                        if line.len() > 120 {
-                            smells.push(CodeSmell {
-                                file: file_path.to_string_lossy().to_string(),
-                                line: i + 1,
-                                smell_type: "Long line".to_string(),
-                                description: format!(
-                                    "Line length {} exceeds 120 characters",
-                                    line.len()
-                                ),
-                                severity: "low".to_string(),
-                            });
-                        }
-                        if line.contains("TODO") || line.contains("FIXME") {
-                            smells.push(CodeSmell {
-                                file: file_path.to_string_lossy().to_string(),
-                                line: i + 1,
-                                smell_type: "TODO comment".to_string(),
-                                description: "Unresolved TODO/FIXME comment".to_string(),
-                                severity: "medium".to_string(),
-                            });
-                        }
-                        if line.contains("unwrap()") || line.contains("expect(") {
-                            smells.push(CodeSmell {
-                                file: file_path.to_string_lossy().to_string(),
-                                line: i + 1,
-                                smell_type: "Panic-inducing code".to_string(),
-                                description: "Use of unwrap/expect may cause panic".to_string(),
-                                severity: "high".to_string(),
-                            });
-                        }
+    /// Return the code portion of a line, with comments and literals blanked out
+    fn strip_line(&mut self, line: &str) -> String {
+        let chars: Vec<char> = line.chars().collect();
+        let mut code = String::with_capacity(line.len());
+        let mut i = 0;
+
+        while i < chars.len() {
+            let c = chars[i];
+            let next = chars.get(i + 1).copied();
+
+            if self.in_block_comment {
+                if c == '*' && next == Some('/') {
+                    i += 1;
+                    self.in_block_comment = false;
+                }
+                code.push(' ');
+            } else if let Some(quote) = self.in_string {
+                if c == '\\' {
+                    i += 1;
+                } else if c == quote {
+                    self.in_string = None;
+                }
+                code.push(' ');
+            } else if self.syntax.c_comments && c == '/' && next == Some('/') {
+                break;
+            } else if self.syntax.c_comments && c == '/' && next == Some('*') {
+                i += 1;
+                self.in_block_comment = true;
+                code.push(' ');
+            } else if self.syntax.hash_comments && c == '#' {
+                break;
+            } else if self.syntax.string_quotes.contains(&c) {
+                self.in_string = Some(c);
+                code.push(' ');
+            } else if self.syntax.char_literals && c == '\'' {
+                match char_literal_len(&chars[i..]) {
+                    Some(len) => {
+                        code.push(' ');
+                        i += len - 1;
                     }
+                    None => code.push(c),
                 }
-            } else if file_path.is_dir() && !is_ignored_dir(&file_path) {
-                smells.extend(detect_code_smells(&file_path)?);
+            } else {
+                code.push(c);
             }
+            i += 1;
         }
+
+        code
     }
+}
 
-    Ok(smells)
+/// Length of the character literal at the start of `chars`, if there is one
+///
+/// Matches `'x'` and escapes such as `'\''` or `'\u{1F600}'`; a `'` that does
+/// not close (a Rust lifetime or loop label) is not a literal.
+fn char_literal_len(chars: &[char]) -> Option<usize> {
+    match chars.get(1)? {
+        '\\' => {
+            let close = chars[3.min(chars.len())..]
+                .iter()
+                .position(|&c| c == '\'')?;
+            Some(3 + close + 1)
+        }
+        '\'' => None,
+        _ => (chars.get(2) == Some(&'\'')).then_some(3),
+    }
+}
+
+/// Split code into identifier-like tokens
+fn code_tokens(code: &str) -> impl Iterator<Item = &str> {
+    code.split(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .filter(|token| !token.is_empty())
 }
 
-fn estimate_test_coverage(path: &Path) -> Result<f32> {
-    let source_count = count_source_files(path);
-    let test_count = count_test_files(path);
+fn detect_code_smells(file: &str, content: &str) -> Vec<CodeSmell> {
+    let mut smells = Vec::new();
+
+    for (i, line) in content.lines().enumerate() {
+        // Detect various code smells
+        if line.len() > 120 {
+            smells.push(CodeSmell {
+                file: file.to_string(),
+                line: i + 1,
+                smell_type: "Long line".to_string(),
+                description: format!("Line length {} exceeds 120 characters", line.len()),
+                severity: "low".to_string(),
+            });
+        }
+        if line.contains("TODO") || line.contains("FIXME") {
+            smells.push(CodeSmell {
+                file: file.to_string(),
+                line: i + 1,
+                smell_type: "TODO comment".to_string(),
+                description: "Unresolved TODO/FIXME comment".to_string(),
+                severity: "medium".to_string(),
+            });
+        }
+        if line.contains("unwrap()") || line.contains("expect(") {
+            smells.push(CodeSmell {
+                file: file.to_string(),
+                line: i + 1,
+                smell_type: "Panic-inducing code".to_string(),
+                description: "Use of unwrap/expect may cause panic".to_string(),
+                severity: "high".to_string(),
+            });
+        }
+    }
+
+    smells
+}
+
+fn estimate_test_coverage(scan: &ProjectScan) -> f32 {
+    let source_count = scan.files.len();
 
     if source_count == 0 {
-        return Ok(0.0);
+        return 0.0;
     }
 
     // Simple estimate: test files / source files * 100
-    Ok((test_count as f32 / source_count as f32) * 100.0)
+    (scan.test_files as f32 / source_count as f32) * 100.0
 }
 
-fn analyze_duplication(_path: &Path) -> Result<DuplicationMetrics> {
-    // Simple duplication detection - in production, use a proper algorithm
-    Ok(DuplicationMetrics {
-        duplicated_lines: 0,
-        duplication_percentage: 0.0,
-        duplicated_blocks: Vec::new(),
-    })
+/// Source file reduced to the lines that take part in duplicate detection
+#[derive(Debug, Clone, PartialEq)]
+struct NormalizedFile {
+    path: String,
+    /// `(original line number, line with whitespace removed)`, blank lines skipped
+    lines: Vec<(usize, String)>,
 }
 
-fn scan_technical_debt(path: &Path) -> Result<Vec<DebtItem>> {
-    let mut debt_items = Vec::new();
+impl NormalizedFile {
+    fn new(path: &str, content: &str) -> Self {
+        let lines = content
+            .lines()
+            .enumerate()
+            .map(|(i, line)| (i + 1, line.split_whitespace().collect::<String>()))
+            .filter(|(_, line)| !line.is_empty())
+            .collect();
 
-    if let Ok(entries) = std::fs::read_dir(path) {
-        for entry in entries.flatten() {
-            let file_path = entry.path();
-            if file_path.is_file() && is_source_file(&file_path) {
-                if let Ok(content) = std::fs::read_to_string(&file_path) {
-                    for line in content.lines() {
-                        if line.contains("TODO") {
-                            debt_items.push(DebtItem {
-                                file: file_path.to_string_lossy().to_string(),
-                                category: "Documentation".to_string(),
-                                description: "TODO comment found".to_string(),
-                                effort_hours: 0.5,
-                                priority: "low".to_string(),
-                            });
-                        }
-                        if line.contains("FIXME") {
-                            debt_items.push(DebtItem {
-                                file: file_path.to_string_lossy().to_string(),
-                                category: "Bug".to_string(),
-                                description: "FIXME comment found".to_string(),
-                                effort_hours: 2.0,
-                                priority: "high".to_string(),
-                            });
-                        }
-                        if line.contains("HACK") {
-                            debt_items.push(DebtItem {
-                                file: file_path.to_string_lossy().to_string(),
-                                category: "Refactoring".to_string(),
-                                description: "HACK comment found".to_string(),
-                                effort_hours: 4.0,
-                                priority: "medium".to_string(),
-                            });
-                        }
-                    }
+        Self {
+            path: path.to_string(),
+            lines,
+        }
+    }
+
+    /// Rolling (Rabin-Karp) hashes of every `window`-line window
+    fn window_hashes(&self, window: usize) -> Vec<u64> {
+        if window == 0 || self.lines.len() < window {
+            return Vec::new();
+        }
+
+        let line_hashes: Vec<u64> = self
+            .lines
+            .iter()
+            .map(|(_, line)| {
+                let mut hasher = DefaultHasher::new();
+                line.hash(&mut hasher);
+                hasher.finish()
+            })
+            .collect();
+
+        // Weight of the line leaving the window: BASE^(window - 1)
+        let leading = (1..window).fold(1u64, |acc, _| acc.wrapping_mul(ROLLING_HASH_BASE));
+
+        let mut hash = line_hashes[..window].iter().fold(0u64, |acc, h| {
+            acc.wrapping_mul(ROLLING_HASH_BASE).wrapping_add(*h)
+        });
+        let mut hashes = vec![hash];
+
+        for i in window..line_hashes.len() {
+            hash = hash
+                .wrapping_sub(line_hashes[i - window].wrapping_mul(leading))
+                .wrapping_mul(ROLLING_HASH_BASE)
+                .wrapping_add(line_hashes[i]);
+            hashes.push(hash);
+        }
+
+        hashes
+    }
+
+    fn window_matches(
+        &self,
+        start: usize,
+        other: &Self,
+        other_start: usize,
+        window: usize,
+    ) -> bool {
+        self.lines[start..start + window]
+            .iter()
+            .zip(&other.lines[other_start..other_start + window])
+            .all(|((_, a), (_, b))| a == b)
+    }
+}
+
+fn analyze_duplication(scan: &ProjectScan, window: usize) -> DuplicationMetrics {
+    let files: Vec<&NormalizedFile> = scan.files.iter().map(|f| &f.normalized).collect();
+    find_duplicates(&files, window)
+}
+
+/// Find blocks of at least `window` lines shared between different files
+///
+/// Matching windows are found by rolling hash, verified line by line, and
+/// consecutive matches between the same pair of files are merged into one block.
+fn find_duplicates(files: &[&NormalizedFile], window: usize) -> DuplicationMetrics {
+    let mut windows: HashMap<u64, Vec<(usize, usize)>> = HashMap::new();
+    for (file_idx, file) in files.iter().enumerate() {
+        for (start, hash) in file.window_hashes(window).into_iter().enumerate() {
+            windows.entry(hash).or_default().push((file_idx, start));
+        }
+    }
+
+    // (file1, start1, file2, start2) with file1 < file2
+    let mut matches: Vec<(usize, usize, usize, usize)> = Vec::new();
+    for positions in windows.values() {
+        for (i, &(fa, sa)) in positions.iter().enumerate() {
+            for &(fb, sb) in &positions[i + 1..] {
+                if fa != fb && files[fa].window_matches(sa, files[fb], sb, window) {
+                    matches.push(if fa < fb {
+                        (fa, sa, fb, sb)
+                    } else {
+                        (fb, sb, fa, sa)
+                    });
                 }
-            } else if file_path.is_dir() && !is_ignored_dir(&file_path) {
-                debt_items.extend(scan_technical_debt(&file_path)?);
             }
         }
     }
+    matches.sort_unstable();
+
+    let found: HashSet<_> = matches.iter().copied().collect();
+    let mut merged = HashSet::new();
+    let mut duplicated: HashSet<(usize, usize)> = HashSet::new();
+    let mut blocks = Vec::new();
+
+    for &(fa, sa, fb, sb) in &matches {
+        if merged.contains(&(fa, sa, fb, sb)) {
+            continue;
+        }
+
+        let mut run = 1;
+        while found.contains(&(fa, sa + run, fb, sb + run)) {
+            merged.insert((fa, sa + run, fb, sb + run));
+            run += 1;
+        }
+
+        let lines = window + run - 1;
+        for offset in 0..lines {
+            duplicated.insert((fa, sa + offset));
+            duplicated.insert((fb, sb + offset));
+        }
+        blocks.push(DuplicatedBlock {
+            file1: files[fa].path.clone(),
+            file2: files[fb].path.clone(),
+            line1: files[fa].lines[sa].0,
+            line2: files[fb].lines[sb].0,
+            lines,
+        });
+    }
+
+    let total_lines: usize = files.iter().map(|f| f.lines.len()).sum();
+    let duplication_percentage = if total_lines > 0 {
+        duplicated.len() as f32 / total_lines as f32 * 100.0
+    } else {
+        0.0
+    };
+
+    DuplicationMetrics {
+        duplicated_lines: duplicated.len(),
+        duplication_percentage,
+        duplicated_blocks: blocks,
+    }
+}
+
+/// Find `TODO`, `FIXME` and `HACK` markers in a file's content
+pub fn scan_technical_debt(file: &str, content: &str) -> Vec<DebtItem> {
+    let mut debt_items = Vec::new();
+
+    for (index, line) in content.lines().enumerate() {
+        let item = |marker: &str, category: &str, effort_hours: f32, priority: &str| DebtItem {
+            file: file.to_string(),
+            line: index + 1,
+            comment: comment_after(line, marker),
+            category: category.to_string(),
+            description: format!("{} comment found", marker),
+            effort_hours,
+            priority: priority.to_string(),
+        };
+
+        if line.contains("TODO") {
+            debt_items.push(item("TODO", "Documentation", 0.5, "low"));
+        }
+        if line.contains("FIXME") {
+            debt_items.push(item("FIXME", "Bug", 2.0, "high"));
+        }
+        if line.contains("HACK") {
+            debt_items.push(item("HACK", "Refactoring", 4.0, "medium"));
+        }
+    }
+
+    debt_items
+}
+
+/// Text following a debt marker, without an `(owner)` and leading `:`
+fn comment_after(line: &str, marker: &str) -> String {
+    let Some((_, rest)) = line.split_once(marker) else {
+        return String::new();
+    };
 
-    Ok(debt_items)
+    let rest = rest.trim_start();
+    let rest = match rest.strip_prefix('(').and_then(|r| r.split_once(')')) {
+        Some((_, after_owner)) => after_owner,
+        None => rest,
+    };
+    rest.trim_start_matches([':', '-'])
+        .trim()
+        .trim_end_matches("*/")
+        .trim_end()
+        .to_string()
 }
 
 fn categorize_debt(items: &[DebtItem]) -> HashMap<String, f32> {
@@ -708,182 +1407,247 @@ fn categorize_debt(items: &[DebtItem]) -> HashMap<String, f32> {
 }
 
 // Summary generation functions
-fn get_structure_summary(path: &Path) -> Result<StructureSummary> {
-    let modules = scan_modules(path)?;
-    let deps = analyze_dependencies(&modules)?;
+fn get_structure_summary(scan: &ProjectScan) -> StructureSummary {
+    let deps: Vec<DependencyInfo> = scan
+        .files
+        .iter()
+        .flat_map(|f| f.dependencies.iter().cloned())
+        .collect();
     let circular = detect_circular_dependencies(&deps);
 
-    Ok(StructureSummary {
-        modules_count: modules.len(),
-        max_depth: calculate_max_depth(path),
+    StructureSummary {
+        modules_count: scan.files.len(),
+        max_depth: scan.max_depth,
         circular_deps: circular.len(),
-    })
+    }
 }
 
-fn get_performance_summary(path: &Path) -> Result<PerformanceSummary> {
-    let hotspots = detect_performance_hotspots(path)?;
+fn get_performance_summary(scan: &ProjectScan) -> PerformanceSummary {
+    let hotspots = scan.hot_spots();
     let critical = hotspots.iter().filter(|h| h.severity == "high").count();
 
-    Ok(PerformanceSummary {
+    PerformanceSummary {
         hot_spots_count: hotspots.len(),
         critical_issues: critical,
-    })
+    }
 }
 
-fn get_quality_summary(path: &Path) -> Result<QualitySummary> {
-    let complexity = calculate_complexity_metrics(path)?;
-    let smells = detect_code_smells(path)?;
-    let coverage = estimate_test_coverage(path)?;
+fn get_quality_summary(scan: &ProjectScan) -> QualitySummary {
+    let complexity = calculate_complexity_metrics(scan);
+    let coverage = estimate_test_coverage(scan);
 
-    Ok(QualitySummary {
+    QualitySummary {
         avg_complexity: complexity.cyclomatic_avg,
-        code_smells_count: smells.len(),
+        code_smells_count: scan.files.iter().map(|f| f.code_smells.len()).sum(),
         test_coverage: coverage,
-    })
+    }
 }
 
-fn get_debt_summary(path: &Path) -> Result<DebtSummary> {
-    let items = scan_technical_debt(path)?;
+fn get_debt_summary(scan: &ProjectScan) -> DebtSummary {
+    let items = scan.debt_items();
     let high_priority = items.iter().filter(|i| i.priority == "high").count();
     let total: f32 = items.iter().map(|i| i.effort_hours).sum();
 
-    Ok(DebtSummary {
+    DebtSummary {
         total_hours: total,
         high_priority_items: high_priority,
-    })
+    }
 }
 
 // Utility functions
-fn is_source_file(path: &Path) -> bool {
-    if let Some(ext) = path.extension() {
-        matches!(
-            ext.to_str(),
-            Some("rs") | Some("js") | Some("ts") | Some("py") | Some("go")
-        )
-    } else {
-        false
-    }
-}
+/// Extensions used when neither `--lang` nor the detected project type gives any
+const DEFAULT_SOURCE_EXTENSIONS: &[&str] = &["rs", "js", "ts", "py", "go"];
+
+/// Files and directories that analysis scans
+///
+/// Files are selected by extension; paths are skipped when they are in the
+/// built-in ignore list, match `[analysis].ignore`, or match the project's
+/// `.gitignore`.
+#[derive(Debug, Clone)]
+struct SourceFilter {
+    extensions: HashSet<String>,
+    gitignore: Option<Gitignore>,
+    /// Patterns from `[analysis].ignore`, in `.gitignore` syntax
+    ignore: Option<Gitignore>,
+}
+
+impl SourceFilter {
+    /// Build the filter from `--lang` values, or from the detected project type
+    ///
+    /// When `use_gitignore` is set, the `.gitignore` at `path` (if any) is applied.
+    fn resolve(langs: &[String], path: &Path, use_gitignore: bool) -> Self {
+        let filter = if !langs.is_empty() {
+            Self::new(langs.iter().map(String::as_str))
+        } else {
+            let project_type = ProjectDetector::new(Some(path))
+                .map(|detector| detector.project_type())
+                .unwrap_or(ProjectType::Unknown);
+            match project_type.extensions() {
+                [] => Self::new(DEFAULT_SOURCE_EXTENSIONS.iter().copied()),
+                extensions => Self::new(extensions.iter().copied()),
+            }
+        };
 
-fn is_ignored_dir(path: &Path) -> bool {
-    if let Some(name) = path.file_name() {
-        let name = name.to_string_lossy();
-        matches!(
-            name.as_ref(),
-            "target" | "node_modules" | ".git" | "dist" | "build"
-        )
-    } else {
-        false
+        if use_gitignore {
+            filter.with_gitignore(path)
+        } else {
+            filter
+        }
     }
-}
-
-fn count_source_files(path: &Path) -> usize {
-    let mut count = 0;
 
-    if let Ok(entries) = std::fs::read_dir(path) {
-        for entry in entries.flatten() {
-            let path = entry.path();
-            if path.is_file() && is_source_file(&path) {
-                count += 1;
-            } else if path.is_dir() && !is_ignored_dir(&path) {
-                count += count_source_files(&path);
-            }
+    fn new<'a>(extensions: impl IntoIterator<Item = &'a str>) -> Self {
+        Self {
+            extensions: extensions
+                .into_iter()
+                .map(|ext| ext.trim().trim_start_matches('.').to_lowercase())
+                .filter(|ext| !ext.is_empty())
+                .collect(),
+            gitignore: None,
+            ignore: None,
         }
     }
 
-    count
-}
-
-fn count_test_files(path: &Path) -> usize {
-    let mut count = 0;
-
-    if let Ok(entries) = std::fs::read_dir(path) {
-        for entry in entries.flatten() {
-            let path = entry.path();
-            if path.is_file() {
-                if let Some(name) = path.file_name() {
-                    if name.to_string_lossy().contains("test")
-                        || name.to_string_lossy().contains("spec")
-                    {
-                        count += 1;
-                    }
-                }
-            } else if path.is_dir() && !is_ignored_dir(&path) {
-                count += count_test_files(&path);
+    /// Also skip paths matching the configured ignore patterns, relative to `root`
+    ///
+    /// Invalid patterns are skipped; `config validate` reports them.
+    fn with_ignore_patterns(mut self, root: &Path, patterns: &[String]) -> Self {
+        if !patterns.is_empty() {
+            let mut builder = GitignoreBuilder::new(root);
+            for pattern in patterns {
+                let _ = builder.add_line(None, pattern);
             }
+            self.ignore = builder.build().ok();
         }
+        self
     }
 
-    count
-}
-
-fn count_total_lines(path: &Path) -> Result<usize> {
-    let mut total = 0;
-
-    if let Ok(entries) = std::fs::read_dir(path) {
-        for entry in entries.flatten() {
-            let path = entry.path();
-            if path.is_file() && is_source_file(&path) {
-                if let Ok(content) = std::fs::read_to_string(&path) {
-                    total += content.lines().count();
-                }
-            } else if path.is_dir() && !is_ignored_dir(&path) {
-                total += count_total_lines(&path)?;
+    /// Apply the `.gitignore` at the project root, if it exists and parses
+    fn with_gitignore(mut self, root: &Path) -> Self {
+        let gitignore_path = root.join(".gitignore");
+        if gitignore_path.is_file() {
+            let mut builder = GitignoreBuilder::new(root);
+            if builder.add(&gitignore_path).is_none() {
+                self.gitignore = builder.build().ok();
             }
         }
+        self
     }
 
-    Ok(total)
-}
-
-fn detect_languages(path: &Path) -> Result<HashMap<String, usize>> {
-    let mut langs = HashMap::new();
+    fn is_gitignored(&self, path: &Path, is_dir: bool) -> bool {
+        [&self.gitignore, &self.ignore]
+            .into_iter()
+            .flatten()
+            .any(|matcher| {
+                path.starts_with(matcher.path())
+                    && matcher
+                        .matched_path_or_any_parents(path, is_dir)
+                        .is_ignore()
+            })
+    }
 
-    if let Ok(entries) = std::fs::read_dir(path) {
-        for entry in entries.flatten() {
-            let file_path = entry.path();
-            if file_path.is_file() {
-                if let Some(ext) = file_path.extension() {
-                    let ext = ext.to_string_lossy().to_string();
-                    *langs.entry(ext).or_insert(0) += 1;
-                }
-            } else if file_path.is_dir() && !is_ignored_dir(&file_path) {
-                for (lang, count) in detect_languages(&file_path)? {
-                    *langs.entry(lang).or_insert(0) += count;
-                }
-            }
-        }
+    fn matches(&self, path: &Path) -> bool {
+        path.extension()
+            .map(|ext| {
+                self.extensions
+                    .contains(&ext.to_string_lossy().to_lowercase())
+            })
+            .unwrap_or(false)
+            && !self.is_gitignored(path, false)
     }
 
-    Ok(langs)
+    /// Whether scanning should descend into a directory
+    fn includes_dir(&self, path: &Path) -> bool {
+        !is_ignored_dir(path) && !self.is_gitignored(path, true)
+    }
 }
 
-fn count_languages(modules: &[ModuleInfo]) -> HashMap<String, usize> {
-    let mut langs = HashMap::new();
-
-    for module in modules {
-        if let Some(ext) = PathBuf::from(&module.path).extension() {
-            let ext = ext.to_string_lossy().to_string();
-            *langs.entry(ext).or_insert(0) += 1;
-        }
+fn is_ignored_dir(path: &Path) -> bool {
+    if let Some(name) = path.file_name() {
+        let name = name.to_string_lossy();
+        matches!(
+            name.as_ref(),
+            "target" | "node_modules" | ".git" | "dist" | "build"
+        )
+    } else {
+        false
     }
+}
+
+/// Source files and directory shape found by one pass over the tree
+#[derive(Debug, Default)]
+struct Walk {
+    /// Files accepted by the filter, sorted by path
+    source_files: Vec<PathBuf>,
+    test_files: usize,
+    max_depth: usize,
+}
 
-    langs
+/// Source files below `root` for the detected project type
+///
+/// Skips ignored build directories and anything matched by `.gitignore`.
+pub fn source_files(root: &Path) -> Vec<PathBuf> {
+    walk(root, &SourceFilter::resolve(&[], root, true)).source_files
 }
 
-fn calculate_max_depth(path: &Path) -> usize {
-    let mut max_depth = 0;
+/// Keep only the source files changed since the latest tag
+///
+/// Returns the tag, or None (leaving the walk untouched) when the repository
+/// has no tags. `root` may be a directory below the repository root. Directory
+/// statistics still describe the whole tree.
+fn restrict_to_tag(walk: &mut Walk, git: &GitUtils, root: &Path) -> Result<Option<String>> {
+    let Some(tag) = git.latest_tag()? else {
+        return Ok(None);
+    };
+
+    // Changed files are relative to the repository root
+    let subdir = root
+        .canonicalize()?
+        .strip_prefix(git.workdir()?.canonicalize()?)
+        .map(Path::to_path_buf)
+        .unwrap_or_default();
+    let changed: HashSet<PathBuf> = git
+        .files_changed_since(&tag)?
+        .into_iter()
+        .map(PathBuf::from)
+        .collect();
+    walk.source_files.retain(|path| {
+        path.strip_prefix(root)
+            .is_ok_and(|relative| changed.contains(&subdir.join(relative)))
+    });
+
+    Ok(Some(tag))
+}
+
+/// Walk the tree below `path` once, skipping directories the filter excludes
+fn walk(path: &Path, filter: &SourceFilter) -> Walk {
+    let mut walk = Walk::default();
+    walk_dir(path, filter, 0, &mut walk);
+    walk.source_files.sort();
+    walk
+}
+
+fn walk_dir(path: &Path, filter: &SourceFilter, depth: usize, walk: &mut Walk) {
+    let Ok(entries) = std::fs::read_dir(path) else {
+        return;
+    };
 
-    if let Ok(entries) = std::fs::read_dir(path) {
-        for entry in entries.flatten() {
-            let path = entry.path();
-            if path.is_dir() && !is_ignored_dir(&path) {
-                max_depth = max_depth.max(1 + calculate_max_depth(&path));
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_file() {
+            if let Some(name) = path.file_name() {
+                let name = name.to_string_lossy();
+                if name.contains("test") || name.contains("spec") {
+                    walk.test_files += 1;
+                }
+            }
+            if filter.matches(&path) {
+                walk.source_files.push(path);
             }
+        } else if path.is_dir() && filter.includes_dir(&path) {
+            walk.max_depth = walk.max_depth.max(depth + 1);
+            walk_dir(&path, filter, depth + 1, walk);
         }
     }
-
-    max_depth
 }
 
 fn count_exports(content: &str) -> usize {
@@ -919,20 +1683,24 @@ fn extract_capacity(line: &str) -> Option<usize> {
         .ok()
 }
 
-fn count_control_flow_keywords(line: &str) -> usize {
-    let keywords = ["if", "else", "match", "loop", "while", "for"];
-    keywords.iter().filter(|k| line.contains(*k)).count()
+/// Count whole-word control-flow keywords in code stripped by [`CodeScanner`]
+fn count_control_flow_keywords(code: &str) -> usize {
+    code_tokens(code)
+        .filter(|token| matches!(*token, "if" | "else" | "match" | "loop" | "while" | "for"))
+        .count()
 }
 
-fn calculate_maintainability_index(avg_complexity: f32) -> f32 {
-    // Simplified MI calculation: 171 - 5.2 * ln(V) - 0.23 * G - 16.2 * ln(LOC)
-    // Higher is better (0-100 scale)
-    if avg_complexity < 1.0 {
-        return 100.0;
-    }
-
-    let base = 100.0 - (avg_complexity * 5.0);
-    base.clamp(0.0, 100.0)
+/// Maintainability index, rescaled from the classic formula to 0..100
+///
+/// MI = 171 - 5.2 * ln(V) - 0.23 * G - 16.2 * ln(LOC), where V is the
+/// Halstead volume, G the cyclomatic complexity and LOC the line count,
+/// multiplied by 100 / 171. Higher is better.
+fn maintainability_index(volume: f64, complexity: usize, lines: usize) -> f32 {
+    let mi = 171.0
+        - 5.2 * volume.max(1.0).ln()
+        - 0.23 * complexity as f64
+        - 16.2 * (lines.max(1) as f64).ln();
+    (mi * 100.0 / 171.0).clamp(0.0, 100.0) as f32
 }
 
 // Score calculation functions
@@ -1011,6 +1779,13 @@ fn output_text(result: &AnalysisResult, output: &OutputHandler) {
         "count",
         &result.summary.total_lines.to_string(),
     ));
+    if result.summary.skipped_files > 0 {
+        output.info(&output.t_format(
+            "analyze-skipped-files",
+            "count",
+            &result.summary.skipped_files.to_string(),
+        ));
+    }
     output.info(&output.t_format(
         "analyze-languages",
         "langs",
@@ -1081,8 +1856,7 @@ fn output_performance_text(performance: &PerformanceAnalysis, output: &OutputHan
                 .replace("{severity}", &hotspot.severity)
                 .replace("{file}", &hotspot.file)
                 .replace("{line}", &hotspot.line.to_string())
-                .replace("{issue}", &hotspot.issue)
-                .to_string(),
+                .replace("{issue}", &hotspot.issue),
         );
     }
 
@@ -1122,6 +1896,17 @@ fn output_quality_text(quality: &QualityAnalysis, output: &OutputHandler) {
         "count",
         &quality.code_smells.len().to_string(),
     ));
+    output.info(&output.t_format(
+        "analyze-duplication",
+        "percent",
+        &format!("{:.1}", quality.duplication.duplication_percentage),
+    ));
+    for block in &quality.duplication.duplicated_blocks {
+        output.list_item(&format!(
+            "{}:{} <-> {}:{} ({} lines)",
+            block.file1, block.line1, block.file2, block.line2, block.lines
+        ));
+    }
 }
 
 fn output_debt_text(debt: &DebtAnalysis, output: &OutputHandler) {
@@ -1191,15 +1976,22 @@ fn output_overview_text(overview: &OverviewAnalysis, output: &OutputHandler) {
 }
 
 fn output_json(result: &AnalysisResult, output: &OutputHandler) -> Result<()> {
-    let json = serde_json::to_string_pretty(result)?;
-    output.info(&json);
+    output.report(&format!("{}\n", serde_json::to_string_pretty(result)?));
     Ok(())
 }
 
-fn output_html(result: &AnalysisResult, output: &OutputHandler) -> Result<()> {
-    let html = format!(
-        r#"
-<!DOCTYPE html>
+/// Write the HTML report to stdout as-is, so it can be redirected into a file
+fn output_html(result: &AnalysisResult, output: &OutputHandler) {
+    output.report(&format!("{}\n", render_html(result)));
+}
+
+fn render_html(result: &AnalysisResult) -> String {
+    if let AnalysisDetails::Overview(overview) = &result.details {
+        return output_html_overview(overview, &result.summary);
+    }
+
+    format!(
+        r#"<!DOCTYPE html>
 <html>
 <head>
     <title>Analysis Report - {}</title>
@@ -1222,15 +2014,979 @@ fn output_html(result: &AnalysisResult, output: &OutputHandler) -> Result<()> {
 </body>
 </html>
 "#,
-        result.target,
-        result.target,
-        result.timestamp,
+        escape_html(&result.target),
+        escape_html(&result.target),
+        escape_html(&result.timestamp),
         result.summary.total_files,
         result.summary.total_lines,
         result.summary.issues_found,
         result.summary.overall_score
+    )
+}
+
+/// Render the overview as a self-contained HTML page with inline SVG charts
+///
+/// The page has no scripts or external resources so it can be viewed offline.
+fn output_html_overview(overview: &OverviewAnalysis, summary: &AnalysisSummary) -> String {
+    let mut languages: Vec<_> = summary.languages.iter().collect();
+    languages.sort();
+    let languages = languages
+        .iter()
+        .map(|(lang, count)| format!("{} ({})", escape_html(lang), count))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let charts = [
+        svg_bar_chart(
+            "Structure",
+            &[
+                ("Modules", overview.structure.modules_count as f32),
+                ("Max depth", overview.structure.max_depth as f32),
+                ("Circular deps", overview.structure.circular_deps as f32),
+            ],
+        ),
+        svg_bar_chart(
+            "Performance",
+            &[
+                ("Hot spots", overview.performance.hot_spots_count as f32),
+                ("Critical", overview.performance.critical_issues as f32),
+            ],
+        ),
+        svg_bar_chart(
+            "Quality",
+            &[
+                ("Avg complexity", overview.quality.avg_complexity),
+                ("Code smells", overview.quality.code_smells_count as f32),
+                ("Test coverage %", overview.quality.test_coverage),
+            ],
+        ),
+        svg_bar_chart(
+            "Technical Debt",
+            &[
+                ("Debt hours", overview.debt.total_hours),
+                ("High priority", overview.debt.high_priority_items as f32),
+            ],
+        ),
+    ]
+    .join("\n");
+
+    format!(
+        r#"<!DOCTYPE html>
+<html>
+<head>
+    <meta charset="utf-8">
+    <title>Analysis Report - Overview</title>
+    <style>
+        body {{ font-family: Arial, sans-serif; margin: 20px; color: #333; }}
+        .summary {{ background: #f5f5f5; padding: 15px; border-radius: 5px; }}
+        .score {{ font-size: 2em; color: #4CAF50; }}
+        .charts {{ display: flex; flex-wrap: wrap; gap: 20px; margin-top: 20px; }}
+        .chart {{ background: #fafafa; border: 1px solid #ddd; border-radius: 5px; padding: 10px; }}
+    </style>
+</head>
+<body>
+    <h1>Overview Analysis Report</h1>
+    <div class="summary">
+        <p><strong>Total Files:</strong> {}</p>
+        <p><strong>Total Lines:</strong> {}</p>
+        <p><strong>Languages:</strong> {}</p>
+        <p><strong>Issues Found:</strong> {}</p>
+        <p class="score">Overall Score: {:.1}/10</p>
+    </div>
+    <div class="charts">
+{}
+    </div>
+</body>
+</html>
+"#,
+        summary.total_files,
+        summary.total_lines,
+        languages,
+        summary.issues_found,
+        summary.overall_score,
+        charts
+    )
+}
+
+/// Horizontal bar chart as an inline SVG element
+///
+/// Bars are scaled to the largest value in the chart and labelled with their
+/// value (whole numbers without decimals).
+fn svg_bar_chart(title: &str, bars: &[(&str, f32)]) -> String {
+    const LABEL_WIDTH: usize = 130;
+    const BAR_WIDTH: f32 = 220.0;
+    const ROW_HEIGHT: usize = 28;
+    const TOP: usize = 30;
+
+    let max = bars.iter().map(|(_, v)| *v).fold(0.0f32, f32::max);
+    let height = TOP + bars.len() * ROW_HEIGHT + 10;
+    let width = LABEL_WIDTH + BAR_WIDTH as usize + 60;
+
+    let mut svg = format!(
+        r#"<div class="chart"><svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" role="img" aria-label="{title}">
+<text x="0" y="18" font-weight="bold">{title}</text>
+"#,
+        width = width,
+        height = height,
+        title = escape_html(title),
     );
 
-    output.info(&html);
+    for (i, (label, value)) in bars.iter().enumerate() {
+        let y = TOP + i * ROW_HEIGHT;
+        let bar = if max > 0.0 {
+            (value.max(0.0) / max * BAR_WIDTH).round()
+        } else {
+            0.0
+        };
+        let display = if value.fract() == 0.0 {
+            format!("{}", *value as i64)
+        } else {
+            format!("{:.1}", value)
+        };
+
+        svg.push_str(&format!(
+            r##"<text x="0" y="{text_y}">{label}</text>
+<rect x="{LABEL_WIDTH}" y="{y}" width="{bar}" height="18" fill="#4CAF50"/>
+<text x="{value_x}" y="{text_y}">{display}</text>
+"##,
+            text_y = y + 14,
+            label = escape_html(label),
+            value_x = LABEL_WIDTH as f32 + bar + 6.0,
+        ));
+    }
+
+    svg.push_str("</svg></div>");
+    svg
+}
+
+/// Escape text for use in HTML content and attribute values
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Write the Markdown report to stdout as-is, so it can be redirected into a file
+fn output_markdown(result: &AnalysisResult, output: &OutputHandler) -> Result<()> {
+    output.report(&render_markdown(result)?);
     Ok(())
 }
+
+/// Render an analysis result as Markdown suitable for pasting into a PR
+fn render_markdown(result: &AnalysisResult) -> Result<String> {
+    let mut md = String::new();
+    let summary = &result.summary;
+
+    writeln!(
+        md,
+        "# {} Analysis Report\n",
+        escape_markdown(&result.target)
+    )?;
+    writeln!(md, "_Generated: {}_\n", escape_markdown(&result.timestamp))?;
+
+    let mut languages: Vec<_> = summary.languages.iter().collect();
+    languages.sort();
+    let languages = languages
+        .iter()
+        .map(|(lang, count)| format!("{} ({})", escape_markdown(lang), count))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    writeln!(md, "## Summary\n")?;
+    writeln!(md, "| Metric | Value |")?;
+    writeln!(md, "|--------|-------|")?;
+    writeln!(md, "| Total files | {} |", summary.total_files)?;
+    writeln!(md, "| Total lines | {} |", summary.total_lines)?;
+    if summary.skipped_files > 0 {
+        writeln!(md, "| Skipped files | {} |", summary.skipped_files)?;
+    }
+    writeln!(md, "| Languages | {} |", languages)?;
+    writeln!(md, "| Issues found | {} |", summary.issues_found)?;
+    writeln!(md, "| Overall score | {:.1}/10 |", summary.overall_score)?;
+
+    match &result.details {
+        AnalysisDetails::Structure(structure) => {
+            writeln!(md, "\n## Structure\n")?;
+            writeln!(md, "- Modules: {}", structure.modules.len())?;
+            writeln!(md, "- Max depth: {}", structure.depth)?;
+            writeln!(md, "- Dependencies: {}", structure.dependencies.len())?;
+            if !structure.circular_dependencies.is_empty() {
+                writeln!(md, "\n### Circular dependencies\n")?;
+                for dep in &structure.circular_dependencies {
+                    writeln!(md, "- {}", escape_markdown(dep))?;
+                }
+            }
+        }
+        AnalysisDetails::Performance(performance) => {
+            writeln!(md, "\n## Performance\n")?;
+            writeln!(md, "- Hot spots: {}", performance.hot_spots.len())?;
+            writeln!(
+                md,
+                "- Large allocations: {}",
+                performance.memory_usage.large_allocations.len()
+            )?;
+            if !performance.hot_spots.is_empty() {
+                writeln!(md, "\n### Hot spots\n")?;
+                for hotspot in &performance.hot_spots {
+                    writeln!(
+                        md,
+                        "- **{}** {}:{} - {}",
+                        escape_markdown(&hotspot.severity),
+                        escape_markdown(&hotspot.file),
+                        hotspot.line,
+                        escape_markdown(&hotspot.issue)
+                    )?;
+                }
+            }
+            if !performance.optimization_suggestions.is_empty() {
+                writeln!(md, "\n### Optimization suggestions\n")?;
+                for suggestion in &performance.optimization_suggestions {
+                    writeln!(md, "- {}", escape_markdown(suggestion))?;
+                }
+            }
+        }
+        AnalysisDetails::Quality(quality) => {
+            let complexity = &quality.complexity_metrics;
+            writeln!(md, "\n## Quality\n")?;
+            writeln!(md, "- Average complexity: {:.1}", complexity.cyclomatic_avg)?;
+            writeln!(md, "- Max complexity: {}", complexity.cyclomatic_max)?;
+            writeln!(
+                md,
+                "- Maintainability index: {:.1}",
+                complexity.maintainability_index
+            )?;
+            writeln!(md, "- Test coverage: {:.1}%", quality.test_coverage)?;
+            writeln!(
+                md,
+                "- Duplication: {:.1}%",
+                quality.duplication.duplication_percentage
+            )?;
+            if !quality.code_smells.is_empty() {
+                writeln!(md, "\n### Code smells\n")?;
+                for smell in &quality.code_smells {
+                    writeln!(
+                        md,
+                        "- **{}** {}:{} - {}",
+                        escape_markdown(&smell.severity),
+                        escape_markdown(&smell.file),
+                        smell.line,
+                        escape_markdown(&smell.description)
+                    )?;
+                }
+            }
+            if !quality.duplication.duplicated_blocks.is_empty() {
+                writeln!(md, "\n### Duplicated blocks\n")?;
+                for block in &quality.duplication.duplicated_blocks {
+                    writeln!(
+                        md,
+                        "- {}:{} and {}:{} ({} lines)",
+                        escape_markdown(&block.file1),
+                        block.line1,
+                        escape_markdown(&block.file2),
+                        block.line2,
+                        block.lines
+                    )?;
+                }
+            }
+        }
+        AnalysisDetails::Debt(debt) => {
+            writeln!(md, "\n## Technical Debt\n")?;
+            writeln!(md, "- Total debt: {:.1} hours", debt.total_debt_hours)?;
+            writeln!(md, "- Debt items: {}", debt.debt_items.len())?;
+            if !debt.debt_by_category.is_empty() {
+                let mut categories: Vec<_> = debt.debt_by_category.iter().collect();
+                categories.sort_by(|a, b| a.0.cmp(b.0));
+
+                writeln!(md, "\n### Debt by category\n")?;
+                for (category, hours) in categories {
+                    writeln!(md, "- {}: {:.1} hours", escape_markdown(category), hours)?;
+                }
+            }
+            if !debt.debt_items.is_empty() {
+                writeln!(md, "\n### Debt items\n")?;
+                for item in &debt.debt_items {
+                    writeln!(
+                        md,
+                        "- **{}** {} - {}",
+                        escape_markdown(&item.priority),
+                        escape_markdown(&item.file),
+                        escape_markdown(&item.description)
+                    )?;
+                }
+            }
+        }
+        AnalysisDetails::Overview(overview) => {
+            writeln!(md, "\n## Overview\n")?;
+            writeln!(
+                md,
+                "- Structure: {} modules, max depth {}, {} circular dependencies",
+                overview.structure.modules_count,
+                overview.structure.max_depth,
+                overview.structure.circular_deps
+            )?;
+            writeln!(
+                md,
+                "- Performance: {} hot spots, {} critical",
+                overview.performance.hot_spots_count, overview.performance.critical_issues
+            )?;
+            writeln!(
+                md,
+                "- Quality: average complexity {:.1}, {} code smells, {:.1}% test coverage",
+                overview.quality.avg_complexity,
+                overview.quality.code_smells_count,
+                overview.quality.test_coverage
+            )?;
+            writeln!(
+                md,
+                "- Debt: {:.1} hours, {} high priority items",
+                overview.debt.total_hours, overview.debt.high_priority_items
+            )?;
+        }
+    }
+
+    Ok(md)
+}
+
+/// Escape text so it renders literally in Markdown (including table cells)
+fn escape_markdown(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '|' | '*' | '_' | '`' | '[' | ']' | '\\' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::output::test_support::capture;
+    use std::fs;
+    use tempfile::TempDir;
+
+    const SHARED_BLOCK: &str = "fn shared(values: &[u32]) -> u32 {
+    let mut total = 0;
+    for value in values {
+        if *value > 10 {
+            total += value * 2;
+        }
+    }
+    total
+}
+";
+
+    fn keywords(line: &str) -> usize {
+        count_control_flow_keywords(&CodeScanner::default().strip_line(line))
+    }
+
+    #[test]
+    fn test_count_control_flow_keywords() {
+        assert_eq!(keywords("let uniform = 1;"), 0);
+        assert_eq!(keywords("if x { } else { }"), 2);
+        assert_eq!(keywords("let identifier_if_name = formatter;"), 0);
+        assert_eq!(keywords(r#"let s = "if \"else\" while";"#), 0);
+        assert_eq!(keywords("x += 1; // if this loops forever"), 0);
+        assert_eq!(keywords("for i in v { /* match */ }"), 1);
+    }
+
+    #[test]
+    fn test_char_literals_do_not_open_strings() {
+        let content = "fn quote(x: bool) -> char {\n    let q = '\"';\n    if x { q } else { '\\'' }\n}\nfn label<'a>(s: &'a str) {\n    'outer: while s.is_empty() { break 'outer; }\n}\n";
+        assert_eq!(
+            function_complexities(content, LexicalSyntax::default()),
+            vec![3, 2]
+        );
+    }
+
+    #[test]
+    fn test_hash_comments_and_single_quotes_per_language() {
+        let python = LexicalSyntax::for_path(Path::new("tool.py"));
+        let mut scanner = CodeScanner::new(python);
+        assert_eq!(
+            count_control_flow_keywords(&scanner.strip_line("x = 1  # if this loops")),
+            0
+        );
+        assert_eq!(
+            count_control_flow_keywords(&scanner.strip_line("s = 'if \\' while'")),
+            0
+        );
+        assert_eq!(
+            count_control_flow_keywords(&scanner.strip_line("if s: pass")),
+            1
+        );
+
+        // `#` is an attribute, not a comment, in Rust
+        let mut scanner = CodeScanner::default();
+        assert_eq!(
+            scanner.strip_line("#[cfg(test)] if x {}").trim_end(),
+            "#[cfg(test)] if x {}"
+        );
+    }
+
+    #[test]
+    fn test_halstead_metrics_counts_tokens() {
+        // `"x"` and the comment are skipped
+        let metrics = halstead_metrics(
+            "let x = a + b; // c * d\nlet y = \"x\";\n",
+            LexicalSyntax::default(),
+        );
+        assert_eq!(
+            metrics,
+            HalsteadMetrics {
+                distinct_operators: 4, // let = + ;
+                distinct_operands: 4,  // x a b y
+                total_operators: 7,
+                total_operands: 4,
+            }
+        );
+        assert!((metrics.volume() - 11.0 * 3.0).abs() < 1e-9);
+        assert_eq!(halstead_metrics("", LexicalSyntax::default()).volume(), 0.0);
+    }
+
+    #[test]
+    fn test_maintainability_index_decreases_with_complexity_and_volume() {
+        let small = "fn add(a: i32, b: i32) -> i32 {\n    a + b\n}\n";
+        let branchy = "fn classify(n: i32, limit: i32) -> &'static str {\n    \
+            if n < 0 {\n        return \"negative\";\n    }\n    \
+            for i in 0..limit {\n        if n == i * i {\n            return \"square\";\n        }\n    }\n    \
+            match n % 3 {\n        0 => \"fizz\",\n        _ => \"other\",\n    }\n}\n";
+
+        let mi = |content: &str| {
+            let complexity = function_complexities(content, LexicalSyntax::default())
+                .iter()
+                .sum::<usize>();
+            maintainability_index(
+                halstead_metrics(content, LexicalSyntax::default()).volume(),
+                complexity,
+                content.lines().count(),
+            )
+        };
+
+        assert!(
+            halstead_metrics(branchy, LexicalSyntax::default()).volume()
+                > halstead_metrics(small, LexicalSyntax::default()).volume()
+        );
+        assert!(mi(branchy) < mi(small));
+        assert!(mi(small) <= 100.0);
+
+        // Each factor alone lowers the index
+        assert!(maintainability_index(100.0, 10, 20) < maintainability_index(100.0, 1, 20));
+        assert!(maintainability_index(1000.0, 1, 20) < maintainability_index(100.0, 1, 20));
+        assert_eq!(maintainability_index(1e300, 10_000, 1_000_000), 0.0);
+    }
+
+    #[test]
+    fn test_function_complexities_skip_comments_and_strings() {
+        let content = r#"
+fn simple() -> u32 {
+    let uniform = 1;
+    uniform
+}
+
+/*
+fn commented_out() {
+    if a { } else { }
+}
+*/
+
+fn branching(x: u32) -> &'static str {
+    let message = "if for while
+        match loop";
+    if x > 1 {
+        message
+    } else {
+        "else"
+    }
+}
+"#;
+
+        assert_eq!(
+            function_complexities(content, LexicalSyntax::default()),
+            vec![1, 3]
+        );
+    }
+
+    fn mixed_language_tree() -> TempDir {
+        let temp_dir = TempDir::new().unwrap();
+        fs::create_dir(temp_dir.path().join("lib")).unwrap();
+        fs::write(
+            temp_dir.path().join("Gemfile"),
+            "source 'https://rubygems.org'\n",
+        )
+        .unwrap();
+        fs::write(
+            temp_dir.path().join("app.rb"),
+            "class App\n  def run\n  end\nend\n",
+        )
+        .unwrap();
+        fs::write(
+            temp_dir.path().join("lib/helper.rb"),
+            "module Helper\nend\n",
+        )
+        .unwrap();
+        fs::write(temp_dir.path().join("Main.java"), "class Main {}\n").unwrap();
+        fs::write(temp_dir.path().join("script.py"), "print('hi')\n").unwrap();
+        temp_dir
+    }
+
+    #[test]
+    fn test_source_filter_from_lang() {
+        let temp_dir = mixed_language_tree();
+        let filter = SourceFilter::resolve(&["java".to_string()], temp_dir.path(), true);
+        let scan =
+            scan_project(walk(temp_dir.path(), &filter), 1, &ProgressHandle::hidden()).unwrap();
+
+        assert_eq!(scan.files.len(), 1);
+        assert_eq!(scan.total_lines(), 1);
+        assert_eq!(scan.languages(), HashMap::from([("java".to_string(), 1)]));
+        assert_eq!(scan.files[0].module.name, "Main");
+    }
+
+    #[test]
+    fn test_config_ignore_patterns_extend_defaults() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        for file in [
+            "src/main.rs",
+            "src/vendor/shim.rs",
+            "vendor/dep/lib.rs",
+            "generated/api.rs",
+            "target/debug/build.rs",
+        ] {
+            fs::create_dir_all(root.join(file).parent().unwrap()).unwrap();
+            fs::write(root.join(file), "fn f() {}\n").unwrap();
+        }
+
+        let config: Config =
+            toml::from_str("[analysis]\nignore = [\"vendor\", \"generated/**\"]\n").unwrap();
+        let filter = SourceFilter::new(["rs"]).with_ignore_patterns(root, &config.analysis.ignore);
+
+        // target/ stays ignored by default; vendor/ is skipped at any depth
+        assert_eq!(
+            walk(root, &filter).source_files,
+            vec![root.join("src/main.rs")]
+        );
+        assert_eq!(walk(root, &SourceFilter::new(["rs"])).source_files.len(), 4);
+    }
+
+    #[test]
+    fn test_restrict_to_tag_keeps_changed_files() {
+        use crate::core::git_utils::run_git_checked;
+
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        let git = |args: &[&str]| run_git_checked(root, args, "git").unwrap();
+        let commit = |message: &str| {
+            git(&["add", "-A"]);
+            git(&[
+                "-c",
+                "user.name=Test",
+                "-c",
+                "user.email=test@example.com",
+                "commit",
+                "-q",
+                "-m",
+                message,
+            ]);
+        };
+        git(&["init", "-q"]);
+        fs::create_dir_all(root.join("src")).unwrap();
+        fs::write(root.join("src/stable.rs"), "fn stable() {}\n").unwrap();
+        fs::write(root.join("src/changed.rs"), "fn changed() {}\n").unwrap();
+        commit("initial");
+
+        let filter = SourceFilter::new(["rs"]);
+        let repo = GitUtils::open(root).unwrap();
+        let mut untagged = walk(root, &filter);
+        assert_eq!(restrict_to_tag(&mut untagged, &repo, root).unwrap(), None);
+        assert_eq!(untagged.source_files.len(), 2);
+
+        git(&["tag", "v1.0.0"]);
+        fs::write(root.join("src/changed.rs"), "fn changed() { todo!() }\n").unwrap();
+        commit("change");
+        fs::write(root.join("src/added.rs"), "fn added() {}\n").unwrap();
+        commit("add");
+
+        let mut files = walk(root, &filter);
+        assert_eq!(
+            restrict_to_tag(&mut files, &repo, root).unwrap(),
+            Some("v1.0.0".to_string())
+        );
+        assert_eq!(
+            files.source_files,
+            vec![root.join("src/added.rs"), root.join("src/changed.rs")]
+        );
+
+        // Analyzing a subdirectory finds the repository above it
+        let src = root.join("src");
+        let repo = GitUtils::discover(&src).unwrap();
+        let mut files = walk(&src, &filter);
+        restrict_to_tag(&mut files, &repo, &src).unwrap();
+        assert_eq!(
+            files.source_files,
+            vec![src.join("added.rs"), src.join("changed.rs")]
+        );
+    }
+
+    #[test]
+    fn test_source_filter_from_project_type() {
+        let temp_dir = mixed_language_tree();
+        let filter = SourceFilter::resolve(&[], temp_dir.path(), true);
+
+        // Gemfile makes this a Ruby project
+        assert_eq!(
+            filter.extensions,
+            SourceFilter::new(["rb", "rake"]).extensions
+        );
+        let scan =
+            scan_project(walk(temp_dir.path(), &filter), 1, &ProgressHandle::hidden()).unwrap();
+        assert_eq!(scan.files.len(), 2);
+        assert_eq!(scan.total_lines(), 6);
+    }
+
+    #[test]
+    fn test_source_filter_defaults_for_unknown_project() {
+        let temp_dir = TempDir::new().unwrap();
+        let filter = SourceFilter::resolve(&[], temp_dir.path(), true);
+
+        assert!(filter.matches(Path::new("main.rs")));
+        assert!(filter.matches(Path::new("index.ts")));
+        assert!(!filter.matches(Path::new("App.java")));
+        assert!(SourceFilter::new([".RB"]).matches(Path::new("app.rb")));
+    }
+
+    #[test]
+    fn test_source_filter_respects_gitignore() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        fs::create_dir_all(root.join("src")).unwrap();
+        fs::create_dir_all(root.join("generated/nested")).unwrap();
+        fs::write(root.join(".gitignore"), "generated/\n*.gen.rs\n").unwrap();
+        fs::write(root.join("src/main.rs"), "fn main() {}\n").unwrap();
+        fs::write(root.join("src/schema.gen.rs"), "fn a() {}\nfn b() {}\n").unwrap();
+        fs::write(root.join("generated/api.rs"), "fn api() {}\n").unwrap();
+        fs::write(root.join("generated/nested/types.rs"), "struct T;\n").unwrap();
+
+        let filter = SourceFilter::resolve(&["rs".to_string()], root, true);
+        let scan = scan_project(walk(root, &filter), 1, &ProgressHandle::hidden()).unwrap();
+        assert_eq!(scan.files.len(), 1);
+        assert_eq!(scan.total_lines(), 1);
+        assert_eq!(scan.files[0].module.name, "main");
+        assert_eq!(scan.max_depth, 1);
+
+        let unfiltered = SourceFilter::resolve(&["rs".to_string()], root, false);
+        let scan = scan_project(walk(root, &unfiltered), 1, &ProgressHandle::hidden()).unwrap();
+        assert_eq!(scan.files.len(), 4);
+        assert_eq!(scan.max_depth, 2);
+    }
+
+    #[test]
+    fn test_scan_skips_minified_and_non_utf8_files() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        fs::write(
+            root.join("app.js"),
+            "function add(a, b) {\n  return a + b;\n}\n",
+        )
+        .unwrap();
+        let minified = (0..200)
+            .map(|i| format!("function f{i}(a){{return a+{i}}}"))
+            .collect::<Vec<_>>()
+            .join(";");
+        fs::write(root.join("vendor.min.js"), minified).unwrap();
+        fs::write(root.join("blob.js"), [0x66, 0x6e, 0xff, 0xfe, 0x0a]).unwrap();
+
+        let filter = SourceFilter::new(["js"]);
+        let scan = scan_project(walk(root, &filter), 1, &ProgressHandle::hidden()).unwrap();
+        assert_eq!(scan.files.len(), 1);
+        assert_eq!(scan.files[0].module.name, "app");
+        assert_eq!(scan.total_lines(), 3);
+        assert_eq!(scan.skipped_files, 2);
+    }
+
+    #[test]
+    fn test_parallel_scan_matches_serial() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        for dir in 0..8 {
+            let dir_path = root.join(format!("module_{}", dir)).join("nested");
+            fs::create_dir_all(&dir_path).unwrap();
+            for file in 0..25 {
+                let content = format!(
+                    "use crate::module_{dir};\n\
+                     pub fn handler_{file}(values: &[u32]) -> u32 {{\n\
+                     \x20   // TODO: handle overflow\n\
+                     \x20   let v = values.iter().next().unwrap();\n\
+                     \x20   if *v > {file} {{ v.clone().clone() }} else {{ 0 }}\n\
+                     }}\n\
+                     let buffer = Vec::with_capacity(20000);\n"
+                );
+                fs::write(dir_path.join(format!("file_{}.rs", file)), content).unwrap();
+            }
+        }
+        fs::write(root.join("lib_test.rs"), "fn test_it() {}\n").unwrap();
+
+        let filter = SourceFilter::new(["rs"]);
+
+        let serial = scan_project(walk(root, &filter), 1, &ProgressHandle::hidden()).unwrap();
+        let parallel = scan_project(walk(root, &filter), 4, &ProgressHandle::hidden()).unwrap();
+
+        assert_eq!(serial.files.len(), 201);
+        assert_eq!(parallel, serial);
+        assert!(serial.hot_spots().len() >= 400);
+        assert_eq!(serial.debt_items().len(), 200);
+    }
+
+    fn deps(edges: &[(&str, &str)]) -> Vec<DependencyInfo> {
+        edges
+            .iter()
+            .map(|(from, to)| DependencyInfo {
+                from: from.to_string(),
+                to: to.to_string(),
+                kind: "module".to_string(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_detect_circular_dependencies_three_node_cycle() {
+        let cycles = detect_circular_dependencies(&deps(&[
+            ("config", "loader"),
+            ("loader", "parser"),
+            ("parser", "config"),
+            ("parser", "util"),
+        ]));
+
+        assert_eq!(cycles, vec!["config -> loader -> parser -> config"]);
+    }
+
+    #[test]
+    fn test_detect_circular_dependencies_multiple_cycles() {
+        let cycles = detect_circular_dependencies(&deps(&[
+            ("a", "b"),
+            ("b", "a"),
+            ("x", "y"),
+            ("y", "z"),
+            ("z", "x"),
+            ("z", "a"),
+        ]));
+
+        assert_eq!(cycles, vec!["a -> b -> a", "x -> y -> z -> x"]);
+    }
+
+    #[test]
+    fn test_detect_circular_dependencies_dag() {
+        let cycles = detect_circular_dependencies(&deps(&[
+            ("main", "cli"),
+            ("main", "core"),
+            ("cli", "core"),
+            ("core", "util"),
+            ("cli", "util"),
+            ("util", "util"),
+        ]));
+
+        assert!(cycles.is_empty());
+    }
+
+    #[test]
+    fn test_output_html_overview() {
+        let overview = OverviewAnalysis {
+            structure: StructureSummary {
+                modules_count: 42,
+                max_depth: 5,
+                circular_deps: 1,
+            },
+            performance: PerformanceSummary {
+                hot_spots_count: 17,
+                critical_issues: 3,
+            },
+            quality: QualitySummary {
+                avg_complexity: 3.5,
+                code_smells_count: 23,
+                test_coverage: 64.2,
+            },
+            debt: DebtSummary {
+                total_hours: 12.5,
+                high_priority_items: 4,
+            },
+        };
+        let summary = AnalysisSummary {
+            total_files: 42,
+            total_lines: 3141,
+            languages: HashMap::from([("rs".to_string(), 40), ("<ts>".to_string(), 2)]),
+            issues_found: 41,
+            overall_score: 7.3,
+            skipped_files: 0,
+        };
+
+        let html = output_html_overview(&overview, &summary);
+
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert_eq!(html.matches("<svg").count(), 4);
+        assert!(!html.contains("<script"));
+        assert!(!html.contains("<link"));
+        for value in ["42", "3141", "17", "3.5", "23", "64.2", "12.5", "7.3/10"] {
+            assert!(html.contains(value), "missing {}", value);
+        }
+        assert!(html.contains("&lt;ts&gt; (2)"));
+        assert!(!html.contains("<ts>"));
+    }
+
+    #[test]
+    fn test_render_markdown_quality() {
+        let result = AnalysisResult {
+            target: "Quality".to_string(),
+            timestamp: "2025-01-01T00:00:00Z".to_string(),
+            summary: AnalysisSummary {
+                total_files: 2,
+                total_lines: 40,
+                languages: HashMap::from([("rs".to_string(), 2)]),
+                issues_found: 1,
+                overall_score: 8.5,
+                skipped_files: 0,
+            },
+            details: AnalysisDetails::Quality(QualityAnalysis {
+                complexity_metrics: ComplexityMetrics {
+                    cyclomatic_avg: 2.0,
+                    cyclomatic_max: 4,
+                    cognitive_avg: 2.4,
+                    maintainability_index: 80.0,
+                },
+                code_smells: vec![CodeSmell {
+                    file: "src/<gen>&co|x.rs".to_string(),
+                    line: 12,
+                    smell_type: "TODO comment".to_string(),
+                    description: "Unresolved TODO/FIXME comment".to_string(),
+                    severity: "medium".to_string(),
+                }],
+                test_coverage: 50.0,
+                duplication: DuplicationMetrics {
+                    duplicated_lines: 0,
+                    duplication_percentage: 0.0,
+                    duplicated_blocks: Vec::new(),
+                },
+            }),
+        };
+
+        let md = render_markdown(&result).unwrap();
+        assert!(md.starts_with("# Quality Analysis Report\n"));
+        assert!(md.contains("| Metric | Value |\n|--------|-------|\n"));
+        assert!(md.contains("| Total files | 2 |"));
+        assert!(md.contains("| Overall score | 8.5/10 |"));
+        assert!(md.contains("## Quality"));
+        assert!(md.contains(
+            "- **medium** src/&lt;gen&gt;&amp;co\\|x.rs:12 - Unresolved TODO/FIXME comment"
+        ));
+        assert!(!md.contains("<gen>"));
+
+        // Written through the output handler: stdout even when status
+        // messages go to stderr, and nothing at all in quiet mode
+        let mut output = OutputHandler::default();
+        let (stdout, stderr) = capture(&mut output);
+        output.set_stderr_only(true);
+        output_markdown(&result, &output).unwrap();
+        assert_eq!(stdout.text(), md);
+        assert!(stderr.text().is_empty());
+
+        let mut quiet = OutputHandler::new(false, true, true);
+        let (stdout, _) = capture(&mut quiet);
+        output_markdown(&result, &quiet).unwrap();
+        assert!(stdout.text().is_empty());
+    }
+
+    #[test]
+    fn test_analyze_duplication_finds_shared_block() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(
+            temp_dir.path().join("a.rs"),
+            format!("use std::fmt;\n\n{}\nfn only_a() {{}}\n", SHARED_BLOCK),
+        )
+        .unwrap();
+        // Same block with different indentation and trailing whitespace
+        let reindented: String = SHARED_BLOCK
+            .lines()
+            .map(|line| format!("  {}  \n", line.trim_start()))
+            .collect();
+        fs::write(
+            temp_dir.path().join("b.rs"),
+            format!("fn only_b() -> u8 {{\n    1\n}}\n\n{}", reindented),
+        )
+        .unwrap();
+
+        let metrics = analyze_duplication(
+            &scan_project(
+                walk(temp_dir.path(), &SourceFilter::new(["rs"])),
+                1,
+                &ProgressHandle::hidden(),
+            )
+            .unwrap(),
+            6,
+        );
+
+        assert_eq!(metrics.duplicated_blocks.len(), 1);
+        let block = &metrics.duplicated_blocks[0];
+        assert!(block.file1.ends_with("a.rs"));
+        assert!(block.file2.ends_with("b.rs"));
+        assert_eq!((block.line1, block.line2), (3, 5));
+        assert_eq!(block.lines, 9);
+        assert_eq!(metrics.duplicated_lines, 18);
+        assert!(metrics.duplication_percentage > 0.0);
+    }
+
+    #[test]
+    fn test_analyze_duplication_ignores_unique_files() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(temp_dir.path().join("a.rs"), SHARED_BLOCK).unwrap();
+        fs::write(
+            temp_dir.path().join("b.rs"),
+            SHARED_BLOCK.replace("total", "sum").replace("10", "20"),
+        )
+        .unwrap();
+
+        let metrics = analyze_duplication(
+            &scan_project(
+                walk(temp_dir.path(), &SourceFilter::new(["rs"])),
+                1,
+                &ProgressHandle::hidden(),
+            )
+            .unwrap(),
+            6,
+        );
+        assert!(metrics.duplicated_blocks.is_empty());
+        assert_eq!(metrics.duplication_percentage, 0.0);
+    }
+
+    #[test]
+    fn test_scan_technical_debt_records_line_and_comment() {
+        let content = "fn a() {}\n// TODO(kim): cache results\n/* HACK - skip retry */\n";
+        let items = scan_technical_debt("src/a.rs", content);
+
+        assert_eq!(items.len(), 2);
+        assert_eq!(
+            (items[0].line, items[0].comment.as_str()),
+            (2, "cache results")
+        );
+        assert_eq!(
+            (items[1].line, items[1].comment.as_str()),
+            (3, "skip retry")
+        );
+        assert_eq!(items[1].description, "HACK comment found");
+    }
+}
@@ -1,9 +1,18 @@
 use crate::cli::output::OutputHandler;
 use crate::core::error::Result;
+use crate::core::learning_db::LearningDatabase;
+use crate::core::learning_record_v2::LearningRecordV2;
+use crate::core::learning_record_v3::LearningRecordV3;
 use crate::core::security::SecurePath;
-use std::collections::HashMap;
+use crate::core::LearningSession;
+use std::collections::{HashMap, HashSet};
 use std::path::Path;
 
+/// Maximum number of learning records shown as examples
+const MAX_EXAMPLES: usize = 5;
+/// Maximum length of a problem or solution excerpt, in characters
+const EXCERPT_CHARS: usize = 160;
+
 /// Explanation result
 #[derive(Debug)]
 pub struct Explanation {
@@ -13,6 +22,7 @@ pub struct Explanation {
     pub usage_examples: Vec<String>,
     pub related_items: Vec<String>,
     pub source_locations: Vec<SourceLocation>,
+    pub learning_examples: Vec<ExampleRef>,
 }
 
 #[derive(Debug)]
@@ -32,7 +42,20 @@ pub struct SourceLocation {
     pub context: String,
 }
 
+/// A learning record that mentions the explained target
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExampleRef {
+    pub session_id: String,
+    pub title: String,
+    pub problem: String,
+    pub solution: Option<String>,
+}
+
 /// Explain a target (function/component/concept)
+///
+/// With `examples`, learning records that mention the target are shown as
+/// real-world examples, and a target known only from learning records is
+/// still explained.
 pub fn explain_target(
     target: &str,
     examples: bool,
@@ -56,27 +79,122 @@ pub fn explain_target(
         crate::core::CldevError::command(format!("Failed to create secure path: {}", e))
     })?;
 
-    let explanation = find_and_explain(&current_dir, target, detailed, &secure_path)?;
+    let learning_examples = if examples {
+        gather_examples(target)
+    } else {
+        Vec::new()
+    };
+    let explanation = find_and_explain(
+        &current_dir,
+        target,
+        detailed,
+        learning_examples,
+        &secure_path,
+    )?;
 
     display_explanation(&explanation, examples, detailed, output);
 
     Ok(())
 }
 
+/// Find learning records that mention `target`, as explanation examples
+///
+/// Records are matched by full-text search and by affected file path. A
+/// missing or unreadable learning database yields no examples.
+pub fn gather_examples(target: &str) -> Vec<ExampleRef> {
+    let Ok(records_dir) = LearningSession::records_dir() else {
+        return Vec::new();
+    };
+    if !records_dir.join("learning.db").exists() {
+        return Vec::new();
+    }
+
+    LearningDatabase::new(records_dir.join("learning.db"), records_dir)
+        .map(|db| gather_examples_from(&db, target))
+        .unwrap_or_default()
+}
+
+/// Find learning records in `db` that mention `target`
+fn gather_examples_from(db: &LearningDatabase, target: &str) -> Vec<ExampleRef> {
+    // Quote the target so FTS treats `::` or `-` literally
+    let phrase = format!("\"{}\"", target.replace('"', "\"\""));
+    let by_keyword = db
+        .query_by_keyword(&phrase, MAX_EXAMPLES)
+        .unwrap_or_default();
+    let by_file = db.query_by_file(target, MAX_EXAMPLES).unwrap_or_default();
+
+    let mut seen = HashSet::new();
+    by_keyword
+        .into_iter()
+        .chain(by_file)
+        .filter(|result| seen.insert(result.session.id.clone()))
+        .take(MAX_EXAMPLES)
+        .map(|result| {
+            let session = result.session;
+            let solution = std::fs::read_to_string(&session.markdown_path)
+                .ok()
+                .and_then(|content| match LearningRecordV2::parse_markdown(&content) {
+                    Ok(record) => record.solution.map(|solution| solution.summary),
+                    // Records migrated to V3 keep the solution in the body
+                    Err(_) => LearningRecordV3::from_markdown_file(&content)
+                        .ok()
+                        .and_then(|record| record.section("Solution")),
+                })
+                .map(|summary| excerpt(&summary));
+
+            ExampleRef {
+                session_id: session.id,
+                title: session.title,
+                problem: excerpt(&session.description),
+                solution,
+            }
+        })
+        .collect()
+}
+
+/// First line of `text`, cut to [`EXCERPT_CHARS`]
+fn excerpt(text: &str) -> String {
+    let line = text.lines().next().unwrap_or_default().trim();
+    if line.chars().count() <= EXCERPT_CHARS {
+        return line.to_string();
+    }
+    let cut: String = line.chars().take(EXCERPT_CHARS).collect();
+    format!("{}…", cut.trim_end())
+}
+
 fn find_and_explain(
     path: &Path,
     target: &str,
     detailed: bool,
+    learning_examples: Vec<ExampleRef>,
     secure_path: &SecurePath,
 ) -> Result<Explanation> {
     let locations = search_in_codebase(path, target, secure_path)?;
 
     if locations.is_empty() {
         // Check if it's a concept
-        if let Some(concept) = explain_concept(target) {
+        if let Some(mut concept) = explain_concept(target) {
+            concept.learning_examples = learning_examples;
             return Ok(concept);
         }
 
+        // Known only from past learning records
+        if !learning_examples.is_empty() {
+            return Ok(Explanation {
+                target: target.to_string(),
+                kind: ExplanationKind::Concept,
+                description: format!(
+                    "Concept: {}\n\nNot found in the codebase, but mentioned in {} learning record(s).",
+                    target,
+                    learning_examples.len()
+                ),
+                usage_examples: Vec::new(),
+                related_items: Vec::new(),
+                source_locations: Vec::new(),
+                learning_examples,
+            });
+        }
+
         return Err(crate::core::CldevError::command(format!(
             "Target '{}' not found in codebase",
             target
@@ -100,6 +218,7 @@ fn find_and_explain(
         usage_examples,
         related_items,
         source_locations: locations,
+        learning_examples,
     })
 }
 
@@ -450,6 +569,7 @@ fn explain_concept(target: &str) -> Option<Explanation> {
             usage_examples: Vec::new(),
             related_items: get_related_concepts(target),
             source_locations: Vec::new(),
+            learning_examples: Vec::new(),
         });
     }
 
@@ -510,6 +630,18 @@ fn display_explanation(
         }
     }
 
+    // Display examples from learning records
+    if show_examples && !explanation.learning_examples.is_empty() {
+        output.info("\n--- Examples from Learning Records ---");
+        for example in &explanation.learning_examples {
+            output.list_item(&format!("{} ({})", example.title, example.session_id));
+            output.info(&format!("    Problem: {}", example.problem));
+            if let Some(solution) = &example.solution {
+                output.info(&format!("    Solution: {}", solution));
+            }
+        }
+    }
+
     // Display related items
     if !explanation.related_items.is_empty() {
         output.info("\n--- Related Items ---");
@@ -578,3 +710,78 @@ fn is_ignored_dir(path: &Path) -> bool {
         false
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::learning_record_v2::*;
+    use tempfile::TempDir;
+
+    fn seed_jwt_record(dir: &Path) -> LearningDatabase {
+        let mut db = LearningDatabase::new(dir.join("learning.db"), dir.to_path_buf()).unwrap();
+        let record = LearningRecordBuilder::new(
+            SessionType::Debug,
+            Priority::High,
+            "Expired JWT accepted",
+            "Tokens past exp were accepted because jwt validation skipped the clock check",
+            Severity::Critical,
+        )
+        .solution(Solution {
+            summary: "Enable exp validation with 30s leeway".to_string(),
+            root_cause: None,
+            steps: Vec::new(),
+            verification: Vec::new(),
+        })
+        .build();
+
+        let path = dir.join(format!("{}.md", record.session_meta.id));
+        let yaml = serde_yaml::to_string(&record).unwrap();
+        std::fs::write(&path, format!("---\n{}---\n", yaml)).unwrap();
+        db.upsert_session(&record, path.to_string_lossy().to_string())
+            .unwrap();
+        db
+    }
+
+    #[test]
+    fn test_gather_examples_finds_records() {
+        let temp_dir = TempDir::new().unwrap();
+        let db = seed_jwt_record(temp_dir.path());
+
+        let examples = gather_examples_from(&db, "jwt");
+        assert_eq!(examples.len(), 1);
+        assert_eq!(examples[0].title, "Expired JWT accepted");
+        assert!(examples[0].problem.contains("jwt validation"));
+        assert_eq!(
+            examples[0].solution.as_deref(),
+            Some("Enable exp validation with 30s leeway")
+        );
+
+        assert!(gather_examples_from(&db, "oauth").is_empty());
+    }
+
+    #[test]
+    fn test_explain_surfaces_learning_examples() {
+        let temp_dir = TempDir::new().unwrap();
+        let records = temp_dir.path().join("records");
+        let project = temp_dir.path().join("project");
+        std::fs::create_dir_all(&records).unwrap();
+        std::fs::create_dir_all(&project).unwrap();
+        let db = seed_jwt_record(&records);
+        let secure_path = SecurePath::new(project.clone()).unwrap();
+
+        let examples = gather_examples_from(&db, "jwt");
+        let explanation = find_and_explain(&project, "jwt", false, examples, &secure_path).unwrap();
+        assert!(matches!(explanation.kind, ExplanationKind::Concept));
+        assert_eq!(explanation.learning_examples.len(), 1);
+
+        // Without examples the unknown target is still an error
+        assert!(find_and_explain(&project, "jwt", false, Vec::new(), &secure_path).is_err());
+    }
+
+    #[test]
+    fn test_excerpt_truncates_first_line() {
+        assert_eq!(excerpt("first\nsecond"), "first");
+        let long = "x".repeat(EXCERPT_CHARS + 10);
+        assert_eq!(excerpt(&long).chars().count(), EXCERPT_CHARS + 1);
+    }
+}
@@ -4,7 +4,11 @@ mod explain;
 mod review_mr;
 mod serena;
 
-pub use analyze::analyze_project;
+pub use analyze::{analyze_project, scan_technical_debt, source_files, ScanScope};
+#[allow(unused_imports)]
+pub use analyze::{halstead_metrics, HalsteadMetrics};
 pub use explain::explain_target;
+#[allow(unused_imports)]
+pub use explain::{gather_examples, ExampleRef};
 pub use review_mr::review_merge_request;
 pub use serena::run_serena;
@@ -105,8 +105,64 @@ pub struct Suggestion {
     pub benefit: String,
 }
 
+/// Operations Serena would perform, built without reading any target
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SerenaPlan {
+    pub mode: String,
+    pub steps: Vec<SerenaStep>,
+}
+
+/// Operations planned for a single target
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SerenaStep {
+    pub target: String,
+    pub operations: Vec<String>,
+}
+
+impl SerenaPlan {
+    /// Plan one step per target for `mode`
+    pub fn new(mode: SerenaMode, targets: &[String]) -> Self {
+        let report = match mode {
+            SerenaMode::Interactive => "Display metrics, patterns, insights and suggestions",
+            SerenaMode::Batch => "Add results to the aggregated batch report",
+            SerenaMode::Watch => "Display a symbol and pattern summary",
+        };
+        let operations = [
+            "Scan .rs, .js, .ts, .py and .go files for symbols",
+            "Build the symbol relationship graph",
+            "Detect design patterns and anti-patterns",
+            "Calculate modularity, coupling and cohesion",
+            "Generate insights and suggestions",
+            report,
+        ];
+
+        Self {
+            mode: format!("{:?}", mode),
+            steps: targets
+                .iter()
+                .map(|target| SerenaStep {
+                    target: target.clone(),
+                    operations: operations.iter().map(|op| op.to_string()).collect(),
+                })
+                .collect(),
+        }
+    }
+}
+
 /// Run Serena semantic code analysis
-pub fn run_serena(mode: SerenaMode, targets: &[String], output: &OutputHandler) -> Result<()> {
+///
+/// With `plan`, the planned operations are printed and nothing is analyzed.
+pub fn run_serena(
+    mode: SerenaMode,
+    targets: &[String],
+    plan: bool,
+    output: &OutputHandler,
+) -> Result<()> {
+    if plan {
+        display_plan(&SerenaPlan::new(mode, &resolve_targets(targets)?), output);
+        return Ok(());
+    }
+
     output.info(&output.t_format("serena-starting", "mode", &format!("{:?}", mode)));
 
     match mode {
@@ -120,12 +176,7 @@ fn run_interactive_mode(targets: &[String], output: &OutputHandler) -> Result<()
     output.info(&output.t("serena-interactive-mode"));
     output.info(&format!("{}\n", output.t("serena-interactive-desc")));
 
-    let current_dir = std::env::current_dir()?;
-    let target_paths = if targets.is_empty() {
-        vec![current_dir.to_string_lossy().to_string()]
-    } else {
-        targets.to_vec()
-    };
+    let target_paths = resolve_targets(targets)?;
 
     for target in &target_paths {
         let path = Path::new(target);
@@ -141,12 +192,7 @@ fn run_interactive_mode(targets: &[String], output: &OutputHandler) -> Result<()
 fn run_batch_mode(targets: &[String], output: &OutputHandler) -> Result<()> {
     output.info(&output.t("serena-batch-mode"));
 
-    let current_dir = std::env::current_dir()?;
-    let target_paths = if targets.is_empty() {
-        vec![current_dir.to_string_lossy().to_string()]
-    } else {
-        targets.to_vec()
-    };
+    let target_paths = resolve_targets(targets)?;
 
     let mut all_results = Vec::new();
 
@@ -176,12 +222,7 @@ fn run_watch_mode(targets: &[String], output: &OutputHandler) -> Result<()> {
     output.warning(&output.t("serena-watch-simple-impl"));
     output.info(&format!("{}\n", output.t("serena-watch-simple-note")));
 
-    let current_dir = std::env::current_dir()?;
-    let target_paths = if targets.is_empty() {
-        vec![current_dir.to_string_lossy().to_string()]
-    } else {
-        targets.to_vec()
-    };
+    let target_paths = resolve_targets(targets)?;
 
     // Initial analysis
     for target in &target_paths {
@@ -197,6 +238,28 @@ fn run_watch_mode(targets: &[String], output: &OutputHandler) -> Result<()> {
     Ok(())
 }
 
+/// The given targets, or the current directory when none are given
+fn resolve_targets(targets: &[String]) -> Result<Vec<String>> {
+    if targets.is_empty() {
+        Ok(vec![std::env::current_dir()?.to_string_lossy().to_string()])
+    } else {
+        Ok(targets.to_vec())
+    }
+}
+
+fn display_plan(plan: &SerenaPlan, output: &OutputHandler) {
+    output.info(&output.t_format("serena-plan-header", "mode", &plan.mode));
+    for step in &plan.steps {
+        output.info(&format!(
+            "\n{}",
+            output.t_format("serena-plan-target", "target", &step.target)
+        ));
+        for operation in &step.operations {
+            output.list_item(operation);
+        }
+    }
+}
+
 fn analyze_semantics(path: &Path, _detailed: bool) -> Result<SerenaResult> {
     let mut symbols = Vec::new();
     let mut relationships = Vec::new();
@@ -774,3 +837,34 @@ fn is_ignored_dir(path: &Path) -> bool {
         false
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plan_has_one_step_per_target() {
+        // Targets that do not exist prove the plan never reads or scans them
+        let targets = vec!["missing/src".to_string(), "missing/lib.rs".to_string()];
+        let plan = SerenaPlan::new(SerenaMode::Batch, &targets);
+
+        assert_eq!(plan.mode, "Batch");
+        let planned: Vec<&str> = plan.steps.iter().map(|s| s.target.as_str()).collect();
+        assert_eq!(planned, ["missing/src", "missing/lib.rs"]);
+        assert!(plan.steps.iter().all(|s| s.operations.len() == 6));
+        assert!(plan.steps[0].operations[5].contains("batch report"));
+        assert!(!Path::new("missing").exists());
+    }
+
+    #[test]
+    fn test_plan_mode_changes_report_step() {
+        let targets = vec!["src".to_string()];
+        let interactive = SerenaPlan::new(SerenaMode::Interactive, &targets);
+        let watch = SerenaPlan::new(SerenaMode::Watch, &targets);
+
+        assert_ne!(
+            interactive.steps[0].operations.last(),
+            watch.steps[0].operations.last()
+        );
+    }
+}
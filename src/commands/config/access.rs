@@ -0,0 +1,92 @@
+//! Dotted-path accessors for individual configuration values
+//!
+//! Backs `cldev config get <key>` and `cldev config set <key> <value>`.
+
+use crate::cli::output::OutputHandler;
+use crate::core::config::Config;
+use crate::core::error::{CldevError, Result};
+use std::path::PathBuf;
+
+/// Print a single configuration value
+///
+/// Scalars are printed bare; sections and arrays are printed as TOML.
+pub fn handle_config_get(
+    key: &str,
+    config_path: Option<PathBuf>,
+    output: &OutputHandler,
+) -> Result<()> {
+    let config = Config::load(config_path)?;
+
+    let value = config.get_path(key).ok_or_else(|| {
+        CldevError::validation(output.t_format("config-get-not-found", "key", key))
+    })?;
+
+    let rendered = match value {
+        toml::Value::String(s) => s,
+        toml::Value::Table(_) => toml::to_string_pretty(&value)?.trim_end().to_string(),
+        other => other.to_string(),
+    };
+    output.raw(&rendered);
+
+    Ok(())
+}
+
+/// Update a single configuration value and save the file
+pub fn handle_config_set(
+    key: &str,
+    value: &str,
+    config_path: Option<PathBuf>,
+    output: &OutputHandler,
+) -> Result<()> {
+    let mut config = Config::load(config_path.clone())?.as_ref().clone();
+    config.set_path(key, value)?;
+    config.save(config_path)?;
+
+    output.success(
+        &output
+            .t_format("config-set-success", "key", key)
+            .replace("{value}", value),
+    );
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_set_then_get_persists() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("config.toml");
+        let output = OutputHandler::new(false, true, true);
+
+        handle_config_set(
+            "performance.timeout_seconds",
+            "600",
+            Some(config_path.clone()),
+            &output,
+        )
+        .unwrap();
+
+        let loaded = Config::load(Some(config_path.clone())).unwrap();
+        assert_eq!(loaded.performance.timeout_seconds, 600);
+        assert!(
+            handle_config_get("performance.timeout_seconds", Some(config_path), &output).is_ok()
+        );
+    }
+
+    #[test]
+    fn test_get_unknown_key() {
+        let temp_dir = TempDir::new().unwrap();
+        let output = OutputHandler::new(false, true, true);
+
+        let result = handle_config_get(
+            "git.nonexistent",
+            Some(temp_dir.path().join("config.toml")),
+            &output,
+        );
+        assert!(result.is_err());
+    }
+}
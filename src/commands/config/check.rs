@@ -11,7 +11,7 @@ use crate::cli::output::OutputHandler;
 use crate::core::config::{validate_version, Config, CONFIG_VERSION};
 use crate::core::error::{CldevError, Result};
 use colored::Colorize;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 /// Validation result for individual checks
 #[derive(Debug)]
@@ -153,6 +153,9 @@ pub fn check_config(
     // Check 7: Git CLI availability
     validate_git_cli(&config, &mut results);
 
+    // Check 8: Semantic constraints on values
+    validate_semantics(&config, &config_path, fix, &mut results, output)?;
+
     // Print results
     print_results(&results, detailed, output);
 
@@ -266,6 +269,43 @@ fn validate_permissions(path: &PathBuf, results: &mut Vec<ValidationResult>) ->
     Ok(())
 }
 
+/// Validate semantic constraints, clamping numeric values when `fix` is set
+fn validate_semantics(
+    config: &Config,
+    config_path: &Path,
+    fix: bool,
+    results: &mut Vec<ValidationResult>,
+    output: &OutputHandler,
+) -> Result<()> {
+    let category = output.i18n().get("config-check-category-semantics");
+    let mut issues = config.validate_semantics()?;
+
+    if fix && !issues.is_empty() {
+        let mut fixed = config.clone();
+        if fixed.clamp_numeric_values() {
+            fixed.save(Some(config_path.to_path_buf()))?;
+            results.push(ValidationResult::success(
+                &output.i18n().get("config-check-category-auto-fix"),
+                &output.i18n().get("config-check-semantics-clamped"),
+            ));
+            issues = fixed.validate_semantics()?;
+        }
+    }
+
+    if issues.is_empty() {
+        results.push(ValidationResult::success(
+            &category,
+            &output.i18n().get("config-check-semantics-ok"),
+        ));
+    } else {
+        for issue in &issues {
+            results.push(ValidationResult::failure(&category, issue));
+        }
+    }
+
+    Ok(())
+}
+
 /// Validate Git CLI tools availability
 fn validate_git_cli(config: &Config, results: &mut Vec<ValidationResult>) {
     use std::process::Command;
@@ -352,6 +392,7 @@ fn print_results(results: &[ValidationResult], detailed: bool, output: &OutputHa
 #[cfg(test)]
 mod tests {
     use super::*;
+    use tempfile::TempDir;
 
     #[test]
     fn test_validation_result_creation() {
@@ -363,4 +404,42 @@ mod tests {
         assert!(!failure.passed);
         assert_eq!(failure.category, "Test");
     }
+
+    #[test]
+    fn test_semantics_reported_without_fix() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("config.toml");
+        let mut config = Config::default();
+        config.performance.max_jobs = 0;
+        config.save(Some(config_path.clone())).unwrap();
+
+        let output = OutputHandler::new(false, true, true);
+        let mut results = Vec::new();
+        validate_semantics(&config, &config_path, false, &mut results, &output).unwrap();
+
+        assert!(results
+            .iter()
+            .any(|r| !r.passed && r.message.contains("max_jobs")));
+        let saved = Config::load(Some(config_path)).unwrap();
+        assert_eq!(saved.performance.max_jobs, 0);
+    }
+
+    #[test]
+    fn test_semantics_fix_clamps_and_saves() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("config.toml");
+        let mut config = Config::default();
+        config.performance.timeout_seconds = 0;
+        config.save(Some(config_path.clone())).unwrap();
+
+        let output = OutputHandler::new(false, true, true);
+        let mut results = Vec::new();
+        validate_semantics(&config, &config_path, true, &mut results, &output).unwrap();
+
+        assert!(!results
+            .iter()
+            .any(|r| !r.passed && r.message.contains("timeout_seconds")));
+        let saved = Config::load(Some(config_path)).unwrap();
+        assert_eq!(saved.performance.timeout_seconds, 300);
+    }
 }
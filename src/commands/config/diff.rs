@@ -0,0 +1,135 @@
+//! Effective configuration across Global → Stack → Project layers
+//!
+//! Backs `cldev config diff`, which shows which layer wins for each
+//! hierarchical setting and which lower-layer values it overrides.
+
+use crate::cli::output::OutputHandler;
+use crate::core::config::{Config, HierarchicalConfig, LayerResolution};
+use crate::core::error::Result;
+use comfy_table::{modifiers::UTF8_ROUND_CORNERS, presets::UTF8_FULL, Table};
+use std::path::PathBuf;
+
+/// Settings resolved across layers, in display order
+const DIFF_KEYS: &[&str] = &[
+    "tech_stack",
+    "project_name",
+    "dev_port",
+    "base_branch",
+    "coverage_threshold",
+];
+
+/// Show the effective value and source layer of each hierarchical setting
+///
+/// # Arguments
+///
+/// * `project_root` - Project directory to read `.cldev/config.toml` from
+///   (uses the current directory, or its repository root, if None)
+/// * `output` - Output handler for formatted messages
+pub fn handle_config_diff(project_root: Option<PathBuf>, output: &OutputHandler) -> Result<()> {
+    let project_root = match project_root {
+        Some(root) => root,
+        None => std::env::current_dir()?,
+    };
+    let config = Config::load_hierarchical(Some(project_root))?;
+
+    output.header(&output.t("config-diff-header"));
+    output.raw(&render_table(&resolve_all(&config), output).to_string());
+
+    Ok(())
+}
+
+/// Resolve the fixed settings followed by every merged command
+fn resolve_all(config: &HierarchicalConfig) -> Vec<LayerResolution> {
+    let mut commands: Vec<String> = config.merged_commands().into_keys().collect();
+    commands.sort();
+
+    DIFF_KEYS
+        .iter()
+        .map(|key| config.explain(key))
+        .chain(
+            commands
+                .iter()
+                .map(|name| config.explain(&format!("commands.{}", name))),
+        )
+        .collect()
+}
+
+/// Build the resolution table
+fn render_table(resolutions: &[LayerResolution], output: &OutputHandler) -> Table {
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .apply_modifier(UTF8_ROUND_CORNERS)
+        .set_header(vec![
+            output.t("config-diff-col-setting"),
+            output.t("config-diff-col-value"),
+            output.t("config-diff-col-layer"),
+            output.t("config-diff-col-overridden"),
+        ]);
+
+    for resolution in resolutions {
+        let overridden = resolution
+            .overridden
+            .iter()
+            .map(|(layer, value)| format!("{}: {}", layer.name(), value))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        table.add_row(vec![
+            resolution.key.clone(),
+            resolution.value.clone().unwrap_or_else(|| "-".to_string()),
+            resolution.layer.name().to_string(),
+            overridden,
+        ]);
+    }
+
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::project_config::ProjectConfig;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_resolve_all_includes_merged_commands() {
+        let mut project = ProjectConfig::default();
+        project
+            .commands
+            .insert("deploy".to_string(), "make deploy".to_string());
+        project
+            .commands
+            .insert("build".to_string(), "make".to_string());
+
+        let config = HierarchicalConfig {
+            global: Arc::new(Config::default()),
+            stack: None,
+            project: Some(project),
+        };
+
+        let keys: Vec<String> = resolve_all(&config).into_iter().map(|r| r.key).collect();
+        assert_eq!(keys.len(), DIFF_KEYS.len() + 2);
+        assert_eq!(
+            &keys[DIFF_KEYS.len()..],
+            ["commands.build", "commands.deploy"]
+        );
+    }
+
+    #[test]
+    fn test_render_table_names_layers() {
+        let mut project = ProjectConfig::default();
+        project.git.base_branch = Some("develop".to_string());
+
+        let config = HierarchicalConfig {
+            global: Arc::new(Config::default()),
+            stack: None,
+            project: Some(project),
+        };
+        let output = OutputHandler::new(false, true, true);
+
+        let rendered = render_table(&resolve_all(&config), &output).to_string();
+        assert!(rendered.contains("develop"));
+        assert!(rendered.contains("global: main"));
+    }
+}
@@ -6,13 +6,18 @@
 //! - --editor command-line option
 //! - Config file editor preference
 //! - Common editor fallbacks
+//!
+//! After the editor exits the file is re-parsed, and the pre-edit contents are
+//! restored if the result is not a valid configuration.
 
 #![allow(dead_code)]
 
 use crate::cli::output::OutputHandler;
 use crate::core::config::Config;
 use crate::core::error::{CldevError, Result};
+use crate::core::security::SecurePath;
 use std::env;
+use std::fs;
 use std::path::Path;
 use std::path::PathBuf;
 use std::process::Command;
@@ -24,6 +29,12 @@ use std::process::Command;
 /// 4. Common editor fallbacks (vim, nano, vi)
 const FALLBACK_EDITORS: &[&str] = &["vim", "nano", "vi"];
 
+/// Editor used when none of the above is available
+#[cfg(windows)]
+const DEFAULT_EDITOR: &str = "notepad";
+#[cfg(not(windows))]
+const DEFAULT_EDITOR: &str = "vi";
+
 /// Edit configuration file
 ///
 /// # Arguments
@@ -34,16 +45,18 @@ const FALLBACK_EDITORS: &[&str] = &["vim", "nano", "vi"];
 ///
 /// # Returns
 ///
-/// Returns Ok(()) if editor was launched successfully
+/// Returns Ok(()) if the edited file parses as a valid configuration.
+/// Otherwise the pre-edit contents are restored and an error is returned.
 pub fn edit_config(
     editor_override: Option<String>,
     config_path: Option<PathBuf>,
     output: &OutputHandler,
 ) -> Result<()> {
     // Determine config file path
-    let config_file = config_path.unwrap_or_else(|| {
-        Config::default_path().unwrap_or_else(|_| PathBuf::from("~/.config/cldev/config.toml"))
-    });
+    let config_file = match config_path {
+        Some(path) => path,
+        None => Config::default_path()?,
+    };
 
     // Ensure config file exists
     if !config_file.exists() {
@@ -64,6 +77,17 @@ pub fn edit_config(
         ));
     }
 
+    // Security: the resolved file must stay inside its config directory (no symlink escapes)
+    let config_dir = config_file
+        .parent()
+        .ok_or_else(|| CldevError::config("Config file has no parent directory"))?;
+    let config_file = SecurePath::new(config_dir.to_path_buf())
+        .and_then(|secure_path| secure_path.validate(&config_file))
+        .map_err(|e| CldevError::security(format!("Invalid config path: {}", e)))?;
+
+    // Keep the pre-edit contents so an invalid edit can be rolled back
+    let backup = fs::read_to_string(&config_file)?;
+
     // Determine which editor to use
     let editor_cmd = determine_editor(editor_override, &config_file, output)?;
 
@@ -81,6 +105,28 @@ pub fn edit_config(
     // Launch editor
     launch_editor(&editor_cmd, &config_file, output)?;
 
+    // Re-parse the edited file and roll back if it is no longer valid
+    let edited = fs::read_to_string(&config_file)?;
+    let config = match toml::from_str::<Config>(&edited) {
+        Ok(config) => config,
+        Err(e) => {
+            fs::write(&config_file, &backup)?;
+            return Err(CldevError::config(output.i18n().format(
+                "config-edit-invalid-restored",
+                "error",
+                &e.to_string(),
+            )));
+        }
+    };
+
+    for issue in config.validate_semantics()? {
+        output.warning(
+            &output
+                .i18n()
+                .format("config-edit-validation-warning", "issue", &issue),
+        );
+    }
+
     output.success(&output.i18n().get("config-edit-completed"));
 
     Ok(())
@@ -115,10 +161,9 @@ fn determine_editor(
         }
     }
 
-    // No editor found
-    Err(CldevError::editor(
-        output.i18n().get("config-edit-no-editor"),
-    ))
+    // Priority 5: Platform default editor
+    output.debug(&format!("Using default editor: {}", DEFAULT_EDITOR));
+    Ok(DEFAULT_EDITOR.to_string())
 }
 
 /// Check if an editor command is available in PATH
@@ -224,4 +269,51 @@ mod tests {
         assert!(result.is_ok());
         assert_eq!(result.unwrap(), "custom-editor");
     }
+
+    /// Write an executable shell script that acts as the editor
+    #[cfg(unix)]
+    fn fake_editor(dir: &Path, body: &str) -> String {
+        use std::os::unix::fs::PermissionsExt;
+
+        let script = dir.join("fake-editor.sh");
+        fs::write(&script, format!("#!/bin/sh\n{}\n", body)).unwrap();
+        fs::set_permissions(&script, fs::Permissions::from_mode(0o755)).unwrap();
+        script.display().to_string()
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_edit_invalid_toml_restores_original() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("config.toml");
+        Config::default().save(Some(config_path.clone())).unwrap();
+        let original = fs::read_to_string(&config_path).unwrap();
+
+        let editor = fake_editor(temp_dir.path(), "echo 'general = [not toml' > \"$1\"");
+        let output = OutputHandler::new(false, true, true);
+
+        let result = edit_config(Some(editor), Some(config_path.clone()), &output);
+
+        assert!(result.is_err());
+        assert_eq!(fs::read_to_string(&config_path).unwrap(), original);
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_edit_valid_toml_is_kept() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("config.toml");
+        Config::default().save(Some(config_path.clone())).unwrap();
+
+        let editor = fake_editor(
+            temp_dir.path(),
+            "printf 'version = \"2.0.0\"\\n[performance]\\ntimeout_seconds = 42\\n' > \"$1\"",
+        );
+        let output = OutputHandler::new(false, true, true);
+
+        edit_config(Some(editor), Some(config_path.clone()), &output).unwrap();
+
+        let loaded = Config::load(Some(config_path)).unwrap();
+        assert_eq!(loaded.performance.timeout_seconds, 42);
+    }
 }
@@ -4,7 +4,7 @@
 //! of cldev, including language selection, directory detection, and shell setup.
 
 use crate::cli::output::OutputHandler;
-use crate::core::config::{Config, GeneralConfig, GitConfig, UiConfig};
+use crate::core::config::{home_dir, Config, GeneralConfig, GitConfig, UiConfig};
 use crate::core::error::{CldevError, Result};
 use dialoguer::{theme::ColorfulTheme, Confirm, Input, Select};
 use indicatif::{ProgressBar, ProgressStyle};
@@ -154,9 +154,7 @@ fn select_language(theme: &ColorfulTheme, output: &OutputHandler) -> Result<Stri
 fn detect_claude_directory(theme: &ColorfulTheme, output: &OutputHandler) -> Result<PathBuf> {
     output.info(&output.i18n().get("config-init-step2-claude-dir"));
 
-    let home_dir =
-        dirs::home_dir().ok_or_else(|| CldevError::config("Cannot determine home directory"))?;
-    let default_claude_dir = home_dir.join(".claude");
+    let default_claude_dir = home_dir()?.join(".claude");
 
     if default_claude_dir.exists() {
         output.info(&output.i18n().format(
@@ -191,9 +189,7 @@ fn detect_claude_directory(theme: &ColorfulTheme, output: &OutputHandler) -> Res
 fn select_projects_directory(theme: &ColorfulTheme, output: &OutputHandler) -> Result<PathBuf> {
     output.info(&output.i18n().get("config-init-step3-projects-dir"));
 
-    let home_dir =
-        dirs::home_dir().ok_or_else(|| CldevError::config("Cannot determine home directory"))?;
-    let default_projects_dir = home_dir.join("projects");
+    let default_projects_dir = home_dir()?.join("projects");
 
     let custom_path: String = Input::with_theme(theme)
         .with_prompt(output.i18n().get("config-init-projects-prompt"))
@@ -264,9 +260,9 @@ fn detect_shell_and_offer_completion(
 
     // Determine shell config file
     let config_file = match shell_name {
-        "zsh" => dirs::home_dir().map(|h| h.join(".zshrc")),
-        "bash" => dirs::home_dir().map(|h| h.join(".bashrc")),
-        "fish" => dirs::home_dir().map(|h| h.join(".config/fish/config.fish")),
+        "zsh" => home_dir().ok().map(|h| h.join(".zshrc")),
+        "bash" => home_dir().ok().map(|h| h.join(".bashrc")),
+        "fish" => home_dir().ok().map(|h| h.join(".config/fish/config.fish")),
         _ => None,
     };
 
@@ -465,6 +461,7 @@ fn generate_config_with_progress(
         projects_dir: projects_dir.clone(),
         tech_stack: None,
         project_name: None,
+        notify_on_complete: false,
     };
     std::thread::sleep(std::time::Duration::from_millis(200));
     pb.inc(1);
@@ -7,7 +7,7 @@
 
 use crate::cli::output::OutputHandler;
 use crate::core::error::Result;
-use crate::core::i18n::Language;
+use crate::core::i18n::{I18n, Language};
 use colored::Colorize;
 use comfy_table::{modifiers::UTF8_ROUND_CORNERS, presets::UTF8_FULL, Cell, Color, Table};
 
@@ -40,8 +40,7 @@ pub fn list_commands(
     detailed: bool,
     output: &OutputHandler,
 ) -> Result<()> {
-    let language = output.i18n().language();
-    let categories = get_all_categories(language);
+    let categories = get_all_categories(output.i18n());
 
     // Filter categories if requested
     let filtered_categories: Vec<&CommandCategory> = if let Some(filter) = category_filter {
@@ -128,242 +127,240 @@ fn print_category(category: &CommandCategory, detailed: bool, output: &OutputHan
 }
 
 /// Helper function to get localized string
-fn t(key: &str, lang: Language) -> &'static str {
-    use crate::core::i18n::I18n;
-    let i18n = I18n::with_language(lang);
+fn t(key: &str, i18n: &I18n) -> &'static str {
     // We need to use Box::leak to convert String to &'static str
     // This is safe for command descriptions as they're loaded once at startup
     Box::leak(i18n.get(key).into_boxed_str())
 }
 
 /// Get all command categories with their commands
-fn get_all_categories(lang: Language) -> Vec<CommandCategory> {
+fn get_all_categories(i18n: &I18n) -> Vec<CommandCategory> {
     vec![
         // Configuration Commands (6 commands)
         CommandCategory {
-            name: t("cmd-cat-configuration", lang),
-            description: t("cmd-cat-configuration-desc", lang),
+            name: t("cmd-cat-configuration", i18n),
+            description: t("cmd-cat-configuration-desc", i18n),
             emoji: "⚙️",
             commands: vec![
                 CommandInfo {
                     name: "config init",
-                    description: t("cmd-config-init-desc", lang),
+                    description: t("cmd-config-init-desc", i18n),
                     usage: "cldev config init [--defaults] [--force]",
                 },
                 CommandInfo {
                     name: "config check",
-                    description: t("cmd-config-check-desc", lang),
+                    description: t("cmd-config-check-desc", i18n),
                     usage: "cldev config check [--detailed] [--fix]",
                 },
                 CommandInfo {
                     name: "config edit",
-                    description: t("cmd-config-edit-desc", lang),
+                    description: t("cmd-config-edit-desc", i18n),
                     usage: "cldev config edit [--editor <EDITOR>]",
                 },
                 CommandInfo {
                     name: "config list",
-                    description: t("cmd-config-list-desc", lang),
+                    description: t("cmd-config-list-desc", i18n),
                     usage: "cldev config list [--detailed]",
                 },
                 CommandInfo {
                     name: "config maintain",
-                    description: t("cmd-config-maintain-desc", lang),
+                    description: t("cmd-config-maintain-desc", i18n),
                     usage: "cldev config maintain [--backup] [--cleanup]",
                 },
                 CommandInfo {
                     name: "config update-docs",
-                    description: t("cmd-config-update-docs-desc", lang),
+                    description: t("cmd-config-update-docs-desc", i18n),
                     usage: "cldev config update-docs [<TYPE>] [--validate]",
                 },
             ],
         },
         // Development Commands (7 commands)
         CommandCategory {
-            name: t("cmd-cat-development", lang),
-            description: t("cmd-cat-development-desc", lang),
+            name: t("cmd-cat-development", i18n),
+            description: t("cmd-cat-development-desc", i18n),
             emoji: "🛠️",
             commands: vec![
                 CommandInfo {
                     name: "dev urgent",
-                    description: t("cmd-dev-urgent-desc", lang),
+                    description: t("cmd-dev-urgent-desc", i18n),
                     usage: "cldev dev urgent <PROBLEM> [-y]",
                 },
                 CommandInfo {
                     name: "dev fix",
-                    description: t("cmd-dev-fix-desc", lang),
+                    description: t("cmd-dev-fix-desc", i18n),
                     usage: "cldev dev fix <TARGET> [--branch]",
                 },
                 CommandInfo {
                     name: "dev debug",
-                    description: t("cmd-dev-debug-desc", lang),
+                    description: t("cmd-dev-debug-desc", i18n),
                     usage: "cldev dev debug <SYMPTOM> [--verbose]",
                 },
                 CommandInfo {
                     name: "dev feature",
-                    description: t("cmd-dev-feature-desc", lang),
+                    description: t("cmd-dev-feature-desc", i18n),
                     usage: "cldev dev feature <NAME> [--skip-confirm]",
                 },
                 CommandInfo {
                     name: "dev refactor",
-                    description: t("cmd-dev-refactor-desc", lang),
+                    description: t("cmd-dev-refactor-desc", i18n),
                     usage: "cldev dev refactor <TARGET> [--scope <SCOPE>]",
                 },
                 CommandInfo {
                     name: "dev optimize",
-                    description: t("cmd-dev-optimize-desc", lang),
+                    description: t("cmd-dev-optimize-desc", i18n),
                     usage: "cldev dev optimize <TARGET> [--focus <AREA>]",
                 },
                 CommandInfo {
                     name: "dev research",
-                    description: t("cmd-dev-research-desc", lang),
+                    description: t("cmd-dev-research-desc", i18n),
                     usage: "cldev dev research <TOPIC> [--format <FMT>]",
                 },
             ],
         },
         // Git Commands (4 commands)
         CommandCategory {
-            name: t("cmd-cat-git", lang),
-            description: t("cmd-cat-git-desc", lang),
+            name: t("cmd-cat-git", i18n),
+            description: t("cmd-cat-git-desc", i18n),
             emoji: "📝",
             commands: vec![
                 CommandInfo {
                     name: "git commit",
-                    description: t("cmd-git-commit-desc", lang),
+                    description: t("cmd-git-commit-desc", i18n),
                     usage: "cldev git commit [<MESSAGE>] [--no-verify] [--amend]",
                 },
                 CommandInfo {
                     name: "git branch",
-                    description: t("cmd-git-branch-desc", lang),
+                    description: t("cmd-git-branch-desc", i18n),
                     usage: "cldev git branch [<NAME>] [--type <TYPE>]",
                 },
                 CommandInfo {
                     name: "git merge-request",
-                    description: t("cmd-git-merge-request-desc", lang),
+                    description: t("cmd-git-merge-request-desc", i18n),
                     usage: "cldev git merge-request [--target <BRANCH>] [--detailed]",
                 },
                 CommandInfo {
                     name: "git status",
-                    description: t("cmd-git-status-desc", lang),
+                    description: t("cmd-git-status-desc", i18n),
                     usage: "cldev git status [--detailed]",
                 },
             ],
         },
         // Quality Commands (3 commands)
         CommandCategory {
-            name: t("cmd-cat-quality", lang),
-            description: t("cmd-cat-quality-desc", lang),
+            name: t("cmd-cat-quality", i18n),
+            description: t("cmd-cat-quality-desc", i18n),
             emoji: "✨",
             commands: vec![
                 CommandInfo {
                     name: "quality lint",
-                    description: t("cmd-quality-lint-desc", lang),
+                    description: t("cmd-quality-lint-desc", i18n),
                     usage: "cldev quality lint [--fix] [<PATHS>...]",
                 },
                 CommandInfo {
                     name: "quality format",
-                    description: t("cmd-quality-format-desc", lang),
+                    description: t("cmd-quality-format-desc", i18n),
                     usage: "cldev quality format [--check] [<PATHS>...]",
                 },
                 CommandInfo {
                     name: "quality test",
-                    description: t("cmd-quality-test-desc", lang),
+                    description: t("cmd-quality-test-desc", i18n),
                     usage: "cldev quality test [<PATTERN>] [--coverage] [--watch]",
                 },
             ],
         },
         // Tech Stack Commands (1 command)
         CommandCategory {
-            name: t("cmd-cat-tech-stack", lang),
-            description: t("cmd-cat-tech-stack-desc", lang),
+            name: t("cmd-cat-tech-stack", i18n),
+            description: t("cmd-cat-tech-stack-desc", i18n),
             emoji: "🔧",
             commands: vec![CommandInfo {
                 name: "tech start",
-                description: t("cmd-tech-start-desc", lang),
+                description: t("cmd-tech-start-desc", i18n),
                 usage: "cldev tech start <STACK> [--port <PORT>] [--env <ENV>]",
             }],
         },
         // Operations Commands (2 commands)
         CommandCategory {
-            name: t("cmd-cat-operations", lang),
-            description: t("cmd-cat-operations-desc", lang),
+            name: t("cmd-cat-operations", i18n),
+            description: t("cmd-cat-operations-desc", i18n),
             emoji: "🚀",
             commands: vec![
                 CommandInfo {
                     name: "ops build",
-                    description: t("cmd-ops-build-desc", lang),
+                    description: t("cmd-ops-build-desc", i18n),
                     usage: "cldev ops build [--env <ENV>] [--analyze] [--clean]",
                 },
                 CommandInfo {
                     name: "ops deploy",
-                    description: t("cmd-ops-deploy-desc", lang),
+                    description: t("cmd-ops-deploy-desc", i18n),
                     usage: "cldev ops deploy <ENV> [-y] [--dry-run]",
                 },
             ],
         },
         // Analysis Commands (4 commands)
         CommandCategory {
-            name: t("cmd-cat-analysis", lang),
-            description: t("cmd-cat-analysis-desc", lang),
+            name: t("cmd-cat-analysis", i18n),
+            description: t("cmd-cat-analysis-desc", i18n),
             emoji: "📊",
             commands: vec![
                 CommandInfo {
                     name: "analysis analyze",
-                    description: t("cmd-analysis-analyze-desc", lang),
+                    description: t("cmd-analysis-analyze-desc", i18n),
                     usage: "cldev analysis analyze [<TARGET>] [--detailed]",
                 },
                 CommandInfo {
                     name: "analysis explain",
-                    description: t("cmd-analysis-explain-desc", lang),
+                    description: t("cmd-analysis-explain-desc", i18n),
                     usage: "cldev analysis explain <TARGET> [--examples] [--detailed]",
                 },
                 CommandInfo {
                     name: "analysis review-mr",
-                    description: t("cmd-analysis-review-mr-desc", lang),
+                    description: t("cmd-analysis-review-mr-desc", i18n),
                     usage: "cldev analysis review-mr <NUMBER> [--security-focus]",
                 },
                 CommandInfo {
                     name: "analysis serena",
-                    description: t("cmd-analysis-serena-desc", lang),
+                    description: t("cmd-analysis-serena-desc", i18n),
                     usage: "cldev analysis serena [--mode <MODE>] [<TARGETS>...]",
                 },
             ],
         },
         // Learning Record Commands (4 commands)
         CommandCategory {
-            name: t("cmd-cat-learning", lang),
-            description: t("cmd-cat-learning-desc", lang),
+            name: t("cmd-cat-learning", i18n),
+            description: t("cmd-cat-learning-desc", i18n),
             emoji: "📚",
             commands: vec![
                 CommandInfo {
                     name: "lr find",
-                    description: t("cmd-lr-find-desc", lang),
+                    description: t("cmd-lr-find-desc", i18n),
                     usage: "cldev lr find <QUERY> [--field <FIELD>] [--limit <N>]",
                 },
                 CommandInfo {
                     name: "lr stats",
-                    description: t("cmd-lr-stats-desc", lang),
+                    description: t("cmd-lr-stats-desc", i18n),
                     usage: "cldev lr stats [--period <PERIOD>] [--detailed]",
                 },
                 CommandInfo {
                     name: "lr problems",
-                    description: t("cmd-lr-problems-desc", lang),
+                    description: t("cmd-lr-problems-desc", i18n),
                     usage: "cldev lr problems [--priority <PRI>] [--recent]",
                 },
                 CommandInfo {
                     name: "lr new",
-                    description: t("cmd-lr-new-desc", lang),
+                    description: t("cmd-lr-new-desc", i18n),
                     usage: "cldev lr new <TOPIC> [--edit]",
                 },
             ],
         },
         // Todo Commands (1 command)
         CommandCategory {
-            name: t("cmd-cat-todo", lang),
-            description: t("cmd-cat-todo-desc", lang),
+            name: t("cmd-cat-todo", i18n),
+            description: t("cmd-cat-todo-desc", i18n),
             emoji: "✅",
             commands: vec![CommandInfo {
                 name: "todo manage",
-                description: t("cmd-todo-manage-desc", lang),
+                description: t("cmd-todo-manage-desc", i18n),
                 usage: "cldev todo manage <ACTION> [<DESCRIPTION>]",
             }],
         },
@@ -373,7 +370,7 @@ fn get_all_categories(lang: Language) -> Vec<CommandCategory> {
 /// Get command count by category
 pub fn get_command_stats() -> Vec<(String, usize)> {
     // Use English for stats by default
-    get_all_categories(Language::English)
+    get_all_categories(&I18n::with_language(Language::English))
         .iter()
         .map(|cat| (cat.name.to_string(), cat.commands.len()))
         .collect()
@@ -385,7 +382,7 @@ mod tests {
 
     #[test]
     fn test_total_command_count() {
-        let categories = get_all_categories(Language::English);
+        let categories = get_all_categories(&I18n::with_language(Language::English));
         let total: usize = categories.iter().map(|cat| cat.commands.len()).sum();
 
         // Verify we have exactly 29 commands as per requirements
@@ -396,7 +393,7 @@ mod tests {
 
     #[test]
     fn test_all_categories_have_commands() {
-        let categories = get_all_categories(Language::English);
+        let categories = get_all_categories(&I18n::with_language(Language::English));
         for category in categories {
             assert!(
                 !category.commands.is_empty(),
@@ -408,7 +405,7 @@ mod tests {
 
     #[test]
     fn test_command_info_completeness() {
-        let categories = get_all_categories(Language::English);
+        let categories = get_all_categories(&I18n::with_language(Language::English));
         for category in categories {
             for cmd in category.commands {
                 assert!(!cmd.name.is_empty(), "Command name is empty");
@@ -1,27 +1,13 @@
 use crate::cli::output::OutputHandler;
 use crate::core::{
-    config::Config, error::CldevError, learning_record_v3::LearningRecordV3, Result,
+    config::{home_dir, Config},
+    error::CldevError,
+    learning_db::LearningDatabase,
+    LearningSession, Result,
 };
-use chrono::{DateTime, Duration, Local};
+use chrono::{DateTime, Duration, Local, NaiveDateTime};
 use std::fs;
-use std::path::PathBuf;
-
-/// Archive configuration
-#[derive(Debug, Clone)]
-pub struct ArchiveConfig {
-    pub retention_days: i64,
-    #[allow(dead_code)]
-    pub auto_archive: bool,
-}
-
-impl Default for ArchiveConfig {
-    fn default() -> Self {
-        Self {
-            retention_days: 365, // 1 year retention by default
-            auto_archive: false,
-        }
-    }
-}
+use std::path::{Path, PathBuf};
 
 pub fn handle_config_maintain(
     backup: bool,
@@ -53,25 +39,28 @@ pub fn handle_config_maintain(
         if !config_path.exists() {
             output.warning(&output.t("config-maintain-backup-none"));
         } else {
-            let backup_dir = config_path
-                .parent()
-                .ok_or_else(|| CldevError::config("Invalid config path"))?
-                .join("backups");
-
-            fs::create_dir_all(&backup_dir)
-                .map_err(|e| CldevError::io(format!("Failed to create backup directory: {}", e)))?;
-
-            let timestamp = chrono::Local::now().format("%Y%m%d_%H%M%S");
-            let backup_path = backup_dir.join(format!("config.toml.{}", timestamp));
-
-            fs::copy(&config_path, &backup_path)
-                .map_err(|e| CldevError::io(format!("Failed to create backup: {}", e)))?;
+            let backup_dir = prepare_backup_dir(&config_path, output)?;
+            let backup_path = backup_config_file_to(&config_path, &backup_dir)?;
 
             output.success(&output.t_format(
                 "config-maintain-backup-created",
                 "path",
                 &backup_path.display().to_string(),
             ));
+
+            let days = retention_days.unwrap_or(365);
+            let rotated = rotate_backups(&backup_dir, days, Local::now())?;
+            if !rotated.is_empty() {
+                output.info(
+                    &output
+                        .t_format(
+                            "config-maintain-backups-rotated",
+                            "count",
+                            &rotated.len().to_string(),
+                        )
+                        .replace("{days}", &days.to_string()),
+                );
+            }
         }
     }
 
@@ -79,39 +68,16 @@ pub fn handle_config_maintain(
     if cleanup {
         output.info(&output.t("config-maintain-cleaning-up"));
 
-        let backup_dir = config_path
-            .parent()
-            .ok_or_else(|| CldevError::config("Invalid config path"))?
-            .join("backups");
+        let backup_dir = prepare_backup_dir(&config_path, output)?;
 
         if !backup_dir.exists() {
             output.info(&output.t("config-maintain-dir-none"));
         } else {
-            let mut backups: Vec<PathBuf> = fs::read_dir(&backup_dir)
-                .map_err(|e| CldevError::io(format!("Failed to read backup directory: {}", e)))?
-                .filter_map(|entry| entry.ok())
-                .map(|entry| entry.path())
-                .filter(|path| {
-                    path.is_file()
-                        && path
-                            .file_name()
-                            .and_then(|n| n.to_str())
-                            .map(|n| n.starts_with("config.toml."))
-                            .unwrap_or(false)
-                })
-                .collect();
+            let backups = list_backups(&backup_dir)?;
 
             if backups.is_empty() {
                 output.info(&output.t("config-maintain-backups-none"));
             } else {
-                // Sort by modification time (newest first)
-                backups.sort_by_key(|path| {
-                    fs::metadata(path)
-                        .and_then(|m| m.modified())
-                        .ok()
-                        .and_then(|t| std::time::SystemTime::now().duration_since(t).ok())
-                });
-
                 let keep_count = 10;
                 let remove_count = backups.len().saturating_sub(keep_count);
 
@@ -127,18 +93,18 @@ pub fn handle_config_maintain(
                     );
 
                     for backup in backups.iter().skip(keep_count) {
-                        match fs::remove_file(backup) {
+                        match fs::remove_file(&backup.path) {
                             Ok(_) => output.success(&output.t_format(
                                 "config-maintain-remove-success",
                                 "file",
-                                &backup.file_name().unwrap().to_string_lossy(),
+                                &backup.file_name(),
                             )),
                             Err(e) => output.warning(
                                 &output
                                     .t_format(
                                         "config-maintain-remove-failed",
                                         "path",
-                                        &backup.display().to_string(),
+                                        &backup.path.display().to_string(),
                                     )
                                     .replace("{error}", &e.to_string()),
                             ),
@@ -155,6 +121,20 @@ pub fn handle_config_maintain(
         }
     }
 
+    // Remove stale entries from the learning record index if requested
+    if cleanup {
+        let report = cleanup_learning_records(&LearningSession::records_dir()?)?;
+        output.info(
+            &output
+                .t_format(
+                    "config-maintain-lr-cleaned",
+                    "orphans",
+                    &report.orphaned_rows.to_string(),
+                )
+                .replace("{empty}", &report.empty_files.to_string()),
+        );
+    }
+
     // Report configuration health
     output.info(&output.t("config-maintain-health-report"));
     output.list_item(&output.t_format(
@@ -193,32 +173,22 @@ pub fn handle_config_maintain(
     // Perform learning records archive if requested
     if archive {
         output.info(&output.t("config-maintain-archiving"));
-        let archive_config = ArchiveConfig {
-            retention_days: retention_days.unwrap_or(365),
-            auto_archive: true,
-        };
-
-        match archive_learning_records(&archive_config, output) {
-            Ok(count) => {
-                if count > 0 {
-                    output.success(&output.t_format(
-                        "config-maintain-archive-success",
-                        "count",
-                        &count.to_string(),
-                    ));
-                } else {
-                    output.info(&output.t("config-maintain-archive-none"));
-                }
-            }
-            Err(e) => {
-                output.error(&output.t_format(
-                    "config-maintain-archive-failed",
-                    "error",
-                    &e.to_string(),
-                ));
-                return Err(e);
-            }
-        }
+        let retention_days = retention_days.unwrap_or(365);
+
+        let records_dir = LearningSession::records_dir()?;
+        let archived = archive_resolved_records(&records_dir, retention_days, Local::now())?;
+        output.info(
+            &output
+                .t_format(
+                    "config-maintain-lr-archived",
+                    "count",
+                    &archived.len().to_string(),
+                )
+                .replace(
+                    "{path}",
+                    &records_dir.join(RECORD_ARCHIVE_DIR).display().to_string(),
+                ),
+        );
     }
 
     if !backup && !cleanup && !archive {
@@ -228,117 +198,423 @@ pub fn handle_config_maintain(
     Ok(())
 }
 
-/// Detect expired learning records based on retention policy
-fn detect_expired_records(retention_days: i64) -> Result<Vec<(String, DateTime<Local>)>> {
-    let home =
-        dirs::home_dir().ok_or_else(|| CldevError::config("Could not determine home directory"))?;
+/// Subdirectory of the learning records directory holding archived records
+pub(crate) const RECORD_ARCHIVE_DIR: &str = "archive";
+
+/// What [`cleanup_learning_records`] removed
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct RecordCleanup {
+    /// Database rows whose markdown file no longer exists
+    pub orphaned_rows: usize,
+    /// Markdown files with no content
+    pub empty_files: usize,
+}
 
-    let lr_dir = home.join(".cldev").join("learning-records");
-    if !lr_dir.exists() {
+/// Move resolved records older than `retention_days` into the `archive/` subdirectory
+///
+/// Archived records are removed from the learning database, which only
+/// indexes the top level of `records_dir`. Returns the archived session IDs.
+pub(crate) fn archive_resolved_records(
+    records_dir: &Path,
+    retention_days: i64,
+    now: DateTime<Local>,
+) -> Result<Vec<String>> {
+    if !records_dir.exists() {
         return Ok(Vec::new());
     }
 
-    let cutoff_date = Local::now() - Duration::days(retention_days);
-    let mut expired = Vec::new();
+    let mut db = LearningDatabase::new(records_dir.join("learning.db"), records_dir.to_path_buf())?;
+    db.build_from_markdown()?;
 
-    for entry in fs::read_dir(&lr_dir)? {
-        let entry = entry?;
-        let path = entry.path();
+    let cutoff = now - Duration::days(retention_days);
+    let archive_dir = records_dir.join(RECORD_ARCHIVE_DIR);
+    let mut archived = Vec::new();
 
-        if path.extension().and_then(|s| s.to_str()) == Some("md") {
-            if let Some(file_name) = path.file_stem().and_then(|s| s.to_str()) {
-                // Try to load the record to get its creation date
-                if let Ok(record) = LearningRecordV3::load(file_name) {
-                    if record.created < cutoff_date {
-                        expired.push((file_name.to_string(), record.created));
-                    }
-                }
-            }
+    for session in db.get_all_sessions()? {
+        let expired = DateTime::parse_from_rfc3339(&session.timestamp)
+            .map(|timestamp| timestamp < cutoff)
+            .unwrap_or(false);
+        if !session.resolved || !expired {
+            continue;
         }
+
+        let path = PathBuf::from(&session.markdown_path);
+        let Some(file_name) = path.file_name() else {
+            continue;
+        };
+        fs::create_dir_all(&archive_dir)?;
+        fs::rename(&path, unused_path(&archive_dir, Path::new(file_name)))?;
+
+        db.delete_session(&session.id)?;
+        archived.push(session.id);
     }
 
-    // Sort by date (oldest first)
-    expired.sort_by(|a, b| a.1.cmp(&b.1));
+    Ok(archived)
+}
 
-    Ok(expired)
+/// Path for `file_name` in `dir` that does not exist yet
+///
+/// An existing file of the same name is kept, and a numeric suffix is added
+/// instead (`record-2.md`, `record-3.md`, ...).
+fn unused_path(dir: &Path, file_name: &Path) -> PathBuf {
+    let path = dir.join(file_name);
+    if !path.exists() {
+        return path;
+    }
+
+    let stem = file_name.file_stem().unwrap_or_default().to_string_lossy();
+    let extension = file_name
+        .extension()
+        .map(|e| format!(".{}", e.to_string_lossy()))
+        .unwrap_or_default();
+    (2..)
+        .map(|n| dir.join(format!("{}-{}{}", stem, n, extension)))
+        .find(|path| !path.exists())
+        .expect("unbounded suffixes")
 }
 
-/// Archive learning records
-fn archive_learning_records(config: &ArchiveConfig, output: &OutputHandler) -> Result<usize> {
-    let home =
-        dirs::home_dir().ok_or_else(|| CldevError::config("Could not determine home directory"))?;
+/// Delete database rows without a markdown file, and empty markdown files
+pub(crate) fn cleanup_learning_records(records_dir: &Path) -> Result<RecordCleanup> {
+    let mut report = RecordCleanup::default();
+    if !records_dir.exists() {
+        return Ok(report);
+    }
 
-    let lr_dir = home.join(".cldev").join("learning-records");
-    let archive_dir = home.join(".cldev").join("learning-records-archive");
+    for entry in fs::read_dir(records_dir)?.flatten() {
+        let path = entry.path();
+        if path.is_file()
+            && path.extension().and_then(|e| e.to_str()) == Some("md")
+            && fs::read_to_string(&path)?.trim().is_empty()
+        {
+            fs::remove_file(&path)?;
+            report.empty_files += 1;
+        }
+    }
 
-    // Create archive directory if it doesn't exist
-    if !archive_dir.exists() {
-        fs::create_dir_all(&archive_dir)?;
+    let mut db = LearningDatabase::new(records_dir.join("learning.db"), records_dir.to_path_buf())?;
+    for session in db.get_all_sessions()? {
+        if !Path::new(&session.markdown_path).exists() && db.delete_session(&session.id)? {
+            report.orphaned_rows += 1;
+        }
+    }
+
+    Ok(report)
+}
+
+/// File name prefix of configuration backups
+const BACKUP_PREFIX: &str = "config.toml.";
+
+/// Timestamp suffix of configuration backups
+const BACKUP_TIMESTAMP_FORMAT: &str = "%Y%m%d_%H%M%S";
+
+/// A timestamped configuration backup
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct ConfigBackup {
+    pub path: PathBuf,
+    pub timestamp: NaiveDateTime,
+}
+
+impl ConfigBackup {
+    pub fn file_name(&self) -> String {
+        self.path
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default()
+    }
+}
+
+/// Directory holding backups of the configuration at `config_path`
+///
+/// Backups of the default configuration go to `~/.cldev/backups/config`. A
+/// configuration chosen with `--config` keeps its backups in the `backups/`
+/// directory next to it, so that backups of different files never mix.
+pub(crate) fn config_backup_dir(config_path: &Path) -> Result<PathBuf> {
+    if Config::path_override().is_some() {
+        return legacy_backup_dir(config_path);
     }
 
-    // Detect expired records
-    let expired_records = detect_expired_records(config.retention_days)?;
+    Ok(home_dir()?.join(".cldev").join("backups").join("config"))
+}
+
+/// The `backups/` directory next to the configuration, used by older versions
+fn legacy_backup_dir(config_path: &Path) -> Result<PathBuf> {
+    Ok(config_path
+        .parent()
+        .ok_or_else(|| CldevError::config("Invalid config path"))?
+        .join("backups"))
+}
 
-    if expired_records.is_empty() {
+/// Move backups left in the legacy `backups/` directory into `backup_dir`
+///
+/// Backups already present in `backup_dir` are left where they are. Returns
+/// the number of backups moved.
+pub(crate) fn adopt_legacy_backups(config_path: &Path, backup_dir: &Path) -> Result<usize> {
+    let legacy_dir = legacy_backup_dir(config_path)?;
+    if legacy_dir == backup_dir {
         return Ok(0);
     }
 
-    output.info(
-        &output
-            .t_format(
-                "config-maintain-archive-found",
-                "count",
-                &expired_records.len().to_string(),
-            )
-            .replace("{days}", &config.retention_days.to_string()),
-    );
-
-    // Create year-based subdirectory
-    let current_year = Local::now().format("%Y").to_string();
-    let year_dir = archive_dir.join(&current_year);
-    if !year_dir.exists() {
-        fs::create_dir_all(&year_dir)?;
+    let mut moved = 0;
+    for backup in list_backups(&legacy_dir)? {
+        let target = backup_dir.join(backup.file_name());
+        if target.exists() {
+            continue;
+        }
+
+        fs::create_dir_all(backup_dir)
+            .map_err(|e| CldevError::io(format!("Failed to create backup directory: {}", e)))?;
+        if fs::rename(&backup.path, &target).is_err() {
+            // Fall back to copying when the directories are on different devices
+            fs::copy(&backup.path, &target)?;
+            fs::remove_file(&backup.path)?;
+        }
+        moved += 1;
+    }
+
+    Ok(moved)
+}
+
+/// Resolve the backup directory for `config_path`, moving legacy backups into it
+pub(crate) fn prepare_backup_dir(config_path: &Path, output: &OutputHandler) -> Result<PathBuf> {
+    let backup_dir = config_backup_dir(config_path)?;
+
+    let moved = adopt_legacy_backups(config_path, &backup_dir)?;
+    if moved > 0 {
+        output.info(
+            &output
+                .t_format("config-backups-moved", "count", &moved.to_string())
+                .replace("{path}", &backup_dir.display().to_string()),
+        );
+    }
+
+    Ok(backup_dir)
+}
+
+/// Copy the configuration file into `backup_dir`
+///
+/// Backups are named `config.toml.<timestamp>` so that they can be rotated
+/// and restored by date. An existing backup is never overwritten: when one already carries the
+/// current timestamp, the next free second is used.
+pub(crate) fn backup_config_file_to(config_path: &Path, backup_dir: &Path) -> Result<PathBuf> {
+    fs::create_dir_all(backup_dir)
+        .map_err(|e| CldevError::io(format!("Failed to create backup directory: {}", e)))?;
+
+    let mut timestamp = Local::now().naive_local();
+    let mut backup_path;
+    loop {
+        backup_path = backup_dir.join(format!(
+            "{}{}",
+            BACKUP_PREFIX,
+            timestamp.format(BACKUP_TIMESTAMP_FORMAT)
+        ));
+        if !backup_path.exists() {
+            break;
+        }
+        timestamp += Duration::seconds(1);
     }
 
-    // Create archive file
-    let timestamp = Local::now().format("%Y%m%d_%H%M%S");
-    let archive_path = year_dir.join(format!("archive_{}.tar.gz", timestamp));
+    fs::copy(config_path, &backup_path)
+        .map_err(|e| CldevError::io(format!("Failed to create backup: {}", e)))?;
 
-    // Use tar and gzip to create compressed archive
-    let tar_gz = fs::File::create(&archive_path)?;
-    let enc = flate2::write::GzEncoder::new(tar_gz, flate2::Compression::default());
-    let mut tar = tar::Builder::new(enc);
+    Ok(backup_path)
+}
 
-    let mut archived_count = 0;
+/// Timestamped backups in `backup_dir`, newest first
+///
+/// Files whose name does not carry a backup timestamp are ignored.
+pub(crate) fn list_backups(backup_dir: &Path) -> Result<Vec<ConfigBackup>> {
+    if !backup_dir.exists() {
+        return Ok(Vec::new());
+    }
 
-    for (record_id, created_date) in &expired_records {
-        let source_path = lr_dir.join(format!("{}.md", record_id));
+    let mut backups: Vec<ConfigBackup> = fs::read_dir(backup_dir)
+        .map_err(|e| CldevError::io(format!("Failed to read backup directory: {}", e)))?
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.is_file())
+        .filter_map(|path| {
+            let name = path.file_name()?.to_str()?;
+            let timestamp = NaiveDateTime::parse_from_str(
+                name.strip_prefix(BACKUP_PREFIX)?,
+                BACKUP_TIMESTAMP_FORMAT,
+            )
+            .ok()?;
+            Some(ConfigBackup { path, timestamp })
+        })
+        .collect();
+    backups.sort_by_key(|backup| std::cmp::Reverse(backup.timestamp));
 
-        if source_path.exists() {
-            // Add to tar archive
-            let mut file = fs::File::open(&source_path)?;
-            tar.append_file(format!("{}.md", record_id), &mut file)?;
+    Ok(backups)
+}
 
-            // Remove original file
-            fs::remove_file(&source_path)?;
+/// Remove backups older than `retention_days` before `now`
+///
+/// The newest backup is always kept. Returns the removed paths.
+pub(crate) fn rotate_backups(
+    backup_dir: &Path,
+    retention_days: i64,
+    now: DateTime<Local>,
+) -> Result<Vec<PathBuf>> {
+    let cutoff = (now - Duration::days(retention_days)).naive_local();
+    let mut removed = Vec::new();
+
+    for backup in list_backups(backup_dir)?.into_iter().skip(1) {
+        if backup.timestamp < cutoff {
+            fs::remove_file(&backup.path)?;
+            removed.push(backup.path);
+        }
+    }
 
-            output.list_item(
-                &output
-                    .t_format("config-maintain-archive-item", "id", record_id)
-                    .replace("{date}", &created_date.format("%Y-%m-%d").to_string()),
-            );
+    Ok(removed)
+}
 
-            archived_count += 1;
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::learning_record_v2::*;
+    use tempfile::TempDir;
+
+    fn write_record(dir: &Path, id: &str, age_days: i64, resolved: bool) {
+        let mut builder = LearningRecordBuilder::new(
+            SessionType::Fix,
+            Priority::Medium,
+            "Stale cache",
+            "Cache entries never expire",
+            Severity::Warning,
+        );
+        if resolved {
+            builder = builder.resolved(20);
         }
+        let mut record = builder.build();
+        record.session_meta.id = id.to_string();
+        record.session_meta.timestamp = Local::now() - Duration::days(age_days);
+
+        let yaml = serde_yaml::to_string(&record).unwrap();
+        fs::write(
+            dir.join(format!("{}.md", id)),
+            format!("---\n{}---\n", yaml),
+        )
+        .unwrap();
     }
 
-    tar.finish()?;
+    fn indexed_ids(dir: &Path) -> Vec<String> {
+        let db = LearningDatabase::new(dir.join("learning.db"), dir.to_path_buf()).unwrap();
+        let mut ids: Vec<String> = db
+            .get_all_sessions()
+            .unwrap()
+            .into_iter()
+            .map(|s| s.id)
+            .collect();
+        ids.sort();
+        ids
+    }
 
-    output.success(&output.t_format(
-        "config-maintain-archive-created",
-        "path",
-        &archive_path.display().to_string(),
-    ));
+    #[test]
+    fn test_archive_moves_only_old_resolved_records() {
+        let temp_dir = TempDir::new().unwrap();
+        let dir = temp_dir.path();
+        write_record(dir, "fix-old-resolved", 400, true);
+        write_record(dir, "fix-old-open", 400, false);
+        write_record(dir, "fix-recent-open", 3, false);
+        write_record(dir, "fix-recent-resolved", 3, true);
+
+        let archived = archive_resolved_records(dir, 365, Local::now()).unwrap();
+        assert_eq!(archived, vec!["fix-old-resolved"]);
+
+        assert!(!dir.join("fix-old-resolved.md").exists());
+        assert!(dir
+            .join(RECORD_ARCHIVE_DIR)
+            .join("fix-old-resolved.md")
+            .exists());
+        assert!(dir.join("fix-old-open.md").exists());
+        assert_eq!(
+            indexed_ids(dir),
+            vec!["fix-old-open", "fix-recent-open", "fix-recent-resolved"]
+        );
+    }
+
+    #[test]
+    fn test_archive_keeps_previously_archived_record() {
+        let temp_dir = TempDir::new().unwrap();
+        let dir = temp_dir.path();
+        let archive_dir = dir.join(RECORD_ARCHIVE_DIR);
+        fs::create_dir_all(&archive_dir).unwrap();
+        fs::write(archive_dir.join("fix-old-resolved.md"), "archived before").unwrap();
+        write_record(dir, "fix-old-resolved", 400, true);
+
+        let archived = archive_resolved_records(dir, 365, Local::now()).unwrap();
+        assert_eq!(archived, vec!["fix-old-resolved"]);
+
+        assert_eq!(
+            fs::read_to_string(archive_dir.join("fix-old-resolved.md")).unwrap(),
+            "archived before"
+        );
+        assert!(archive_dir.join("fix-old-resolved-2.md").exists());
+        assert!(!dir.join("fix-old-resolved.md").exists());
+        assert!(indexed_ids(dir).is_empty());
+    }
+
+    #[test]
+    fn test_cleanup_removes_orphans_and_empty_files() {
+        let temp_dir = TempDir::new().unwrap();
+        let dir = temp_dir.path();
+        write_record(dir, "fix-kept", 3, false);
+        write_record(dir, "fix-deleted", 3, true);
+        let mut db = LearningDatabase::new(dir.join("learning.db"), dir.to_path_buf()).unwrap();
+        db.build_from_markdown().unwrap();
+        drop(db);
+
+        fs::remove_file(dir.join("fix-deleted.md")).unwrap();
+        fs::write(dir.join("blank.md"), "  \n").unwrap();
+
+        let report = cleanup_learning_records(dir).unwrap();
+        assert_eq!(
+            report,
+            RecordCleanup {
+                orphaned_rows: 1,
+                empty_files: 1
+            }
+        );
+        assert!(!dir.join("blank.md").exists());
+        assert_eq!(indexed_ids(dir), vec!["fix-kept"]);
+    }
 
-    Ok(archived_count)
+    #[test]
+    fn test_rotate_backups_keeps_those_within_retention() {
+        let temp_dir = TempDir::new().unwrap();
+        let backup_dir = temp_dir.path();
+        let now = Local::now();
+
+        for days in [1, 10, 40, 400] {
+            let name = (now - Duration::days(days)).format(BACKUP_TIMESTAMP_FORMAT);
+            fs::write(backup_dir.join(format!("{}{}", BACKUP_PREFIX, name)), "").unwrap();
+        }
+        fs::write(backup_dir.join("notes.txt"), "").unwrap();
+
+        let removed = rotate_backups(backup_dir, 30, now).unwrap();
+        assert_eq!(removed.len(), 2);
+
+        let kept = list_backups(backup_dir).unwrap();
+        assert_eq!(kept.len(), 2);
+        assert!(kept[0].timestamp > kept[1].timestamp);
+        assert!(kept
+            .iter()
+            .all(|b| b.timestamp >= (now - Duration::days(30)).naive_local()));
+        assert!(backup_dir.join("notes.txt").exists());
+    }
+
+    #[test]
+    fn test_rotate_backups_keeps_newest() {
+        let temp_dir = TempDir::new().unwrap();
+        let now = Local::now();
+        let name = (now - Duration::days(90)).format(BACKUP_TIMESTAMP_FORMAT);
+        fs::write(
+            temp_dir.path().join(format!("{}{}", BACKUP_PREFIX, name)),
+            "",
+        )
+        .unwrap();
+
+        assert!(rotate_backups(temp_dir.path(), 30, now).unwrap().is_empty());
+        assert_eq!(list_backups(temp_dir.path()).unwrap().len(), 1);
+    }
 }
@@ -0,0 +1,143 @@
+//! Configuration schema migration
+//!
+//! Upgrades an older configuration file to the current `CONFIG_VERSION` by
+//! applying the registered schema migrations to the raw TOML document.
+
+use super::maintain::{backup_config_file_to, config_backup_dir};
+use crate::cli::output::OutputHandler;
+use crate::core::config::{migrate, validate_version, Config, ConfigVersion, CONFIG_VERSION};
+use crate::core::error::{CldevError, Result};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Migrate the configuration file to the current schema version
+///
+/// # Arguments
+///
+/// * `config_path` - Optional path to config file (uses default if None)
+/// * `backup` - Copy the original file into `~/.cldev/backups/config` before rewriting it
+/// * `output` - Output handler for formatted messages
+pub fn handle_config_migrate(
+    config_path: Option<PathBuf>,
+    backup: bool,
+    output: &OutputHandler,
+) -> Result<()> {
+    let config_path = match config_path {
+        Some(path) => path,
+        None => Config::default_path()?,
+    };
+    let backup_dir = if backup {
+        Some(config_backup_dir(&config_path)?)
+    } else {
+        None
+    };
+
+    migrate_config_file(&config_path, backup_dir.as_deref(), output)
+}
+
+/// Migrate the configuration file at `config_path`, backing it up into `backup_dir` first
+pub(crate) fn migrate_config_file(
+    config_path: &Path,
+    backup_dir: Option<&Path>,
+    output: &OutputHandler,
+) -> Result<()> {
+    if !config_path.exists() {
+        return Err(CldevError::config(output.t_format(
+            "config-migrate-not-found",
+            "path",
+            &config_path.display().to_string(),
+        )));
+    }
+
+    let content = fs::read_to_string(config_path).map_err(|e| {
+        CldevError::config(format!(
+            "Failed to read config file {}: {}",
+            config_path.display(),
+            e
+        ))
+    })?;
+    let old: toml::Value = toml::from_str(&content)?;
+
+    let from = old
+        .get("version")
+        .and_then(|v| v.as_str())
+        .unwrap_or(CONFIG_VERSION)
+        .to_string();
+
+    // Files on the current major version only differ by backward-compatible
+    // additions, so there is nothing to rewrite
+    let (from_major, _, _) = ConfigVersion::parse(&from)?;
+    let (current_major, _, _) = ConfigVersion::parse(CONFIG_VERSION)?;
+    if from_major == current_major {
+        validate_version(&from)?;
+        output.success(&output.t_format("config-migrate-up-to-date", "version", &from));
+        return Ok(());
+    }
+
+    let config = migrate(old, &from, CONFIG_VERSION)?;
+
+    if let Some(backup_dir) = backup_dir {
+        let backup_path = backup_config_file_to(config_path, backup_dir)?;
+        output.success(&output.t_format(
+            "config-maintain-backup-created",
+            "path",
+            &backup_path.display().to_string(),
+        ));
+    }
+
+    config.save(Some(config_path.to_path_buf()))?;
+
+    output.success(
+        &output
+            .t_format("config-migrate-success", "from", &from)
+            .replace("{to}", CONFIG_VERSION),
+    );
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_migrate_without_path_leaves_file_untouched() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("config.toml");
+        fs::write(
+            &config_path,
+            "version = \"0.9.0\"\n\n[general]\nlanguage = \"en\"\n",
+        )
+        .unwrap();
+
+        // No migration step is registered from v0, so nothing may be written
+        let output = OutputHandler::new(false, true, true);
+        assert!(handle_config_migrate(Some(config_path.clone()), true, &output).is_err());
+        assert!(fs::read_to_string(&config_path).unwrap().contains("0.9.0"));
+        assert!(!temp_dir.path().join("backups").exists());
+    }
+
+    #[test]
+    fn test_migrate_current_version_is_noop() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("config.toml");
+        Config::default().save(Some(config_path.clone())).unwrap();
+        let before = fs::read_to_string(&config_path).unwrap();
+
+        let output = OutputHandler::new(false, true, true);
+        handle_config_migrate(Some(config_path.clone()), true, &output).unwrap();
+
+        assert_eq!(fs::read_to_string(&config_path).unwrap(), before);
+        assert!(!temp_dir.path().join("backups").exists());
+    }
+
+    #[test]
+    fn test_migrate_missing_file() {
+        let temp_dir = TempDir::new().unwrap();
+        let output = OutputHandler::new(false, true, true);
+        let result =
+            handle_config_migrate(Some(temp_dir.path().join("missing.toml")), false, &output);
+        assert!(result.is_err());
+    }
+}
@@ -1,14 +1,22 @@
 /// Configuration management commands
+mod access;
 mod check;
+mod diff;
 mod edit;
 mod init;
 mod list;
 mod maintain;
+mod migrate;
+mod restore;
 mod update_docs;
 
+pub use access::{handle_config_get, handle_config_set};
 pub use check::check_config;
+pub use diff::handle_config_diff;
 pub use edit::edit_config;
 pub use init::run_interactive_init;
 pub use list::list_commands;
 pub use maintain::handle_config_maintain;
+pub use migrate::handle_config_migrate;
+pub use restore::handle_config_restore;
 pub use update_docs::handle_update_docs;
@@ -0,0 +1,258 @@
+//! Restore the configuration file from a timestamped backup
+//!
+//! Without a backup to restore, the available backups are listed newest
+//! first. A backup is only restored once it parses as a valid configuration,
+//! and the configuration it replaces is backed up first.
+
+use super::maintain::{backup_config_file_to, list_backups, prepare_backup_dir};
+use crate::cli::output::OutputHandler;
+use crate::core::config::Config;
+use crate::core::error::{CldevError, Result};
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/// Handle config restore command
+///
+/// # Arguments
+///
+/// * `from` - Backup to restore: a path, a backup file name, or its timestamp
+/// * `output` - Output handler for formatted messages
+pub fn handle_config_restore(from: Option<&str>, output: &OutputHandler) -> Result<()> {
+    let config_path = Config::default_path()?;
+    let backup_dir = prepare_backup_dir(&config_path, output)?;
+
+    let Some(from) = from else {
+        let backups = list_backups(&backup_dir)?;
+        if backups.is_empty() {
+            output.info(&output.t("config-maintain-backups-none"));
+            return Ok(());
+        }
+
+        output.section(&output.t_format(
+            "config-restore-available",
+            "path",
+            &backup_dir.display().to_string(),
+        ));
+        for backup in &backups {
+            output.list_item(&format!(
+                "{}  ({})",
+                backup.file_name(),
+                backup.timestamp.format("%Y-%m-%d %H:%M:%S")
+            ));
+        }
+        output.info(&output.t("config-restore-usage"));
+        return Ok(());
+    };
+
+    let backup_path = resolve_backup(from, &backup_dir).ok_or_else(|| {
+        CldevError::config(output.t_format("config-restore-not-found", "backup", from))
+    })?;
+    if let Some(previous) = restore_backup(&backup_path, &config_path, &backup_dir)? {
+        output.info(&output.t_format(
+            "config-restore-previous-saved",
+            "path",
+            &previous.display().to_string(),
+        ));
+    }
+
+    output.success(&output.t_format(
+        "config-restore-success",
+        "path",
+        &backup_path.display().to_string(),
+    ));
+    Ok(())
+}
+
+/// Find the backup named by `from` in `backup_dir`, or at the path `from`
+fn resolve_backup(from: &str, backup_dir: &Path) -> Option<PathBuf> {
+    [
+        backup_dir.join(format!("config.toml.{}", from)),
+        backup_dir.join(from),
+        PathBuf::from(from),
+    ]
+    .into_iter()
+    .find(|path| path.is_file())
+}
+
+/// Replace the configuration at `config_path` with the backup at `backup_path`
+///
+/// The backup must parse as a configuration with a supported version, or
+/// one that [`Config::load`] migrates from an older major version. The
+/// current configuration, if any, is then backed up into `backup_dir` and its
+/// backup path returned. The backup is written to a temporary file next to
+/// `config_path` and renamed over it, so the configuration is never left half
+/// written.
+pub fn restore_backup(
+    backup_path: &Path,
+    config_path: &Path,
+    backup_dir: &Path,
+) -> Result<Option<PathBuf>> {
+    let content = fs::read_to_string(backup_path).map_err(|e| {
+        CldevError::config(format!(
+            "Failed to read backup {}: {}",
+            backup_path.display(),
+            e
+        ))
+    })?;
+    // Backups from an older major version, such as the one `config migrate
+    // --backup` keeps, are restored as-is and migrated again on load
+    Config::parse(&content, backup_path)?;
+
+    let previous = if config_path.is_file() {
+        Some(backup_config_file_to(config_path, backup_dir)?)
+    } else {
+        None
+    };
+
+    if let Some(parent) = config_path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let tmp = config_path.with_extension(format!("toml.tmp.{}", std::process::id()));
+    write_private(&tmp, &content)?;
+
+    fs::rename(&tmp, config_path).inspect_err(|_| {
+        let _ = fs::remove_file(&tmp);
+    })?;
+
+    Ok(previous)
+}
+
+/// Create `path` readable by its owner only and write `content` to it
+fn write_private(path: &Path, content: &str) -> Result<()> {
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+
+    let mut file = options.open(path)?;
+    file.write_all(content.as_bytes()).inspect_err(|_| {
+        let _ = fs::remove_file(path);
+    })?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::maintain::adopt_legacy_backups;
+    use super::super::migrate::migrate_config_file;
+    use super::*;
+    use crate::core::config::CONFIG_VERSION;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_restore_round_trips_config() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("config.toml");
+        let backup_dir = temp_dir.path().join("backups");
+
+        let mut config = Config::default();
+        config.general.language = "ja".to_string();
+        config.save(Some(config_path.clone())).unwrap();
+        let original = fs::read_to_string(&config_path).unwrap();
+
+        let backup_path = backup_config_file_to(&config_path, &backup_dir).unwrap();
+        config.general.language = "en".to_string();
+        config.save(Some(config_path.clone())).unwrap();
+        assert_ne!(fs::read_to_string(&config_path).unwrap(), original);
+
+        let name = backup_path.file_name().unwrap().to_str().unwrap();
+        let timestamp = name.strip_prefix("config.toml.").unwrap();
+        let resolved = resolve_backup(timestamp, &backup_dir).unwrap();
+        assert_eq!(resolved, backup_path);
+
+        let replaced = fs::read_to_string(&config_path).unwrap();
+        let previous = restore_backup(&resolved, &config_path, &backup_dir)
+            .unwrap()
+            .unwrap();
+        assert_eq!(fs::read_to_string(&config_path).unwrap(), original);
+        let restored = Config::load(Some(config_path)).unwrap();
+        assert_eq!(restored.general.language, "ja");
+
+        // The replaced configuration is kept as a new backup
+        assert_ne!(previous, backup_path);
+        assert_eq!(fs::read_to_string(&previous).unwrap(), replaced);
+        assert_eq!(fs::read_to_string(&backup_path).unwrap(), original);
+        assert_eq!(list_backups(&backup_dir).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_restore_rolls_back_migration() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("config.toml");
+        let backup_dir = temp_dir.path().join("backups");
+        let v1 = "version = \"1.0.0\"\n\n[performance]\nparallel_tasks = 3\n";
+        fs::write(&config_path, v1).unwrap();
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            fs::set_permissions(&config_path, fs::Permissions::from_mode(0o600)).unwrap();
+        }
+
+        let output = OutputHandler::new(false, true, true);
+        migrate_config_file(&config_path, Some(&backup_dir), &output).unwrap();
+        assert!(fs::read_to_string(&config_path)
+            .unwrap()
+            .contains(CONFIG_VERSION));
+
+        let backups = list_backups(&backup_dir).unwrap();
+        assert_eq!(backups.len(), 1);
+        restore_backup(&backups[0].path, &config_path, &backup_dir).unwrap();
+
+        assert_eq!(fs::read_to_string(&config_path).unwrap(), v1);
+        let restored = Config::load(Some(config_path.clone())).unwrap();
+        assert_eq!(restored.performance.max_jobs, 3);
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = fs::metadata(&config_path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+    }
+
+    #[test]
+    fn test_restore_rejects_invalid_backup() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("config.toml");
+        Config::default().save(Some(config_path.clone())).unwrap();
+        let before = fs::read_to_string(&config_path).unwrap();
+
+        let backup_path = temp_dir.path().join("config.toml.20240101_000000");
+        fs::write(&backup_path, "version = [not toml").unwrap();
+
+        let backup_dir = temp_dir.path().join("backups");
+        assert!(restore_backup(&backup_path, &config_path, &backup_dir).is_err());
+        assert_eq!(fs::read_to_string(&config_path).unwrap(), before);
+        assert!(!backup_dir.exists());
+    }
+
+    #[test]
+    fn test_adopt_legacy_backups() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("config.toml");
+        Config::default().save(Some(config_path.clone())).unwrap();
+
+        let legacy_dir = temp_dir.path().join("backups");
+        let old = backup_config_file_to(&config_path, &legacy_dir).unwrap();
+        fs::write(legacy_dir.join("notes.txt"), "not a backup").unwrap();
+
+        let backup_dir = temp_dir.path().join("home/.cldev/backups/config");
+        assert_eq!(adopt_legacy_backups(&config_path, &backup_dir).unwrap(), 1);
+        assert!(!old.exists());
+        assert!(legacy_dir.join("notes.txt").exists());
+
+        let backups = list_backups(&backup_dir).unwrap();
+        assert_eq!(backups.len(), 1);
+        assert_eq!(
+            backups[0].file_name(),
+            old.file_name().unwrap().to_str().unwrap()
+        );
+
+        // Backups already in their own directory stay put
+        assert_eq!(adopt_legacy_backups(&config_path, &legacy_dir).unwrap(), 0);
+    }
+}
@@ -1,8 +1,14 @@
+use std::fs;
 use std::path::Path;
 
-use crate::cli::args::DocType;
+use clap::{Arg, Command, CommandFactory};
+
+use crate::cli::args::{Cli, DocType};
 use crate::cli::output::OutputHandler;
-use crate::core::Result;
+use crate::core::{CldevError, Result};
+
+/// Where `update-docs commands` writes the command reference
+pub const COMMAND_REFERENCE_PATH: &str = "docs/COMMAND_REFERENCE.md";
 
 /// Handle config update-docs command
 pub fn handle_update_docs(
@@ -16,6 +22,7 @@ pub fn handle_update_docs(
         output.list_item(&output.t("config-update-docs-type-impl"));
         output.list_item(&output.t("config-update-docs-type-api"));
         output.list_item(&output.t("config-update-docs-type-arch"));
+        output.list_item(&output.t("config-update-docs-type-commands"));
         output.raw("");
         output.info(&output.t("config-update-docs-usage"));
         output.info(&output.t("config-update-docs-usage-validate"));
@@ -24,6 +31,10 @@ pub fn handle_update_docs(
 
     let doc_type = doc_type.unwrap();
 
+    if matches!(doc_type, DocType::Commands) {
+        return update_command_reference(Path::new(COMMAND_REFERENCE_PATH), validate, output);
+    }
+
     // Validation mode
     if validate {
         output.section(&output.t("config-update-docs-validating"));
@@ -109,6 +120,7 @@ pub fn handle_update_docs(
             output.list_item(&output.t("config-update-docs-arch-patterns"));
             output.list_item(&output.t("config-update-docs-arch-guides"));
         }
+        DocType::Commands => unreachable!("handled above"),
     }
 
     output.raw("");
@@ -117,3 +129,205 @@ pub fn handle_update_docs(
 
     Ok(())
 }
+
+/// Write the command reference to `path`, or check it is current when `validate` is set
+///
+/// Validation fails when the file is missing or differs from the reference
+/// generated from the current CLI, so CI can catch a stale reference.
+fn update_command_reference(path: &Path, validate: bool, output: &OutputHandler) -> Result<()> {
+    let reference = render_command_reference(&Cli::command());
+    let display = path.display().to_string();
+
+    if validate {
+        output.section(&output.t("config-update-docs-validating"));
+        return match fs::read_to_string(path) {
+            Ok(current) if current == reference => {
+                output.success(&output.t_format(
+                    "config-update-docs-commands-current",
+                    "path",
+                    &display,
+                ));
+                Ok(())
+            }
+            _ => Err(CldevError::validation(output.t_format(
+                "config-update-docs-commands-stale",
+                "path",
+                &display,
+            ))),
+        };
+    }
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, reference)?;
+    output.success(&output.t_format("config-update-docs-commands-written", "path", &display));
+    Ok(())
+}
+
+/// Render a Markdown reference of `cmd` and every visible subcommand
+///
+/// The options of `cmd` itself are listed once as global options; each
+/// subcommand gets a heading nested by its depth in the command tree.
+pub fn render_command_reference(cmd: &Command) -> String {
+    let mut md = String::from("# Command Reference\n\n");
+    md.push_str(&format!(
+        "<!-- Generated by `{} config update-docs commands`. Do not edit by hand. -->\n",
+        cmd.get_name()
+    ));
+    if let Some(about) = cmd.get_about() {
+        md.push_str(&format!("\n{}\n", about));
+    }
+
+    md.push_str("\n## Global Options\n");
+    append_arguments(cmd, &mut md);
+
+    for subcommand in cmd.get_subcommands().filter(|sub| !sub.is_hide_set()) {
+        append_command(
+            subcommand,
+            &format!("{} {}", cmd.get_name(), subcommand.get_name()),
+            2,
+            &mut md,
+        );
+    }
+    md
+}
+
+/// Append the section for `cmd` (invoked as `name`) and its subcommands
+fn append_command(cmd: &Command, name: &str, level: usize, md: &mut String) {
+    md.push_str(&format!("\n{} `{}`\n", "#".repeat(level.min(6)), name));
+    if let Some(about) = cmd.get_about() {
+        md.push_str(&format!("\n{}\n", about));
+    }
+    append_arguments(cmd, md);
+
+    for subcommand in cmd.get_subcommands().filter(|sub| !sub.is_hide_set()) {
+        append_command(
+            subcommand,
+            &format!("{} {}", name, subcommand.get_name()),
+            level + 1,
+            md,
+        );
+    }
+}
+
+/// Append a table of the visible arguments of `cmd`, if it has any
+fn append_arguments(cmd: &Command, md: &mut String) {
+    let args: Vec<&Arg> = cmd
+        .get_arguments()
+        .filter(|arg| !arg.is_hide_set())
+        .collect();
+    if args.is_empty() {
+        return;
+    }
+
+    md.push_str("\n| Argument | Description |\n|----------|-------------|\n");
+    for arg in args {
+        md.push_str(&format!(
+            "| `{}` | {} |\n",
+            arg_usage(arg),
+            table_cell(&arg_description(arg))
+        ));
+    }
+}
+
+/// Usage form of an argument, e.g. `-f, --format <FORMAT>` or `<PATH>`
+fn arg_usage(arg: &Arg) -> String {
+    let value_name = arg
+        .get_value_names()
+        .and_then(|names| names.first())
+        .map(|name| name.to_string())
+        .unwrap_or_else(|| arg.get_id().as_str().to_uppercase());
+
+    if arg.is_positional() {
+        return format!("<{}>", value_name);
+    }
+
+    let flag = match (arg.get_short(), arg.get_long()) {
+        (Some(short), Some(long)) => format!("-{}, --{}", short, long),
+        (None, Some(long)) => format!("--{}", long),
+        (Some(short), None) => format!("-{}", short),
+        (None, None) => arg.get_id().to_string(),
+    };
+    if arg.get_action().takes_values() {
+        format!("{} <{}>", flag, value_name)
+    } else {
+        flag
+    }
+}
+
+/// Help text of an argument with its possible and default values
+fn arg_description(arg: &Arg) -> String {
+    let mut description = arg
+        .get_help()
+        .map(|help| help.to_string())
+        .unwrap_or_default();
+
+    // Flags have implicit `true`/`false` values that are not worth listing
+    if !arg.get_action().takes_values() {
+        return description.trim().to_string();
+    }
+
+    let values: Vec<String> = arg
+        .get_possible_values()
+        .iter()
+        .filter(|value| !value.is_hide_set())
+        .map(|value| format!("`{}`", value.get_name()))
+        .collect();
+    if !values.is_empty() {
+        description.push_str(&format!(" (values: {})", values.join(", ")));
+    }
+
+    let defaults: Vec<String> = arg
+        .get_default_values()
+        .iter()
+        .map(|value| format!("`{}`", value.to_string_lossy()))
+        .collect();
+    if !defaults.is_empty() {
+        description.push_str(&format!(" (default: {})", defaults.join(", ")));
+    }
+
+    description.trim().to_string()
+}
+
+/// Keep text on one Markdown table row
+fn table_cell(text: &str) -> String {
+    text.replace('|', "\\|").replace('\n', " ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::help::init_help_i18n;
+    use crate::core::i18n::Language;
+
+    #[test]
+    fn test_command_reference_lists_every_top_level_command() {
+        init_help_i18n(Language::English);
+        let cmd = Cli::command();
+        let reference = render_command_reference(&cmd);
+
+        assert!(reference.starts_with("# Command Reference"));
+        for subcommand in cmd.get_subcommands().filter(|sub| !sub.is_hide_set()) {
+            let heading = format!("\n## `cldev {}`\n", subcommand.get_name());
+            assert!(reference.contains(&heading), "missing {}", heading.trim());
+        }
+        assert!(reference.contains("\n### `cldev config update-docs`\n"));
+        assert!(reference.contains("| `--no-color` | Disable colored output |"));
+    }
+
+    #[test]
+    fn test_validate_fails_on_stale_reference() {
+        init_help_i18n(Language::English);
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("docs").join("COMMAND_REFERENCE.md");
+        let output = OutputHandler::new(false, true, true);
+
+        assert!(update_command_reference(&path, true, &output).is_err());
+        update_command_reference(&path, false, &output).unwrap();
+        update_command_reference(&path, true, &output).unwrap();
+
+        fs::write(&path, "# Command Reference\n").unwrap();
+        assert!(update_command_reference(&path, true, &output).is_err());
+    }
+}
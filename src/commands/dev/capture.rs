@@ -0,0 +1,253 @@
+//! Auto-capture of the active development session
+//!
+//! When `dev fix`, `dev debug` or `dev urgent` completes inside a session
+//! started with `cldev session start`, the session context is scored by
+//! [`analyze_session`]. Sessions that warrant a full record are saved as an
+//! additional learning session tagged `auto-capture`.
+
+use crate::cli::output::OutputHandler;
+use crate::cli::session::get_session_path;
+use crate::core::session_recorder::{LearningSession, LearningSessionBuilder};
+use crate::core::{
+    analyze_session, sanitize_text_with_allowlist, CaptureConfig, Config, RecordLevel, Result,
+    SessionContext, TodoStatus,
+};
+use regex::Regex;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+/// Maximum number of errors and failed commands copied into the record
+const MAX_CAPTURED_ITEMS: usize = 5;
+
+/// File stem suffix keeping a capture apart from the session saved by the command
+const AUTO_CAPTURE_SUFFIX: &str = "-auto";
+
+/// Save an auto-captured record of the active session, if it warrants one
+///
+/// Capture never fails the dev command: problems are reported as warnings.
+pub fn capture_active_session(session_type: &str, description: &str, output: &OutputHandler) {
+    match save_active_session(session_type, description) {
+        Ok(Some(path)) => output.info(&output.t_format(
+            "dev-auto-capture-saved",
+            "path",
+            &path.display().to_string(),
+        )),
+        Ok(None) => {}
+        Err(e) => {
+            output.warning(&output.t_format("dev-auto-capture-failed", "error", &e.to_string()))
+        }
+    }
+}
+
+/// Load the active session and save it when auto-capture recommends a full record
+fn save_active_session(session_type: &str, description: &str) -> Result<Option<PathBuf>> {
+    let session_path = get_session_path();
+    if !session_path.exists() {
+        return Ok(None);
+    }
+
+    let ctx: SessionContext = serde_json::from_str(&std::fs::read_to_string(&session_path)?)?;
+    let lr = Config::load(None)
+        .map(|config| config.lr.clone())
+        .unwrap_or_default();
+    let allowlist = lr.sanitizer_allowlist_patterns()?;
+    match session_from_context(session_type, description, &ctx, &lr.capture, &allowlist) {
+        Some(session) => Ok(Some(save_capture(
+            session,
+            &LearningSession::sessions_dir()?,
+        )?)),
+        None => Ok(None),
+    }
+}
+
+/// Save a captured session under `sessions_dir` without replacing the
+/// record the dev command saved for the same type and description
+fn save_capture(session: LearningSessionBuilder, sessions_dir: &Path) -> Result<PathBuf> {
+    session
+        .build()
+        .save_to_with_suffix(sessions_dir, AUTO_CAPTURE_SUFFIX)
+}
+
+/// Build a learning session from a session context
+///
+/// Returns `None` unless [`analyze_session`] recommends [`RecordLevel::Full`].
+/// Secrets in error messages not matching `allowlist` are redacted.
+pub fn session_from_context(
+    session_type: &str,
+    description: &str,
+    ctx: &SessionContext,
+    thresholds: &CaptureConfig,
+    allowlist: &[Regex],
+) -> Option<LearningSessionBuilder> {
+    let recommendation = analyze_session(ctx, thresholds);
+    if recommendation.level != RecordLevel::Full {
+        return None;
+    }
+
+    let mut session = LearningSessionBuilder::new(session_type, description)
+        .tag("auto-capture")
+        .learning(format!("Auto-captured: {}", recommendation.reason))
+        .metadata("session_id", &ctx.session_id)
+        .metadata("auto_capture_score", format!("{:.2}", recommendation.score));
+
+    for error in ctx
+        .errors_encountered
+        .iter()
+        .filter(|e| !e.resolved)
+        .take(MAX_CAPTURED_ITEMS)
+    {
+        session = session.learning(format!(
+            "{}: {}",
+            error.error_type,
+            sanitize_text_with_allowlist(&error.message, allowlist).sanitized
+        ));
+    }
+
+    for command in ctx
+        .command_history
+        .iter()
+        .filter(|c| c.exit_code != 0)
+        .take(MAX_CAPTURED_ITEMS)
+    {
+        session = session.step(format!(
+            "Failed: {} (exit {})",
+            command.command, command.exit_code
+        ));
+    }
+
+    for todo in ctx
+        .todo_history
+        .iter()
+        .filter(|t| matches!(t.status, TodoStatus::Completed))
+    {
+        session = session.step(format!("Done: {}", todo.content));
+    }
+
+    let mut seen = HashSet::new();
+    for file in &ctx.files_modified {
+        if seen.insert(file.file_path.as_str()) {
+            session = session.file(&file.file_path);
+        }
+    }
+
+    Some(session)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{ErrorCapture, FileModification, ModificationType};
+    use chrono::{Duration, Local};
+    use tempfile::TempDir;
+
+    fn busy_session() -> SessionContext {
+        let mut ctx = SessionContext::new("session-test".to_string());
+        ctx.start_time = Local::now() - Duration::minutes(45);
+
+        for i in 0..6 {
+            ctx.add_error(ErrorCapture {
+                timestamp: Local::now(),
+                error_type: "CommandError".to_string(),
+                message: format!("connection refused on attempt {}", i),
+                context: None,
+                resolved: false,
+            });
+        }
+        for i in 0..12 {
+            ctx.add_file_modification(FileModification {
+                file_path: format!("src/module_{}.rs", i),
+                modification_type: ModificationType::Modified,
+                lines_added: 3,
+                lines_deleted: 1,
+                timestamp: Local::now(),
+            });
+        }
+
+        ctx
+    }
+
+    #[test]
+    fn test_full_recommendation_writes_markdown() {
+        let temp_dir = TempDir::new().unwrap();
+
+        let session = session_from_context(
+            "fix",
+            "Flaky database connection",
+            &busy_session(),
+            &CaptureConfig::default(),
+            &[],
+        )
+        .expect("busy session should be captured");
+        let (session, path) = session.save_to(temp_dir.path()).unwrap();
+
+        assert!(path.starts_with(temp_dir.path()));
+        assert!(session.tags.contains(&"auto-capture".to_string()));
+
+        let markdown = std::fs::read_to_string(&path).unwrap();
+        assert!(markdown.contains("type: fix"));
+        assert!(markdown.contains("CommandError: connection refused on attempt 0"));
+        assert!(markdown.contains("- `src/module_11.rs`"));
+    }
+
+    #[test]
+    fn test_capture_honors_sanitizer_allowlist() {
+        let mut ctx = busy_session();
+        ctx.errors_encountered[0].message = "refused 10.0.0.12 and 10.1.0.7".to_string();
+        let allowlist = [Regex::new(r"10\.0\.0\.12").unwrap()];
+
+        let session = session_from_context(
+            "fix",
+            "Flaky database connection",
+            &ctx,
+            &CaptureConfig::default(),
+            &allowlist,
+        )
+        .unwrap()
+        .build();
+
+        assert!(session
+            .learnings
+            .contains(&"CommandError: refused 10.0.0.12 and [REDACTED_IP]".to_string()));
+    }
+
+    #[test]
+    fn test_capture_keeps_manual_session() {
+        let temp_dir = TempDir::new().unwrap();
+        let description = "Flaky database connection";
+
+        let (_, manual_path) = LearningSessionBuilder::new("fix", description)
+            .root_cause("Pool exhausted by leaked connections")
+            .save_to(temp_dir.path())
+            .unwrap();
+        let capture = session_from_context(
+            "fix",
+            description,
+            &busy_session(),
+            &CaptureConfig::default(),
+            &[],
+        )
+        .unwrap();
+        let capture_path = save_capture(capture, temp_dir.path()).unwrap();
+
+        assert_ne!(capture_path, manual_path);
+        let manual = std::fs::read_to_string(&manual_path).unwrap();
+        assert!(manual.contains("Pool exhausted by leaked connections"));
+        assert!(!manual.contains("auto-capture"));
+        assert!(std::fs::read_to_string(&capture_path)
+            .unwrap()
+            .contains("auto-capture"));
+    }
+
+    #[test]
+    fn test_quiet_session_is_not_captured() {
+        let ctx = SessionContext::new("session-quiet".to_string());
+        assert!(session_from_context(
+            "debug",
+            "Nothing happened",
+            &ctx,
+            &CaptureConfig::default(),
+            &[]
+        )
+        .is_none());
+    }
+}
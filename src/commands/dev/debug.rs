@@ -406,6 +406,8 @@ pub fn handle_debug(symptom: Option<String>, output: &OutputHandler) -> Result<(
             &path.display().to_string().cyan().to_string()
         )
     );
+
+    super::capture::capture_active_session("debug", &symptom_desc, output);
     println!();
 
     println!("{}", output.t("debug-tips-header").cyan().bold());
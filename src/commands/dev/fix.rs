@@ -313,6 +313,8 @@ pub fn handle_fix(target: Option<String>, output: &OutputHandler) -> Result<()>
             &path.display().to_string().cyan().to_string()
         )
     );
+
+    super::capture::capture_active_session("fix", &bug_desc, output);
     println!();
 
     if resolved {
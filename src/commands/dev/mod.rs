@@ -10,7 +10,9 @@
 /// - research: Technical research and learning sessions
 ///
 /// All commands integrate with the learning session recorder to capture
-/// insights and build knowledge over time.
+/// insights and build knowledge over time. When run inside an active
+/// `cldev session`, the session is also auto-captured if it warrants a record.
+pub mod capture;
 pub mod debug;
 pub mod feature;
 pub mod fix;
@@ -245,6 +245,8 @@ pub fn handle_urgent(problem: Option<String>, output: &OutputHandler) -> Result<
             &path.display().to_string().cyan().to_string()
         )
     );
+
+    super::capture::capture_active_session("urgent", &problem_desc, output);
     println!();
 
     if resolved {
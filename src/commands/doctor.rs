@@ -0,0 +1,44 @@
+//! Check the tools needed by the current project
+
+use crate::cli::output::OutputHandler;
+use crate::core::doctor::check_tools;
+use crate::core::{Config, ProjectDetector, Result};
+use comfy_table::{modifiers::UTF8_ROUND_CORNERS, presets::UTF8_FULL, Table};
+
+/// Handle doctor command
+///
+/// Detects the project stack and prints a ✓/✗ table of the tools it needs,
+/// with an install hint for each missing one.
+pub fn run_doctor(output: &OutputHandler) -> Result<()> {
+    let detector = ProjectDetector::new(Some(&Config::project_root()?))?;
+    output.info(&output.t_format("doctor-project", "type", detector.project_type().name()));
+
+    let checks = check_tools(&detector);
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .apply_modifier(UTF8_ROUND_CORNERS)
+        .set_header(vec!["", "Tool", "Used for", "Install"]);
+    for check in &checks {
+        table.add_row(vec![
+            if check.installed { "✓" } else { "✗" },
+            check.tool,
+            check.purpose,
+            if check.installed {
+                ""
+            } else {
+                check.install_hint
+            },
+        ]);
+    }
+    output.raw(&table.to_string());
+
+    let missing = checks.iter().filter(|check| !check.installed).count();
+    if missing == 0 {
+        output.success(&output.t("doctor-all-found"));
+    } else {
+        output.warning(&output.t_format("doctor-missing", "count", &missing.to_string()));
+    }
+
+    Ok(())
+}
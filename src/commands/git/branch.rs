@@ -7,8 +7,8 @@ use crate::cli::args::BranchType;
 use crate::cli::output::OutputHandler;
 use crate::core::error::Result;
 use crate::core::git_utils::GitUtils;
+use crate::core::security::{exec_checked, safe_arg};
 use dialoguer::{Input, Select};
-use std::process::Command;
 
 impl BranchType {
     /// Get the branch prefix (e.g., "feature/", "fix/")
@@ -101,12 +101,15 @@ pub fn create_branch(
     // Create the branch
     output.info(&output.t_format("git-branch-creating-name", "name", &branch_name));
 
-    let status = Command::new("git")
-        .args(["checkout", "-b", &branch_name])
-        .status()
-        .map_err(|e| {
-            crate::core::error::CldevError::command(format!("Failed to create branch: {}", e))
-        })?;
+    let args = ["checkout", "-b", branch_name.as_str()];
+    if output.is_dry_run() {
+        output.dry_run_command("git", &args);
+        return Ok(());
+    }
+
+    let status = exec_checked("git", &args).map_err(|e| {
+        crate::core::error::CldevError::command(format!("Failed to create branch: {}", e))
+    })?;
 
     if status.success() {
         output.success(&output.t_format("git-branch-success", "name", &branch_name));
@@ -227,6 +230,9 @@ fn validate_branch_name(name: &str) -> Result<()> {
         ));
     }
 
+    // The name is passed to git as an argument; reject shell metacharacters
+    safe_arg(name).map_err(|e| crate::core::error::CldevError::security(e.to_string()))?;
+
     Ok(())
 }
 
@@ -269,4 +275,11 @@ mod tests {
         assert!(validate_branch_name("in//valid").is_err());
         assert!(validate_branch_name("in valid").is_err());
     }
+
+    #[test]
+    fn test_validate_branch_name_rejects_injection() {
+        assert!(validate_branch_name("; rm -rf /").is_err());
+        assert!(validate_branch_name("feature/x;rm").is_err());
+        assert!(validate_branch_name("feature/$(id)").is_err());
+    }
 }
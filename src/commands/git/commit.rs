@@ -5,11 +5,24 @@
 //! Co-Authored-By attribution for Claude.
 
 use crate::cli::output::OutputHandler;
-use crate::core::error::Result;
-use crate::core::git_utils::GitUtils;
-use dialoguer::{Input, Select};
+use crate::commands::quality::{format_code_advanced, run_lint_advanced, run_tests_advanced};
+use crate::core::config::{Config, QualityConfig};
+use crate::core::error::{CldevError, Result};
+use crate::core::git_utils::{FileDiff, GitUtils};
+use crate::core::project_detector::ProjectDetector;
+use dialoguer::{Editor, Input, Select};
+use std::path::Path;
 use std::process::Command;
 
+/// Maximum length of a commit subject, in characters
+const MAX_SUBJECT_LEN: usize = 72;
+
+/// Number of changed paths listed in a suggested commit body
+const SUGGESTED_BODY_PATHS: usize = 5;
+
+/// Directories too generic to use as a commit scope
+const GENERIC_SCOPE_DIRS: &[&str] = &["src", "lib", "tests", "test"];
+
 /// Conventional commit types with their corresponding emojis
 #[derive(Debug, Clone, Copy)]
 enum CommitType {
@@ -45,7 +58,7 @@ impl CommitType {
     }
 
     /// Get the commit type prefix (e.g., "feat", "fix")
-    fn prefix(&self) -> &str {
+    fn prefix(&self) -> &'static str {
         match self {
             Self::Feat => "feat",
             Self::Fix => "fix",
@@ -61,6 +74,11 @@ impl CommitType {
         }
     }
 
+    /// Look up a commit type by its prefix
+    fn from_prefix(prefix: &str) -> Option<Self> {
+        Self::all().into_iter().find(|t| t.prefix() == prefix)
+    }
+
     /// Get the emoji for this commit type
     fn emoji(&self) -> &str {
         match self {
@@ -146,17 +164,426 @@ impl CommitType {
     }
 }
 
+/// Parsed header of a conventional commit message
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommitParts {
+    pub commit_type: String,
+    pub scope: Option<String>,
+    pub breaking: bool,
+    pub subject: String,
+}
+
+/// Validate a commit message against the Conventional Commits format
+///
+/// Checks the first line for `type(scope)!: subject`, where the type must be
+/// one of the supported commit types and scope and `!` are optional. A
+/// leading emoji, as produced by the interactive mode, or gitmoji shortcode
+/// is allowed. The subject must be at most 72 characters and must not end
+/// with a period.
+pub fn validate_conventional(message: &str) -> Result<CommitParts> {
+    let header = message.lines().next().unwrap_or_default().trim();
+
+    // Skip the emoji prepended by interactive mode
+    let header = match header.split_once(' ') {
+        Some((word, rest)) if is_emoji(word) => rest.trim_start(),
+        _ => header,
+    };
+
+    let (prefix, subject) = header.split_once(':').ok_or_else(|| {
+        CldevError::validation(format!(
+            "Commit message must follow 'type(scope): subject': {}",
+            header
+        ))
+    })?;
+
+    let (prefix, breaking) = match prefix.strip_suffix('!') {
+        Some(prefix) => (prefix, true),
+        None => (prefix, false),
+    };
+
+    let (commit_type, scope) = match prefix.split_once('(') {
+        Some((commit_type, scope)) => {
+            let scope = scope
+                .strip_suffix(')')
+                .filter(|s| !s.is_empty() && !s.contains(['(', ')']))
+                .ok_or_else(|| {
+                    CldevError::validation(format!("Invalid commit scope: {}", prefix))
+                })?;
+            (commit_type, Some(scope.to_string()))
+        }
+        None => (prefix, None),
+    };
+
+    if CommitType::from_prefix(commit_type).is_none() {
+        let allowed: Vec<&str> = CommitType::all().iter().map(|t| t.prefix()).collect();
+        return Err(CldevError::validation(format!(
+            "Unknown commit type '{}' (allowed: {})",
+            commit_type,
+            allowed.join(", ")
+        )));
+    }
+
+    let subject = subject.trim();
+    if subject.is_empty() {
+        return Err(CldevError::validation("Commit subject must not be empty"));
+    }
+    let subject_len = subject.chars().count();
+    if subject_len > MAX_SUBJECT_LEN {
+        return Err(CldevError::validation(format!(
+            "Commit subject is {} characters long (max {})",
+            subject_len, MAX_SUBJECT_LEN
+        )));
+    }
+    if subject.ends_with('.') {
+        return Err(CldevError::validation(
+            "Commit subject must not end with a period",
+        ));
+    }
+
+    Ok(CommitParts {
+        commit_type: commit_type.to_string(),
+        scope,
+        breaking,
+        subject: subject.to_string(),
+    })
+}
+
+/// Suggest a conventional commit message from staged diff statistics
+///
+/// The type is `docs` when only markdown changed and `test` when only test
+/// files changed. Otherwise it is `feat` when the change mostly adds lines
+/// and `fix` when it mostly rewrites or removes them. The scope is the
+/// deepest directory shared by all paths, and the body lists the paths with
+/// the most changed lines.
+pub fn suggest_commit_message(files: &[FileDiff]) -> String {
+    let paths: Vec<&str> = files.iter().map(|f| f.path.as_str()).collect();
+    let added: usize = files.iter().map(|f| f.added).sum();
+    let removed: usize = files.iter().map(|f| f.removed).sum();
+
+    let commit_type = if paths.iter().all(|p| p.ends_with(".md")) {
+        CommitType::Docs
+    } else if paths.iter().all(|p| is_test_path(p)) {
+        CommitType::Test
+    } else if added > removed * 2 {
+        CommitType::Feat
+    } else {
+        CommitType::Fix
+    };
+
+    let scope = common_scope(&paths)
+        .map(|scope| format!("({})", scope))
+        .unwrap_or_default();
+
+    let subject = match paths.as_slice() {
+        [path] => format!("update {}", path.rsplit('/').next().unwrap_or(path)),
+        _ => format!("update {} files", paths.len()),
+    };
+
+    let mut top: Vec<_> = files.iter().collect();
+    top.sort_by(|a, b| b.changed().cmp(&a.changed()).then(a.path.cmp(&b.path)));
+
+    let mut body: Vec<String> = top
+        .iter()
+        .take(SUGGESTED_BODY_PATHS)
+        .map(|f| format!("- {} (+{} -{})", f.path, f.added, f.removed))
+        .collect();
+    if top.len() > SUGGESTED_BODY_PATHS {
+        body.push(format!(
+            "- and {} more file(s)",
+            top.len() - SUGGESTED_BODY_PATHS
+        ));
+    }
+
+    format!(
+        "{}{}: {}\n\n{}",
+        commit_type.prefix(),
+        scope,
+        subject,
+        body.join("\n")
+    )
+}
+
+/// Check whether a path looks like a test file
+fn is_test_path(path: &str) -> bool {
+    path.starts_with("tests/")
+        || path.contains("/tests/")
+        || path.ends_with("_test.rs")
+        || path.ends_with(".test.ts")
+        || path.ends_with(".spec.ts")
+        || path
+            .rsplit('/')
+            .next()
+            .is_some_and(|name| name.starts_with("test_"))
+}
+
+/// Get the name of the deepest directory shared by all paths
+///
+/// Returns None when the paths share no directory or only a generic one
+/// such as `src`.
+fn common_scope(paths: &[&str]) -> Option<String> {
+    let dirs: Vec<Vec<&str>> = paths
+        .iter()
+        .map(|p| {
+            let mut parts: Vec<&str> = p.split('/').collect();
+            parts.pop();
+            parts
+        })
+        .collect();
+
+    let first = dirs.first()?;
+    let shared = (0..first.len())
+        .take_while(|&i| dirs.iter().all(|d| d.get(i) == first.get(i)))
+        .count();
+
+    first[..shared]
+        .last()
+        .filter(|dir| !GENERIC_SCOPE_DIRS.contains(dir))
+        .map(|dir| dir.to_string())
+}
+
+/// Check whether `word` is an emoji or a gitmoji shortcode such as `:sparkles:`
+fn is_emoji(word: &str) -> bool {
+    let shortcode = word
+        .strip_prefix(':')
+        .and_then(|w| w.strip_suffix(':'))
+        .is_some_and(|name| {
+            !name.is_empty()
+                && name.chars().all(|c| {
+                    c.is_ascii_lowercase() || c.is_ascii_digit() || matches!(c, '_' | '-' | '+')
+                })
+        });
+    shortcode || (!word.is_empty() && word.chars().all(|c| !c.is_ascii() && !c.is_alphanumeric()))
+}
+
+/// Prepend the gitmoji for the message's commit type
+///
+/// Messages that already start with an emoji, or whose type is not a known
+/// commit type, are returned unchanged.
+fn prefix_emoji(message: &str, enabled: bool) -> String {
+    if !enabled || !message.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        return message.to_string();
+    }
+
+    let prefix_end = message.find(['(', '!', ':']).unwrap_or(message.len());
+    match CommitType::from_prefix(&message[..prefix_end]) {
+        Some(commit_type) => format!("{} {}", commit_type.emoji(), message),
+        None => message.to_string(),
+    }
+}
+
+/// Reject a commit message that does not follow the Conventional Commits format
+fn check_message(message: &str, output: &OutputHandler) -> Result<()> {
+    validate_conventional(message).map(|_| ()).inspect_err(|e| {
+        output.error(&output.t_format("git-commit-invalid-message", "error", &e.to_string()));
+        output.info(&output.t("git-commit-invalid-message-hint"));
+    })
+}
+
+/// Quality checks run before committing
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum QualityCheck {
+    Lint,
+    FormatCheck,
+    Test,
+}
+
+impl QualityCheck {
+    /// Get the short name used in failure reports
+    fn name(&self) -> &str {
+        match self {
+            Self::Lint => "lint",
+            Self::FormatCheck => "format",
+            Self::Test => "test",
+        }
+    }
+
+    /// Get the checks to run before a commit
+    ///
+    /// Lint and format checks run when `[quality].lint_before_commit` is set,
+    /// and tests when `[quality].run_tests_before_commit` is set.
+    fn pre_commit(quality: &QualityConfig) -> Vec<Self> {
+        let mut checks = Vec::new();
+        if quality.lint_before_commit {
+            checks.extend([Self::Lint, Self::FormatCheck]);
+        }
+        if quality.run_tests_before_commit {
+            checks.push(Self::Test);
+        }
+        checks
+    }
+
+    /// Get the command this check runs for the detected project
+    fn command(&self, detector: &ProjectDetector, staged: &[String]) -> Result<Vec<String>> {
+        match self {
+            Self::Lint => detector.get_lint_command(false, staged.is_empty()),
+            Self::FormatCheck => detector.get_format_command(true),
+            Self::Test => detector.get_test_command(None, false, false),
+        }
+    }
+
+    /// Run this check in the project at `root` against the staged source files
+    fn run(&self, root: &Path, staged: &[String], output: &OutputHandler) -> Result<()> {
+        match self {
+            Self::Lint => run_lint_advanced(staged, false, Some(root), output),
+            Self::FormatCheck => format_code_advanced(staged, true, Some(root), output),
+            Self::Test => run_tests_advanced(None, false, false, Some(root), output),
+        }
+    }
+}
+
+/// Keep the staged files with one of the detected project's source extensions
+///
+/// Other files, such as `Cargo.toml` or `README.md`, would make a linter or
+/// formatter given them as arguments fail.
+fn staged_sources(staged: &[String], detector: &ProjectDetector) -> Vec<String> {
+    let project_type = detector.project_type();
+    let extensions = project_type.extensions();
+    staged
+        .iter()
+        .filter(|path| {
+            Path::new(path)
+                .extension()
+                .and_then(|ext| ext.to_str())
+                .is_some_and(|ext| extensions.contains(&ext))
+        })
+        .cloned()
+        .collect()
+}
+
+/// Keep the checks the detected project supports, paired with their commands
+///
+/// A check without a command for this project (an unknown project type, or
+/// no linter installed) is skipped with a notice instead of failing the commit.
+fn supported_checks(
+    checks: &[QualityCheck],
+    detector: &ProjectDetector,
+    staged: &[String],
+    output: &OutputHandler,
+) -> Vec<(QualityCheck, Vec<String>)> {
+    checks
+        .iter()
+        .filter_map(|check| match check.command(detector, staged) {
+            Ok(command) => Some((*check, command)),
+            Err(e) => {
+                output.info(
+                    &output
+                        .t_format("git-commit-check-unsupported", "check", check.name())
+                        .replace("{reason}", &e.to_string()),
+                );
+                None
+            }
+        })
+        .collect()
+}
+
+/// Run every check and fail with a combined report if any of them failed
+///
+/// All checks are run even after a failure so that the report is complete.
+fn run_quality_gate<F>(checks: &[QualityCheck], mut runner: F, output: &OutputHandler) -> Result<()>
+where
+    F: FnMut(QualityCheck) -> Result<()>,
+{
+    output.info(&output.t("git-commit-checks-running"));
+
+    let failures: Vec<(QualityCheck, CldevError)> = checks
+        .iter()
+        .filter_map(|check| runner(*check).err().map(|e| (*check, e)))
+        .collect();
+
+    if failures.is_empty() {
+        output.success(&output.t("git-commit-checks-passed"));
+        return Ok(());
+    }
+
+    output.error(&output.t("git-commit-checks-failed"));
+    for (check, error) in &failures {
+        output.list_item(&format!("{}: {}", check.name(), error));
+    }
+    output.info(&output.t("git-commit-checks-skip-hint"));
+
+    let names: Vec<&str> = failures.iter().map(|(check, _)| check.name()).collect();
+    Err(CldevError::validation(format!(
+        "Pre-commit checks failed: {}",
+        names.join(", ")
+    )))
+}
+
+/// Options for a commit, resolved from the command line and configuration
+struct CommitSettings {
+    no_verify: bool,
+    amend: bool,
+    /// Quality checks to run on the staged files first
+    checks: Vec<QualityCheck>,
+    /// Prefix the message with the commit type's gitmoji
+    emoji: bool,
+}
+
 /// Create a conventional commit
+///
+/// `emoji` overrides `[ui].emoji` for prefixing the message with the
+/// commit type's gitmoji. With `--dry-run`, the `git commit` command is
+/// printed instead of run.
 pub fn create_commit(
     message: Option<String>,
     no_verify: bool,
     amend: bool,
+    skip_checks: bool,
+    emoji: Option<bool>,
     output: &OutputHandler,
 ) -> Result<()> {
-    output.info(&output.t("git-commit-creating"));
-
     // Open the Git repository
     let git_utils = GitUtils::open_current()?;
+    let root = git_utils.workdir()?.to_path_buf();
+
+    let config = Config::load(None);
+    let checks = match &config {
+        _ if skip_checks => Vec::new(),
+        Ok(config) => QualityCheck::pre_commit(&config.quality),
+        Err(e) => {
+            output.warning(&output.t_format(
+                "git-commit-checks-config-unreadable",
+                "error",
+                &e.to_string(),
+            ));
+            Vec::new()
+        }
+    };
+    let settings = CommitSettings {
+        no_verify,
+        amend,
+        checks,
+        emoji: emoji.unwrap_or_else(|| config.as_ref().map_or(true, |c| c.ui.emoji)),
+    };
+
+    commit_in(
+        &git_utils,
+        message,
+        &settings,
+        |check, staged| check.run(&root, staged, output),
+        output,
+    )
+}
+
+/// Create a conventional commit in an opened repository
+///
+/// `run_check` runs one quality check against the staged source files, in
+/// the repository's working directory; the commit is aborted if any of them
+/// fails.
+fn commit_in<F>(
+    git_utils: &GitUtils,
+    message: Option<String>,
+    settings: &CommitSettings,
+    mut run_check: F,
+    output: &OutputHandler,
+) -> Result<()>
+where
+    F: FnMut(QualityCheck, &[String]) -> Result<()>,
+{
+    let CommitSettings {
+        no_verify, amend, ..
+    } = *settings;
+
+    output.info(&output.t("git-commit-creating"));
 
     // Check if there are changes to commit (unless amending)
     if !amend {
@@ -173,14 +600,49 @@ pub fn create_commit(
         }
     }
 
+    // Reject a non-conventional message before running the quality gate
+    if let Some(msg) = message.as_deref().filter(|_| !no_verify) {
+        check_message(msg, output)?;
+    }
+
+    // Run the pre-commit quality gate on staged files
+    if !settings.checks.is_empty() {
+        // Staged paths are relative to the repository root, so the project
+        // is detected there rather than in the current directory
+        let detector = ProjectDetector::new(Some(git_utils.workdir()?))?;
+        let staged = staged_sources(&git_utils.staged_files()?, &detector);
+        let checks = supported_checks(&settings.checks, &detector, &staged, output);
+        if output.is_dry_run() {
+            for (_, command) in &checks {
+                if let Some((program, args)) = command.split_first() {
+                    output.dry_run_command(program, args);
+                }
+            }
+        } else if !checks.is_empty() {
+            let checks: Vec<QualityCheck> = checks.into_iter().map(|(check, _)| check).collect();
+            run_quality_gate(&checks, |check| run_check(check, &staged), output)?;
+        }
+    }
+
     // If message is provided, use it directly
     let commit_message = if let Some(msg) = message {
         msg
     } else {
-        // Interactive mode: build commit message
-        build_commit_message_interactive(&git_utils, output)?
+        // Propose a message from the staged diff, or build one interactively
+        let files = git_utils.diff_stats(true)?;
+        let msg = if files.is_empty() {
+            build_commit_message_interactive(git_utils, output)?
+        } else {
+            confirm_suggested_message(&suggest_commit_message(&files), git_utils, output)?
+        };
+        if !no_verify {
+            check_message(&msg, output)?;
+        }
+        msg
     };
 
+    let commit_message = prefix_emoji(&commit_message, settings.emoji);
+
     // Add Claude attribution (localized)
     let full_message = format!(
         "{}\n\n🤖 {}\n\n{}",
@@ -190,17 +652,24 @@ pub fn create_commit(
     );
 
     // Execute git commit
-    let mut cmd = Command::new("git");
-    cmd.arg("commit").arg("-m").arg(&full_message);
+    let mut args = vec!["commit", "-m", full_message.as_str()];
 
     if no_verify {
-        cmd.arg("--no-verify");
+        args.push("--no-verify");
     }
 
     if amend {
-        cmd.arg("--amend");
+        args.push("--amend");
+    }
+
+    if output.is_dry_run() {
+        output.dry_run_command("git", &args);
+        return Ok(());
     }
 
+    let mut cmd = Command::new("git");
+    cmd.args(&args).current_dir(git_utils.workdir()?);
+
     output.debug(&format!("Executing: git commit -m \"{}\"", commit_message));
 
     let status = cmd.status().map_err(|e| {
@@ -223,6 +692,41 @@ pub fn create_commit(
     Ok(())
 }
 
+/// Let the user accept, edit, or replace a suggested commit message
+fn confirm_suggested_message(
+    suggestion: &str,
+    git_utils: &GitUtils,
+    output: &OutputHandler,
+) -> Result<String> {
+    output.info(&format!(
+        "\n{}\n{}",
+        output.t("git-commit-suggested"),
+        suggestion
+    ));
+
+    let items = [
+        output.t("git-commit-suggested-use"),
+        output.t("git-commit-suggested-edit"),
+        output.t("git-commit-suggested-manual"),
+    ];
+    let selection = Select::new()
+        .items(&items)
+        .default(0)
+        .interact()
+        .map_err(|e| CldevError::command(format!("Failed to select an option: {}", e)))?;
+
+    match selection {
+        0 => Ok(suggestion.to_string()),
+        1 => Editor::new()
+            .edit(suggestion)
+            .map_err(|e| CldevError::editor(format!("Failed to edit commit message: {}", e)))?
+            .map(|msg| msg.trim().to_string())
+            .filter(|msg| !msg.is_empty())
+            .ok_or_else(|| CldevError::command("Commit message edit was aborted")),
+        _ => build_commit_message_interactive(git_utils, output),
+    }
+}
+
 /// Build commit message interactively
 fn build_commit_message_interactive(
     git_utils: &GitUtils,
@@ -319,8 +823,7 @@ fn build_commit_message_interactive(
     let breaking_part = if is_breaking { "!" } else { "" };
 
     let message = format!(
-        "{} {}{}{}: {}",
-        commit_type.emoji(),
+        "{}{}{}: {}",
         commit_type.prefix(),
         scope_part,
         breaking_part,
@@ -340,6 +843,11 @@ fn build_commit_message_interactive(
 mod tests {
     use super::*;
 
+    /// Parse `git diff --numstat` output with every file modified
+    fn parse_numstat(output: &str) -> Vec<FileDiff> {
+        FileDiff::parse(output, "")
+    }
+
     #[test]
     fn test_commit_type_prefix() {
         assert_eq!(CommitType::Feat.prefix(), "feat");
@@ -385,6 +893,351 @@ mod tests {
         assert!(matches!(detected, Some(CommitType::Build)));
     }
 
+    #[test]
+    fn test_validate_conventional_valid() {
+        let parts = validate_conventional("feat(cli): add notify flag").unwrap();
+        assert_eq!(parts.commit_type, "feat");
+        assert_eq!(parts.scope.as_deref(), Some("cli"));
+        assert!(!parts.breaking);
+        assert_eq!(parts.subject, "add notify flag");
+
+        let parts = validate_conventional("fix!: drop legacy config\n\nBody text.").unwrap();
+        assert_eq!(parts.commit_type, "fix");
+        assert_eq!(parts.scope, None);
+        assert!(parts.breaking);
+
+        // Interactive mode prepends an emoji
+        let parts = validate_conventional("✨ feat(lr): add link command").unwrap();
+        assert_eq!(parts.commit_type, "feat");
+        let parts = validate_conventional(":bug: fix: handle empty diff").unwrap();
+        assert_eq!(parts.commit_type, "fix");
+    }
+
+    #[test]
+    fn test_validate_conventional_only_skips_emoji() {
+        assert!(validate_conventional("[WIP] feat: add thing").is_err());
+        assert!(validate_conventional("123 feat: add thing").is_err());
+        assert!(validate_conventional("修正 feat: add thing").is_err());
+        assert!(validate_conventional("🚧 feat: add thing").is_ok());
+    }
+
+    #[test]
+    fn test_validate_conventional_invalid_type() {
+        let err = validate_conventional("feature: add thing").unwrap_err();
+        assert!(err.to_string().contains("Unknown commit type 'feature'"));
+
+        assert!(validate_conventional("Feat: add thing").is_err());
+    }
+
+    #[test]
+    fn test_validate_conventional_missing_colon() {
+        let err = validate_conventional("feat add thing").unwrap_err();
+        assert!(err.to_string().contains("type(scope): subject"));
+
+        assert!(validate_conventional("feat(): add thing").is_err());
+        assert!(validate_conventional("feat: ").is_err());
+    }
+
+    #[test]
+    fn test_validate_conventional_subject_rules() {
+        let subject = "a".repeat(MAX_SUBJECT_LEN);
+        assert!(validate_conventional(&format!("docs: {}", subject)).is_ok());
+
+        let err = validate_conventional(&format!("docs: {}a", subject)).unwrap_err();
+        assert!(err.to_string().contains("max 72"));
+
+        let err = validate_conventional("docs: update readme.").unwrap_err();
+        assert!(err.to_string().contains("period"));
+    }
+
+    #[test]
+    fn test_suggest_docs_only() {
+        let stats = parse_numstat("10\t2\tdocs/guide/setup.md\n3\t0\tdocs/guide/faq.md\n");
+        let message = suggest_commit_message(&stats);
+
+        assert!(message.starts_with("docs(guide): update 2 files\n\n"));
+        assert!(message.contains("- docs/guide/setup.md (+10 -2)"));
+        assert!(validate_conventional(&message).is_ok());
+    }
+
+    #[test]
+    fn test_suggest_tests_only() {
+        let stats = parse_numstat("40\t0\ttests/cli_test.rs\n5\t1\ttests/common/mod.rs\n");
+        let parts = validate_conventional(&suggest_commit_message(&stats)).unwrap();
+
+        assert_eq!(parts.commit_type, "test");
+        assert_eq!(parts.scope, None);
+    }
+
+    #[test]
+    fn test_suggest_feat_for_additions() {
+        let stats =
+            parse_numstat("120\t4\tsrc/commands/git/commit.rs\n6\t0\tsrc/commands/git/mod.rs\n");
+        let parts = validate_conventional(&suggest_commit_message(&stats)).unwrap();
+
+        assert_eq!(parts.commit_type, "feat");
+        assert_eq!(parts.scope.as_deref(), Some("git"));
+        assert_eq!(parts.subject, "update 2 files");
+    }
+
+    #[test]
+    fn test_suggest_fix_for_rewrites() {
+        let stats = parse_numstat("8\t7\tsrc/core/config.rs\n2\t2\tsrc/main.rs\n");
+        let parts = validate_conventional(&suggest_commit_message(&stats)).unwrap();
+
+        assert_eq!(parts.commit_type, "fix");
+        // Only the generic `src` directory is shared
+        assert_eq!(parts.scope, None);
+
+        let single = parse_numstat("3\t3\tsrc/core/config.rs\n");
+        let parts = validate_conventional(&suggest_commit_message(&single)).unwrap();
+        assert_eq!(parts.scope.as_deref(), Some("core"));
+        assert_eq!(parts.subject, "update config.rs");
+    }
+
+    #[test]
+    fn test_suggest_lists_top_paths() {
+        let numstat: String = (1..=7)
+            .map(|i| format!("{}\t0\tsrc/file{}.rs\n", i * 10, i))
+            .collect();
+        let message = suggest_commit_message(&parse_numstat(&numstat));
+        let body: Vec<&str> = message.lines().skip(2).collect();
+
+        assert_eq!(body.len(), SUGGESTED_BODY_PATHS + 1);
+        assert_eq!(body[0], "- src/file7.rs (+70 -0)");
+        assert_eq!(body[SUGGESTED_BODY_PATHS], "- and 2 more file(s)");
+    }
+
+    #[test]
+    fn test_prefix_emoji_per_type() {
+        let cases = [
+            ("feat", "✨"),
+            ("fix", "🐛"),
+            ("docs", "📝"),
+            ("refactor", "♻️"),
+            ("perf", "⚡"),
+            ("test", "✅"),
+        ];
+
+        for (prefix, emoji) in cases {
+            let message = format!("{}(core): update parser", prefix);
+            assert_eq!(
+                prefix_emoji(&message, true),
+                format!("{} {}", emoji, message)
+            );
+        }
+
+        assert_eq!(prefix_emoji("fix!: drop v1", true), "🐛 fix!: drop v1");
+    }
+
+    #[test]
+    fn test_prefix_emoji_disabled() {
+        assert_eq!(prefix_emoji("feat: add flag", false), "feat: add flag");
+    }
+
+    #[test]
+    fn test_prefix_emoji_leaves_existing_or_unknown() {
+        assert_eq!(prefix_emoji("✨ feat: add flag", true), "✨ feat: add flag");
+        assert_eq!(prefix_emoji("WIP stuff", true), "WIP stuff");
+    }
+
+    #[test]
+    fn test_emoji_does_not_count_toward_subject_length() {
+        let message = format!("refactor: {}", "a".repeat(MAX_SUBJECT_LEN));
+        let parts = validate_conventional(&prefix_emoji(&message, true)).unwrap();
+
+        assert_eq!(parts.commit_type, "refactor");
+        assert_eq!(parts.subject.chars().count(), MAX_SUBJECT_LEN);
+    }
+
+    fn quality(lint_before_commit: bool, run_tests_before_commit: bool) -> QualityConfig {
+        QualityConfig {
+            lint_before_commit,
+            run_tests_before_commit,
+            ..QualityConfig::default()
+        }
+    }
+
+    #[test]
+    fn test_pre_commit_checks_follow_their_switches() {
+        assert_eq!(
+            QualityCheck::pre_commit(&QualityConfig::default()),
+            vec![QualityCheck::Test]
+        );
+        assert_eq!(
+            QualityCheck::pre_commit(&quality(true, false)),
+            vec![QualityCheck::Lint, QualityCheck::FormatCheck]
+        );
+        assert_eq!(
+            QualityCheck::pre_commit(&quality(false, true)),
+            vec![QualityCheck::Test]
+        );
+        assert!(QualityCheck::pre_commit(&quality(false, false)).is_empty());
+    }
+
+    #[test]
+    fn test_unsupported_checks_are_skipped() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        std::fs::write(temp_dir.path().join("README.md"), "# Notes\n").unwrap();
+        let detector = ProjectDetector::new(Some(temp_dir.path())).unwrap();
+
+        let output = OutputHandler::new(false, true, true);
+        let checks = supported_checks(
+            &QualityCheck::pre_commit(&quality(true, true)),
+            &detector,
+            &[],
+            &output,
+        );
+        assert!(checks.is_empty());
+    }
+
+    #[test]
+    fn test_quality_gate_passes() {
+        let output = OutputHandler::new(false, true, true);
+        let result = run_quality_gate(
+            &QualityCheck::pre_commit(&quality(true, true)),
+            |_| Ok(()),
+            &output,
+        );
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_quality_gate_runs_every_check() {
+        let output = OutputHandler::new(false, true, true);
+        let mut ran = Vec::new();
+
+        let result = run_quality_gate(
+            &QualityCheck::pre_commit(&quality(true, true)),
+            |check| {
+                ran.push(check);
+                match check {
+                    QualityCheck::Lint => Err(CldevError::command("clippy found 2 errors")),
+                    _ => Ok(()),
+                }
+            },
+            &output,
+        );
+
+        // Every check still runs so the report is complete
+        assert_eq!(ran.len(), 3);
+        let err = result.unwrap_err().to_string();
+        assert!(err.contains("Pre-commit checks failed: lint"));
+        assert!(!err.contains("format"));
+    }
+
+    /// Create a repository with a staged, formatted Rust project and README
+    fn staged_rust_repo() -> tempfile::TempDir {
+        use crate::core::git_utils::run_git_checked;
+
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let repo = temp_dir.path();
+        run_git_checked(repo, &["init", "-q"], "git init").unwrap();
+        run_git_checked(repo, &["config", "user.name", "Test"], "git config").unwrap();
+        run_git_checked(
+            repo,
+            &["config", "user.email", "test@example.com"],
+            "git config",
+        )
+        .unwrap();
+        std::fs::write(
+            repo.join("Cargo.toml"),
+            "[package]\nname = \"x\"\nversion = \"0.1.0\"\nedition = \"2021\"\n",
+        )
+        .unwrap();
+        std::fs::create_dir(repo.join("src")).unwrap();
+        std::fs::write(repo.join("src/lib.rs"), "pub fn x() -> u32 {\n    1\n}\n").unwrap();
+        std::fs::write(repo.join("README.md"), "# x\n").unwrap();
+        run_git_checked(repo, &["add", "."], "git add").unwrap();
+        temp_dir
+    }
+
+    fn settings(checks: Vec<QualityCheck>) -> CommitSettings {
+        CommitSettings {
+            no_verify: false,
+            amend: false,
+            checks,
+            emoji: false,
+        }
+    }
+
+    #[test]
+    fn test_failing_lint_aborts_commit() {
+        use crate::core::git_utils::run_git_checked;
+
+        let temp_dir = staged_rust_repo();
+        let repo = temp_dir.path();
+        let git_utils = GitUtils::open(repo).unwrap();
+        let output = OutputHandler::new(false, true, true);
+        let mut ran = Vec::new();
+
+        let result = commit_in(
+            &git_utils,
+            Some("feat: add x".to_string()),
+            &settings(QualityCheck::pre_commit(&quality(true, false))),
+            |check, staged| {
+                // Only source files are passed on to the checks
+                assert_eq!(staged, ["src/lib.rs"]);
+                ran.push(check);
+                match check {
+                    QualityCheck::Lint => Err(CldevError::command("clippy found 2 errors")),
+                    _ => Ok(()),
+                }
+            },
+            &output,
+        );
+
+        assert_eq!(ran, vec![QualityCheck::Lint, QualityCheck::FormatCheck]);
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("Pre-commit checks failed: lint"));
+        // No commit was created
+        assert!(run_git_checked(repo, &["rev-parse", "--verify", "HEAD"], "git").is_err());
+    }
+
+    #[test]
+    fn test_passing_checks_commit() {
+        use crate::core::git_utils::run_git_checked;
+
+        let temp_dir = staged_rust_repo();
+        let repo = temp_dir.path();
+        let git_utils = GitUtils::open(repo).unwrap();
+        let output = OutputHandler::new(false, true, true);
+
+        commit_in(
+            &git_utils,
+            Some("feat: add x".to_string()),
+            &settings(QualityCheck::pre_commit(&quality(true, true))),
+            |_, _| Ok(()),
+            &output,
+        )
+        .unwrap();
+
+        assert!(run_git_checked(repo, &["rev-parse", "--verify", "HEAD"], "git").is_ok());
+    }
+
+    #[test]
+    fn test_format_check_ignores_staged_non_source_files() {
+        use crate::core::git_utils::run_git_checked;
+
+        let temp_dir = staged_rust_repo();
+        let repo = temp_dir.path();
+        let git_utils = GitUtils::open(repo).unwrap();
+        let output = OutputHandler::new(false, true, true);
+
+        commit_in(
+            &git_utils,
+            Some("feat: add x".to_string()),
+            &settings(vec![QualityCheck::FormatCheck]),
+            |check, staged| check.run(repo, staged, &output),
+            &output,
+        )
+        .unwrap();
+
+        assert!(run_git_checked(repo, &["rev-parse", "--verify", "HEAD"], "git").is_ok());
+    }
+
     #[test]
     fn test_commit_message_localization() {
         use crate::cli::output::OutputHandler;
@@ -404,4 +1257,37 @@ mod tests {
         assert!(attribution_ja.contains("で生成"));
         assert!(coauthor_ja.contains("共同著者"));
     }
+
+    #[test]
+    fn test_create_commit_dry_run() {
+        use crate::cli::output::test_support::capture;
+        use crate::core::git_utils::run_git_checked;
+        use tempfile::TempDir;
+
+        let temp_dir = TempDir::new().unwrap();
+        let repo = temp_dir.path();
+        run_git_checked(repo, &["init", "-q"], "git init").unwrap();
+        std::fs::write(repo.join("x.txt"), "x\n").unwrap();
+        run_git_checked(repo, &["add", "x.txt"], "git add").unwrap();
+
+        let mut output = OutputHandler::default();
+        output.set_dry_run(true);
+        let (stdout, _stderr) = capture(&mut output);
+
+        let git_utils = GitUtils::open(repo).unwrap();
+        commit_in(
+            &git_utils,
+            Some("feat: add x".to_string()),
+            &settings(Vec::new()),
+            |_, _| Ok(()),
+            &output,
+        )
+        .unwrap();
+
+        let printed = stdout.text();
+        assert!(printed.contains("git commit -m 'feat: add x"));
+
+        // No commit was created
+        assert!(run_git_checked(repo, &["rev-parse", "--verify", "HEAD"], "git").is_err());
+    }
 }
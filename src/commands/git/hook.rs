@@ -0,0 +1,154 @@
+//! Git hook installation
+//!
+//! Writes a small shell hook into `.git/hooks` that runs a cldev command,
+//! e.g. `cldev quality fix-all --skip-tests` before every commit. Hooks that
+//! were not written by cldev are never replaced without `--force`.
+
+use crate::cli::args::HookKind;
+use crate::cli::output::OutputHandler;
+use crate::core::error::{CldevError, Result};
+use crate::core::git_utils::GitUtils;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Comment line identifying hooks written by cldev
+const HOOK_MARKER: &str = "# Installed by cldev";
+
+impl HookKind {
+    /// Get the hook file name inside `.git/hooks`
+    fn file_name(&self) -> &'static str {
+        match self {
+            Self::PreCommit => "pre-commit",
+            Self::PrePush => "pre-push",
+        }
+    }
+
+    /// Get the command the hook runs when none is given
+    fn default_command(&self) -> &'static str {
+        match self {
+            Self::PreCommit => "cldev quality fix-all --skip-tests",
+            Self::PrePush => "cldev quality test",
+        }
+    }
+}
+
+/// Install a Git hook running a cldev command in the current repository
+///
+/// `command` overrides the hook's default command.
+pub fn install_hook(
+    kind: HookKind,
+    command: Option<&str>,
+    force: bool,
+    output: &OutputHandler,
+) -> Result<()> {
+    let git_utils = GitUtils::open_current()?;
+    let command = command.unwrap_or(kind.default_command());
+
+    let path = install_hook_in(git_utils.repo_path(), kind, command, force)?;
+
+    output.success(&output.t_format("git-hook-installed", "path", &path.display().to_string()));
+    output.info(&output.t_format("git-hook-command", "command", command));
+    Ok(())
+}
+
+/// Write the hook script into `<git_dir>/hooks` and make it executable
+///
+/// Fails if a hook not written by cldev already exists, unless `force` is set.
+fn install_hook_in(git_dir: &Path, kind: HookKind, command: &str, force: bool) -> Result<PathBuf> {
+    let hooks_dir = git_dir.join("hooks");
+    fs::create_dir_all(&hooks_dir)?;
+
+    let path = hooks_dir.join(kind.file_name());
+    if !force && path.exists() {
+        let existing = fs::read_to_string(&path).unwrap_or_default();
+        if !existing.contains(HOOK_MARKER) {
+            return Err(CldevError::validation(format!(
+                "{} already exists and was not installed by cldev; re-run with --force to replace it",
+                path.display()
+            )));
+        }
+    }
+
+    fs::write(&path, hook_script(command))?;
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o755))?;
+    }
+
+    Ok(path)
+}
+
+/// Render the hook script
+fn hook_script(command: &str) -> String {
+    format!(
+        "#!/bin/sh\n{}; re-run `cldev git install-hook --force` to replace\nexec {}\n",
+        HOOK_MARKER, command
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::git_utils::run_git_checked;
+    use tempfile::TempDir;
+
+    fn init_repo() -> TempDir {
+        let temp_dir = TempDir::new().unwrap();
+        run_git_checked(temp_dir.path(), &["init", "-q"], "git init").unwrap();
+        temp_dir
+    }
+
+    #[test]
+    fn test_install_pre_commit_hook() {
+        let repo = init_repo();
+        let git_dir = repo.path().join(".git");
+
+        let path = install_hook_in(
+            &git_dir,
+            HookKind::PreCommit,
+            HookKind::PreCommit.default_command(),
+            false,
+        )
+        .unwrap();
+
+        assert_eq!(path, git_dir.join("hooks/pre-commit"));
+        let script = fs::read_to_string(&path).unwrap();
+        assert!(script.starts_with("#!/bin/sh\n"));
+        assert!(script.contains("exec cldev quality fix-all --skip-tests\n"));
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = fs::metadata(&path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o111, 0o111);
+        }
+
+        // Re-installing over a cldev hook needs no --force
+        install_hook_in(&git_dir, HookKind::PreCommit, "cldev quality lint", false).unwrap();
+        assert!(fs::read_to_string(&path)
+            .unwrap()
+            .contains("exec cldev quality lint\n"));
+    }
+
+    #[test]
+    fn test_existing_hook_requires_force() {
+        let repo = init_repo();
+        let git_dir = repo.path().join(".git");
+        let path = git_dir.join("hooks/pre-commit");
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, "#!/bin/sh\nnpx lint-staged\n").unwrap();
+
+        let err = install_hook_in(&git_dir, HookKind::PreCommit, "cldev quality lint", false)
+            .unwrap_err();
+        assert!(err.to_string().contains("--force"));
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            "#!/bin/sh\nnpx lint-staged\n"
+        );
+
+        install_hook_in(&git_dir, HookKind::PreCommit, "cldev quality lint", true).unwrap();
+        assert!(fs::read_to_string(&path).unwrap().contains(HOOK_MARKER));
+    }
+}
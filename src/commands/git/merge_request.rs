@@ -4,11 +4,104 @@
 //! or pull requests (GitHub) with automatic platform detection and CLI tool usage.
 
 use crate::cli::output::OutputHandler;
+use crate::core::config::{Config, PerformanceConfig};
 use crate::core::error::Result;
-use crate::core::git_utils::{check_cli_for_remote, GitUtils, RemoteType};
+use crate::core::git_utils::{check_gh_cli, check_glab_cli, GitUtils, RemoteType};
+use crate::core::retry::with_backoff;
+use crate::core::security::{output_checked, safe_arg};
 use dialoguer::Input;
+use std::io::{self, Write};
 use std::process::Command;
 
+/// CLI tool used to open the merge request / pull request
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum MrTool {
+    /// GitHub CLI
+    Gh,
+    /// GitLab CLI
+    Glab,
+}
+
+impl MrTool {
+    /// Get the executable name
+    pub(crate) fn program(&self) -> &'static str {
+        match self {
+            Self::Gh => "gh",
+            Self::Glab => "glab",
+        }
+    }
+
+    /// Build the arguments that create the MR/PR
+    fn create_args(&self, title: &str, body: &str, target: &str) -> Vec<String> {
+        let args: &[&str] = match self {
+            Self::Gh => &[
+                "pr", "create", "--title", title, "--body", body, "--base", target,
+            ],
+            Self::Glab => &[
+                "mr",
+                "create",
+                "--target-branch",
+                target,
+                "--title",
+                title,
+                "--description",
+                body,
+            ],
+        };
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    /// Build the arguments that print the diff of MR/PR `number`
+    pub(crate) fn diff_args(&self, number: u32) -> Vec<String> {
+        let kind = match self {
+            Self::Gh => "pr",
+            Self::Glab => "mr",
+        };
+        vec![kind.to_string(), "diff".to_string(), number.to_string()]
+    }
+}
+
+/// Which MR/PR CLI tools are installed
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct CliAvailability {
+    gh: bool,
+    glab: bool,
+}
+
+impl CliAvailability {
+    /// Check the installed tools
+    pub(crate) fn detect() -> Result<Self> {
+        Ok(Self {
+            gh: check_gh_cli()?,
+            glab: check_glab_cli()?,
+        })
+    }
+}
+
+/// Choose the CLI tool for the detected remote
+///
+/// github.com and gitlab remotes use their own tool. For other hosts, such
+/// as self-hosted GitLab, `glab` is used when `[git].gitlab_cli` is enabled
+/// or when `gh` is unavailable and `glab` is installed.
+pub(crate) fn select_mr_tool(
+    remote_type: RemoteType,
+    gitlab_cli: bool,
+    available: CliAvailability,
+) -> Option<MrTool> {
+    let tool = match remote_type {
+        RemoteType::GitHub => MrTool::Gh,
+        RemoteType::GitLab => MrTool::Glab,
+        RemoteType::Other if gitlab_cli || (!available.gh && available.glab) => MrTool::Glab,
+        RemoteType::Other => MrTool::Gh,
+    };
+
+    let installed = match tool {
+        MrTool::Gh => available.gh,
+        MrTool::Glab => available.glab,
+    };
+    installed.then_some(tool)
+}
+
 /// Create a merge request (GitLab) or pull request (GitHub)
 pub fn create_merge_request(
     target: &str,
@@ -18,6 +111,11 @@ pub fn create_merge_request(
 ) -> Result<()> {
     output.info(&output.t("git-mr-creating"));
 
+    // The target branch is passed to git and gh/glab as an argument
+    let target =
+        safe_arg(target).map_err(|e| crate::core::error::CldevError::security(e.to_string()))?;
+    let target = target.as_str();
+
     // Open the Git repository
     let git_utils = GitUtils::open_current()?;
 
@@ -38,9 +136,12 @@ pub fn create_merge_request(
     let remote_type = git_utils.detect_remote_type("origin")?;
     output.info(&output.t_format("git-mr-remote-detected", "type", remote_type.display_name()));
 
-    // Check if CLI tool is available
-    if !check_cli_for_remote(remote_type)? {
-        let tool = remote_type.cli_tool().unwrap_or("unknown");
+    // Choose the CLI tool for this remote
+    let config = Config::load(None).ok();
+    let gitlab_cli = config.as_ref().is_some_and(|c| c.git.gitlab_cli);
+    let retry = config.map(|c| c.performance.clone()).unwrap_or_default();
+    let Some(tool) = select_mr_tool(remote_type, gitlab_cli, CliAvailability::detect()?) else {
+        let tool = remote_type.cli_tool().unwrap_or("gh/glab");
         output.error(&output.t_format("git-mr-tool-not-found", "tool", tool));
         output.info(&output.t_format("git-mr-tool-install", "type", remote_type.display_name()));
 
@@ -62,27 +163,24 @@ pub fn create_merge_request(
             "Required CLI tool '{}' not found",
             tool
         )));
-    }
+    };
 
     // Check for unpushed commits
     let unpushed = git_utils.unpushed_commits("origin")?;
     if unpushed > 0 {
         output.warning(&output.t_format("git-mr-unpushed", "count", &unpushed.to_string()));
-        output.info(&output.t("git-mr-pushing"));
-
-        let status = Command::new("git")
-            .args(["push", "-u", "origin", &current_branch])
-            .status()
-            .map_err(|e| {
-                crate::core::error::CldevError::command(format!("Failed to push: {}", e))
-            })?;
-
-        if !status.success() {
-            output.error(&output.t("git-mr-push-failed"));
-            return Err(crate::core::error::CldevError::git("Push failed"));
-        }
 
-        output.success(&output.t("git-mr-push-success"));
+        let push = ["push", "-u", "origin", current_branch.as_str()];
+        if output.is_dry_run() {
+            output.dry_run_command("git", &push);
+        } else {
+            output.info(&output.t("git-mr-pushing"));
+            if let Err(e) = run_with_retry(&retry, "git", &push, "Push failed", output) {
+                output.error(&output.t("git-mr-push-failed"));
+                return Err(e);
+            }
+            output.success(&output.t("git-mr-push-success"));
+        }
     }
 
     // Get or generate MR/PR title
@@ -91,24 +189,24 @@ pub fn create_merge_request(
     } else {
         generate_mr_title(&current_branch, output)?
     };
+    if mr_title.chars().any(char::is_control) {
+        return Err(crate::core::error::CldevError::validation(
+            "MR/PR title must be a single line without control characters",
+        ));
+    }
 
     // Generate MR/PR body
     let body = generate_mr_body(&git_utils, &current_branch, target, detailed, output)?;
 
-    // Create MR/PR based on remote type
-    match remote_type {
-        RemoteType::GitHub => {
-            create_github_pr(&mr_title, &body, target, output)?;
-        }
-        RemoteType::GitLab => {
-            create_gitlab_mr(&mr_title, &body, target, output)?;
-        }
-        RemoteType::Other => {
-            output.error(&output.t("git-mr-unsupported"));
-            return Err(crate::core::error::CldevError::validation(
-                "Unsupported remote type",
-            ));
-        }
+    if output.is_dry_run() {
+        output.dry_run_command(tool.program(), &tool.create_args(&mr_title, &body, target));
+        return Ok(());
+    }
+
+    // Create MR/PR with the selected tool
+    match tool {
+        MrTool::Gh => create_github_pr(&retry, &mr_title, &body, target, output)?,
+        MrTool::Glab => create_gitlab_mr(&retry, &mr_title, &body, target, output)?,
     }
 
     Ok(())
@@ -210,20 +308,58 @@ fn get_commit_log(_git_utils: &GitUtils, branch: &str, target: &str) -> Result<S
     Ok(String::from_utf8_lossy(&output.stdout).to_string())
 }
 
+/// Run an allowed command, retrying transient failures per `[performance]`
+///
+/// The command's output is forwarded once it exits. A failure is reported
+/// with the command's stderr so that network errors can be told apart from
+/// rejections. Each retry is announced as a warning.
+fn run_with_retry(
+    retry: &PerformanceConfig,
+    program: &str,
+    args: &[impl AsRef<str>],
+    context: &str,
+    output: &OutputHandler,
+) -> Result<()> {
+    let on_retry = |attempt, e: &crate::core::error::CldevError, delay| {
+        output.warn_retry(attempt, retry.retry_attempts, e, delay)
+    };
+    with_backoff(retry.retry_attempts, retry.retry_delay(), on_retry, || {
+        let result = output_checked(program, args)
+            .map_err(|e| crate::core::error::CldevError::command(format!("{}: {}", context, e)))?;
+        io::stdout().write_all(&result.stdout).ok();
+        io::stderr().write_all(&result.stderr).ok();
+
+        if result.status.success() {
+            Ok(())
+        } else {
+            Err(crate::core::error::CldevError::git(format!(
+                "{}: {}",
+                context,
+                String::from_utf8_lossy(&result.stderr).trim()
+            )))
+        }
+    })
+}
+
 /// Create GitHub pull request
-fn create_github_pr(title: &str, body: &str, target: &str, output: &OutputHandler) -> Result<()> {
+fn create_github_pr(
+    retry: &PerformanceConfig,
+    title: &str,
+    body: &str,
+    target: &str,
+    output: &OutputHandler,
+) -> Result<()> {
     output.info(&output.t("git-mr-creating-github"));
 
-    let status = Command::new("gh")
-        .args([
-            "pr", "create", "--title", title, "--body", body, "--base", target,
-        ])
-        .status()
-        .map_err(|e| {
-            crate::core::error::CldevError::command(format!("Failed to create PR: {}", e))
-        })?;
+    let created = run_with_retry(
+        retry,
+        MrTool::Gh.program(),
+        &MrTool::Gh.create_args(title, body, target),
+        "PR creation failed",
+        output,
+    );
 
-    if status.success() {
+    if created.is_ok() {
         output.success(&output.t("git-mr-github-success"));
 
         // Get PR URL
@@ -243,33 +379,31 @@ fn create_github_pr(title: &str, body: &str, target: &str, output: &OutputHandle
         output.list_item(&output.t("git-mr-next-github-checks"));
     } else {
         output.error(&output.t("git-mr-github-failed"));
-        return Err(crate::core::error::CldevError::git("PR creation failed"));
+        return created;
     }
 
     Ok(())
 }
 
 /// Create GitLab merge request
-fn create_gitlab_mr(title: &str, body: &str, target: &str, output: &OutputHandler) -> Result<()> {
+fn create_gitlab_mr(
+    retry: &PerformanceConfig,
+    title: &str,
+    body: &str,
+    target: &str,
+    output: &OutputHandler,
+) -> Result<()> {
     output.info(&output.t("git-mr-creating-gitlab"));
 
-    let status = Command::new("glab")
-        .args([
-            "mr",
-            "create",
-            "--title",
-            title,
-            "--description",
-            body,
-            "--target-branch",
-            target,
-        ])
-        .status()
-        .map_err(|e| {
-            crate::core::error::CldevError::command(format!("Failed to create MR: {}", e))
-        })?;
+    let created = run_with_retry(
+        retry,
+        MrTool::Glab.program(),
+        &MrTool::Glab.create_args(title, body, target),
+        "MR creation failed",
+        output,
+    );
 
-    if status.success() {
+    if created.is_ok() {
         output.success(&output.t("git-mr-gitlab-success"));
 
         output.info(&format!("\n{}", output.t("git-mr-next-steps")));
@@ -277,7 +411,7 @@ fn create_gitlab_mr(title: &str, body: &str, target: &str, output: &OutputHandle
         output.list_item(&output.t("git-mr-next-gitlab-ci"));
     } else {
         output.error(&output.t("git-mr-gitlab-failed"));
-        return Err(crate::core::error::CldevError::git("MR creation failed"));
+        return created;
     }
 
     Ok(())
@@ -285,6 +419,83 @@ fn create_gitlab_mr(title: &str, body: &str, target: &str, output: &OutputHandle
 
 #[cfg(test)]
 mod tests {
+    use super::*;
+
+    const BOTH: CliAvailability = CliAvailability {
+        gh: true,
+        glab: true,
+    };
+    const GLAB_ONLY: CliAvailability = CliAvailability {
+        gh: false,
+        glab: true,
+    };
+
+    #[test]
+    fn test_create_merge_request_rejects_injected_target() {
+        let output = OutputHandler::default();
+        let err = create_merge_request("; rm -rf /", None, false, &output).unwrap_err();
+        assert!(err.to_string().contains("Unsafe argument"));
+    }
+
+    #[test]
+    fn test_select_tool_from_remote_host() {
+        let github = GitUtils::detect_remote_type_from_url("git@github.com:user/repo.git");
+        let gitlab = GitUtils::detect_remote_type_from_url("https://gitlab.com/user/repo.git");
+
+        assert_eq!(select_mr_tool(github, false, BOTH), Some(MrTool::Gh));
+        assert_eq!(select_mr_tool(gitlab, false, BOTH), Some(MrTool::Glab));
+        assert_eq!(select_mr_tool(github, false, GLAB_ONLY), None);
+    }
+
+    #[test]
+    fn test_select_tool_for_self_hosted_remote() {
+        let other = GitUtils::detect_remote_type_from_url("git@git.example.com:team/repo.git");
+        assert_eq!(other, RemoteType::Other);
+
+        assert_eq!(select_mr_tool(other, false, BOTH), Some(MrTool::Gh));
+        assert_eq!(select_mr_tool(other, true, BOTH), Some(MrTool::Glab));
+        assert_eq!(select_mr_tool(other, false, GLAB_ONLY), Some(MrTool::Glab));
+        assert_eq!(
+            select_mr_tool(other, false, CliAvailability::default()),
+            None
+        );
+    }
+
+    #[test]
+    fn test_glab_invocation() {
+        assert_eq!(MrTool::Glab.program(), "glab");
+        assert_eq!(
+            MrTool::Glab.create_args("Add login", "## Summary", "develop"),
+            [
+                "mr",
+                "create",
+                "--target-branch",
+                "develop",
+                "--title",
+                "Add login",
+                "--description",
+                "## Summary",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_gh_invocation() {
+        assert_eq!(MrTool::Gh.program(), "gh");
+        assert_eq!(
+            MrTool::Gh.create_args("Add login", "## Summary", "main"),
+            [
+                "pr",
+                "create",
+                "--title",
+                "Add login",
+                "--body",
+                "## Summary",
+                "--base",
+                "main",
+            ]
+        );
+    }
 
     #[test]
     fn test_generate_title_from_branch() {
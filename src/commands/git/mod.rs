@@ -4,14 +4,27 @@
 //! - Conventional commits with emoji support
 //! - Conventional branch naming
 //! - GitHub/GitLab merge request creation
-//! - Enhanced Git status with recommendations
+//! - Enhanced Git status with upstream tracking and recommendations
+//! - Syncing the current branch with its base branch
+//! - Installing cldev as a Git hook
 
 mod branch;
 mod commit;
+mod hook;
 mod merge_request;
 mod status;
+mod sync;
+#[cfg(test)]
+mod test_support;
 
 pub use branch::create_branch;
 pub use commit::create_commit;
+#[allow(unused_imports)]
+pub use commit::{suggest_commit_message, validate_conventional, CommitParts};
+pub use hook::install_hook;
 pub use merge_request::create_merge_request;
+pub(crate) use merge_request::{select_mr_tool, CliAvailability};
 pub use status::show_status;
+#[allow(unused_imports)]
+pub use status::BranchStatus;
+pub use sync::sync_with_base;
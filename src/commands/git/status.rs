@@ -8,9 +8,106 @@
 
 use crate::cli::output::OutputHandler;
 use crate::core::error::Result;
-use crate::core::git_utils::GitUtils;
+use crate::core::git_utils::{run_git, run_git_checked, GitUtils};
 use comfy_table::{Cell, Color, Table};
 use git2::{Status, StatusOptions};
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Branch position relative to its upstream plus a working tree summary
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BranchStatus {
+    /// Upstream branch (e.g. `origin/main`), if one is configured
+    pub upstream: Option<String>,
+    /// Commits on the branch that are not on the upstream
+    pub ahead: usize,
+    /// Commits on the upstream that are not on the branch
+    pub behind: usize,
+    /// Number of stash entries
+    pub stashes: usize,
+    /// Files with staged changes
+    pub staged: usize,
+    /// Tracked files with unstaged changes
+    pub modified: usize,
+    /// Untracked files
+    pub untracked: usize,
+}
+
+impl BranchStatus {
+    /// Collect the status of the repository at `repo`
+    ///
+    /// Ahead/behind counts come from `git rev-list --count` against the
+    /// configured upstream and stay at zero when there is none.
+    pub fn collect(repo: &Path) -> Result<Self> {
+        let mut status = Self::default();
+
+        let upstream = run_git(
+            repo,
+            &["rev-parse", "--abbrev-ref", "--symbolic-full-name", "@{u}"],
+        )?;
+        if upstream.status.success() {
+            status.upstream = Some(String::from_utf8_lossy(&upstream.stdout).trim().to_string());
+
+            let counts = run_git_checked(
+                repo,
+                &["rev-list", "--left-right", "--count", "HEAD...@{u}"],
+                "Failed to compare with upstream",
+            )?;
+            let mut counts = counts.split_whitespace().map(|n| n.parse().unwrap_or(0));
+            status.ahead = counts.next().unwrap_or(0);
+            status.behind = counts.next().unwrap_or(0);
+        }
+
+        status.stashes = run_git_checked(repo, &["stash", "list"], "Failed to list stashes")?
+            .lines()
+            .count();
+
+        let porcelain = run_git_checked(
+            repo,
+            &["status", "--porcelain"],
+            "Failed to read repository status",
+        )?;
+        for line in porcelain.lines() {
+            let mut codes = line.chars();
+            let (index, worktree) = (codes.next().unwrap_or(' '), codes.next().unwrap_or(' '));
+
+            if index == '?' {
+                status.untracked += 1;
+                continue;
+            }
+            if index != ' ' {
+                status.staged += 1;
+            }
+            if worktree != ' ' {
+                status.modified += 1;
+            }
+        }
+
+        Ok(status)
+    }
+
+    /// Recommended actions for the branch position and stash
+    pub fn recommendations(&self, output: &OutputHandler) -> Vec<String> {
+        let mut recommendations = Vec::new();
+
+        if self.behind > 0 {
+            recommendations.push(output.t_format(
+                "git-status-recommend-sync",
+                "count",
+                &self.behind.to_string(),
+            ));
+        }
+        if self.stashes > 0 {
+            recommendations.push(output.t_format(
+                "git-status-recommend-stash-pop",
+                "count",
+                &self.stashes.to_string(),
+            ));
+        }
+
+        recommendations
+    }
+}
 
 /// Show enhanced Git status
 pub fn show_status(detailed: bool, output: &OutputHandler) -> Result<()> {
@@ -25,11 +122,17 @@ pub fn show_status(detailed: bool, output: &OutputHandler) -> Result<()> {
     // Display remote information
     display_remote_info(&git_utils, output)?;
 
+    // Upstream position, stashes, and change counts
+    let branch_status = BranchStatus::collect(git_utils.workdir()?)?;
+    if detailed {
+        display_branch_status(&branch_status, output);
+    }
+
     // Display file status
     display_file_status(&git_utils, detailed, output)?;
 
     // Display recommended next actions
-    display_recommendations(&git_utils, output)?;
+    display_recommendations(&git_utils, &branch_status, output)?;
 
     Ok(())
 }
@@ -54,6 +157,43 @@ fn display_branch_info(git_utils: &GitUtils, output: &OutputHandler) -> Result<(
     Ok(())
 }
 
+/// Display ahead/behind, stash, and change counts
+fn display_branch_status(status: &BranchStatus, output: &OutputHandler) {
+    output.section(&output.t("git-status-tracking-info"));
+
+    match &status.upstream {
+        Some(upstream) => {
+            output.info(&output.t_format("git-status-upstream", "upstream", upstream));
+            output.info(&output.t_plural(
+                "git-status-ahead",
+                status.ahead,
+                "count",
+                &status.ahead.to_string(),
+            ));
+            output.info(&output.t_plural(
+                "git-status-behind",
+                status.behind,
+                "count",
+                &status.behind.to_string(),
+            ));
+        }
+        None => output.warning(&output.t("git-status-upstream-none")),
+    }
+
+    output.info(&output.t_format("git-status-stashes", "count", &status.stashes.to_string()));
+    let count = |key: &str, count: usize| output.t_plural(key, count, "count", &count.to_string());
+    let staged = count("git-status-breakdown-staged", status.staged);
+    let modified = count("git-status-breakdown-modified", status.modified);
+    let untracked = count("git-status-breakdown-untracked", status.untracked);
+    let mut vars = HashMap::new();
+    vars.insert("staged", staged.as_str());
+    vars.insert("modified", modified.as_str());
+    vars.insert("untracked", untracked.as_str());
+    output.info(&output.t_with_vars("git-status-breakdown", &vars));
+
+    println!();
+}
+
 /// Display remote information
 fn display_remote_info(git_utils: &GitUtils, output: &OutputHandler) -> Result<()> {
     match git_utils.get_remote_url("origin") {
@@ -259,7 +399,11 @@ fn display_file_table(files: &[(String, Status)], _output: &OutputHandler) {
 }
 
 /// Display recommended next actions
-fn display_recommendations(git_utils: &GitUtils, output: &OutputHandler) -> Result<()> {
+fn display_recommendations(
+    git_utils: &GitUtils,
+    branch_status: &BranchStatus,
+    output: &OutputHandler,
+) -> Result<()> {
     output.section(&output.t("git-status-recommend-header"));
 
     let is_clean = git_utils.is_clean()?;
@@ -286,6 +430,10 @@ fn display_recommendations(git_utils: &GitUtils, output: &OutputHandler) -> Resu
         output.list_item(&output.t("git-status-recommend-mr"));
     }
 
+    for recommendation in branch_status.recommendations(output) {
+        output.list_item(&recommendation);
+    }
+
     println!();
     Ok(())
 }
@@ -293,7 +441,104 @@ fn display_recommendations(git_utils: &GitUtils, output: &OutputHandler) -> Resu
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::commands::git::test_support::{clone, commit_file, run};
     use git2::Status;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_branch_status_with_upstream() {
+        let temp_dir = TempDir::new().unwrap();
+        let origin = temp_dir.path().join("origin.git");
+        let work = temp_dir.path().join("work");
+        let other = temp_dir.path().join("other");
+
+        fs::create_dir(&origin).unwrap();
+        run(&origin, &["init", "-q", "--bare", "-b", "main"]);
+        clone(&origin, &work);
+        run(&work, &["symbolic-ref", "HEAD", "refs/heads/main"]);
+        commit_file(&work, "a.txt", "a\n", "initial");
+        run(&work, &["push", "-q", "-u", "origin", "main"]);
+
+        // Upstream moves ahead by two commits
+        clone(&origin, &other);
+        commit_file(&other, "b.txt", "b\n", "upstream one");
+        commit_file(&other, "c.txt", "c\n", "upstream two");
+        run(&other, &["push", "-q", "origin", "main"]);
+        run(&work, &["fetch", "-q", "origin"]);
+
+        // One local commit, one stash, and a mix of working tree changes
+        commit_file(&work, "d.txt", "d\n", "local");
+        fs::write(work.join("a.txt"), "stashed\n").unwrap();
+        run(&work, &["stash", "push", "-q"]);
+        fs::write(work.join("a.txt"), "modified\n").unwrap();
+        fs::write(work.join("staged.txt"), "staged\n").unwrap();
+        run(&work, &["add", "staged.txt"]);
+        fs::write(work.join("untracked.txt"), "untracked\n").unwrap();
+
+        let status = BranchStatus::collect(&work).unwrap();
+        assert_eq!(
+            status,
+            BranchStatus {
+                upstream: Some("origin/main".to_string()),
+                ahead: 1,
+                behind: 2,
+                stashes: 1,
+                staged: 1,
+                modified: 1,
+                untracked: 1,
+            }
+        );
+
+        let output = OutputHandler::new(false, true, true);
+        let recommendations = status.recommendations(&output);
+        assert_eq!(recommendations.len(), 2);
+        assert!(recommendations[0].contains("cldev git sync"));
+        assert!(recommendations[0].contains('2'));
+    }
+
+    #[test]
+    fn test_branch_status_without_upstream() {
+        let temp_dir = TempDir::new().unwrap();
+        let repo = temp_dir.path();
+        run(repo, &["init", "-q"]);
+        run(repo, &["config", "user.name", "Test"]);
+        run(repo, &["config", "user.email", "test@example.com"]);
+        run(repo, &["config", "commit.gpgsign", "false"]);
+        commit_file(repo, "a.txt", "a\n", "initial");
+
+        let status = BranchStatus::collect(repo).unwrap();
+        assert_eq!(status, BranchStatus::default());
+
+        let output = OutputHandler::new(false, true, true);
+        assert!(status.recommendations(&output).is_empty());
+    }
+
+    #[test]
+    fn test_branch_status_breakdown_is_localized() {
+        use crate::cli::output::test_support::capture;
+        use crate::core::i18n::Language;
+
+        let status = BranchStatus {
+            staged: 1,
+            modified: 2,
+            ..BranchStatus::default()
+        };
+
+        let mut output = OutputHandler::with_language(false, false, true, Language::English);
+        let (stdout, _) = capture(&mut output);
+        display_branch_status(&status, &output);
+        assert!(stdout
+            .text()
+            .contains("Changes: 1 staged file, 2 modified files, 0 untracked files"));
+
+        let mut output = OutputHandler::with_language(false, false, true, Language::Japanese);
+        let (stdout, _) = capture(&mut output);
+        display_branch_status(&status, &output);
+        assert!(stdout
+            .text()
+            .contains("変更: ステージ済み 1 件、変更 2 件、未追跡 0 件"));
+    }
 
     #[test]
     fn test_format_file_status() {
@@ -0,0 +1,254 @@
+//! Sync the current branch with its base branch
+//!
+//! Fetches the base branch from origin and rebases the current branch onto
+//! it, optionally stashing uncommitted changes around the rebase. A
+//! conflicting rebase is aborted so the repository is left as it was.
+
+use crate::cli::output::OutputHandler;
+use crate::core::config::Config;
+use crate::core::error::{CldevError, Result};
+use crate::core::git_utils::{run_git, run_git_checked, GitUtils};
+use std::path::Path;
+
+/// Remote the base branch is fetched from
+const REMOTE: &str = "origin";
+
+/// Message attached to the stash created by `--autostash`
+const AUTOSTASH_MESSAGE: &str = "cldev sync autostash";
+
+/// Result of a successful sync
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SyncOutcome {
+    /// Upstream ref the branch was rebased onto (e.g. `origin/main`)
+    pub upstream: String,
+    /// Number of upstream commits that were not yet in the branch
+    pub behind: usize,
+    /// Whether uncommitted changes were stashed and restored
+    pub stashed: bool,
+}
+
+/// Fetch origin and rebase the current branch onto the effective base branch
+///
+/// The base branch comes from the project's `[git].base_branch`, falling
+/// back to the global `[git].default_base_branch`.
+pub fn sync_with_base(autostash: bool, output: &OutputHandler) -> Result<()> {
+    let git_utils = GitUtils::open_current()?;
+    let workdir = git_utils.workdir()?;
+
+    let base = Config::load_hierarchical(Some(workdir.to_path_buf()))?.effective_base_branch();
+    output.info(&output.t_format(
+        "git-sync-fetching",
+        "upstream",
+        &format!("{}/{}", REMOTE, base),
+    ));
+
+    if output.is_dry_run() {
+        print_sync_plan(&base, autostash, output);
+        return Ok(());
+    }
+
+    let outcome = rebase_onto_base(workdir, &base, autostash)?;
+
+    if outcome.behind == 0 {
+        output.success(&output.t_format("git-sync-up-to-date", "upstream", &outcome.upstream));
+    } else {
+        output.success(&output.t_format("git-sync-success", "upstream", &outcome.upstream));
+        output.info(&output.t_plural(
+            "git-sync-commits",
+            outcome.behind,
+            "count",
+            &outcome.behind.to_string(),
+        ));
+    }
+    if outcome.stashed {
+        output.info(&output.t("git-sync-stash-restored"));
+    }
+
+    Ok(())
+}
+
+/// Print the commands a sync would run without running them (`--dry-run`)
+fn print_sync_plan(base: &str, autostash: bool, output: &OutputHandler) {
+    let upstream = format!("{}/{}", REMOTE, base);
+
+    output.dry_run_command("git", &["fetch", REMOTE, base]);
+    if autostash {
+        output.dry_run_command("git", &["stash", "push", "-m", AUTOSTASH_MESSAGE]);
+    }
+    output.dry_run_command("git", &["rebase", &upstream]);
+    if autostash {
+        output.dry_run_command("git", &["stash", "pop"]);
+    }
+}
+
+/// Fetch `origin/<base>` and rebase the repository's current branch onto it
+fn rebase_onto_base(repo: &Path, base: &str, autostash: bool) -> Result<SyncOutcome> {
+    let upstream = format!("{}/{}", REMOTE, base);
+
+    run_git_checked(repo, &["fetch", REMOTE, base], "Failed to fetch")?;
+
+    let behind = run_git_checked(
+        repo,
+        &["rev-list", "--count", &format!("HEAD..{}", upstream)],
+        "Failed to compare with the base branch",
+    )?
+    .trim()
+    .parse()
+    .unwrap_or(0);
+
+    let dirty = !run_git_checked(
+        repo,
+        &["status", "--porcelain", "--untracked-files=no"],
+        "Failed to read repository status",
+    )?
+    .trim()
+    .is_empty();
+
+    if dirty && !autostash {
+        return Err(CldevError::git(
+            "Working tree has uncommitted changes; commit them or re-run with --autostash",
+        ));
+    }
+    if dirty {
+        run_git_checked(
+            repo,
+            &["stash", "push", "-m", AUTOSTASH_MESSAGE],
+            "Failed to stash changes",
+        )?;
+    }
+
+    let rebase = run_git(repo, &["rebase", &upstream])?;
+    if !rebase.status.success() {
+        let conflicted = run_git_checked(
+            repo,
+            &["diff", "--name-only", "--diff-filter=U"],
+            "Failed to list conflicted files",
+        )
+        .unwrap_or_default();
+        run_git_checked(repo, &["rebase", "--abort"], "Failed to abort the rebase")?;
+        if dirty {
+            run_git_checked(repo, &["stash", "pop"], "Failed to restore stashed changes")?;
+        }
+
+        let files: Vec<&str> = conflicted.lines().filter(|l| !l.is_empty()).collect();
+        return Err(CldevError::git(format!(
+            "Rebase onto {} hit conflicts in: {}. The rebase was aborted and the branch is unchanged; \
+             run `git rebase {}` to resolve them manually",
+            upstream,
+            if files.is_empty() {
+                "(unknown files)".to_string()
+            } else {
+                files.join(", ")
+            },
+            upstream
+        )));
+    }
+
+    if dirty {
+        run_git_checked(
+            repo,
+            &["stash", "pop"],
+            "Rebase succeeded but restoring stashed changes failed; resolve the conflicts and run `git stash drop`",
+        )?;
+    }
+
+    Ok(SyncOutcome {
+        upstream,
+        behind,
+        stashed: dirty,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::git::test_support::{clone, commit_file, run};
+    use std::fs;
+    use tempfile::TempDir;
+
+    /// Create an origin with one commit on main, a working clone on a
+    /// feature branch, and a second clone used to push upstream changes
+    fn setup() -> (TempDir, std::path::PathBuf, std::path::PathBuf) {
+        let temp_dir = TempDir::new().unwrap();
+        let origin = temp_dir.path().join("origin.git");
+        let work = temp_dir.path().join("work");
+        let other = temp_dir.path().join("other");
+
+        fs::create_dir(&origin).unwrap();
+        run(&origin, &["init", "-q", "--bare", "-b", "main"]);
+
+        clone(&origin, &work);
+        run(&work, &["symbolic-ref", "HEAD", "refs/heads/main"]);
+        commit_file(&work, "shared.txt", "base\n", "initial");
+        run(&work, &["push", "-q", "origin", "main"]);
+        run(&work, &["checkout", "-q", "-b", "feature"]);
+
+        clone(&origin, &other);
+
+        (temp_dir, work, other)
+    }
+
+    #[test]
+    fn test_clean_rebase() {
+        let (_temp, work, other) = setup();
+
+        commit_file(&other, "upstream.txt", "upstream\n", "upstream change");
+        run(&other, &["push", "-q", "origin", "main"]);
+        commit_file(&work, "feature.txt", "feature\n", "feature change");
+
+        let outcome = rebase_onto_base(&work, "main", false).unwrap();
+        assert_eq!(outcome.upstream, "origin/main");
+        assert_eq!(outcome.behind, 1);
+        assert!(!outcome.stashed);
+
+        let log = run(&work, &["log", "--format=%s"]);
+        assert_eq!(
+            log.lines().collect::<Vec<_>>(),
+            ["feature change", "upstream change", "initial"]
+        );
+    }
+
+    #[test]
+    fn test_autostash_restores_changes() {
+        let (_temp, work, other) = setup();
+
+        commit_file(&other, "upstream.txt", "upstream\n", "upstream change");
+        run(&other, &["push", "-q", "origin", "main"]);
+        fs::write(work.join("shared.txt"), "base\nlocal edit\n").unwrap();
+
+        let err = rebase_onto_base(&work, "main", false).unwrap_err();
+        assert!(err.to_string().contains("--autostash"));
+
+        let outcome = rebase_onto_base(&work, "main", true).unwrap();
+        assert!(outcome.stashed);
+        assert!(work.join("upstream.txt").exists());
+        assert_eq!(
+            fs::read_to_string(work.join("shared.txt")).unwrap(),
+            "base\nlocal edit\n"
+        );
+        assert!(run(&work, &["stash", "list"]).trim().is_empty());
+    }
+
+    #[test]
+    fn test_conflict_aborts_rebase() {
+        let (_temp, work, other) = setup();
+
+        commit_file(&other, "shared.txt", "upstream\n", "upstream edit");
+        run(&other, &["push", "-q", "origin", "main"]);
+        commit_file(&work, "shared.txt", "feature\n", "feature edit");
+        let head_before = run(&work, &["rev-parse", "HEAD"]);
+
+        let err = rebase_onto_base(&work, "main", false).unwrap_err();
+        let message = err.to_string();
+        assert!(message.contains("conflicts in: shared.txt"));
+        assert!(message.contains("aborted"));
+
+        // The branch is left exactly as it was
+        assert_eq!(run(&work, &["rev-parse", "HEAD"]), head_before);
+        assert_eq!(
+            run(&work, &["rev-parse", "--abbrev-ref", "HEAD"]).trim(),
+            "feature"
+        );
+        assert!(run(&work, &["status", "--porcelain"]).trim().is_empty());
+    }
+}
@@ -0,0 +1,33 @@
+//! Repository helpers shared by the Git command tests
+
+use crate::core::git_utils::run_git_checked;
+use std::fs;
+use std::path::Path;
+
+/// Run a Git command in `repo`, panicking on failure
+pub(super) fn run(repo: &Path, args: &[&str]) -> String {
+    run_git_checked(repo, args, "git").unwrap()
+}
+
+/// Write `file` and commit it with `message`
+pub(super) fn commit_file(repo: &Path, file: &str, content: &str, message: &str) {
+    fs::write(repo.join(file), content).unwrap();
+    run(repo, &["add", file]);
+    run(repo, &["commit", "-q", "-m", message]);
+}
+
+/// Clone `origin` into `dir` with a committer identity configured
+pub(super) fn clone(origin: &Path, dir: &Path) {
+    run(
+        origin.parent().unwrap(),
+        &[
+            "clone",
+            "-q",
+            origin.to_str().unwrap(),
+            dir.to_str().unwrap(),
+        ],
+    );
+    run(dir, &["config", "user.name", "Test"]);
+    run(dir, &["config", "user.email", "test@example.com"]);
+    run(dir, &["config", "commit.gpgsign", "false"]);
+}
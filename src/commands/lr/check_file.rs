@@ -1,6 +1,27 @@
+use crate::core::git_utils::{file_churn, RECENT_CHURN_DAYS};
 use crate::core::learning_db::LearningDatabase;
-use crate::core::Result;
+use crate::core::{ChurnStats, LearningRecordV3, Result};
 use colored::Colorize;
+use std::path::Path;
+
+/// Commit count at which git churn contributes its full weight
+const CHURN_SATURATION: f64 = 20.0;
+/// Recent commit count at which recent churn contributes its full weight
+const RECENT_CHURN_SATURATION: f64 = 5.0;
+/// Learning session count at which past issues contribute their full weight
+const SESSION_SATURATION: f64 = 5.0;
+
+/// Combined risk of editing a file, from 0.0 (quiet) to 1.0 (hot)
+///
+/// Overall churn and recent churn weigh 0.3 and 0.2; sessions in learning
+/// records that touched the file weigh 0.5, since they are actual past issues.
+pub fn risk_score(churn: &ChurnStats, session_count: usize) -> f64 {
+    let saturate = |value: usize, at: f64| (value as f64 / at).min(1.0);
+
+    0.3 * saturate(churn.commits, CHURN_SATURATION)
+        + 0.2 * saturate(churn.recent_commits, RECENT_CHURN_SATURATION)
+        + 0.5 * saturate(session_count, SESSION_SATURATION)
+}
 
 /// Check if a file is a hotspot and warn about past issues
 ///
@@ -19,9 +40,7 @@ pub fn handle_check_file(file_path: &str) -> Result<()> {
     println!("{}", "🔍 Checking file hotspot status...".cyan().bold());
 
     // Get learning records directory
-    let home_dir = dirs::home_dir().expect("Could not find home directory");
-    let cldev_dir = home_dir.join(".cldev");
-    let learning_dir = cldev_dir.join("learning-records");
+    let learning_dir = LearningRecordV3::records_dir()?;
 
     // Initialize learning database
     let db_path = learning_dir.join("index.db");
@@ -36,6 +55,34 @@ pub fn handle_check_file(file_path: &str) -> Result<()> {
         .iter()
         .find(|h| h.file_path.contains(file_path) || file_path.contains(&h.file_path));
 
+    // Git history is best effort: files outside a repository have no churn
+    let churn = file_churn(Path::new(file_path)).ok();
+    if let Some(churn) = &churn {
+        println!("{}", "📈 Git History:".cyan().bold());
+        println!(
+            "Commits: {} ({} in the last {} days)",
+            churn.commits.to_string().yellow(),
+            churn.recent_commits,
+            RECENT_CHURN_DAYS
+        );
+        if let Some(last_modified) = &churn.last_modified {
+            println!("Last modified: {}", last_modified);
+        }
+
+        let sessions = matching_hotspot.map_or(0, |h| h.session_count);
+        let score = risk_score(churn, sessions);
+        let label = format!("{:.2}", score);
+        let label = if score >= 0.6 {
+            format!("{} (high)", label).red().bold()
+        } else if score >= 0.3 {
+            format!("{} (medium)", label).yellow()
+        } else {
+            format!("{} (low)", label).green()
+        };
+        println!("Risk score: {}", label);
+        println!();
+    }
+
     match matching_hotspot {
         Some(hotspot) => {
             // File is a hotspot - display warning
@@ -136,6 +183,25 @@ mod tests {
         assert!(hotspots[0].file_path.contains("test.rs"));
     }
 
+    #[test]
+    fn test_risk_score_combines_churn_and_sessions() {
+        let quiet = ChurnStats {
+            commits: 1,
+            recent_commits: 0,
+            last_modified: Some("2024-01-01".to_string()),
+        };
+        let hot = ChurnStats {
+            commits: 40,
+            recent_commits: 8,
+            last_modified: Some("2024-06-01".to_string()),
+        };
+
+        assert!(risk_score(&quiet, 0) < 0.05);
+        assert!((risk_score(&hot, 0) - 0.5).abs() < 1e-9);
+        assert!((risk_score(&hot, 10) - 1.0).abs() < 1e-9);
+        assert!(risk_score(&quiet, 3) > risk_score(&quiet, 0));
+    }
+
     #[test]
     fn test_check_file_no_hotspot() {
         let temp_dir = TempDir::new().unwrap();
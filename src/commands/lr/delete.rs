@@ -1,4 +1,4 @@
-use crate::core::{LearningDatabase, LearningRecordV2, Result};
+use crate::core::{LearningDatabase, LearningRecordV2, LearningSession, Result};
 use colored::Colorize;
 use dialoguer::Confirm;
 use std::path::PathBuf;
@@ -46,13 +46,7 @@ pub fn handle_delete(id: String, skip_confirm: bool) -> Result<()> {
     }
 
     // Get paths
-    let home = std::env::var("HOME")
-        .ok()
-        .map(PathBuf::from)
-        .or_else(dirs::home_dir)
-        .ok_or_else(|| crate::core::CldevError::config("Failed to get home directory"))?;
-
-    let records_dir = home.join(".claude").join("learning-records");
+    let records_dir = LearningSession::records_dir()?;
     let markdown_path = records_dir.join(format!("{}.md", id));
     let db_path = records_dir.join("learning.db");
 
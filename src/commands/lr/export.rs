@@ -0,0 +1,286 @@
+//! Export all learning records into a single knowledge base file
+//!
+//! Records are read through the learning database, sanitized, grouped by
+//! session type, and ordered by hotspot score within each group.
+
+use crate::cli::args::LrExportFormat;
+use crate::core::learning_db::LearningDatabase;
+use crate::core::learning_record_v2::LearningRecordV2;
+use crate::core::sanitizer::sanitize_text_with_allowlist;
+use crate::core::{Config, LearningRecordV3, LearningSession, Result};
+use colored::Colorize;
+use regex::Regex;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// A sanitized learning record in the knowledge base
+#[derive(Debug, Clone, Serialize)]
+pub struct ExportedRecord {
+    pub id: String,
+    pub session_type: String,
+    pub title: String,
+    pub timestamp: String,
+    pub resolved: bool,
+    pub hotspot_score: f64,
+    pub tags: Vec<String>,
+    pub description: String,
+    pub solution: Option<String>,
+    pub learnings: Vec<String>,
+    pub notes: String,
+}
+
+/// Records grouped by session type, each group ordered by hotspot score
+pub type KnowledgeBase = BTreeMap<String, Vec<ExportedRecord>>;
+
+/// Handle learning record export command
+///
+/// # Arguments
+///
+/// * `format` - Output format (Markdown or JSON)
+/// * `output` - File to write (default: `knowledge-base.md` or `knowledge-base.json`)
+pub fn handle_export(format: LrExportFormat, output: Option<PathBuf>) -> Result<()> {
+    println!("{}", "📦 Exporting learning records...".cyan().bold());
+
+    let records_dir = LearningSession::records_dir()?;
+
+    // Values matching `lr.sanitizer_allowlist` are never redacted
+    let allowlist = Config::load(None)
+        .map(|config| config.lr.clone())
+        .unwrap_or_default()
+        .sanitizer_allowlist_patterns()?;
+
+    let knowledge_base = collect_records(&records_dir, &allowlist)?;
+    let total: usize = knowledge_base.values().map(Vec::len).sum();
+    if total == 0 {
+        println!("{}", "\n⚠️  No learning records found".yellow());
+        return Ok(());
+    }
+
+    let (content, default_name) = match format {
+        LrExportFormat::Markdown => (render_markdown(&knowledge_base), "knowledge-base.md"),
+        LrExportFormat::Json => (render_json(&knowledge_base)?, "knowledge-base.json"),
+    };
+    let output = output.unwrap_or_else(|| PathBuf::from(default_name));
+    fs::write(&output, content)?;
+
+    println!(
+        "{}",
+        format!("\n✅ Exported {} record(s) to {}", total, output.display()).green()
+    );
+
+    Ok(())
+}
+
+/// Load and sanitize every record indexed from `records_dir`
+///
+/// The index is refreshed from the markdown files first. Records migrated to
+/// V3 are exported from their V3 file. Records whose markdown can no longer be
+/// read are skipped.
+pub fn collect_records(records_dir: &Path, allowlist: &[Regex]) -> Result<KnowledgeBase> {
+    let mut db = LearningDatabase::new(records_dir.join("learning.db"), records_dir.to_path_buf())?;
+    db.build_from_markdown()?;
+
+    let sanitize = |text: &str| sanitize_text_with_allowlist(text, allowlist).sanitized;
+    let mut knowledge_base = KnowledgeBase::new();
+
+    for session in db.get_all_sessions()? {
+        let Ok(content) = fs::read_to_string(&session.markdown_path) else {
+            continue;
+        };
+
+        let exported = if let Ok(record) = LearningRecordV2::parse_markdown(&content) {
+            let notes = content.splitn(3, "---\n").nth(2).unwrap_or_default().trim();
+            ExportedRecord {
+                id: session.id,
+                session_type: session.session_type,
+                title: sanitize(&record.problem.title),
+                timestamp: session.timestamp,
+                resolved: session.resolved,
+                hotspot_score: session.hotspot_score,
+                tags: record.context.tags,
+                description: sanitize(&record.problem.description),
+                solution: record.solution.map(|s| sanitize(&s.summary)),
+                learnings: record
+                    .learnings
+                    .iter()
+                    .map(|l| sanitize(&l.insight))
+                    .collect(),
+                notes: sanitize(notes),
+            }
+        } else if let Ok(record) = LearningRecordV3::from_markdown_file(&content) {
+            // Records migrated to V3 keep their index entry; the structured
+            // fields now live in the markdown body
+            ExportedRecord {
+                id: session.id,
+                session_type: session.session_type,
+                title: sanitize(&session.title),
+                timestamp: session.timestamp,
+                resolved: session.resolved,
+                hotspot_score: session.hotspot_score,
+                tags: record.tags,
+                description: sanitize(&session.description),
+                solution: None,
+                learnings: Vec::new(),
+                notes: sanitize(&record.markdown_body),
+            }
+        } else {
+            continue;
+        };
+
+        knowledge_base
+            .entry(exported.session_type.clone())
+            .or_default()
+            .push(exported);
+    }
+
+    Ok(knowledge_base)
+}
+
+/// Render the knowledge base as a single Markdown document
+pub fn render_markdown(knowledge_base: &KnowledgeBase) -> String {
+    let mut md = String::from("# Knowledge Base\n");
+
+    for (session_type, records) in knowledge_base {
+        md.push_str(&format!("\n## {} ({})\n", session_type, records.len()));
+
+        for record in records {
+            md.push_str(&format!("\n### {}\n\n", record.title));
+            md.push_str(&format!(
+                "- **ID**: {}\n- **Date**: {}\n- **Resolved**: {}\n- **Hotspot**: {:.2}\n",
+                record.id,
+                record.timestamp,
+                if record.resolved { "yes" } else { "no" },
+                record.hotspot_score
+            ));
+            if !record.tags.is_empty() {
+                md.push_str(&format!("- **Tags**: {}\n", record.tags.join(", ")));
+            }
+
+            md.push_str(&format!("\n{}\n", record.description));
+            if let Some(solution) = &record.solution {
+                md.push_str(&format!("\n**Solution**: {}\n", solution));
+            }
+            if !record.learnings.is_empty() {
+                md.push_str("\n**Learnings**:\n\n");
+                for learning in &record.learnings {
+                    md.push_str(&format!("- {}\n", learning));
+                }
+            }
+            if !record.notes.is_empty() {
+                md.push_str(&format!("\n{}\n", demote_headings(&record.notes, 3)));
+            }
+        }
+    }
+
+    md
+}
+
+/// Render the knowledge base as JSON, keyed by session type
+pub fn render_json(knowledge_base: &KnowledgeBase) -> Result<String> {
+    Ok(serde_json::to_string_pretty(knowledge_base)?)
+}
+
+/// Push Markdown headings down by `levels` so notes nest under their record
+fn demote_headings(markdown: &str, levels: usize) -> String {
+    let prefix = "#".repeat(levels);
+    markdown
+        .lines()
+        .map(|line| {
+            if line.starts_with('#') {
+                format!("{}{}", prefix, line)
+            } else {
+                line.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::learning_record_v2::*;
+    use tempfile::TempDir;
+
+    fn write_record(dir: &Path, id: &str, session_type: SessionType, title: &str, notes: &str) {
+        let mut record = LearningRecordBuilder::new(
+            session_type,
+            Priority::Medium,
+            title,
+            "Deploy failed with password = MySecretPass123",
+            Severity::Error,
+        )
+        .tag("deploy")
+        .build();
+        record.session_meta.id = id.to_string();
+
+        let yaml = serde_yaml::to_string(&record).unwrap();
+        let content = format!("---\n{}---\n\n# Session Notes\n\n{}\n", yaml, notes);
+        fs::write(dir.join(format!("{}.md", id)), content).unwrap();
+    }
+
+    fn knowledge_base(dir: &Path) -> KnowledgeBase {
+        write_record(
+            dir,
+            "debug-a",
+            SessionType::Debug,
+            "Pool timeout",
+            "api_key: sk-1234567890abcdefghij",
+        );
+        write_record(
+            dir,
+            "debug-b",
+            SessionType::Debug,
+            "Cache miss storm",
+            "Contact ops@example.com",
+        );
+        write_record(
+            dir,
+            "fix-a",
+            SessionType::Fix,
+            "Broken migration",
+            "Fixed the column order",
+        );
+        collect_records(dir, &[]).unwrap()
+    }
+
+    #[test]
+    fn test_export_markdown_includes_all_records_redacted() {
+        let temp_dir = TempDir::new().unwrap();
+        let markdown = render_markdown(&knowledge_base(temp_dir.path()));
+
+        assert!(markdown.contains("## debug (2)"));
+        assert!(markdown.contains("## fix (1)"));
+        for title in ["Pool timeout", "Cache miss storm", "Broken migration"] {
+            assert!(markdown.contains(&format!("### {}", title)));
+        }
+        assert!(markdown.contains("#### Session Notes"));
+
+        assert!(!markdown.contains("MySecretPass123"));
+        assert!(!markdown.contains("sk-1234567890abcdefghij"));
+        assert!(!markdown.contains("ops@example.com"));
+        assert!(markdown.contains("[REDACTED_PASSWORD]"));
+    }
+
+    #[test]
+    fn test_export_json_groups_by_session_type() {
+        let temp_dir = TempDir::new().unwrap();
+        let json = render_json(&knowledge_base(temp_dir.path())).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(value["debug"].as_array().unwrap().len(), 2);
+        assert_eq!(value["fix"][0]["id"], "fix-a");
+        assert!(!json.contains("MySecretPass123"));
+        assert!(!json.contains("sk-1234567890abcdefghij"));
+    }
+
+    #[test]
+    fn test_demote_headings() {
+        assert_eq!(
+            demote_headings("# Notes\ntext\n## Sub", 2),
+            "### Notes\ntext\n#### Sub"
+        );
+    }
+}
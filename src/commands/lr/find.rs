@@ -1,9 +1,26 @@
 use crate::cli::args::SearchField;
-use crate::core::{LearningRecordV3, Result};
+use crate::cli::output::OutputHandler;
+use crate::cli::pager::Pager;
+use crate::core::learning_db::{HIGHLIGHT_END, HIGHLIGHT_START};
+use crate::core::{
+    Config, LearningDatabase, LearningRecordV3, LearningSession, QueryResult, Result,
+};
 use colored::Colorize;
+use std::collections::HashMap;
+use std::fmt::{self, Write};
 
 /// Handle find learning records command
-pub fn handle_find(query: String, field: Option<SearchField>, limit: usize) -> Result<()> {
+///
+/// Content searches also list matching indexed records with a highlighted
+/// excerpt. The result list is shown through the pager when it is too long
+/// for the terminal.
+pub fn handle_find(
+    query: String,
+    field: Option<SearchField>,
+    limit: usize,
+    pager: &Pager,
+    output: &OutputHandler,
+) -> Result<()> {
     println!("{}", "🔍 Searching learning records...".cyan().bold());
     println!("{} Query: {}", "ℹ️".cyan(), query.green());
 
@@ -15,10 +32,17 @@ pub fn handle_find(query: String, field: Option<SearchField>, limit: usize) -> R
         );
     }
 
+    // Full-text search over indexed records, with excerpts
+    let indexed = if matches!(field, None | Some(SearchField::Content)) {
+        search_indexed(&query, limit, output)
+    } else {
+        Vec::new()
+    };
+
     // Load V3 records (new format)
     let v3_ids = LearningRecordV3::list_all().unwrap_or_default();
 
-    if v3_ids.is_empty() {
+    if v3_ids.is_empty() && indexed.is_empty() {
         println!("{}", "\n⚠️  No learning records found".yellow());
         println!("Create your first record with: cldev session start");
         return Ok(());
@@ -29,6 +53,10 @@ pub fn handle_find(query: String, field: Option<SearchField>, limit: usize) -> R
     let query_lower = query.to_lowercase();
 
     for id in v3_ids {
+        // Records migrated from V2 are already listed with the indexed results
+        if indexed.iter().any(|(result, _)| result.session.id == id) {
+            continue;
+        }
         if let Ok(record) = LearningRecordV3::load(&id) {
             let matches = match field {
                 Some(SearchField::Topic) => record.id.to_lowercase().contains(&query_lower),
@@ -57,48 +85,149 @@ pub fn handle_find(query: String, field: Option<SearchField>, limit: usize) -> R
     }
 
     // Display results
-    if matching_records.is_empty() {
+    if matching_records.is_empty() && indexed.is_empty() {
         println!("{}", "\n⚠️  No matching records found".yellow());
         return Ok(());
     }
 
-    println!(
+    let mut out = String::new();
+    if !indexed.is_empty() {
+        writeln!(
+            out,
+            "\n{} {} indexed record(s) mention the query",
+            "🗂️".cyan(),
+            indexed.len()
+        )?;
+        for (result, snippet) in &indexed {
+            writeln!(
+                out,
+                "\n  {} {}",
+                result.session.id.yellow(),
+                result.session.title.bold()
+            )?;
+            writeln!(out, "    {}", render_snippet(snippet, output))?;
+        }
+    }
+
+    if matching_records.is_empty() {
+        return pager.display(&out);
+    }
+
+    writeln!(
+        out,
         "\n{} Found {} matching record(s)",
         "✅".green(),
         matching_records.len()
-    );
+    )?;
 
     // Limit results
     let display_count = limit.min(matching_records.len());
-    println!("{} Displaying top {}", "ℹ️".cyan(), display_count);
+    writeln!(out, "{} Displaying top {}", "ℹ️".cyan(), display_count)?;
 
     // Display records
     for (i, record) in matching_records.iter().take(limit).enumerate() {
-        display_record_brief(record, i + 1);
+        display_record_brief(&mut out, record, i + 1)?;
     }
 
     if matching_records.len() > limit {
-        println!(
+        writeln!(
+            out,
             "\n{} {} more record(s) not shown. Increase --limit to see more.",
             "ℹ️".cyan(),
             matching_records.len() - limit
-        );
+        )?;
     }
 
     // Provide next steps
-    println!("\n{}", "💡 Next Steps:".yellow().bold());
-    println!("  • View details: Check files in ~/.cldev/learning-records/");
-    println!("  • Filter by tag: cldev lr find <query> --field tag");
-    println!("  • See stats: cldev lr stats");
+    writeln!(out, "\n{}", "💡 Next Steps:".yellow().bold())?;
+    writeln!(
+        out,
+        "  • View details: Check files in ~/.cldev/learning-records/"
+    )?;
+    writeln!(out, "  • Filter by tag: cldev lr find <query> --field tag")?;
+    writeln!(out, "  • See stats: cldev lr stats")?;
+
+    pager.display(&out)
+}
 
-    Ok(())
+/// Search the indexed records, returning each match with its excerpt
+///
+/// Results are ranked by [`LearningDatabase::search_hybrid`] with the
+/// `[lr.search]` weights. Matches found only by TF-IDF have no full-text
+/// excerpt and show their description instead. The index is only rebuilt
+/// when markdown files changed since it was written. A missing index yields
+/// no results, and a failing search is reported as a warning.
+fn search_indexed(query: &str, limit: usize, output: &OutputHandler) -> Vec<(QueryResult, String)> {
+    let Ok(records_dir) = LearningSession::records_dir() else {
+        return Vec::new();
+    };
+    if !records_dir.join("learning.db").exists() {
+        return Vec::new();
+    }
+
+    let weights = Config::load(None)
+        .map(|config| config.lr.search)
+        .unwrap_or_default();
+
+    let search = || -> Result<Vec<(QueryResult, String)>> {
+        let mut db = LearningDatabase::new(records_dir.join("learning.db"), records_dir.clone())?;
+        // Either path populates the in-memory TF-IDF index used by the hybrid ranking
+        if db.is_stale()? {
+            db.build_from_markdown()?;
+        } else {
+            db.load_tfidf_index()?;
+        }
+
+        let mut snippets: HashMap<String, String> = db
+            .query_by_keyword_with_snippets(query, limit)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(result, snippet)| (result.session.id, snippet))
+            .collect();
+
+        Ok(db
+            .search_hybrid(query, limit, &weights)?
+            .into_iter()
+            .map(|result| {
+                let snippet = snippets
+                    .remove(&result.session.id)
+                    .unwrap_or_else(|| result.session.description.clone());
+                (result, snippet)
+            })
+            .collect())
+    };
+
+    search().unwrap_or_else(|e| {
+        output.warning(&format!("Indexed search failed: {}", e));
+        Vec::new()
+    })
+}
+
+/// Replace the highlight markers in a snippet with emphasized terms
+pub fn render_snippet(snippet: &str, output: &OutputHandler) -> String {
+    let mut rendered = String::new();
+    let mut rest = snippet;
+
+    while let Some(start) = rest.find(HIGHLIGHT_START) {
+        rendered.push_str(&rest[..start]);
+        let after = &rest[start + HIGHLIGHT_START.len()..];
+        let Some(end) = after.find(HIGHLIGHT_END) else {
+            rest = after;
+            break;
+        };
+        rendered.push_str(&output.highlight(&after[..end]));
+        rest = &after[end + HIGHLIGHT_END.len()..];
+    }
+    rendered.push_str(rest);
+
+    rendered.split_whitespace().collect::<Vec<_>>().join(" ")
 }
 
 /// Display brief V3 record information
-fn display_record_brief(record: &LearningRecordV3, index: usize) {
+fn display_record_brief(out: &mut String, record: &LearningRecordV3, index: usize) -> fmt::Result {
     use comfy_table::{modifiers::UTF8_ROUND_CORNERS, presets::UTF8_FULL, Cell, Color, Table};
 
-    println!("\n{} Record #{}", "📄".cyan(), index);
+    writeln!(out, "\n{} Record #{}", "📄".cyan(), index)?;
 
     let mut table = Table::new();
     table
@@ -146,12 +275,12 @@ fn display_record_brief(record: &LearningRecordV3, index: usize) {
         table.add_row(vec!["Duration", &format!("{} min", duration)]);
     }
 
-    println!("{}", table);
+    writeln!(out, "{}", table)?;
 
     // Display first few lines of markdown body
     let lines: Vec<&str> = record.markdown_body.lines().take(3).collect();
     if !lines.is_empty() {
-        println!("\n  {} Preview:", "👁️".yellow());
+        writeln!(out, "\n  {} Preview:", "👁️".yellow())?;
         for line in lines {
             let truncated = if line.chars().count() > 70 {
                 let t: String = line.chars().take(67).collect();
@@ -159,13 +288,31 @@ fn display_record_brief(record: &LearningRecordV3, index: usize) {
             } else {
                 line.to_string()
             };
-            println!("    {}", truncated);
+            writeln!(out, "    {}", truncated)?;
         }
     }
+
+    Ok(())
 }
 
 #[cfg(test)]
 mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_snippet_strips_markers() {
+        let output = OutputHandler::new(false, false, true);
+        let snippet = format!(
+            "…invalidate the\n{}tokenstore{} after {}logout{}",
+            HIGHLIGHT_START, HIGHLIGHT_END, HIGHLIGHT_START, HIGHLIGHT_END
+        );
+
+        assert_eq!(
+            render_snippet(&snippet, &output),
+            "…invalidate the tokenstore after logout"
+        );
+    }
+
     #[test]
     fn test_query_matching() {
         let query = "test".to_lowercase();
@@ -0,0 +1,110 @@
+//! List the files most often involved in learning records
+//!
+//! The CSV format is written to stdout without any decoration so it can be
+//! redirected straight into a spreadsheet.
+
+use crate::cli::args::LrHotspotsFormat;
+use crate::core::learning_db::{Hotspot, LearningDatabase};
+use crate::core::{LearningSession, Result};
+use colored::Colorize;
+use comfy_table::{modifiers::UTF8_ROUND_CORNERS, presets::UTF8_FULL, Table};
+
+/// Column header of the CSV output
+pub const CSV_HEADER: &str = "file_path,session_count,avg_hotspot_score,last_accessed";
+
+/// Handle learning record hotspots command
+///
+/// # Arguments
+///
+/// * `format` - Output format (table or CSV)
+/// * `limit` - Maximum number of hotspots to list
+pub fn handle_hotspots(format: LrHotspotsFormat, limit: usize) -> Result<()> {
+    let records_dir = LearningSession::records_dir()?;
+
+    let db = LearningDatabase::new(records_dir.join("learning.db"), records_dir)?;
+    let hotspots = db.get_hotspots(limit)?;
+
+    match format {
+        LrHotspotsFormat::Csv => print!("{}", render_csv(&hotspots)),
+        LrHotspotsFormat::Table => {
+            println!("{}", "🔥 File Hotspots".cyan().bold());
+            if hotspots.is_empty() {
+                println!("{}", "\n✅ No hotspots recorded yet".green());
+                return Ok(());
+            }
+
+            let mut table = Table::new();
+            table
+                .load_preset(UTF8_FULL)
+                .apply_modifier(UTF8_ROUND_CORNERS)
+                .set_header(vec!["File", "Sessions", "Avg Score", "Last Accessed"]);
+            for hotspot in &hotspots {
+                table.add_row(vec![
+                    hotspot.file_path.clone(),
+                    hotspot.session_count.to_string(),
+                    format!("{:.2}", hotspot.avg_hotspot_score),
+                    hotspot.last_accessed.clone(),
+                ]);
+            }
+            println!("{}", table);
+        }
+    }
+
+    Ok(())
+}
+
+/// Render hotspots as CSV with a header row
+pub fn render_csv(hotspots: &[Hotspot]) -> String {
+    let mut csv = format!("{}\n", CSV_HEADER);
+    for hotspot in hotspots {
+        csv.push_str(&format!(
+            "{},{},{:.2},{}\n",
+            csv_field(&hotspot.file_path),
+            hotspot.session_count,
+            hotspot.avg_hotspot_score,
+            csv_field(&hotspot.last_accessed)
+        ));
+    }
+    csv
+}
+
+/// Quote a CSV field per RFC 4180 when it contains a delimiter, quote or newline
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hotspot(file_path: &str) -> Hotspot {
+        Hotspot {
+            file_path: file_path.to_string(),
+            session_count: 3,
+            avg_hotspot_score: 7.5,
+            last_accessed: "2024-06-01T10:00:00Z".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_render_csv_has_header() {
+        let csv = render_csv(&[hotspot("src/main.rs")]);
+        let mut lines = csv.lines();
+        assert_eq!(lines.next(), Some(CSV_HEADER));
+        assert_eq!(
+            lines.next(),
+            Some("src/main.rs,3,7.50,2024-06-01T10:00:00Z")
+        );
+        assert_eq!(render_csv(&[]), format!("{}\n", CSV_HEADER));
+    }
+
+    #[test]
+    fn test_render_csv_quotes_paths_with_commas() {
+        let csv = render_csv(&[hotspot("docs/a,b \"draft\".md")]);
+        assert!(csv.contains("\n\"docs/a,b \"\"draft\"\".md\",3,7.50,"));
+    }
+}
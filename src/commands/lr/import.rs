@@ -0,0 +1,419 @@
+//! Import learning records exported by another cldev installation
+//!
+//! A source is either a directory of Markdown records, a single Markdown
+//! record, or a JSON file holding an array of records. Each record must parse
+//! as a [`LearningRecordV2`] or [`LearningRecordV3`]; V2 records are copied
+//! into the indexed records directory and V3 records into the V3 directory.
+//! Secrets in the imported notes are redacted as in `lr new`.
+
+use super::new::{list_redactions, print_dry_run_notice};
+use crate::core::config::Config;
+use crate::core::learning_db::LearningDatabase;
+use crate::core::learning_record_v2::LearningRecordV2;
+use crate::core::{sanitize_with_spans, CldevError, LearningRecordV3, LearningSession, Result};
+use colored::Colorize;
+use regex::Regex;
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Notes body written for V2 records imported from JSON
+const DEFAULT_NOTES: &str = "# Session Notes\n\nAdd your notes here...\n";
+
+/// Outcome of an import
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ImportReport {
+    /// IDs of the records written, after any renaming
+    pub imported: Vec<String>,
+    /// `(original ID, new ID)` for records renamed because the ID was taken
+    pub renamed: Vec<(String, String)>,
+    /// `(source, reason)` for records that were not imported
+    pub skipped: Vec<(String, String)>,
+    /// `(ID, text to sanitize)` for each record a dry run did not write
+    pub unwritten: Vec<(String, String)>,
+}
+
+/// A record that passed schema validation
+enum ParsedRecord {
+    V2 {
+        record: Box<LearningRecordV2>,
+        body: String,
+    },
+    V3(LearningRecordV3),
+}
+
+impl ParsedRecord {
+    fn id(&self) -> &str {
+        match self {
+            ParsedRecord::V2 { record, .. } => &record.session_meta.id,
+            ParsedRecord::V3(record) => &record.id,
+        }
+    }
+
+    fn set_id(&mut self, id: String) {
+        match self {
+            ParsedRecord::V2 { record, .. } => record.session_meta.id = id,
+            ParsedRecord::V3(record) => record.id = id,
+        }
+    }
+}
+
+/// Handle learning record import command
+///
+/// # Arguments
+///
+/// * `path` - Directory of records, a single record, or a JSON array of records
+/// * `dry_run` - Show what would be imported and redacted without writing anything
+pub fn handle_import(path: &Path, dry_run: bool) -> Result<()> {
+    println!("{}", "📥 Importing learning records...".cyan().bold());
+
+    let v2_dir = LearningSession::records_dir()?;
+    let v3_dir = LearningRecordV3::records_dir()?;
+
+    // Values matching `lr.sanitizer_allowlist` are never redacted
+    let allowlist = Config::load(None)
+        .map(|config| config.lr.clone())
+        .unwrap_or_default()
+        .sanitizer_allowlist_patterns()?;
+
+    let report = import_records(path, &v2_dir, &v3_dir, &allowlist, dry_run)?;
+
+    if dry_run {
+        print_dry_run_notice();
+        for (id, text) in &report.unwritten {
+            println!("\n{} {}", "📄".cyan(), id.yellow());
+            list_redactions(text, &allowlist);
+        }
+    }
+
+    let verb = if dry_run { "Would import" } else { "Imported" };
+    println!(
+        "\n{} {}: {}  Renamed: {}  Skipped: {}",
+        "📊".cyan(),
+        verb,
+        report.imported.len().to_string().green(),
+        report.renamed.len().to_string().yellow(),
+        report.skipped.len().to_string().red()
+    );
+    for (from, to) in &report.renamed {
+        println!("  {} {} → {}", "↪".yellow(), from, to.yellow());
+    }
+    for (source, reason) in &report.skipped {
+        println!("  {} {}: {}", "✗".red(), source, reason.dimmed());
+    }
+
+    Ok(())
+}
+
+/// Import records from `source` into the V2 and V3 record directories
+///
+/// Records whose ID already exists, or repeats within the import, get a fresh
+/// `-2`, `-3`, ... suffix. Secrets not matching `allowlist` are redacted from
+/// the notes of V2 records and from the whole of V3 records; the YAML fields
+/// of V2 records are kept as-is. The learning database is reindexed when any
+/// V2 record is imported. With `dry_run`, nothing is written and the text
+/// that would be sanitized is returned in [`ImportReport::unwritten`].
+pub fn import_records(
+    source: &Path,
+    v2_dir: &Path,
+    v3_dir: &Path,
+    allowlist: &[Regex],
+    dry_run: bool,
+) -> Result<ImportReport> {
+    if !source.exists() {
+        return Err(CldevError::config(format!(
+            "Import source not found: {}",
+            source.display()
+        )));
+    }
+
+    let mut report = ImportReport::default();
+    let mut taken = HashSet::new();
+    let mut imported_v2 = false;
+
+    for (label, parsed) in read_source(source)? {
+        let mut record = match parsed {
+            Ok(record) => record,
+            Err(reason) => {
+                report.skipped.push((label, reason));
+                continue;
+            }
+        };
+
+        let original = record.id().to_string();
+        if !is_safe_id(&original) {
+            report
+                .skipped
+                .push((label, format!("unsafe record ID \"{}\"", original)));
+            continue;
+        }
+
+        let dir = match record {
+            ParsedRecord::V2 { .. } => v2_dir,
+            ParsedRecord::V3(_) => v3_dir,
+        };
+        let id = fresh_id(&original, dir, &taken);
+        if id != original {
+            record.set_id(id.clone());
+            report.renamed.push((original, id.clone()));
+        }
+
+        let (sanitize, content) = match &record {
+            ParsedRecord::V2 { record, body } => {
+                let yaml = serde_yaml::to_string(record.as_ref()).map_err(|e| {
+                    CldevError::config(format!("Failed to serialize record: {}", e))
+                })?;
+                let (sanitized, _) = sanitize_with_spans(body, allowlist);
+                (body.clone(), format!("---\n{}---\n\n{}", yaml, sanitized))
+            }
+            ParsedRecord::V3(record) => {
+                let content = record.to_markdown_file();
+                (content.clone(), sanitize_with_spans(&content, allowlist).0)
+            }
+        };
+
+        taken.insert(id.clone());
+        report.imported.push(id.clone());
+        if dry_run {
+            report.unwritten.push((id, sanitize));
+            continue;
+        }
+
+        fs::create_dir_all(dir)?;
+        fs::write(dir.join(format!("{}.md", id)), content)?;
+        imported_v2 |= matches!(record, ParsedRecord::V2 { .. });
+    }
+
+    if imported_v2 {
+        let mut db = LearningDatabase::new(v2_dir.join("learning.db"), v2_dir.to_path_buf())?;
+        db.build_from_markdown()?;
+    }
+
+    Ok(report)
+}
+
+/// Read every candidate record from a source path
+///
+/// Each entry is labelled with the file (and array index for JSON) it came
+/// from, so skipped records can be reported.
+fn read_source(source: &Path) -> Result<Vec<(String, std::result::Result<ParsedRecord, String>)>> {
+    let label = |path: &Path| path.display().to_string();
+
+    if source.is_dir() {
+        let mut paths: Vec<PathBuf> = fs::read_dir(source)?
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| {
+                path.is_file() && path.extension().and_then(|e| e.to_str()) == Some("md")
+            })
+            .collect();
+        paths.sort();
+
+        return paths
+            .into_iter()
+            .map(|path| Ok((label(&path), parse_markdown(&fs::read_to_string(&path)?))))
+            .collect();
+    }
+
+    let content = fs::read_to_string(source)?;
+    if source.extension().and_then(|e| e.to_str()) != Some("json") {
+        return Ok(vec![(label(source), parse_markdown(&content))]);
+    }
+
+    let values: Vec<serde_json::Value> = serde_json::from_str(&content).map_err(|e| {
+        CldevError::config(format!(
+            "Expected a JSON array of records in {}: {}",
+            source.display(),
+            e
+        ))
+    })?;
+    Ok(values
+        .into_iter()
+        .enumerate()
+        .map(|(i, value)| (format!("{}[{}]", label(source), i), parse_json(value)))
+        .collect())
+}
+
+/// Validate a Markdown record against the V2 and then the V3 schema
+fn parse_markdown(content: &str) -> std::result::Result<ParsedRecord, String> {
+    match LearningRecordV2::parse_markdown(content) {
+        Ok(record) => Ok(ParsedRecord::V2 {
+            record: Box::new(record),
+            body: content
+                .splitn(3, "---\n")
+                .nth(2)
+                .unwrap_or_default()
+                .trim_start()
+                .to_string(),
+        }),
+        Err(v2_error) => LearningRecordV3::from_markdown_file(content)
+            .map(ParsedRecord::V3)
+            .map_err(|_| format!("not a V2 or V3 learning record ({})", v2_error)),
+    }
+}
+
+/// Validate a JSON record against the V2 and then the V3 schema
+fn parse_json(value: serde_json::Value) -> std::result::Result<ParsedRecord, String> {
+    match serde_json::from_value::<LearningRecordV2>(value.clone()) {
+        Ok(record) => Ok(ParsedRecord::V2 {
+            record: Box::new(record),
+            body: DEFAULT_NOTES.to_string(),
+        }),
+        Err(v2_error) => serde_json::from_value::<LearningRecordV3>(value)
+            .map(ParsedRecord::V3)
+            .map_err(|_| format!("not a V2 or V3 learning record ({})", v2_error)),
+    }
+}
+
+/// Whether a record ID is safe to use as a file name
+pub(crate) fn is_safe_id(id: &str) -> bool {
+    !id.is_empty()
+        && !id.starts_with('.')
+        && id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+}
+
+/// `id`, or the first `id-N` that is neither on disk in `dir` nor in `taken`
+fn fresh_id(id: &str, dir: &Path, taken: &HashSet<String>) -> String {
+    let is_free = |candidate: &str| {
+        !taken.contains(candidate) && !dir.join(format!("{}.md", candidate)).exists()
+    };
+
+    if is_free(id) {
+        return id.to_string();
+    }
+    (2..)
+        .map(|n| format!("{}-{}", id, n))
+        .find(|candidate| is_free(candidate))
+        .unwrap_or_else(|| id.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::learning_record_v2::*;
+    use tempfile::TempDir;
+
+    fn record_markdown(id: &str, title: &str) -> String {
+        let mut record = LearningRecordBuilder::new(
+            SessionType::Fix,
+            Priority::High,
+            title,
+            "Imported from a teammate",
+            Severity::Error,
+        )
+        .tag("import")
+        .build();
+        record.session_meta.id = id.to_string();
+
+        format!(
+            "---\n{}---\n\n# Session Notes\n\nShared notes\n",
+            serde_yaml::to_string(&record).unwrap()
+        )
+    }
+
+    #[test]
+    fn test_import_skips_malformed_record() {
+        let temp_dir = TempDir::new().unwrap();
+        let source = temp_dir.path().join("export");
+        let v2_dir = temp_dir.path().join("v2");
+        let v3_dir = temp_dir.path().join("v3");
+        fs::create_dir_all(&source).unwrap();
+
+        fs::write(
+            source.join("a.md"),
+            record_markdown("fix-pool", "Pool timeout"),
+        )
+        .unwrap();
+        fs::write(
+            source.join("b.md"),
+            record_markdown("fix-cache", "Cache storm"),
+        )
+        .unwrap();
+        fs::write(source.join("c.md"), "# Just notes\n\nNo frontmatter here\n").unwrap();
+
+        let report = import_records(&source, &v2_dir, &v3_dir, &[], false).unwrap();
+        assert_eq!(report.imported, vec!["fix-pool", "fix-cache"]);
+        assert!(report.renamed.is_empty());
+        assert_eq!(report.skipped.len(), 1);
+        assert!(report.skipped[0].0.ends_with("c.md"));
+        assert!(report.skipped[0].1.contains("missing YAML frontmatter"));
+
+        let notes = fs::read_to_string(v2_dir.join("fix-pool.md")).unwrap();
+        assert!(notes.ends_with("# Session Notes\n\nShared notes\n"));
+
+        let db = LearningDatabase::new(v2_dir.join("learning.db"), v2_dir.clone()).unwrap();
+        assert_eq!(db.get_all_sessions().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_import_renames_colliding_ids() {
+        let temp_dir = TempDir::new().unwrap();
+        let v2_dir = temp_dir.path().join("v2");
+        let v3_dir = temp_dir.path().join("v3");
+        fs::create_dir_all(&v2_dir).unwrap();
+        fs::write(
+            v2_dir.join("fix-pool.md"),
+            record_markdown("fix-pool", "Existing"),
+        )
+        .unwrap();
+
+        let single = temp_dir.path().join("fix-pool.md");
+        fs::write(&single, record_markdown("fix-pool", "Pool timeout")).unwrap();
+
+        let report = import_records(&single, &v2_dir, &v3_dir, &[], false).unwrap();
+        assert_eq!(
+            report.renamed,
+            vec![("fix-pool".to_string(), "fix-pool-2".to_string())]
+        );
+
+        let imported = LearningRecordV2::parse_markdown(
+            &fs::read_to_string(v2_dir.join("fix-pool-2.md")).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(imported.session_meta.id, "fix-pool-2");
+        assert_eq!(imported.problem.title, "Pool timeout");
+    }
+
+    #[test]
+    fn test_import_json_array() {
+        let temp_dir = TempDir::new().unwrap();
+        let v2_dir = temp_dir.path().join("v2");
+        let v3_dir = temp_dir.path().join("v3");
+
+        let mut v3 = LearningRecordV3::new("auto-1".to_string(), "# Notes".to_string());
+        v3.tags = vec!["import".to_string()];
+        let records = serde_json::json!([v3, v3, { "id": "../escape" }]);
+        let source = temp_dir.path().join("records.json");
+        fs::write(&source, records.to_string()).unwrap();
+
+        let report = import_records(&source, &v2_dir, &v3_dir, &[], false).unwrap();
+        assert_eq!(report.imported, vec!["auto-1", "auto-1-2"]);
+        assert_eq!(report.skipped.len(), 1);
+        assert!(report.skipped[0].0.ends_with("records.json[2]"));
+        assert!(v3_dir.join("auto-1-2.md").exists());
+    }
+
+    #[test]
+    fn test_import_redacts_notes_and_dry_run_writes_nothing() {
+        let temp_dir = TempDir::new().unwrap();
+        let v2_dir = temp_dir.path().join("v2");
+        let v3_dir = temp_dir.path().join("v3");
+        let source = temp_dir.path().join("fix-pool.md");
+        let markdown = record_markdown("fix-pool", "Pool timeout")
+            .replace("Shared notes", "Ask ops@example.com");
+        fs::write(&source, &markdown).unwrap();
+
+        let dry = import_records(&source, &v2_dir, &v3_dir, &[], true).unwrap();
+        assert_eq!(dry.imported, vec!["fix-pool"]);
+        assert_eq!(dry.unwritten.len(), 1);
+        assert!(dry.unwritten[0].1.contains("ops@example.com"));
+        assert!(!v2_dir.exists());
+
+        let report = import_records(&source, &v2_dir, &v3_dir, &[], false).unwrap();
+        assert!(report.unwritten.is_empty());
+        let notes = fs::read_to_string(v2_dir.join("fix-pool.md")).unwrap();
+        assert!(notes.ends_with("# Session Notes\n\nAsk [REDACTED_EMAIL]\n"));
+    }
+}
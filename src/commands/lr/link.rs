@@ -0,0 +1,399 @@
+use super::import::is_safe_id;
+use super::new::preview_redactions;
+use crate::cli::output::{OutputHandler, ProgressHandle};
+use crate::core::learning_db::{LearningDatabase, QueryResult};
+use crate::core::learning_record_v2::LearningRecordV2;
+use crate::core::{sanitize_with_spans, CldevError, Config, LearningSession, Result};
+use colored::Colorize;
+use regex::Regex;
+use std::fs;
+use std::path::PathBuf;
+
+/// Heading of the generated cross-link section
+const RELATED_HEADING: &str = "## Related";
+
+/// Add a "Related" section linking a record to its most similar records
+///
+/// Re-running the command replaces the existing section instead of appending
+/// a new one, and the record is reindexed after its markdown is updated.
+/// Under `--dry-run`, the section is previewed with its redactions and the
+/// record is left untouched.
+///
+/// # Arguments
+///
+/// * `id` - ID of the learning record to link
+/// * `limit` - Maximum number of related records to link
+/// * `output` - Output handler used for the indexing progress bar and dry run
+pub fn handle_link(id: &str, limit: usize, output: &OutputHandler) -> Result<()> {
+    println!("{}", "🔗 Linking related learning records...".cyan().bold());
+    println!("Session ID: {}", id.green());
+    println!();
+
+    let records_dir = LearningSession::records_dir()?;
+
+    // Values matching `lr.sanitizer_allowlist` are never redacted
+    let allowlist = Config::load(None)
+        .map(|config| config.lr.clone())
+        .unwrap_or_default()
+        .sanitizer_allowlist_patterns()?;
+
+    let mut db = LearningDatabase::new(records_dir.join("learning.db"), records_dir)?;
+    let progress = output.progress(0);
+    let related = link_record(
+        &mut db,
+        id,
+        limit,
+        &allowlist,
+        output.is_dry_run(),
+        &progress,
+    );
+    progress.finish();
+    let related = related?;
+
+    if related.is_empty() {
+        println!("{}", "No similar records found".yellow());
+        return Ok(());
+    }
+
+    if output.is_dry_run() {
+        let section = related_section(&links(&related));
+        println!("{}", section.trim_end());
+        preview_redactions(&section, &allowlist);
+        return Ok(());
+    }
+
+    println!(
+        "{}",
+        format!("✅ Linked {} related record(s)", related.len())
+            .green()
+            .bold()
+    );
+    for result in &related {
+        println!(
+            "  {} {}",
+            result.session.id.yellow(),
+            result.session.title.dimmed()
+        );
+    }
+
+    Ok(())
+}
+
+/// Write the related-records section into a record's markdown and reindex it
+///
+/// Returns the linked records. The record is looked up in the index, so
+/// records migrated to V3 are linked in their V3 file. The markdown is left
+/// untouched when no similar records are found. Related records whose ID is
+/// not a valid file name are skipped. Secrets in their titles not matching
+/// `allowlist` are redacted. With `dry_run`, the markdown is not written.
+/// `progress` tracks indexing of the records.
+pub fn link_record(
+    db: &mut LearningDatabase,
+    id: &str,
+    limit: usize,
+    allowlist: &[Regex],
+    dry_run: bool,
+    progress: &ProgressHandle,
+) -> Result<Vec<QueryResult>> {
+    if !is_safe_id(id) {
+        return Err(CldevError::validation(format!(
+            "Invalid learning record ID: {}",
+            id
+        )));
+    }
+
+    // Populates the in-memory TF-IDF index used by `most_similar`
+    db.build_from_markdown_with_progress(|done, total| {
+        progress.set_length(total as u64);
+        progress.set_position(done as u64);
+    })?;
+
+    let path = db
+        .get_session(id)?
+        .map(|session| PathBuf::from(session.markdown_path))
+        .filter(|path| path.exists())
+        .ok_or_else(|| CldevError::config(format!("Learning record not found: {}", id)))?;
+
+    let related: Vec<QueryResult> = db
+        .most_similar(id, limit)?
+        .into_iter()
+        .filter(|r| is_safe_id(&r.session.id))
+        .collect();
+    if related.is_empty() || dry_run {
+        return Ok(related);
+    }
+
+    let titles: Vec<String> = related
+        .iter()
+        .map(|r| sanitize_with_spans(&r.session.title, allowlist).0)
+        .collect();
+    let links: Vec<(&str, &str)> = related
+        .iter()
+        .zip(&titles)
+        .map(|(r, title)| (r.session.id.as_str(), title.as_str()))
+        .collect();
+
+    let content = fs::read_to_string(&path)?;
+    fs::write(&path, upsert_related_section(&content, &links))?;
+
+    // V3 records keep the index entry written when they were migrated
+    let content = fs::read_to_string(&path)?;
+    if let Ok(record) = LearningRecordV2::parse_markdown(&content) {
+        db.upsert_session(&record, path.to_string_lossy().to_string())?;
+    }
+
+    Ok(related)
+}
+
+/// `(id, title)` pairs of the related records
+fn links(related: &[QueryResult]) -> Vec<(&str, &str)> {
+    related
+        .iter()
+        .map(|r| (r.session.id.as_str(), r.session.title.as_str()))
+        .collect()
+}
+
+/// Render the related-records section
+///
+/// `links` are `(id, title)` pairs; titles are escaped so that they cannot
+/// end the link text early.
+fn related_section(links: &[(&str, &str)]) -> String {
+    let mut section = format!("{}\n\n", RELATED_HEADING);
+    for (id, title) in links {
+        section.push_str(&format!("- [{}]({}.md)\n", escape_link_text(title), id));
+    }
+    section
+}
+
+/// Insert or replace the related-records section in markdown content
+///
+/// The section runs from its heading up to the next heading of the same or
+/// higher level, or the end of the document.
+pub fn upsert_related_section(content: &str, links: &[(&str, &str)]) -> String {
+    let section = related_section(links);
+
+    let lines: Vec<&str> = content.lines().collect();
+    let Some(start) = lines.iter().position(|l| l.trim_end() == RELATED_HEADING) else {
+        return format!("{}\n\n{}", content.trim_end(), section);
+    };
+
+    let end = lines[start + 1..]
+        .iter()
+        .position(|l| l.starts_with("# ") || l.starts_with("## "))
+        .map_or(lines.len(), |offset| start + 1 + offset);
+
+    let before = lines[..start].join("\n");
+    let after = lines[end..].join("\n");
+
+    let mut updated = format!("{}\n\n{}", before.trim_end(), section);
+    if !after.is_empty() {
+        updated.push('\n');
+        updated.push_str(&after);
+        updated.push('\n');
+    }
+    updated
+}
+
+/// Escape markdown link text so that brackets and backslashes stay literal
+fn escape_link_text(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' | '[' | ']' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            '\n' | '\r' => escaped.push(' '),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::learning_record_v2::*;
+    use std::path::Path;
+    use tempfile::TempDir;
+
+    fn write_record(dir: &Path, id: &str, title: &str, description: &str, tag: &str) {
+        let mut record = LearningRecordBuilder::new(
+            SessionType::Debug,
+            Priority::Medium,
+            title,
+            description,
+            Severity::Error,
+        )
+        .tag(tag)
+        .build();
+        record.session_meta.id = id.to_string();
+
+        let yaml = serde_yaml::to_string(&record).unwrap();
+        let content = format!(
+            "---\n{}---\n\n# Session Notes\n\nAdd your notes here...\n",
+            yaml
+        );
+        fs::write(dir.join(format!("{}.md", id)), content).unwrap();
+    }
+
+    #[test]
+    fn test_link_record_inserts_related_section() {
+        let temp_dir = TempDir::new().unwrap();
+        let records_dir = temp_dir.path().to_path_buf();
+
+        write_record(
+            &records_dir,
+            "pool-timeout",
+            "Database connection pool timeout",
+            "Requests hang waiting for a pooled connection",
+            "database",
+        );
+        write_record(
+            &records_dir,
+            "pool-exhausted",
+            "Connection pool exhausted under load",
+            "Database pool runs out of connections",
+            "database",
+        );
+        write_record(
+            &records_dir,
+            "css-layout",
+            "Sidebar layout breaks on mobile",
+            "Flexbox wrapping pushes the sidebar offscreen",
+            "css",
+        );
+
+        let mut db =
+            LearningDatabase::new(records_dir.join("learning.db"), records_dir.clone()).unwrap();
+
+        let related = link_record(
+            &mut db,
+            "pool-timeout",
+            5,
+            &[],
+            false,
+            &ProgressHandle::hidden(),
+        )
+        .unwrap();
+        assert_eq!(related.len(), 1);
+        assert_eq!(related[0].session.id, "pool-exhausted");
+
+        let content = fs::read_to_string(records_dir.join("pool-timeout.md")).unwrap();
+        assert!(content.contains(
+            "## Related\n\n- [Connection pool exhausted under load](pool-exhausted.md)\n"
+        ));
+        assert!(!content.contains("css-layout.md"));
+        assert!(content.contains("# Session Notes"));
+
+        // Re-running replaces the section instead of appending another one
+        link_record(
+            &mut db,
+            "pool-timeout",
+            5,
+            &[],
+            false,
+            &ProgressHandle::hidden(),
+        )
+        .unwrap();
+        let rerun = fs::read_to_string(records_dir.join("pool-timeout.md")).unwrap();
+        assert_eq!(rerun.matches(RELATED_HEADING).count(), 1);
+        assert_eq!(rerun, content);
+    }
+
+    #[test]
+    fn test_link_record_dry_run_leaves_record_untouched() {
+        let temp_dir = TempDir::new().unwrap();
+        let records_dir = temp_dir.path().to_path_buf();
+        write_record(
+            &records_dir,
+            "pool-timeout",
+            "Database connection pool timeout",
+            "Requests hang waiting for a pooled connection",
+            "database",
+        );
+        write_record(
+            &records_dir,
+            "pool-exhausted",
+            "Connection pool exhausted for ops@example.com",
+            "Database pool runs out of connections",
+            "database",
+        );
+        write_record(
+            &records_dir,
+            "css-layout",
+            "Sidebar layout breaks on mobile",
+            "Flexbox wrapping pushes the sidebar offscreen",
+            "css",
+        );
+        let before = fs::read_to_string(records_dir.join("pool-timeout.md")).unwrap();
+
+        let mut db =
+            LearningDatabase::new(records_dir.join("learning.db"), records_dir.clone()).unwrap();
+        let related = link_record(
+            &mut db,
+            "pool-timeout",
+            5,
+            &[],
+            true,
+            &ProgressHandle::hidden(),
+        )
+        .unwrap();
+        assert_eq!(related.len(), 1);
+        assert_eq!(
+            fs::read_to_string(records_dir.join("pool-timeout.md")).unwrap(),
+            before
+        );
+
+        link_record(
+            &mut db,
+            "pool-timeout",
+            5,
+            &[],
+            false,
+            &ProgressHandle::hidden(),
+        )
+        .unwrap();
+        let linked = fs::read_to_string(records_dir.join("pool-timeout.md")).unwrap();
+        assert!(linked.contains("exhausted for \\[REDACTED_EMAIL\\]](pool-exhausted.md)"));
+        assert!(!linked.contains("ops@example.com"));
+    }
+
+    #[test]
+    fn test_link_record_missing() {
+        let temp_dir = TempDir::new().unwrap();
+        let records_dir = temp_dir.path().to_path_buf();
+        let mut db =
+            LearningDatabase::new(records_dir.join("learning.db"), records_dir.clone()).unwrap();
+
+        assert!(link_record(&mut db, "missing", 5, &[], false, &ProgressHandle::hidden()).is_err());
+
+        let err = link_record(
+            &mut db,
+            "../escape",
+            5,
+            &[],
+            false,
+            &ProgressHandle::hidden(),
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("Invalid learning record ID"));
+    }
+
+    #[test]
+    fn test_upsert_related_section_escapes_titles() {
+        let updated = upsert_related_section("# Notes\n", &[("fix", "Fix [x](evil) \\ ok")]);
+        assert!(updated.contains("- [Fix \\[x\\](evil) \\\\ ok](fix.md)\n"));
+    }
+
+    #[test]
+    fn test_upsert_related_section_keeps_following_sections() {
+        let content = "# Notes\n\nText\n\n## Related\n\n- [Old](old.md)\n\n## Follow-up\n\nTodo\n";
+        let updated = upsert_related_section(content, &[("new", "New")]);
+
+        assert_eq!(
+            updated,
+            "# Notes\n\nText\n\n## Related\n\n- [New](new.md)\n\n## Follow-up\n\nTodo\n"
+        );
+    }
+}
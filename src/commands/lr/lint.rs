@@ -0,0 +1,210 @@
+use crate::core::{LearningSession, Result};
+use colored::Colorize;
+
+/// Descriptions shorter than this are flagged as too brief to be useful
+const MIN_DESCRIPTION_LEN: usize = 20;
+
+/// A quality problem found in a learning record
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LintIssue {
+    MissingRootCause,
+    MissingSolution,
+    MissingTags,
+    MissingFiles,
+    ShortDescription,
+}
+
+impl LintIssue {
+    /// Points deducted from the record score
+    fn penalty(&self) -> u8 {
+        match self {
+            Self::MissingRootCause | Self::MissingSolution => 25,
+            Self::ShortDescription => 20,
+            Self::MissingTags | Self::MissingFiles => 15,
+        }
+    }
+
+    /// Human-readable description of the issue
+    fn message(&self) -> &'static str {
+        match self {
+            Self::MissingRootCause => "missing root cause",
+            Self::MissingSolution => "missing solution",
+            Self::MissingTags => "no tags",
+            Self::MissingFiles => "no affected files",
+            Self::ShortDescription => "description is too short",
+        }
+    }
+}
+
+/// Lint result for a single learning record
+#[derive(Debug, Clone)]
+pub struct RecordLint {
+    pub id: String,
+    pub score: u8,
+    pub issues: Vec<LintIssue>,
+}
+
+impl RecordLint {
+    /// Check whether the record has no quality issues
+    pub fn passed(&self) -> bool {
+        self.issues.is_empty()
+    }
+}
+
+/// Handle learning record lint command
+///
+/// Loads every record through the regular Markdown parser, scores each one
+/// from 0 to 100, and lists the issues that lowered the score.
+pub fn handle_lint() -> Result<()> {
+    println!("{}", "🔍 Linting Learning Records...".cyan().bold());
+
+    let session_ids = LearningSession::list_all()?;
+
+    if session_ids.is_empty() {
+        println!("{}", "\n⚠️  No learning records found".yellow());
+        return Ok(());
+    }
+
+    let mut results = Vec::new();
+    let mut unreadable = Vec::new();
+
+    for id in session_ids {
+        match LearningSession::load(&id) {
+            Ok(session) => results.push(lint_session(&session)),
+            Err(e) => unreadable.push((id, e)),
+        }
+    }
+
+    display_report(&results);
+
+    if !unreadable.is_empty() {
+        println!("\n{}", "❌ Unreadable Records:".red().bold());
+        for (id, e) in &unreadable {
+            println!("  {} {}", id.yellow(), e.to_string().dimmed());
+        }
+    }
+
+    Ok(())
+}
+
+/// Score a single learning record
+pub fn lint_session(session: &LearningSession) -> RecordLint {
+    let is_blank = |value: &Option<String>| value.as_deref().is_none_or(|v| v.trim().is_empty());
+
+    let mut issues = Vec::new();
+    if is_blank(&session.root_cause) {
+        issues.push(LintIssue::MissingRootCause);
+    }
+    if is_blank(&session.solution) {
+        issues.push(LintIssue::MissingSolution);
+    }
+    if session.tags.is_empty() {
+        issues.push(LintIssue::MissingTags);
+    }
+    if session.files_affected.is_empty() {
+        issues.push(LintIssue::MissingFiles);
+    }
+    if session.description.trim().chars().count() < MIN_DESCRIPTION_LEN {
+        issues.push(LintIssue::ShortDescription);
+    }
+
+    let penalty: u8 = issues.iter().map(LintIssue::penalty).sum();
+
+    RecordLint {
+        id: session.id.clone(),
+        score: 100u8.saturating_sub(penalty),
+        issues,
+    }
+}
+
+/// Display the per-record scores and a summary
+fn display_report(results: &[RecordLint]) {
+    println!("\n{}", "📋 Record Quality:".cyan().bold());
+
+    for result in results {
+        let score = format!("{:>3}", result.score);
+        let score = if result.passed() {
+            score.green()
+        } else if result.score >= 50 {
+            score.yellow()
+        } else {
+            score.red()
+        };
+
+        println!("  {} {}", score, result.id);
+        for issue in &result.issues {
+            println!("      {} {}", "•".dimmed(), issue.message());
+        }
+    }
+
+    let passed = results.iter().filter(|r| r.passed()).count();
+    let average = if results.is_empty() {
+        0.0
+    } else {
+        results.iter().map(|r| r.score as f64).sum::<f64>() / results.len() as f64
+    };
+
+    println!("\n{}", "📊 Summary:".cyan().bold());
+    println!("  Records: {}", results.len());
+    println!("  Passed: {}", passed.to_string().green());
+    println!(
+        "  Flagged: {}",
+        (results.len() - passed).to_string().yellow()
+    );
+    println!("  Average score: {:.1}", average);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::LearningSessionBuilder;
+
+    #[test]
+    fn test_minimal_record_is_flagged() {
+        let session = LearningSessionBuilder::new("debug", "bug").build();
+        let result = lint_session(&session);
+
+        assert!(!result.passed());
+        assert_eq!(
+            result.issues,
+            vec![
+                LintIssue::MissingRootCause,
+                LintIssue::MissingSolution,
+                LintIssue::MissingTags,
+                LintIssue::MissingFiles,
+                LintIssue::ShortDescription,
+            ]
+        );
+        assert_eq!(result.score, 0);
+    }
+
+    #[test]
+    fn test_complete_record_passes() {
+        let session = LearningSessionBuilder::new("debug", "Login fails after token refresh")
+            .root_cause("Refresh token was not persisted")
+            .solution("Persist the refreshed token before retrying")
+            .tag("auth")
+            .file("src/auth/session.rs")
+            .build();
+        let result = lint_session(&session);
+
+        assert!(result.passed());
+        assert_eq!(result.score, 100);
+    }
+
+    #[test]
+    fn test_partial_record_score() {
+        let session = LearningSessionBuilder::new("debug", "Login fails after token refresh")
+            .root_cause("Refresh token was not persisted")
+            .solution("   ")
+            .tag("auth")
+            .build();
+        let result = lint_session(&session);
+
+        assert_eq!(
+            result.issues,
+            vec![LintIssue::MissingSolution, LintIssue::MissingFiles]
+        );
+        assert_eq!(result.score, 60);
+    }
+}
@@ -0,0 +1,266 @@
+//! Migrate learning records from the V2 to the V3 format
+//!
+//! Each V2 record is converted with [`LearningRecordV3::from_v2`] and written
+//! to the V3 records directory. The original is moved into a `v2-backup`
+//! directory, and its learning database entry is re-pointed at the V3 file so
+//! indexed commands (export, hotspots, link, explain) keep finding it.
+
+use crate::core::learning_db::LearningDatabase;
+use crate::core::{LearningRecordV2, LearningRecordV3, LearningSession, Result};
+use colored::Colorize;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Backup directory for migrated V2 records, inside the V2 records directory
+pub const BACKUP_DIR: &str = "v2-backup";
+
+/// Outcome of a migration
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct MigrationReport {
+    /// IDs of the records converted to V3
+    pub migrated: Vec<String>,
+    /// `(file, reason)` for records left in place
+    pub skipped: Vec<(String, String)>,
+}
+
+/// Handle learning record migrate command
+///
+/// # Arguments
+///
+/// * `dry_run` - Report what would be migrated without changing any files
+pub fn handle_migrate(dry_run: bool) -> Result<()> {
+    println!("{}", "🔄 Migrating learning records to V3...".cyan().bold());
+
+    let v2_dir = LearningSession::records_dir()?;
+    let v3_dir = LearningRecordV3::records_dir()?;
+
+    let report = migrate_records(&v2_dir, &v3_dir, dry_run)?;
+
+    if report.migrated.is_empty() && report.skipped.is_empty() {
+        println!("{}", "\n✅ No V2 learning records to migrate".green());
+        return Ok(());
+    }
+
+    let verb = if dry_run { "Would migrate" } else { "Migrated" };
+    println!(
+        "\n{}",
+        format!("✅ {} {} record(s)", verb, report.migrated.len()).green()
+    );
+    for id in &report.migrated {
+        println!("  {} {}", "•".dimmed(), id);
+    }
+    for (file, reason) in &report.skipped {
+        println!("  {} {}: {}", "⚠️".yellow(), file, reason.dimmed());
+    }
+    if !dry_run && !report.migrated.is_empty() {
+        println!(
+            "\n{} Originals backed up to {}",
+            "ℹ️".cyan(),
+            v2_dir.join(BACKUP_DIR).display()
+        );
+    }
+
+    Ok(())
+}
+
+/// Convert every V2 record in `v2_dir` into a V3 record in `v3_dir`
+///
+/// Records that do not parse as V2, or whose ID already exists as a V3
+/// record, are skipped and left in place.
+pub fn migrate_records(v2_dir: &Path, v3_dir: &Path, dry_run: bool) -> Result<MigrationReport> {
+    let mut report = MigrationReport::default();
+    if !v2_dir.exists() {
+        return Ok(report);
+    }
+
+    let mut paths: Vec<PathBuf> = fs::read_dir(v2_dir)?
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.is_file() && path.extension().and_then(|e| e.to_str()) == Some("md"))
+        .collect();
+    paths.sort();
+
+    let backup_dir = v2_dir.join(BACKUP_DIR);
+    let mut db = None;
+
+    for path in paths {
+        let file = path.display().to_string();
+        let content = fs::read_to_string(&path)?;
+        let v2 = match LearningRecordV2::parse_markdown(&content) {
+            Ok(v2) => v2,
+            Err(e) => {
+                report.skipped.push((file, e.to_string()));
+                continue;
+            }
+        };
+
+        let id = v2.session_meta.id.clone();
+        let target = v3_dir.join(format!("{}.md", id));
+        if target.exists() {
+            report
+                .skipped
+                .push((file, "a V3 record with this ID already exists".to_string()));
+            continue;
+        }
+
+        if !dry_run {
+            // Notes written below the frontmatter carry over after the converted fields
+            let notes = content.splitn(3, "---\n").nth(2).unwrap_or_default().trim();
+            let mut v3 = LearningRecordV3::from_v2(v2.clone());
+            if !notes.is_empty() {
+                v3.markdown_body.push_str(&format!("\n{}\n", notes));
+            }
+
+            fs::create_dir_all(v3_dir)?;
+            fs::write(&target, v3.to_markdown_file())?;
+
+            fs::create_dir_all(&backup_dir)?;
+            let file_name = path.file_name().unwrap_or_default();
+            fs::rename(&path, backup_dir.join(file_name))?;
+
+            if db.is_none() {
+                db = Some(LearningDatabase::new(
+                    v2_dir.join("learning.db"),
+                    v2_dir.to_path_buf(),
+                )?);
+            }
+            if let Some(db) = db.as_mut() {
+                db.upsert_session(&v2, target.to_string_lossy().to_string())?;
+            }
+        }
+
+        report.migrated.push(id);
+    }
+
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::lr::export::collect_records;
+    use crate::core::learning_record_v2::*;
+    use crate::core::RecordStatus;
+    use tempfile::TempDir;
+
+    fn write_v2(dir: &Path, id: &str, resolved: bool) {
+        write_v2_titled(dir, id, resolved, "Flaky test", "Test fails on CI only");
+    }
+
+    fn write_v2_titled(dir: &Path, id: &str, resolved: bool, title: &str, description: &str) {
+        let mut builder = LearningRecordBuilder::new(
+            SessionType::Debug,
+            Priority::Medium,
+            title,
+            description,
+            Severity::Warning,
+        );
+        if resolved {
+            builder = builder.resolved(15);
+        }
+        let mut record = builder
+            .files(vec![FileAffected {
+                path: "tests/flaky.rs".to_string(),
+                role: FileRole::Primary,
+                changes_summary: None,
+                hotspot_score: 0.5,
+            }])
+            .build();
+        record.session_meta.id = id.to_string();
+
+        let yaml = serde_yaml::to_string(&record).unwrap();
+        fs::write(
+            dir.join(format!("{}.md", id)),
+            format!("---\n{}---\n\n# Session Notes\n\nSeed the RNG\n", yaml),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_migrate_records_backs_up_originals() {
+        let temp_dir = TempDir::new().unwrap();
+        let v2_dir = temp_dir.path().join("v2");
+        let v3_dir = temp_dir.path().join("v3");
+        fs::create_dir_all(&v2_dir).unwrap();
+
+        write_v2(&v2_dir, "debug-a", true);
+        write_v2(&v2_dir, "debug-b", false);
+        fs::write(v2_dir.join("notes.md"), "# Not a record\n").unwrap();
+
+        let dry = migrate_records(&v2_dir, &v3_dir, true).unwrap();
+        assert_eq!(dry.migrated, vec!["debug-a", "debug-b"]);
+        assert!(!v3_dir.exists());
+
+        let report = migrate_records(&v2_dir, &v3_dir, false).unwrap();
+        assert_eq!(report.migrated, vec!["debug-a", "debug-b"]);
+        assert_eq!(report.skipped.len(), 1);
+        assert!(report.skipped[0].0.ends_with("notes.md"));
+
+        assert!(!v2_dir.join("debug-a.md").exists());
+        assert!(v2_dir.join(BACKUP_DIR).join("debug-a.md").exists());
+
+        let a = LearningRecordV3::from_markdown_file(
+            &fs::read_to_string(v3_dir.join("debug-a.md")).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(a.status, RecordStatus::Resolved);
+        assert!(a.markdown_body.contains("# Flaky test"));
+        assert!(a.markdown_body.contains("Seed the RNG"));
+
+        let b = LearningRecordV3::from_markdown_file(
+            &fs::read_to_string(v3_dir.join("debug-b.md")).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(b.status, RecordStatus::Pending);
+
+        // The index now points at the V3 files
+        let db = LearningDatabase::new(v2_dir.join("learning.db"), v2_dir.clone()).unwrap();
+        let sessions = db.get_all_sessions().unwrap();
+        assert_eq!(sessions.len(), 2);
+        assert!(sessions
+            .iter()
+            .all(|s| Path::new(&s.markdown_path).starts_with(&v3_dir)));
+    }
+
+    #[test]
+    fn test_migrated_records_stay_indexed() {
+        let temp_dir = TempDir::new().unwrap();
+        let v2_dir = temp_dir.path().join("v2");
+        let v3_dir = temp_dir.path().join("v3");
+        fs::create_dir_all(&v2_dir).unwrap();
+
+        write_v2(&v2_dir, "debug-a", true);
+        write_v2(&v2_dir, "debug-b", false);
+        write_v2_titled(
+            &v2_dir,
+            "debug-c",
+            true,
+            "Sidebar layout",
+            "Flexbox pushes the sidebar offscreen",
+        );
+        let mut db = LearningDatabase::new(v2_dir.join("learning.db"), v2_dir.clone()).unwrap();
+        db.build_from_markdown().unwrap();
+        drop(db);
+
+        migrate_records(&v2_dir, &v3_dir, false).unwrap();
+
+        let knowledge_base = collect_records(&v2_dir, &[]).unwrap();
+        let exported: Vec<_> = knowledge_base.values().flatten().collect();
+        assert_eq!(exported.len(), 3);
+        assert!(exported
+            .iter()
+            .any(|r| r.id == "debug-a" && r.title == "Flaky test"));
+        assert!(exported.iter().all(|r| r.notes.contains("Seed the RNG")));
+
+        let mut db = LearningDatabase::new(v2_dir.join("learning.db"), v2_dir.clone()).unwrap();
+        let hotspots = db.get_hotspots(10).unwrap();
+        assert_eq!(hotspots.len(), 1);
+        assert_eq!(hotspots[0].file_path, "tests/flaky.rs");
+        assert_eq!(hotspots[0].session_count, 3);
+
+        db.build_from_markdown().unwrap();
+        let similar = db.most_similar("debug-a", 5).unwrap();
+        assert_eq!(similar.len(), 1);
+        assert_eq!(similar[0].session.id, "debug-b");
+    }
+}
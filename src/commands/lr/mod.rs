@@ -1,6 +1,12 @@
 /// Learning record commands module
 pub mod check_file;
+pub mod export;
 pub mod find;
+pub mod hotspots;
+pub mod import;
+pub mod link;
+pub mod lint;
+pub mod migrate;
 pub mod new;
 pub mod problems;
 pub mod similar;
@@ -8,7 +14,13 @@ pub mod stats;
 pub mod suggest;
 
 pub use check_file::handle_check_file;
+pub use export::handle_export;
 pub use find::handle_find;
+pub use hotspots::handle_hotspots;
+pub use import::handle_import;
+pub use link::handle_link;
+pub use lint::handle_lint;
+pub use migrate::handle_migrate;
 pub use new::handle_new;
 pub use problems::handle_problems;
 pub use similar::handle_similar;
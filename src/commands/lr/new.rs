@@ -1,12 +1,18 @@
-use crate::core::{LearningRecordV3, RecordStatus, Result};
+use crate::core::config::Config;
+use crate::core::{sanitize_with_spans, LearningRecordV3, RecordStatus, RedactionSpan, Result};
 use colored::Colorize;
 use dialoguer::MultiSelect;
+use regex::Regex;
 use std::fs;
 use std::path::PathBuf;
 use std::process::Command;
 
 /// Handle new learning record command
-pub fn handle_new(topic: String, edit: bool) -> Result<()> {
+///
+/// With `edit`, the body is edited before the record is saved so the
+/// sanitizer sees the final text. With `dry_run`, shows what the sanitizer
+/// would redact from that text and exits without writing anything.
+pub fn handle_new(topic: String, edit: bool, dry_run: bool) -> Result<()> {
     println!("{}", "📝 Creating new learning record...".cyan().bold());
     println!("{} Topic: {}", "ℹ️".cyan(), topic.green());
 
@@ -14,6 +20,12 @@ pub fn handle_new(topic: String, edit: bool) -> Result<()> {
     let timestamp = chrono::Local::now().format("%Y%m%d_%H%M%S");
     let id = format!("{}-{}", sanitize_topic(&topic), timestamp);
 
+    // Values matching `lr.sanitizer_allowlist` are never redacted
+    let allowlist = Config::load(None)
+        .map(|config| config.lr.clone())
+        .unwrap_or_default()
+        .sanitizer_allowlist_patterns()?;
+
     // Collect tags interactively
     println!("\n{}", "📋 Additional Information (optional)".yellow());
 
@@ -45,15 +57,25 @@ pub fn handle_new(topic: String, edit: bool) -> Result<()> {
     };
 
     // Create minimal markdown template
-    let markdown_body = create_template(&topic);
+    let mut markdown_body = create_template(&topic);
+
+    // Edit before saving so the sanitizer runs on the edited text
+    if edit {
+        markdown_body = edit_body(&id, markdown_body)?;
+    }
 
     // Create V3 record
     let mut record = LearningRecordV3::new(id.clone(), markdown_body);
     record.tags = tags;
     record.status = RecordStatus::Pending;
 
+    if dry_run {
+        preview_redactions(&record.to_markdown_file(), &allowlist);
+        return Ok(());
+    }
+
     // Save to file
-    let path = save_record(&record)?;
+    let path = save_record(&record, &allowlist)?;
 
     println!(
         "\n{} Learning record created successfully!",
@@ -69,11 +91,6 @@ pub fn handle_new(topic: String, edit: bool) -> Result<()> {
     // Display summary
     display_record_summary(&record);
 
-    // Open editor if requested
-    if edit {
-        open_record_in_editor(&path.to_string_lossy())?;
-    }
-
     // Provide next steps
     println!("\n{}", "💡 Next Steps:".yellow().bold());
     println!("  • Edit the markdown: Fill in Problem, Solution, and Key Takeaways");
@@ -84,6 +101,48 @@ pub fn handle_new(topic: String, edit: bool) -> Result<()> {
     Ok(())
 }
 
+/// Show what the sanitizer would redact from `content`, without saving it
+pub(crate) fn preview_redactions(content: &str, allowlist: &[Regex]) {
+    print_dry_run_notice();
+    list_redactions(content, allowlist);
+}
+
+/// Announce that a dry run saves nothing
+pub(crate) fn print_dry_run_notice() {
+    println!("\n{}", "🔍 Dry run: nothing will be saved".yellow().bold());
+}
+
+/// List what the sanitizer would redact from `content`
+pub(crate) fn list_redactions(content: &str, allowlist: &[Regex]) {
+    let (_, spans) = sanitize_with_spans(content, allowlist);
+    if spans.is_empty() {
+        println!("{} No sensitive information detected", "✅".green());
+    } else {
+        println!(
+            "{} {} value(s) would be redacted:",
+            "⚠️".yellow(),
+            spans.len()
+        );
+        for line in redaction_preview(content, &spans) {
+            println!("  • {}", line);
+        }
+    }
+}
+
+/// Let the user edit `body` in a draft file and return the edited text
+///
+/// The draft lives in the temp directory and is removed afterwards, so
+/// nothing unsanitized is left behind.
+fn edit_body(id: &str, body: String) -> Result<String> {
+    let draft = std::env::temp_dir().join(format!("cldev-{}.md", id));
+    fs::write(&draft, &body)?;
+
+    let edited = open_record_in_editor(&draft.to_string_lossy())
+        .and_then(|()| Ok(fs::read_to_string(&draft)?));
+    let _ = fs::remove_file(&draft);
+    edited
+}
+
 /// Create markdown template for the record
 fn create_template(topic: &str) -> String {
     format!(
@@ -127,26 +186,41 @@ fn sanitize_topic(topic: &str) -> String {
 }
 
 /// Save V3 record to file
-fn save_record(record: &LearningRecordV3) -> Result<PathBuf> {
-    use crate::core::CldevError;
-
+fn save_record(record: &LearningRecordV3, allowlist: &[Regex]) -> Result<PathBuf> {
     // Get learning records directory
-    let home =
-        dirs::home_dir().ok_or_else(|| CldevError::config("Failed to get home directory"))?;
-
-    let records_dir = home.join(".cldev").join("learning-records");
+    let records_dir = LearningRecordV3::records_dir()?;
     if !records_dir.exists() {
         fs::create_dir_all(&records_dir)?;
     }
 
-    // Save as markdown file with V3 frontmatter
+    // Save as markdown file with V3 frontmatter, secrets redacted
     let path = records_dir.join(format!("{}.md", record.id));
-    let content = record.to_markdown_file();
+    let (content, _) = sanitize_with_spans(&record.to_markdown_file(), allowlist);
     fs::write(&path, content)?;
 
     Ok(path)
 }
 
+/// Describe each redaction as `line L, col C: <kind> -> <placeholder>`
+///
+/// Columns count characters from 1; the secret itself is not shown.
+fn redaction_preview(content: &str, spans: &[RedactionSpan]) -> Vec<String> {
+    spans
+        .iter()
+        .map(|span| {
+            let before = &content[..span.start];
+            let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+            format!(
+                "line {}, col {}: {} -> {}",
+                before.matches('\n').count() + 1,
+                before[line_start..].chars().count() + 1,
+                span.kind.label(),
+                span.kind.placeholder()
+            )
+        })
+        .collect()
+}
+
 /// Display record summary
 fn display_record_summary(record: &LearningRecordV3) {
     use comfy_table::{modifiers::UTF8_ROUND_CORNERS, presets::UTF8_FULL, Table};
@@ -174,7 +248,7 @@ fn display_record_summary(record: &LearningRecordV3) {
     println!("\n{}", table);
 }
 
-/// Open record file in editor
+/// Open a record draft in the user's editor
 fn open_record_in_editor(path: &str) -> Result<()> {
     println!("\n{} Opening in editor...", "📝".cyan());
 
@@ -198,9 +272,8 @@ fn open_record_in_editor(path: &str) -> Result<()> {
     }
 
     println!(
-        "{} Could not open editor. Edit manually: {}",
-        "⚠️".yellow(),
-        path
+        "{} Could not open an editor; keeping the template",
+        "⚠️".yellow()
     );
     Ok(())
 }
@@ -234,6 +307,24 @@ mod tests {
         assert!(template.contains("## Key Takeaways"));
     }
 
+    #[test]
+    fn test_redaction_preview() {
+        let content = "# Deploy notes\n\nssh to 10.0.0.12 as ops@example.com\n";
+        let (sanitized, spans) = sanitize_with_spans(content, &[]);
+
+        assert_eq!(
+            redaction_preview(content, &spans),
+            vec![
+                "line 3, col 8: IP address -> [REDACTED_IP]",
+                "line 3, col 21: email -> [REDACTED_EMAIL]",
+            ]
+        );
+        assert_eq!(
+            sanitized,
+            "# Deploy notes\n\nssh to [REDACTED_IP] as [REDACTED_EMAIL]\n"
+        );
+    }
+
     #[test]
     fn test_record_creation() {
         let id = "test-record-123".to_string();
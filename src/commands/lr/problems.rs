@@ -1,10 +1,15 @@
 use crate::cli::args::Priority;
+use crate::cli::pager::Pager;
 use crate::core::{LearningSession, Result};
 use colored::Colorize;
 use std::collections::HashMap;
+use std::fmt::{self, Write};
 
 /// Handle problems analysis command
-pub fn handle_problems(priority: Option<Priority>, recent: bool) -> Result<()> {
+///
+/// The problem and hotspot listings are shown through the pager when they
+/// are too long for the terminal.
+pub fn handle_problems(priority: Option<Priority>, recent: bool, pager: &Pager) -> Result<()> {
     println!("{}", "🔍 Analyzing Problem Patterns...".cyan().bold());
 
     if let Some(ref prio) = priority {
@@ -51,25 +56,27 @@ pub fn handle_problems(priority: Option<Priority>, recent: bool) -> Result<()> {
         return Ok(());
     }
 
-    println!(
+    let mut out = String::new();
+    writeln!(
+        out,
         "\n{} Found {} unresolved problem(s)",
         "⚠️".yellow(),
         unresolved_sessions.len()
-    );
+    )?;
 
     // Analyze and categorize problems
     let analysis = analyze_problems(&unresolved_sessions);
 
     // Display problem categories
-    display_problem_categories(&analysis);
+    display_problem_categories(&mut out, &analysis)?;
 
     // Display individual problems
-    display_unresolved_problems(&unresolved_sessions, priority);
+    display_unresolved_problems(&mut out, &unresolved_sessions, priority)?;
 
     // Display recommendations
-    display_recommendations(&analysis);
+    display_recommendations(&mut out, &analysis)?;
 
-    Ok(())
+    pager.display(&out)
 }
 
 /// Check if timestamp is recent (within last 7 days)
@@ -123,10 +130,10 @@ fn analyze_problems(sessions: &[LearningSession]) -> ProblemAnalysis {
 }
 
 /// Display problem categories
-fn display_problem_categories(analysis: &ProblemAnalysis) {
+fn display_problem_categories(out: &mut String, analysis: &ProblemAnalysis) -> fmt::Result {
     use comfy_table::{modifiers::UTF8_ROUND_CORNERS, presets::UTF8_FULL, Table};
 
-    println!("\n{}", "📊 Problem Categories".green().bold());
+    writeln!(out, "\n{}", "📊 Problem Categories".green().bold())?;
 
     // By type
     if !analysis.by_type.is_empty() {
@@ -143,12 +150,12 @@ fn display_problem_categories(analysis: &ProblemAnalysis) {
             type_table.add_row(vec![type_name, &count.to_string()]);
         }
 
-        println!("{}\n", type_table);
+        writeln!(out, "{}\n", type_table)?;
     }
 
     // By tag
     if !analysis.by_tag.is_empty() {
-        println!("{}", "🏷️  Most Common Tags".green().bold());
+        writeln!(out, "{}", "🏷️  Most Common Tags".green().bold())?;
         let mut tag_table = Table::new();
         tag_table
             .load_preset(UTF8_FULL)
@@ -162,7 +169,7 @@ fn display_problem_categories(analysis: &ProblemAnalysis) {
             tag_table.add_row(vec![tag, &count.to_string()]);
         }
 
-        println!("{}\n", tag_table);
+        writeln!(out, "{}\n", tag_table)?;
     }
 
     // Recurring files
@@ -173,7 +180,11 @@ fn display_problem_categories(analysis: &ProblemAnalysis) {
         .collect();
 
     if !hotspots.is_empty() {
-        println!("{}", "🔥 Problem Hotspots (Recurring Files)".red().bold());
+        writeln!(
+            out,
+            "{}",
+            "🔥 Problem Hotspots (Recurring Files)".red().bold()
+        )?;
         let mut file_table = Table::new();
         file_table
             .load_preset(UTF8_FULL)
@@ -187,13 +198,19 @@ fn display_problem_categories(analysis: &ProblemAnalysis) {
             file_table.add_row(vec![file, &count.to_string()]);
         }
 
-        println!("{}\n", file_table);
+        writeln!(out, "{}\n", file_table)?;
     }
+
+    Ok(())
 }
 
 /// Display unresolved problems
-fn display_unresolved_problems(sessions: &[LearningSession], priority: Option<Priority>) {
-    println!("{}", "⚠️  Unresolved Problems".yellow().bold());
+fn display_unresolved_problems(
+    out: &mut String,
+    sessions: &[LearningSession],
+    priority: Option<Priority>,
+) -> fmt::Result {
+    writeln!(out, "{}", "⚠️  Unresolved Problems".yellow().bold())?;
 
     // Sort by timestamp (most recent first)
     let mut sorted_sessions = sessions.to_vec();
@@ -209,8 +226,10 @@ fn display_unresolved_problems(sessions: &[LearningSession], priority: Option<Pr
             }
         }
 
-        display_problem_brief(session, i + 1);
+        display_problem_brief(out, session, i + 1)?;
     }
+
+    Ok(())
 }
 
 /// Infer priority from session data
@@ -245,10 +264,10 @@ fn matches_priority(session_priority: &Priority, filter: &Priority) -> bool {
 }
 
 /// Display brief problem information
-fn display_problem_brief(session: &LearningSession, index: usize) {
+fn display_problem_brief(out: &mut String, session: &LearningSession, index: usize) -> fmt::Result {
     use comfy_table::{modifiers::UTF8_ROUND_CORNERS, presets::UTF8_FULL, Cell, Color, Table};
 
-    println!("\n{} Problem #{}", "🔴".red(), index);
+    writeln!(out, "\n{} Problem #{}", "🔴".red(), index)?;
 
     let mut table = Table::new();
     table
@@ -297,12 +316,12 @@ fn display_problem_brief(session: &LearningSession, index: usize) {
         table.add_row(vec!["Files", &files]);
     }
 
-    println!("{}", table);
+    writeln!(out, "{}", table)
 }
 
 /// Display recommendations
-fn display_recommendations(analysis: &ProblemAnalysis) {
-    println!("\n{}", "💡 Recommendations".yellow().bold());
+fn display_recommendations(out: &mut String, analysis: &ProblemAnalysis) -> fmt::Result {
+    writeln!(out, "\n{}", "💡 Recommendations".yellow().bold())?;
 
     // Hotspot recommendation
     let critical_hotspots: Vec<_> = analysis
@@ -312,38 +331,41 @@ fn display_recommendations(analysis: &ProblemAnalysis) {
         .collect();
 
     if !critical_hotspots.is_empty() {
-        println!(
+        writeln!(
+            out,
             "  {} {} file(s) have recurring issues - consider refactoring:",
             "⚠️".yellow(),
             critical_hotspots.len()
-        );
+        )?;
         for (file, count) in critical_hotspots.iter().take(5) {
-            println!("    • {} ({} issues)", file, count);
+            writeln!(out, "    • {} ({} issues)", file, count)?;
         }
     }
 
     // Pattern-based recommendations
     if let Some((common_type, count)) = analysis.by_type.iter().max_by_key(|(_, c)| *c) {
         if *count > 2 {
-            println!(
+            writeln!(
+                out,
                 "  {} Multiple {} issues detected ({}) - consider systematic review",
                 "💡".cyan(),
                 common_type,
                 count
-            );
+            )?;
         }
     }
 
     // General recommendations
     if analysis.total_count > 5 {
-        println!(
+        writeln!(
+            out,
             "  {} {} unresolved issues - consider dedicating time to address backlog",
             "📋".cyan(),
             analysis.total_count
-        );
+        )?;
     }
 
-    println!();
+    writeln!(out)
 }
 
 #[cfg(test)]
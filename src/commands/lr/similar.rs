@@ -1,5 +1,6 @@
 use crate::core::learning_db::LearningDatabase;
 use crate::core::CldevError;
+use crate::core::LearningRecordV3;
 use crate::core::Result;
 use colored::Colorize;
 
@@ -28,9 +29,7 @@ pub fn handle_similar(session_id: &str, limit: Option<usize>) -> Result<()> {
     println!();
 
     // Get learning records directory
-    let home_dir = dirs::home_dir().expect("Could not find home directory");
-    let cldev_dir = home_dir.join(".cldev");
-    let learning_dir = cldev_dir.join("learning-records");
+    let learning_dir = LearningRecordV3::records_dir()?;
 
     // Initialize learning database
     let db_path = learning_dir.join("index.db");
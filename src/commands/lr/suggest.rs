@@ -1,5 +1,5 @@
 use crate::core::learning_db::LearningDatabase;
-use crate::core::Result;
+use crate::core::{LearningRecordV3, Result};
 use colored::Colorize;
 
 /// Suggest similar problems based on an error message
@@ -40,9 +40,7 @@ pub fn handle_suggest(error_msg: &str, threshold: Option<f64>, limit: Option<usi
     println!();
 
     // Get learning records directory
-    let home_dir = dirs::home_dir().expect("Could not find home directory");
-    let cldev_dir = home_dir.join(".cldev");
-    let learning_dir = cldev_dir.join("learning-records");
+    let learning_dir = LearningRecordV3::records_dir()?;
 
     // Initialize learning database
     let db_path = learning_dir.join("index.db");
@@ -2,6 +2,7 @@
 pub mod analysis;
 pub mod config;
 pub mod dev;
+pub mod doctor;
 pub mod git;
 pub mod lr;
 pub mod ops;
@@ -9,4 +10,4 @@ pub mod quality;
 pub mod tech;
 pub mod todo;
 
-pub use analysis::{analyze_project, explain_target, review_merge_request, run_serena};
+pub use analysis::{analyze_project, explain_target, review_merge_request, run_serena, ScanScope};
@@ -0,0 +1,280 @@
+//! Build artifact size reporting for `ops build --analyze`
+//!
+//! Sizes are compared against the previous analyzed build of the same
+//! project, stored in `~/.cldev/build-sizes.json`, to flag regressions.
+
+use crate::core::config::home_dir;
+use crate::core::{CldevError, Result};
+use colored::Colorize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Build output directories inspected, relative to the project root
+pub const ARTIFACT_DIRS: &[&str] = &["target/release", "dist", "build"];
+
+/// Cargo intermediate directories under `target/release` that are not artifacts
+const CARGO_INTERMEDIATE_DIRS: &[&str] = &["build", "deps", "examples", "incremental"];
+
+/// Number of artifacts listed in the report
+const TOP_ARTIFACTS: usize = 10;
+
+/// Growth over the baseline, as a fraction, reported as a regression
+const REGRESSION_THRESHOLD: f64 = 0.10;
+
+/// Stored sizes: project root → artifact path → size in bytes
+type SizeBaselines = BTreeMap<String, BTreeMap<String, u64>>;
+
+/// List the files under a build output directory with their sizes
+///
+/// Paths are relative to `dir` and sorted largest first. Hidden entries are
+/// skipped, as are Cargo's intermediate directories inside `target/release`.
+/// A missing directory has no artifacts.
+pub fn report_artifacts(dir: &Path) -> Vec<(PathBuf, u64)> {
+    let skip: &[&str] = if dir.ends_with("target/release") {
+        CARGO_INTERMEDIATE_DIRS
+    } else {
+        &[]
+    };
+
+    let mut artifacts = Vec::new();
+    collect_files(dir, dir, skip, &mut artifacts);
+    artifacts.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    artifacts
+}
+
+/// Recursively collect files below `dir`, skipping `skip` at the top level
+fn collect_files(base: &Path, dir: &Path, skip: &[&str], out: &mut Vec<(PathBuf, u64)>) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+
+    for entry in entries.flatten() {
+        let name = entry.file_name();
+        let name = name.to_string_lossy();
+        if name.starts_with('.') || (dir == base && skip.contains(&name.as_ref())) {
+            continue;
+        }
+
+        let Ok(metadata) = entry.metadata() else {
+            continue;
+        };
+        let path = entry.path();
+        if metadata.is_dir() {
+            collect_files(base, &path, skip, out);
+        } else if let Ok(relative) = path.strip_prefix(base) {
+            out.push((relative.to_path_buf(), metadata.len()));
+        }
+    }
+}
+
+/// Format a byte count as a human-readable size
+pub fn format_size(bytes: u64) -> String {
+    const UNITS: &[&str] = &["KB", "MB", "GB"];
+
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = UNITS[0];
+    for next in &UNITS[1..] {
+        if size < 1024.0 {
+            break;
+        }
+        size /= 1024.0;
+        unit = next;
+    }
+    format!("{:.1} {}", size, unit)
+}
+
+/// Artifacts that grew by more than [`REGRESSION_THRESHOLD`] since the baseline
+///
+/// Returns `(path, previous size, current size)`. New artifacts are not
+/// regressions.
+pub fn size_regressions(
+    baseline: &BTreeMap<String, u64>,
+    current: &BTreeMap<String, u64>,
+) -> Vec<(String, u64, u64)> {
+    current
+        .iter()
+        .filter_map(|(path, &size)| {
+            let previous = *baseline.get(path)?;
+            let grown = size as f64 > previous as f64 * (1.0 + REGRESSION_THRESHOLD);
+            grown.then(|| (path.clone(), previous, size))
+        })
+        .collect()
+}
+
+/// Print the artifact size report for a built project and update its baseline
+pub fn print_artifact_report(root: &Path) -> Result<()> {
+    let mut artifacts: Vec<(PathBuf, u64)> = ARTIFACT_DIRS
+        .iter()
+        .flat_map(|dir| {
+            report_artifacts(&root.join(dir))
+                .into_iter()
+                .map(move |(path, size)| (Path::new(dir).join(path), size))
+        })
+        .collect();
+    artifacts.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+
+    if artifacts.is_empty() {
+        println!("  {} No build artifacts found", "⚠️".yellow());
+        return Ok(());
+    }
+
+    let total: u64 = artifacts.iter().map(|(_, size)| size).sum();
+    display_artifacts(&artifacts, total);
+
+    let current: BTreeMap<String, u64> = artifacts
+        .iter()
+        .map(|(path, size)| (path.to_string_lossy().to_string(), *size))
+        .collect();
+
+    let baseline_path = baseline_path()?;
+    let mut baselines = load_baselines(&baseline_path)?;
+    let project = root
+        .canonicalize()
+        .unwrap_or_else(|_| root.to_path_buf())
+        .to_string_lossy()
+        .to_string();
+
+    if let Some(previous) = baselines.get(&project) {
+        let regressions = size_regressions(previous, &current);
+        if regressions.is_empty() {
+            println!("  {} No size regressions since the last build", "✓".green());
+        }
+        for (path, before, after) in regressions {
+            println!(
+                "  {} {} grew from {} to {}",
+                "⚠️".yellow(),
+                path.yellow(),
+                format_size(before),
+                format_size(after)
+            );
+        }
+    }
+
+    baselines.insert(project, current);
+    save_baselines(&baseline_path, &baselines)
+}
+
+/// Display the largest artifacts and the total size
+fn display_artifacts(artifacts: &[(PathBuf, u64)], total: u64) {
+    use comfy_table::{modifiers::UTF8_ROUND_CORNERS, presets::UTF8_FULL, Table};
+
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .apply_modifier(UTF8_ROUND_CORNERS)
+        .set_header(vec!["Artifact", "Size"]);
+
+    for (path, size) in artifacts.iter().take(TOP_ARTIFACTS) {
+        table.add_row(vec![path.display().to_string(), format_size(*size)]);
+    }
+    table.add_row(vec![
+        format!("Total ({} files)", artifacts.len()),
+        format_size(total),
+    ]);
+
+    println!("\n{}", table);
+}
+
+/// Path of the size baselines (`~/.cldev/build-sizes.json`)
+fn baseline_path() -> Result<PathBuf> {
+    Ok(home_dir()?.join(".cldev").join("build-sizes.json"))
+}
+
+/// Load stored size baselines, empty if none have been saved
+fn load_baselines(path: &Path) -> Result<SizeBaselines> {
+    if !path.exists() {
+        return Ok(SizeBaselines::new());
+    }
+
+    let content = fs::read_to_string(path)?;
+    serde_json::from_str(&content).map_err(|e| {
+        CldevError::config(format!(
+            "Failed to parse build size baseline {}: {}",
+            path.display(),
+            e
+        ))
+    })
+}
+
+/// Save size baselines
+fn save_baselines(path: &Path, baselines: &SizeBaselines) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, serde_json::to_string_pretty(baselines)?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_report_artifacts_sorted_by_size() {
+        let temp_dir = TempDir::new().unwrap();
+        let dist = temp_dir.path().join("dist");
+        fs::create_dir_all(dist.join("assets")).unwrap();
+        fs::write(dist.join("index.html"), vec![0u8; 100]).unwrap();
+        fs::write(dist.join("assets/app.js"), vec![0u8; 4096]).unwrap();
+        fs::write(dist.join("assets/app.css"), vec![0u8; 512]).unwrap();
+        fs::write(dist.join(".DS_Store"), vec![0u8; 9999]).unwrap();
+
+        let artifacts = report_artifacts(&dist);
+        assert_eq!(
+            artifacts,
+            vec![
+                (PathBuf::from("assets/app.js"), 4096),
+                (PathBuf::from("assets/app.css"), 512),
+                (PathBuf::from("index.html"), 100),
+            ]
+        );
+
+        assert!(report_artifacts(&temp_dir.path().join("missing")).is_empty());
+    }
+
+    #[test]
+    fn test_report_artifacts_skips_cargo_intermediates() {
+        let temp_dir = TempDir::new().unwrap();
+        let release = temp_dir.path().join("target/release");
+        fs::create_dir_all(release.join("deps")).unwrap();
+        fs::write(release.join("deps/libfoo.rlib"), vec![0u8; 2048]).unwrap();
+        fs::write(release.join("demo"), vec![0u8; 1024]).unwrap();
+
+        assert_eq!(
+            report_artifacts(&release),
+            vec![(PathBuf::from("demo"), 1024)]
+        );
+    }
+
+    #[test]
+    fn test_format_size() {
+        assert_eq!(format_size(512), "512 B");
+        assert_eq!(format_size(2048), "2.0 KB");
+        assert_eq!(format_size(5 * 1024 * 1024 + 512 * 1024), "5.5 MB");
+        assert_eq!(format_size(3 * 1024 * 1024 * 1024), "3.0 GB");
+    }
+
+    #[test]
+    fn test_size_regressions() {
+        let baseline = BTreeMap::from([
+            ("dist/app.js".to_string(), 1000),
+            ("dist/app.css".to_string(), 1000),
+        ]);
+        let current = BTreeMap::from([
+            ("dist/app.js".to_string(), 1200),
+            ("dist/app.css".to_string(), 1050),
+            ("dist/new.js".to_string(), 5000),
+        ]);
+
+        assert_eq!(
+            size_regressions(&baseline, &current),
+            vec![("dist/app.js".to_string(), 1000, 1200)]
+        );
+    }
+}
@@ -1,15 +1,39 @@
+use super::artifacts::print_artifact_report;
 use crate::cli::args::Environment;
-use crate::core::{CldevError, ProjectDetector, ProjectType, Result};
+use crate::cli::output::OutputHandler;
+use crate::core::exec::{
+    configured_timeout, ensure_success, run_with_timeout, status_with_timeout,
+};
+use crate::core::{CldevError, Config, ProjectDetector, ProjectEnvironment, ProjectType, Result};
 use colored::Colorize;
 use indicatif::{ProgressBar, ProgressStyle};
+use std::path::Path;
 use std::process::Command;
 
 /// Handle build command
-pub fn handle_build(env: Environment, analyze: bool, clean: bool) -> Result<()> {
+///
+/// With `--dry-run`, prints the commands the build would run and exits.
+pub fn handle_build(
+    env: Environment,
+    analyze: bool,
+    clean: bool,
+    output: &OutputHandler,
+) -> Result<()> {
+    build_project(&Config::project_root()?, env, analyze, clean, output)
+}
+
+/// Build the project rooted at `root`
+fn build_project(
+    root: &Path,
+    env: Environment,
+    analyze: bool,
+    clean: bool,
+    output: &OutputHandler,
+) -> Result<()> {
     println!("{}", "🏗️  Starting build process...".cyan().bold());
 
     // Detect project type
-    let detector = ProjectDetector::new(None)?;
+    let detector = ProjectDetector::new(Some(root))?;
     let project_type = detector.project_type();
     println!(
         "{} Project type: {}",
@@ -18,17 +42,17 @@ pub fn handle_build(env: Environment, analyze: bool, clean: bool) -> Result<()>
     );
 
     // Environment configuration
-    let env_name = match env {
-        Environment::Development => "development",
-        Environment::Staging => "staging",
-        Environment::Production => "production",
-    };
-    println!("{} Environment: {}", "ℹ️".cyan(), env_name.yellow());
+    println!("{} Environment: {}", "ℹ️".cyan(), env.name().yellow());
+    let overrides = Config::load_hierarchical(Some(root.to_path_buf()))?.environment(env.name());
+
+    if output.is_dry_run() {
+        return print_build_plan(root, env, project_type, &overrides, clean, output);
+    }
 
     // Clean if requested
     if clean {
         println!("\n{}", "🧹 Cleaning build artifacts...".yellow());
-        clean_build()?;
+        clean_build(root)?;
     }
 
     // Create progress bar
@@ -41,38 +65,36 @@ pub fn handle_build(env: Environment, analyze: bool, clean: bool) -> Result<()>
 
     // Pre-build checks
     pb.set_message("Running pre-build checks...");
-    run_prebuild_checks()?;
+    run_prebuild_checks(root)?;
     pb.finish_with_message("✓ Pre-build checks passed".to_string());
 
     // Execute build
     println!("\n{}", "🔨 Building project...".cyan().bold());
-    let build_result = execute_build(env, project_type)?;
+    let build_result = execute_build(root, env, project_type, &overrides)?;
 
-    if build_result.success {
-        println!("{}", "\n✅ Build completed successfully!".green().bold());
+    println!("{}", "\n✅ Build completed successfully!".green().bold());
 
-        // Analyze bundle if requested
-        if analyze {
-            println!("\n{}", "📊 Analyzing bundle...".cyan());
-            analyze_bundle()?;
-        }
+    // Analyze bundle if requested
+    if analyze {
+        println!("\n{}", "📊 Analyzing bundle...".cyan());
+        analyze_bundle(root)?;
+        print_artifact_report(root)?;
+    }
 
-        // Show build stats
-        display_build_stats(&build_result);
+    // Show build stats
+    display_build_stats(&build_result);
 
-        Ok(())
-    } else {
-        Err(CldevError::command("Build failed"))
-    }
+    Ok(())
 }
 
-/// Clean build artifacts
-fn clean_build() -> Result<()> {
-    let paths_to_clean = vec!["dist", "build", "target", ".next", "out"];
+/// Build artifact directories removed by `--clean`
+const CLEAN_PATHS: &[&str] = &["dist", "build", "target", ".next", "out"];
 
-    for path in paths_to_clean {
-        if std::path::Path::new(path).exists() {
-            std::fs::remove_dir_all(path)?;
+/// Clean build artifacts
+fn clean_build(root: &Path) -> Result<()> {
+    for path in CLEAN_PATHS {
+        if root.join(path).exists() {
+            std::fs::remove_dir_all(root.join(path))?;
             println!("  {} Removed {}", "✓".green(), path);
         }
     }
@@ -80,25 +102,40 @@ fn clean_build() -> Result<()> {
     Ok(())
 }
 
-/// Run pre-build checks
-fn run_prebuild_checks() -> Result<()> {
+/// Commands run before building: installing Node.js dependencies and `cargo check`
+fn prebuild_commands(root: &Path) -> Vec<(&'static str, Vec<&'static str>)> {
+    let mut commands = Vec::new();
+
     // Check if node_modules exists for Node.js projects
-    if std::path::Path::new("package.json").exists()
-        && !std::path::Path::new("node_modules").exists()
-    {
-        println!("  {} Installing dependencies...", "⚠️".yellow());
-        let status = Command::new("npm").arg("install").status()?;
-        if !status.success() {
-            return Err(CldevError::command("Failed to install dependencies"));
-        }
+    if root.join("package.json").exists() && !root.join("node_modules").exists() {
+        commands.push(("npm", vec!["install"]));
     }
 
     // Check if Cargo.lock exists for Rust projects
-    if std::path::Path::new("Cargo.toml").exists() {
-        let status = Command::new("cargo").arg("check").status()?;
-        if !status.success() {
-            return Err(CldevError::command("Cargo check failed"));
+    if root.join("Cargo.toml").exists() {
+        commands.push(("cargo", vec!["check"]));
+    }
+
+    commands
+}
+
+/// Run pre-build checks
+fn run_prebuild_checks(root: &Path) -> Result<()> {
+    let timeout = configured_timeout();
+    for (program, args) in prebuild_commands(root) {
+        if program == "npm" {
+            println!("  {} Installing dependencies...", "⚠️".yellow());
         }
+
+        let mut cmd = Command::new(program);
+        cmd.args(&args).current_dir(root);
+        // A first install can take far longer than any command timeout
+        let status = if program == "npm" {
+            cmd.status()?
+        } else {
+            status_with_timeout(&mut cmd, timeout)?
+        };
+        ensure_success(&cmd, status)?;
     }
 
     Ok(())
@@ -106,112 +143,137 @@ fn run_prebuild_checks() -> Result<()> {
 
 /// Build result
 struct BuildResult {
-    success: bool,
     duration_secs: f64,
     output_size: Option<u64>,
 }
 
-/// Execute build based on project type
-fn execute_build(env: Environment, project_type: ProjectType) -> Result<BuildResult> {
-    use crate::core::ProjectType;
-
-    let start_time = std::time::Instant::now();
-
-    let status = match project_type {
+/// Get the build command for a project type, or `None` if unsupported
+///
+/// `root` is the project directory, used to tell Maven and Gradle apart.
+fn build_command(
+    env: Environment,
+    project_type: ProjectType,
+    root: &Path,
+) -> Option<(&'static str, Vec<&'static str>)> {
+    let production = matches!(env, Environment::Production);
+
+    let command = match project_type {
         ProjectType::NodeJs => {
-            let mut cmd = Command::new("npm");
-            cmd.arg("run");
-
-            match env {
-                Environment::Development => cmd.arg("build:dev"),
-                Environment::Staging => cmd.arg("build:staging"),
-                Environment::Production => cmd.arg("build"),
+            let script = match env {
+                Environment::Development => "build:dev",
+                Environment::Staging => "build:staging",
+                Environment::Production => "build",
             };
-
-            cmd.status()?
+            ("npm", vec!["run", script])
+        }
+        ProjectType::Rust if production => ("cargo", vec!["build", "--release"]),
+        ProjectType::Rust => ("cargo", vec!["build"]),
+        // For Python, we might build wheels or containers
+        ProjectType::Python => ("python", vec!["-m", "build"]),
+        // Strip debug info from production binaries
+        ProjectType::Go if production => ("go", vec!["build", "-ldflags", "-s -w"]),
+        ProjectType::Go => ("go", vec!["build"]),
+        ProjectType::Ruby => ("bundle", vec!["exec", "rake", "build"]),
+        ProjectType::Java if root.join("pom.xml").exists() => ("mvn", vec!["package"]),
+        ProjectType::Java | ProjectType::Kotlin => ("./gradlew", vec!["build"]),
+        ProjectType::Php => ("composer", vec!["install", "--no-dev"]),
+        ProjectType::DotNet if production => {
+            ("dotnet", vec!["build", "--configuration", "Release"])
         }
-        ProjectType::Rust => {
-            let mut cmd = Command::new("cargo");
-            cmd.arg("build");
+        ProjectType::DotNet => ("dotnet", vec!["build"]),
+        ProjectType::Elixir => ("mix", vec!["compile"]),
+        ProjectType::Swift if production => ("swift", vec!["build", "-c", "release"]),
+        ProjectType::Swift => ("swift", vec!["build"]),
+        ProjectType::Scala => ("sbt", vec!["compile"]),
+        _ => return None,
+    };
 
-            if matches!(env, Environment::Production) {
-                cmd.arg("--release");
-            }
+    Some(command)
+}
 
-            cmd.status()?
-        }
-        ProjectType::Python => {
-            // For Python, we might build wheels or containers
-            Command::new("python").args(["-m", "build"]).status()?
-        }
-        ProjectType::Go => {
-            let mut cmd = Command::new("go");
-            cmd.arg("build");
+/// Resolve the build command for `env`
+///
+/// A `build_command` from `[environments.<env>]` is run through `sh -c`;
+/// otherwise the command detected for the project type is used.
+fn resolve_build_command(
+    env: Environment,
+    project_type: ProjectType,
+    root: &Path,
+    overrides: &ProjectEnvironment,
+) -> Result<(String, Vec<String>)> {
+    if let Some(command) = &overrides.build_command {
+        return Ok(("sh".to_string(), vec!["-c".to_string(), command.clone()]));
+    }
 
-            if matches!(env, Environment::Production) {
-                cmd.args(["-ldflags", "-s -w"]); // Strip debug info
-            }
+    let (program, args) = build_command(env, project_type, root).ok_or_else(|| {
+        CldevError::command(format!(
+            "Build not supported for project type: {:?}",
+            project_type
+        ))
+    })?;
+    Ok((
+        program.to_string(),
+        args.into_iter().map(String::from).collect(),
+    ))
+}
 
-            cmd.status()?
-        }
-        ProjectType::Ruby => Command::new("bundle")
-            .args(["exec", "rake", "build"])
-            .status()?,
-        ProjectType::Java => {
-            if std::path::Path::new("pom.xml").exists() {
-                Command::new("mvn").arg("package").status()?
-            } else {
-                Command::new("./gradlew").arg("build").status()?
-            }
-        }
-        ProjectType::Php => Command::new("composer")
-            .arg("install")
-            .arg("--no-dev")
-            .status()?,
-        ProjectType::DotNet => {
-            let mut cmd = Command::new("dotnet");
-            cmd.arg("build");
-            if matches!(env, Environment::Production) {
-                cmd.arg("--configuration").arg("Release");
-            }
-            cmd.status()?
-        }
-        ProjectType::Elixir => Command::new("mix").arg("compile").status()?,
-        ProjectType::Kotlin => Command::new("./gradlew").arg("build").status()?,
-        ProjectType::Swift => {
-            let mut cmd = Command::new("swift");
-            cmd.arg("build");
-            if matches!(env, Environment::Production) {
-                cmd.arg("-c").arg("release");
-            }
-            cmd.status()?
-        }
-        ProjectType::Scala => Command::new("sbt").arg("compile").status()?,
-        _ => {
-            return Err(CldevError::command(format!(
-                "Build not supported for project type: {:?}",
-                project_type
-            )))
-        }
-    };
+/// Execute build based on project type
+fn execute_build(
+    root: &Path,
+    env: Environment,
+    project_type: ProjectType,
+    overrides: &ProjectEnvironment,
+) -> Result<BuildResult> {
+    let start_time = std::time::Instant::now();
+
+    let (program, args) = resolve_build_command(env, project_type, root, overrides)?;
+    let mut cmd = Command::new(program);
+    cmd.args(&args).envs(&overrides.env_vars).current_dir(root);
+    let status = status_with_timeout(&mut cmd, configured_timeout())?;
+    ensure_success(&cmd, status)?;
 
     let duration = start_time.elapsed();
     let duration_secs = duration.as_secs_f64();
 
     Ok(BuildResult {
-        success: status.success(),
         duration_secs,
-        output_size: calculate_output_size(),
+        output_size: calculate_output_size(root),
     })
 }
 
+/// Print the commands a build would run without running them (`--dry-run`)
+fn print_build_plan(
+    root: &Path,
+    env: Environment,
+    project_type: ProjectType,
+    overrides: &ProjectEnvironment,
+    clean: bool,
+    output: &OutputHandler,
+) -> Result<()> {
+    if clean {
+        for path in CLEAN_PATHS.iter().filter(|p| root.join(p).exists()) {
+            println!("  {} Would remove {}", "→".cyan(), path);
+        }
+    }
+
+    for (program, args) in prebuild_commands(root) {
+        output.dry_run_command(program, &args);
+    }
+
+    let (program, args) = resolve_build_command(env, project_type, root, overrides)?;
+    output.dry_run_command(&program, &args);
+
+    Ok(())
+}
+
 /// Calculate output directory size
-fn calculate_output_size() -> Option<u64> {
+fn calculate_output_size(root: &Path) -> Option<u64> {
     let output_dirs = vec!["dist", "build", "target/release", ".next"];
 
     for dir in output_dirs {
-        if std::path::Path::new(dir).exists() {
-            if let Ok(size) = calculate_dir_size(dir) {
+        let dir = root.join(dir);
+        if dir.exists() {
+            if let Ok(size) = calculate_dir_size(&dir) {
                 return Some(size);
             }
         }
@@ -221,7 +283,7 @@ fn calculate_output_size() -> Option<u64> {
 }
 
 /// Calculate directory size recursively
-fn calculate_dir_size(path: &str) -> Result<u64> {
+fn calculate_dir_size(path: &Path) -> Result<u64> {
     let mut total_size = 0u64;
 
     for entry in std::fs::read_dir(path)? {
@@ -229,7 +291,7 @@ fn calculate_dir_size(path: &str) -> Result<u64> {
         let metadata = entry.metadata()?;
 
         if metadata.is_dir() {
-            total_size += calculate_dir_size(entry.path().to_str().unwrap())?;
+            total_size += calculate_dir_size(&entry.path())?;
         } else {
             total_size += metadata.len();
         }
@@ -239,23 +301,31 @@ fn calculate_dir_size(path: &str) -> Result<u64> {
 }
 
 /// Analyze bundle size and composition
-fn analyze_bundle() -> Result<()> {
+fn analyze_bundle(root: &Path) -> Result<()> {
     // For Node.js projects with webpack or vite
-    if std::path::Path::new("package.json").exists() {
+    if root.join("package.json").exists() {
         println!("  {} Running bundle analyzer...", "→".cyan());
 
         // Try webpack-bundle-analyzer
-        let webpack_status = Command::new("npx")
-            .args(["webpack-bundle-analyzer", "--help"])
-            .output();
+        let timeout = configured_timeout();
+        let webpack_status =
+            run_with_timeout("npx", &["webpack-bundle-analyzer", "--help"], timeout);
 
         if webpack_status.is_ok() {
-            let _status = Command::new("npm").args(["run", "analyze"]).status()?;
+            let _status = status_with_timeout(
+                Command::new("npm")
+                    .args(["run", "analyze"])
+                    .current_dir(root),
+                timeout,
+            )?;
         } else {
             // Try source-map-explorer
-            let _status = Command::new("npx")
-                .args(["source-map-explorer", "dist/**/*.js"])
-                .status()?;
+            let _status = status_with_timeout(
+                Command::new("npx")
+                    .args(["source-map-explorer", "dist/**/*.js"])
+                    .current_dir(root),
+                timeout,
+            )?;
         }
     }
 
@@ -288,17 +358,119 @@ fn display_build_stats(result: &BuildResult) {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::cli::output::test_support::capture;
+    use std::fs;
+    use tempfile::TempDir;
 
     #[test]
     fn test_build_result_creation() {
         let result = BuildResult {
-            success: true,
             duration_secs: 10.5,
             output_size: Some(1048576),
         };
 
-        assert!(result.success);
         assert_eq!(result.duration_secs, 10.5);
         assert_eq!(result.output_size, Some(1048576));
     }
+
+    #[test]
+    fn test_build_command() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+
+        assert_eq!(
+            build_command(Environment::Production, ProjectType::Rust, root),
+            Some(("cargo", vec!["build", "--release"]))
+        );
+        assert_eq!(
+            build_command(Environment::Staging, ProjectType::NodeJs, root),
+            Some(("npm", vec!["run", "build:staging"]))
+        );
+        assert_eq!(
+            build_command(Environment::Development, ProjectType::Java, root),
+            Some(("./gradlew", vec!["build"]))
+        );
+
+        fs::write(root.join("pom.xml"), "<project/>").unwrap();
+        assert_eq!(
+            build_command(Environment::Development, ProjectType::Java, root),
+            Some(("mvn", vec!["package"]))
+        );
+    }
+
+    #[test]
+    fn test_handle_build_dry_run() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        fs::write(
+            root.join("Cargo.toml"),
+            "[package]\nname = \"demo\"\nversion = \"0.1.0\"\n",
+        )
+        .unwrap();
+        fs::create_dir(root.join("dist")).unwrap();
+
+        let mut output = OutputHandler::default();
+        output.set_dry_run(true);
+        let (stdout, _stderr) = capture(&mut output);
+
+        build_project(root, Environment::Production, false, true, &output).unwrap();
+
+        let printed = stdout.text();
+        assert!(printed.contains("cargo check"));
+        assert!(printed.contains("cargo build --release"));
+
+        // Nothing was built or cleaned
+        assert!(!root.join("target").exists());
+        assert!(root.join("dist").exists());
+    }
+
+    #[test]
+    fn test_environment_override_selects_build_command() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        fs::write(
+            root.join("Cargo.toml"),
+            "[package]\nname = \"demo\"\nversion = \"0.1.0\"\n",
+        )
+        .unwrap();
+        fs::create_dir(root.join(".cldev")).unwrap();
+        fs::write(
+            root.join(".cldev/config.toml"),
+            "[environments.production]\nbuild_command = \"make release\"\n\n[environments.production.env_vars]\nAPP_ENV = \"prod\"\n",
+        )
+        .unwrap();
+
+        let config = Config::load_hierarchical(Some(root.to_path_buf())).unwrap();
+        let production = config.environment(Environment::Production.name());
+        assert_eq!(
+            production.env_vars.get("APP_ENV").map(String::as_str),
+            Some("prod")
+        );
+        assert_eq!(
+            resolve_build_command(
+                Environment::Production,
+                ProjectType::Rust,
+                root,
+                &production
+            )
+            .unwrap(),
+            (
+                "sh".to_string(),
+                vec!["-c".to_string(), "make release".to_string()]
+            )
+        );
+
+        // Environments without an override fall back to the detected command
+        let staging = config.environment(Environment::Staging.name());
+        assert_eq!(
+            resolve_build_command(Environment::Staging, ProjectType::Rust, root, &staging).unwrap(),
+            ("cargo".to_string(), vec!["build".to_string()])
+        );
+
+        let mut output = OutputHandler::default();
+        output.set_dry_run(true);
+        let (stdout, _stderr) = capture(&mut output);
+        build_project(root, Environment::Production, false, false, &output).unwrap();
+        assert!(stdout.text().contains("sh -c 'make release'"));
+    }
 }
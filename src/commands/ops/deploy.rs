@@ -1,22 +1,29 @@
+use super::status::{append_record, deploy_log_path, DeployRecord};
 use crate::cli::args::Environment;
-use crate::core::{CldevError, ProjectDetector, ProjectType, Result};
+use crate::cli::output::OutputHandler;
+use crate::core::config::PerformanceConfig;
+use crate::core::exec::{
+    configured_timeout, ensure_success, piped_output_with_timeout, run_with_timeout,
+    status_with_timeout,
+};
+use crate::core::git_utils::run_git_checked;
+use crate::core::retry::with_backoff;
+use crate::core::{CldevError, Config, ProjectDetector, ProjectEnvironment, ProjectType, Result};
 use colored::Colorize;
 use dialoguer::Confirm;
 use indicatif::{ProgressBar, ProgressStyle};
-use std::process::Command;
+use std::collections::HashMap;
+use std::io::{self, Write};
+use std::path::Path;
+use std::process::{Command, Stdio};
 
 /// Handle deploy command
-pub fn handle_deploy(env: Environment, yes: bool, dry_run: bool) -> Result<()> {
+pub fn handle_deploy(env: Environment, yes: bool, output: &OutputHandler) -> Result<()> {
+    let dry_run = output.is_dry_run();
     println!("{}", "🚀 Starting deployment process...".cyan().bold());
+    let start_time = std::time::Instant::now();
 
-    // Environment configuration
-    let env_name = match env {
-        Environment::Development => "development",
-        Environment::Staging => "staging",
-        Environment::Production => "production",
-    };
-
-    println!("{} Target: {}", "ℹ️".cyan(), env_name.yellow().bold());
+    println!("{} Target: {}", "ℹ️".cyan(), env.name().yellow().bold());
 
     if dry_run {
         println!(
@@ -28,8 +35,12 @@ pub fn handle_deploy(env: Environment, yes: bool, dry_run: bool) -> Result<()> {
     }
 
     // Detect project type
-    let detector = ProjectDetector::new(None)?;
+    let root = Config::project_root()?;
+    let detector = ProjectDetector::new(Some(&root))?;
     let project_type = detector.project_type();
+    let config = Config::load_hierarchical(Some(root.clone()))?;
+    let overrides = config.environment(env.name());
+    let retry = &config.global.performance;
     println!(
         "{} Project type: {}",
         "ℹ️".cyan(),
@@ -38,7 +49,7 @@ pub fn handle_deploy(env: Environment, yes: bool, dry_run: bool) -> Result<()> {
 
     // Pre-deployment checks
     println!("\n{}", "🔍 Running pre-deployment checks...".cyan());
-    run_predeployment_checks(&env)?;
+    run_predeployment_checks(&env, &root, output)?;
 
     // Confirmation for production
     if matches!(env, Environment::Production) && !yes && !dry_run {
@@ -59,7 +70,7 @@ pub fn handle_deploy(env: Environment, yes: bool, dry_run: bool) -> Result<()> {
     // Build phase
     if !dry_run {
         println!("\n{}", "🔨 Building for deployment...".cyan());
-        build_for_deployment(&env)?;
+        build_for_deployment(&env, &root, &overrides)?;
     } else {
         println!("\n{}", "[DRY RUN] Would build for deployment".yellow());
     }
@@ -67,16 +78,17 @@ pub fn handle_deploy(env: Environment, yes: bool, dry_run: bool) -> Result<()> {
     // Deploy phase
     if !dry_run {
         println!("\n{}", "📦 Deploying application...".cyan());
-        execute_deployment(&env, project_type)?;
+        execute_deployment(&env, project_type, &root, &overrides, retry, output)?;
     } else {
         println!("\n{}", "[DRY RUN] Would deploy application".yellow());
-        show_deployment_plan(&env, project_type)?;
+        show_deployment_plan(&env, project_type, &overrides)?;
     }
 
     // Post-deployment verification
     if !dry_run {
         println!("\n{}", "✅ Verifying deployment...".cyan());
         verify_deployment(&env)?;
+        record_deployment(&env, &root, start_time.elapsed().as_secs_f64());
     }
 
     println!(
@@ -88,8 +100,33 @@ pub fn handle_deploy(env: Environment, yes: bool, dry_run: bool) -> Result<()> {
     Ok(())
 }
 
+/// npm scripts run as pre-deployment checks in Node.js projects
+const NPM_TEST_ARGS: [&str; 2] = ["run", "test"];
+const NPM_LINT_ARGS: [&str; 2] = ["run", "lint"];
+const NPM_AUDIT_ARGS: [&str; 2] = ["audit", "--audit-level=moderate"];
+
+/// Git queries that guard production deployments
+const GIT_STATUS_ARGS: [&str; 2] = ["status", "--porcelain"];
+const GIT_BRANCH_ARGS: [&str; 3] = ["rev-parse", "--abbrev-ref", "HEAD"];
+
 /// Run pre-deployment checks
-fn run_predeployment_checks(env: &Environment) -> Result<()> {
+///
+/// Under `--dry-run` the check commands are printed instead of run.
+fn run_predeployment_checks(env: &Environment, root: &Path, output: &OutputHandler) -> Result<()> {
+    let has_package_json = root.join("package.json").exists();
+    if output.is_dry_run() {
+        if matches!(env, Environment::Production) {
+            output.dry_run_command("git", &GIT_STATUS_ARGS);
+            output.dry_run_command("git", &GIT_BRANCH_ARGS);
+        }
+        if has_package_json {
+            for args in [NPM_TEST_ARGS, NPM_LINT_ARGS, NPM_AUDIT_ARGS] {
+                output.dry_run_command("npm", &args);
+            }
+        }
+        return Ok(());
+    }
+
     let pb = ProgressBar::new_spinner();
     pb.set_style(
         ProgressStyle::default_spinner()
@@ -99,35 +136,41 @@ fn run_predeployment_checks(env: &Environment) -> Result<()> {
 
     // Check 1: Git status
     pb.set_message("Checking git status...");
-    check_git_status(env)?;
+    check_git_status(env, root)?;
     println!("  {} Git status clean", "✓".green());
 
     // Check 2: Tests
     pb.set_message("Running tests...");
-    if std::path::Path::new("package.json").exists() {
-        let status = Command::new("npm").args(["run", "test"]).status();
-
-        if let Ok(status) = status {
-            if !status.success() {
-                return Err(CldevError::command("Tests failed"));
-            }
+    let timeout = configured_timeout();
+    if has_package_json {
+        let mut cmd = Command::new("npm");
+        cmd.args(NPM_TEST_ARGS).current_dir(root);
+        match status_with_timeout(&mut cmd, timeout) {
+            Ok(status) => ensure_success(&cmd, status)?,
+            Err(e @ CldevError::Timeout { .. }) => return Err(e),
+            // npm is not installed; the tests cannot be run here
+            Err(_) => {}
         }
     }
     println!("  {} Tests passed", "✓".green());
 
     // Check 3: Linting
     pb.set_message("Running linter...");
-    if std::path::Path::new("package.json").exists() {
-        let _status = Command::new("npm").args(["run", "lint"]).status();
+    if has_package_json {
+        let _status = status_with_timeout(
+            Command::new("npm").args(NPM_LINT_ARGS).current_dir(root),
+            timeout,
+        );
     }
     println!("  {} Linting passed", "✓".green());
 
     // Check 4: Security audit
     pb.set_message("Running security audit...");
-    if std::path::Path::new("package.json").exists() {
-        let _status = Command::new("npm")
-            .args(["audit", "--audit-level=moderate"])
-            .status();
+    if has_package_json {
+        let _status = status_with_timeout(
+            Command::new("npm").args(NPM_AUDIT_ARGS).current_dir(root),
+            timeout,
+        );
     }
     println!("  {} Security audit passed", "✓".green());
 
@@ -136,11 +179,12 @@ fn run_predeployment_checks(env: &Environment) -> Result<()> {
 }
 
 /// Check git status
-fn check_git_status(env: &Environment) -> Result<()> {
+fn check_git_status(env: &Environment, root: &Path) -> Result<()> {
     // For production, ensure we're on main/master and clean
     if matches!(env, Environment::Production) {
         let output = Command::new("git")
-            .args(["status", "--porcelain"])
+            .args(GIT_STATUS_ARGS)
+            .current_dir(root)
             .output()?;
 
         if !output.stdout.is_empty() {
@@ -151,7 +195,8 @@ fn check_git_status(env: &Environment) -> Result<()> {
 
         // Check branch
         let branch_output = Command::new("git")
-            .args(["rev-parse", "--abbrev-ref", "HEAD"])
+            .args(GIT_BRANCH_ARGS)
+            .current_dir(root)
             .output()?;
 
         let branch = String::from_utf8_lossy(&branch_output.stdout)
@@ -170,28 +215,42 @@ fn check_git_status(env: &Environment) -> Result<()> {
 }
 
 /// Build for deployment
-fn build_for_deployment(env: &Environment) -> Result<()> {
-    let status = Command::new("npm")
-        .args(["run", "build"])
-        .env(
-            "NODE_ENV",
-            match env {
-                Environment::Development => "development",
-                Environment::Staging => "staging",
-                Environment::Production => "production",
-            },
-        )
-        .status()?;
-
-    if !status.success() {
-        return Err(CldevError::command("Build failed"));
-    }
-
-    Ok(())
+///
+/// Runs `[environments.<env>].build_command` when configured, otherwise
+/// `npm run build` with `NODE_ENV` set to the environment name.
+fn build_for_deployment(
+    env: &Environment,
+    root: &Path,
+    overrides: &ProjectEnvironment,
+) -> Result<()> {
+    let mut command = match &overrides.build_command {
+        Some(build) => {
+            let mut command = Command::new("sh");
+            command.args(["-c", build]);
+            command
+        }
+        None => {
+            let mut command = Command::new("npm");
+            command.args(["run", "build"]).env("NODE_ENV", env.name());
+            command
+        }
+    };
+    command.current_dir(root).envs(&overrides.env_vars);
+    let status = status_with_timeout(&mut command, configured_timeout())?;
+    ensure_success(&command, status)
 }
 
 /// Execute deployment
-fn execute_deployment(env: &Environment, project_type: ProjectType) -> Result<()> {
+///
+/// `[environments.<env>].deploy_command` replaces the detected deployment.
+fn execute_deployment(
+    env: &Environment,
+    project_type: ProjectType,
+    root: &Path,
+    overrides: &ProjectEnvironment,
+    retry: &PerformanceConfig,
+    output: &OutputHandler,
+) -> Result<()> {
     use crate::core::ProjectType;
 
     let pb = ProgressBar::new_spinner();
@@ -203,18 +262,33 @@ fn execute_deployment(env: &Environment, project_type: ProjectType) -> Result<()
 
     pb.set_message("Deploying...");
 
+    let envs = &overrides.env_vars;
+    if let Some(deploy) = &overrides.deploy_command {
+        run_deploy_command(
+            "sh",
+            &["-c", deploy],
+            root,
+            envs,
+            retry,
+            "Deploy command",
+            output,
+        )?;
+        pb.finish_with_message("✓ Deployed successfully".to_string());
+        return Ok(());
+    }
+
     match project_type {
         ProjectType::NodeJs => {
-            deploy_web_app(env)?;
+            deploy_web_app(env, root, envs, retry, output)?;
         }
         ProjectType::Rust => {
-            deploy_rust_app(env)?;
+            deploy_rust_app(env, root, envs, retry, output)?;
         }
         ProjectType::Python => {
-            deploy_python_app(env)?;
+            deploy_python_app(env, root)?;
         }
         ProjectType::Go => {
-            deploy_go_app(env)?;
+            deploy_go_app(env, root)?;
         }
         ProjectType::Ruby
         | ProjectType::Java
@@ -244,50 +318,60 @@ fn execute_deployment(env: &Environment, project_type: ProjectType) -> Result<()
 }
 
 /// Deploy web application
-fn deploy_web_app(env: &Environment) -> Result<()> {
+fn deploy_web_app(
+    env: &Environment,
+    root: &Path,
+    envs: &HashMap<String, String>,
+    retry: &PerformanceConfig,
+    output: &OutputHandler,
+) -> Result<()> {
     // Check for common deployment tools
-    let env_name = match env {
-        Environment::Development => "development",
-        Environment::Staging => "staging",
-        Environment::Production => "production",
-    };
-
     // Try Vercel
-    if Command::new("vercel").arg("--version").status().is_ok() {
+    let timeout = configured_timeout();
+    if run_with_timeout("vercel", &["--version"], timeout).is_ok() {
         println!("  {} Deploying with Vercel...", "→".cyan());
-        let mut cmd = Command::new("vercel");
-
-        if matches!(env, Environment::Production) {
-            cmd.arg("--prod");
-        }
-
-        let status = cmd.status()?;
-        if !status.success() {
-            return Err(CldevError::command("Vercel deployment failed"));
-        }
-        return Ok(());
+        let args: &[&str] = if matches!(env, Environment::Production) {
+            &["--prod"]
+        } else {
+            &[]
+        };
+        return run_deploy_command(
+            "vercel",
+            args,
+            root,
+            envs,
+            retry,
+            "Vercel deployment",
+            output,
+        );
     }
 
     // Try Netlify
-    if Command::new("netlify").arg("--version").status().is_ok() {
+    if run_with_timeout("netlify", &["--version"], timeout).is_ok() {
         println!("  {} Deploying with Netlify...", "→".cyan());
-        let status = Command::new("netlify")
-            .args(["deploy", "--prod"])
-            .status()?;
-        if !status.success() {
-            return Err(CldevError::command("Netlify deployment failed"));
-        }
-        return Ok(());
+        return run_deploy_command(
+            "netlify",
+            &["deploy", "--prod"],
+            root,
+            envs,
+            retry,
+            "Netlify deployment",
+            output,
+        );
     }
 
     // Try custom deploy script
-    if std::path::Path::new("deploy.sh").exists() {
+    if root.join("deploy.sh").exists() {
         println!("  {} Running custom deploy script...", "→".cyan());
-        let status = Command::new("sh").args(["deploy.sh", env_name]).status()?;
-        if !status.success() {
-            return Err(CldevError::command("Custom deploy script failed"));
-        }
-        return Ok(());
+        return run_deploy_command(
+            "sh",
+            &["deploy.sh", env.name()],
+            root,
+            envs,
+            retry,
+            "Custom deploy script",
+            output,
+        );
     }
 
     Err(CldevError::command(
@@ -296,61 +380,123 @@ fn deploy_web_app(env: &Environment) -> Result<()> {
 }
 
 /// Deploy Rust application
-fn deploy_rust_app(env: &Environment) -> Result<()> {
+fn deploy_rust_app(
+    env: &Environment,
+    root: &Path,
+    envs: &HashMap<String, String>,
+    retry: &PerformanceConfig,
+    output: &OutputHandler,
+) -> Result<()> {
     // Build release binary
     let mut cmd = Command::new("cargo");
-    cmd.arg("build").arg("--release");
+    cmd.arg("build")
+        .arg("--release")
+        .current_dir(root)
+        .envs(envs);
 
-    let status = cmd.status()?;
-    if !status.success() {
-        return Err(CldevError::command("Cargo build failed"));
-    }
+    let status = status_with_timeout(&mut cmd, configured_timeout())?;
+    ensure_success(&cmd, status)?;
 
     // Deploy using custom script or Docker
-    let env_name = match env {
-        Environment::Development => "development",
-        Environment::Staging => "staging",
-        Environment::Production => "production",
-    };
-
-    if std::path::Path::new("deploy.sh").exists() {
-        let status = Command::new("sh").args(["deploy.sh", env_name]).status()?;
-        if !status.success() {
-            return Err(CldevError::command("Deploy script failed"));
-        }
+    if root.join("deploy.sh").exists() {
+        run_deploy_command(
+            "sh",
+            &["deploy.sh", env.name()],
+            root,
+            envs,
+            retry,
+            "Deploy script",
+            output,
+        )?;
     }
 
     Ok(())
 }
 
+/// Run a deployment command, retrying transient failures per `[performance]`
+///
+/// stdout is streamed as usual. stderr is captured, forwarded, and included
+/// in the error so that network failures can be told apart from broken
+/// deployments, which are not retried. Each retry is announced as a warning.
+/// The command runs in `root`; `envs` come from the environment's `env_vars`.
+fn run_deploy_command(
+    program: &str,
+    args: &[&str],
+    root: &Path,
+    envs: &HashMap<String, String>,
+    retry: &PerformanceConfig,
+    what: &str,
+    output: &OutputHandler,
+) -> Result<()> {
+    let timeout = retry.timeout();
+
+    let on_retry =
+        |attempt, e: &CldevError, delay| output.warn_retry(attempt, retry.retry_attempts, e, delay);
+    with_backoff(retry.retry_attempts, retry.retry_delay(), on_retry, || {
+        let result = piped_output_with_timeout(
+            Command::new(program)
+                .args(args)
+                .current_dir(root)
+                .envs(envs)
+                .stdout(Stdio::inherit())
+                .stderr(Stdio::piped()),
+            timeout,
+        )?;
+        io::stderr().write_all(&result.stderr).ok();
+
+        if result.status.success() {
+            Ok(())
+        } else {
+            Err(CldevError::command(format!(
+                "{} failed: {}",
+                what,
+                String::from_utf8_lossy(&result.stderr).trim()
+            )))
+        }
+    })
+}
+
 /// Deploy Python application
-fn deploy_python_app(_env: &Environment) -> Result<()> {
+fn deploy_python_app(_env: &Environment, root: &Path) -> Result<()> {
     // Build Python package
-    let status = Command::new("python").args(["-m", "build"]).status()?;
-
-    if !status.success() {
-        return Err(CldevError::command("Python build failed"));
-    }
-
-    Ok(())
+    let mut cmd = Command::new("python");
+    cmd.args(["-m", "build"]).current_dir(root);
+    let status = status_with_timeout(&mut cmd, configured_timeout())?;
+    ensure_success(&cmd, status)
 }
 
 /// Deploy Go application
-fn deploy_go_app(_env: &Environment) -> Result<()> {
+fn deploy_go_app(_env: &Environment, root: &Path) -> Result<()> {
     // Build Go binary
-    let status = Command::new("go")
-        .args(["build", "-ldflags", "-s -w"])
-        .status()?;
-
-    if !status.success() {
-        return Err(CldevError::command("Go build failed"));
-    }
+    let mut cmd = Command::new("go");
+    cmd.args(["build", "-ldflags", "-s -w"]).current_dir(root);
+    let status = status_with_timeout(&mut cmd, configured_timeout())?;
+    ensure_success(&cmd, status)
+}
 
-    Ok(())
+/// Describe the build and deploy steps, naming configured override commands
+fn deployment_steps(
+    env: &Environment,
+    project_type: ProjectType,
+    overrides: &ProjectEnvironment,
+) -> [String; 2] {
+    let build = match &overrides.build_command {
+        Some(command) => format!("Run `{}`", command),
+        None => format!("Build for {:?}", env),
+    };
+    let deploy = match &overrides.deploy_command {
+        Some(command) => format!("Run `{}`", command),
+        None => format!("Deploy {:?} app", project_type),
+    };
+    [build, deploy]
 }
 
 /// Show deployment plan (dry run)
-fn show_deployment_plan(env: &Environment, project_type: ProjectType) -> Result<()> {
+fn show_deployment_plan(
+    env: &Environment,
+    project_type: ProjectType,
+    overrides: &ProjectEnvironment,
+) -> Result<()> {
     use comfy_table::{modifiers::UTF8_ROUND_CORNERS, presets::UTF8_FULL, Table};
 
     let mut table = Table::new();
@@ -360,8 +506,9 @@ fn show_deployment_plan(env: &Environment, project_type: ProjectType) -> Result<
         .set_header(vec!["Step", "Action"]);
 
     table.add_row(vec!["1", "Run pre-deployment checks"]);
-    table.add_row(vec!["2", &format!("Build for {:?}", env)]);
-    table.add_row(vec!["3", &format!("Deploy {:?} app", project_type)]);
+    let [build, deploy] = deployment_steps(env, project_type, overrides);
+    table.add_row(vec!["2", &build]);
+    table.add_row(vec!["3", &deploy]);
     table.add_row(vec!["4", "Verify deployment"]);
 
     println!("\n{}", table);
@@ -382,6 +529,27 @@ fn verify_deployment(_env: &Environment) -> Result<()> {
     Ok(())
 }
 
+/// Append the deployment to the deploy log read by `ops status`
+///
+/// A log that cannot be written only produces a warning, since the
+/// deployment itself has already succeeded.
+fn record_deployment(env: &Environment, root: &Path, duration_secs: f64) {
+    let git_sha = run_git_checked(root, &["rev-parse", "HEAD"], "git rev-parse")
+        .ok()
+        .map(|sha| sha.trim().to_string());
+
+    let record = DeployRecord {
+        environment: env.name().to_string(),
+        timestamp: chrono::Local::now(),
+        git_sha,
+        duration_secs,
+    };
+
+    if let Err(e) = deploy_log_path().and_then(|path| append_record(&path, record)) {
+        println!("{} Failed to record deployment: {}", "⚠️".yellow(), e);
+    }
+}
+
 /// Display deployment information
 fn display_deployment_info(env: &Environment) {
     use comfy_table::{modifiers::UTF8_ROUND_CORNERS, presets::UTF8_FULL, Table};
@@ -404,12 +572,41 @@ mod tests {
 
     #[test]
     fn test_environment_names() {
+        assert_eq!(Environment::Development.name(), "development");
+        assert_eq!(Environment::Staging.name(), "staging");
+        assert_eq!(Environment::Production.name(), "production");
+    }
+
+    #[test]
+    fn test_deployment_steps_use_environment_override() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let root = temp_dir.path();
+        std::fs::create_dir(root.join(".cldev")).unwrap();
+        std::fs::write(
+            root.join(".cldev/config.toml"),
+            "[environments.production]\ndeploy_command = \"./ship.sh --prod\"\n",
+        )
+        .unwrap();
+
+        let config = Config::load_hierarchical(Some(root.to_path_buf())).unwrap();
+
+        let production = config.environment(Environment::Production.name());
+        assert_eq!(
+            deployment_steps(&Environment::Production, ProjectType::Rust, &production),
+            [
+                "Build for Production".to_string(),
+                "Run `./ship.sh --prod`".to_string()
+            ]
+        );
+
+        let staging = config.environment(Environment::Staging.name());
+        assert_eq!(staging, ProjectEnvironment::default());
         assert_eq!(
-            match Environment::Development {
-                Environment::Development => "development",
-                _ => "",
-            },
-            "development"
+            deployment_steps(&Environment::Staging, ProjectType::Rust, &staging),
+            [
+                "Build for Staging".to_string(),
+                "Deploy Rust app".to_string()
+            ]
         );
     }
 }
@@ -1,6 +1,11 @@
 /// Operations commands module
+pub mod artifacts;
 pub mod build;
 pub mod deploy;
+pub mod status;
 
+#[allow(unused_imports)]
+pub use artifacts::report_artifacts;
 pub use build::handle_build;
 pub use deploy::handle_deploy;
+pub use status::handle_status;
@@ -0,0 +1,168 @@
+//! Deployment history for `ops status`
+//!
+//! Every successful `ops deploy` appends a [`DeployRecord`] to
+//! `~/.cldev/deploys.json`; `ops status` shows the latest one per environment.
+
+use crate::core::config::home_dir;
+use crate::core::{CldevError, Result};
+use chrono::{DateTime, Local};
+use colored::Colorize;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// A successful deployment
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DeployRecord {
+    /// Environment name (`development`, `staging`, `production`)
+    pub environment: String,
+    /// When the deployment finished
+    pub timestamp: DateTime<Local>,
+    /// Commit that was deployed, if the project is a git repository
+    pub git_sha: Option<String>,
+    /// Time taken by the build and deploy phases
+    pub duration_secs: f64,
+}
+
+/// Handle ops status command
+pub fn handle_status() -> Result<()> {
+    println!("{}", "📋 Deployment status".cyan().bold());
+
+    let path = deploy_log_path()?;
+    let records = load_records(&path)?;
+    let latest = latest_per_environment(&records);
+
+    if latest.is_empty() {
+        println!("\n{}", "No deployments recorded yet".yellow());
+        return Ok(());
+    }
+
+    display_status(&latest);
+    println!("\n{} Deploy log: {}", "ℹ️".cyan(), path.display());
+
+    Ok(())
+}
+
+/// Path of the deploy log (`~/.cldev/deploys.json`)
+pub fn deploy_log_path() -> Result<PathBuf> {
+    Ok(home_dir()?.join(".cldev").join("deploys.json"))
+}
+
+/// Load all deploy records, oldest first
+///
+/// A missing log yields no records.
+pub fn load_records(path: &Path) -> Result<Vec<DeployRecord>> {
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+
+    let content = fs::read_to_string(path)?;
+    serde_json::from_str(&content).map_err(|e| {
+        CldevError::config(format!(
+            "Failed to parse deploy log {}: {}",
+            path.display(),
+            e
+        ))
+    })
+}
+
+/// Append a record to the deploy log, creating it if needed
+pub fn append_record(path: &Path, record: DeployRecord) -> Result<()> {
+    let mut records = load_records(path)?;
+    records.push(record);
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, serde_json::to_string_pretty(&records)?)?;
+    Ok(())
+}
+
+/// Pick the most recent record for each environment, ordered by environment name
+pub fn latest_per_environment(records: &[DeployRecord]) -> Vec<&DeployRecord> {
+    let mut latest: BTreeMap<&str, &DeployRecord> = BTreeMap::new();
+    for record in records {
+        latest
+            .entry(record.environment.as_str())
+            .and_modify(|current| {
+                if record.timestamp >= current.timestamp {
+                    *current = record;
+                }
+            })
+            .or_insert(record);
+    }
+    latest.into_values().collect()
+}
+
+/// Display the latest deployments as a table
+fn display_status(records: &[&DeployRecord]) {
+    use comfy_table::{modifiers::UTF8_ROUND_CORNERS, presets::UTF8_FULL, Table};
+
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .apply_modifier(UTF8_ROUND_CORNERS)
+        .set_header(vec!["Environment", "Deployed At", "Commit", "Duration"]);
+
+    for record in records {
+        let sha = record
+            .git_sha
+            .as_deref()
+            .map(|sha| sha.chars().take(7).collect())
+            .unwrap_or_else(|| "-".to_string());
+
+        table.add_row(vec![
+            record.environment.clone(),
+            record.timestamp.format("%Y-%m-%d %H:%M:%S").to_string(),
+            sha,
+            format!("{:.1}s", record.duration_secs),
+        ]);
+    }
+
+    println!("\n{}", table);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use tempfile::TempDir;
+
+    fn record(environment: &str, hour: u32, sha: &str) -> DeployRecord {
+        DeployRecord {
+            environment: environment.to_string(),
+            timestamp: Local.with_ymd_and_hms(2024, 5, 1, hour, 0, 0).unwrap(),
+            git_sha: Some(sha.to_string()),
+            duration_secs: 12.5,
+        }
+    }
+
+    #[test]
+    fn test_status_shows_latest_deploy_per_environment() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join(".cldev").join("deploys.json");
+
+        append_record(&path, record("production", 9, "aaa1111")).unwrap();
+        append_record(&path, record("staging", 10, "bbb2222")).unwrap();
+        append_record(&path, record("production", 11, "ccc3333")).unwrap();
+
+        let records = load_records(&path).unwrap();
+        assert_eq!(records.len(), 3);
+
+        let latest = latest_per_environment(&records);
+        assert_eq!(latest.len(), 2);
+        assert_eq!(latest[0].environment, "production");
+        assert_eq!(latest[0].git_sha.as_deref(), Some("ccc3333"));
+        assert_eq!(latest[1].environment, "staging");
+        assert_eq!(latest[1].git_sha.as_deref(), Some("bbb2222"));
+    }
+
+    #[test]
+    fn test_load_records_missing_log() {
+        let temp_dir = TempDir::new().unwrap();
+        assert!(load_records(&temp_dir.path().join("deploys.json"))
+            .unwrap()
+            .is_empty());
+    }
+}
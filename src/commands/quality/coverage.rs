@@ -0,0 +1,122 @@
+/// Coverage percentage extraction from test tool output
+///
+/// Used by `quality test --coverage` to enforce the effective coverage
+/// threshold from the project and stack configuration.
+use crate::core::error::CldevError;
+use crate::core::project_detector::ProjectType;
+use lazy_static::lazy_static;
+use regex::Regex;
+
+lazy_static! {
+    /// cargo-tarpaulin summary, e.g. `74.07% coverage, 20/27 lines covered`
+    static ref TARPAULIN_TOTAL: Regex =
+        Regex::new(r"(\d+(?:\.\d+)?)% coverage, \d+/\d+ lines covered").unwrap();
+
+    /// pytest-cov report total, e.g. `TOTAL     120     30    75%`
+    static ref PYTEST_COV_TOTAL: Regex =
+        Regex::new(r"(?m)^TOTAL\s.*?(\d+(?:\.\d+)?)%\s*$").unwrap();
+
+    /// nyc/istanbul table row; the fourth column is line coverage
+    static ref ISTANBUL_TABLE: Regex = Regex::new(
+        r"All files\s*\|\s*[\d.]+\s*\|\s*[\d.]+\s*\|\s*[\d.]+\s*\|\s*(\d+(?:\.\d+)?)"
+    )
+    .unwrap();
+
+    /// nyc/istanbul text-summary, e.g. `Lines        : 85.71% ( 6/7 )`
+    static ref ISTANBUL_SUMMARY: Regex =
+        Regex::new(r"(?m)^Lines\s*:\s*(\d+(?:\.\d+)?)%").unwrap();
+}
+
+/// Error for a test run whose coverage is below `threshold` percent
+///
+/// A shortfall fails validation rather than the test command, which passed.
+pub fn below_threshold(coverage: f32, threshold: u8) -> CldevError {
+    CldevError::validation(format!(
+        "Coverage {:.2}% is below the required threshold of {}%",
+        coverage, threshold
+    ))
+}
+
+/// Extract the total line coverage percentage from a coverage tool's output
+///
+/// Understands cargo-tarpaulin (Rust), pytest-cov (Python), and nyc/istanbul
+/// (Node.js) reports. Returns `None` for other project types or when no
+/// summary is found.
+pub fn parse_coverage(project_type: ProjectType, stdout: &str) -> Option<f32> {
+    let text = console::strip_ansi_codes(stdout);
+
+    let patterns: &[&Regex] = match project_type {
+        ProjectType::Rust => &[&TARPAULIN_TOTAL],
+        ProjectType::Python => &[&PYTEST_COV_TOTAL],
+        ProjectType::NodeJs => &[&ISTANBUL_TABLE, &ISTANBUL_SUMMARY],
+        _ => &[],
+    };
+
+    // Tools may print intermediate summaries; the last one is the total
+    patterns.iter().find_map(|pattern| {
+        pattern
+            .captures_iter(&text)
+            .last()
+            .and_then(|caps| caps[1].parse().ok())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_tarpaulin_output() {
+        let output = "\
+|| Tested/Total Lines:
+|| src/lib.rs: 20/27 +0.00%
+||
+74.07% coverage, 20/27 lines covered, +0.00% change in coverage
+";
+        assert_eq!(parse_coverage(ProjectType::Rust, output), Some(74.07));
+    }
+
+    #[test]
+    fn test_parse_pytest_cov_output() {
+        let output = "\
+---------- coverage: platform linux, python 3.12.1-final-0 -----------
+Name                 Stmts   Miss  Cover
+----------------------------------------
+app/__init__.py          4      0   100%
+app/models.py          116     30    74%
+----------------------------------------
+TOTAL                  120     30    75%
+
+============ 12 passed in 0.42s ============
+";
+        assert_eq!(parse_coverage(ProjectType::Python, output), Some(75.0));
+
+        let branches = "TOTAL    120     30     40      6  71.43%\n";
+        assert_eq!(parse_coverage(ProjectType::Python, branches), Some(71.43));
+    }
+
+    #[test]
+    fn test_parse_istanbul_output() {
+        let table = "\
+----------|---------|----------|---------|---------|-------------------
+File      | % Stmts | % Branch | % Funcs | % Lines | Uncovered Line #s
+----------|---------|----------|---------|---------|-------------------
+All files |   85.71 |       50 |     100 |   83.33 |
+ index.js |   85.71 |       50 |     100 |   83.33 | 7
+----------|---------|----------|---------|---------|-------------------
+";
+        assert_eq!(parse_coverage(ProjectType::NodeJs, table), Some(83.33));
+
+        let summary = "Statements   : 90% ( 9/10 )\nLines        : 88.89% ( 8/9 )\n";
+        assert_eq!(parse_coverage(ProjectType::NodeJs, summary), Some(88.89));
+    }
+
+    #[test]
+    fn test_parse_coverage_missing_summary() {
+        assert_eq!(parse_coverage(ProjectType::Rust, "test result: ok"), None);
+        assert_eq!(
+            parse_coverage(ProjectType::Go, "coverage: 80.0% of statements"),
+            None
+        );
+    }
+}
@@ -0,0 +1,138 @@
+/// `quality fix-all`: format, lint with fixes, then test
+///
+/// Chains the existing quality commands in the order developers run them
+/// before committing, stopping at the first step that fails.
+use super::{format_code, run_lint, run_tests};
+use crate::cli::output::OutputHandler;
+use crate::core::error::{CldevError, Result};
+
+/// A step of `quality fix-all`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FixStep {
+    Format,
+    Lint,
+    Test,
+}
+
+impl FixStep {
+    /// Get the short name used in progress and failure reports
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Format => "format",
+            Self::Lint => "lint",
+            Self::Test => "test",
+        }
+    }
+
+    /// Get the steps to run, in order
+    fn plan(skip_tests: bool) -> Vec<Self> {
+        let mut steps = vec![Self::Format, Self::Lint];
+        if !skip_tests {
+            steps.push(Self::Test);
+        }
+        steps
+    }
+
+    /// Run this step on the whole project
+    fn run(&self, output: &OutputHandler) -> Result<()> {
+        match self {
+            Self::Format => format_code(&[], false, false, output),
+            Self::Lint => run_lint(&[], true, true, false, output),
+            Self::Test => run_tests(None, false, false, false, output),
+        }
+    }
+}
+
+/// Format the code, apply lint fixes, and run the tests
+pub fn fix_all(skip_tests: bool, output: &OutputHandler) -> Result<()> {
+    run_steps(&FixStep::plan(skip_tests), |step| step.run(output), output)
+}
+
+/// Run the steps in order, stopping at the first failure
+///
+/// On failure, the steps that completed and the ones that were skipped are
+/// listed so it is clear how far the run got.
+fn run_steps<F>(steps: &[FixStep], mut runner: F, output: &OutputHandler) -> Result<()>
+where
+    F: FnMut(FixStep) -> Result<()>,
+{
+    for (index, step) in steps.iter().enumerate() {
+        output.info(&output.t_format("quality-fix-all-step", "step", step.name()));
+
+        if let Err(error) = runner(*step) {
+            output.error(&output.t_format("quality-fix-all-failed", "step", step.name()));
+            for done in &steps[..index] {
+                output.list_item(&format!("✓ {}", done.name()));
+            }
+            output.list_item(&format!("✗ {}: {}", step.name(), error));
+            for skipped in &steps[index + 1..] {
+                output.list_item(&format!("- {} (skipped)", skipped.name()));
+            }
+
+            return Err(CldevError::validation(format!(
+                "fix-all stopped at {}: {}",
+                step.name(),
+                error
+            )));
+        }
+    }
+
+    let names: Vec<&str> = steps.iter().map(FixStep::name).collect();
+    output.success(&output.t_format("quality-fix-all-success", "steps", &names.join(" → ")));
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plan_respects_skip_tests() {
+        assert_eq!(
+            FixStep::plan(false),
+            vec![FixStep::Format, FixStep::Lint, FixStep::Test]
+        );
+        assert_eq!(FixStep::plan(true), vec![FixStep::Format, FixStep::Lint]);
+    }
+
+    #[test]
+    fn test_steps_run_in_order() {
+        let output = OutputHandler::new(false, true, true);
+        let mut ran = Vec::new();
+
+        let result = run_steps(
+            &FixStep::plan(false),
+            |step| {
+                ran.push(step);
+                Ok(())
+            },
+            &output,
+        );
+
+        assert!(result.is_ok());
+        assert_eq!(ran, vec![FixStep::Format, FixStep::Lint, FixStep::Test]);
+    }
+
+    #[test]
+    fn test_format_failure_stops_the_chain() {
+        let output = OutputHandler::new(false, true, true);
+        let mut ran = Vec::new();
+
+        let result = run_steps(
+            &FixStep::plan(false),
+            |step| {
+                ran.push(step);
+                match step {
+                    FixStep::Format => Err(CldevError::command("prettier exited with code 2")),
+                    _ => Ok(()),
+                }
+            },
+            &output,
+        );
+
+        assert_eq!(ran, vec![FixStep::Format]);
+        let err = result.unwrap_err().to_string();
+        assert!(err.contains("fix-all stopped at format"));
+        assert!(err.contains("prettier exited with code 2"));
+    }
+}
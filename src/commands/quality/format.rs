@@ -1,8 +1,12 @@
 /// Code formatting command implementation
 ///
 /// Automatically detects project type and runs the appropriate formatter
+use super::report::{QualityKind, QualityReport};
+use super::workspace::run_in_members;
 use crate::cli::output::OutputHandler;
+use crate::core::config::Config;
 use crate::core::error::Result;
+use crate::core::exec::{configured_timeout, output_with_timeout, status_with_timeout};
 use crate::core::project_detector::ProjectDetector;
 use std::path::Path;
 use std::process::Command;
@@ -12,19 +16,37 @@ use std::process::Command;
 /// # Arguments
 /// * `paths` - Specific files or patterns to format (empty for all files)
 /// * `check` - Whether to check formatting without modifying files
+/// * `json` - Print a machine-readable summary to stdout
 /// * `output` - Output handler for user feedback
 ///
 /// # Returns
 /// Result indicating success or error
-pub fn format_code(paths: &[String], check: bool, output: &OutputHandler) -> Result<()> {
+pub fn format_code(
+    paths: &[String],
+    check: bool,
+    json: bool,
+    output: &OutputHandler,
+) -> Result<()> {
     output.info(&output.t("quality-format-detecting"));
 
     // Detect project type
-    let detector = ProjectDetector::new(None)?;
+    let detector = ProjectDetector::new(Some(&Config::project_root()?))?;
     let project_type = detector.project_type();
 
     output.success(&output.t_format("quality-format-detected", "type", project_type.name()));
 
+    // Format every member of a workspace monorepo in parallel
+    let members = detector.workspace_members();
+    if paths.is_empty() && !json && !members.is_empty() {
+        return run_in_members(
+            "format",
+            detector.root(),
+            &members,
+            |member| member.get_format_command(check),
+            output,
+        );
+    }
+
     // Get format command based on project type
     let command_parts = detector.get_format_command(check)?;
 
@@ -60,13 +82,12 @@ pub fn format_code(paths: &[String], check: bool, output: &OutputHandler) -> Res
 
     output.debug(&format!("Executing command: {:?}", cmd));
 
+    if json {
+        return QualityReport::capture(QualityKind::Format, cmd)?.finish();
+    }
+
     // Run the command
-    let status = cmd.status().map_err(|e| {
-        crate::core::error::CldevError::Config(format!(
-            "Failed to execute format command '{}': {}",
-            command_parts[0], e
-        ))
-    })?;
+    let status = status_with_timeout(&mut cmd, configured_timeout())?;
 
     if status.success() {
         if check {
@@ -88,10 +109,9 @@ pub fn format_code(paths: &[String], check: bool, output: &OutputHandler) -> Res
             output.error(&output.t_format("quality-format-failed", "code", &exit_code.to_string()));
         }
 
-        Err(crate::core::error::CldevError::Config(format!(
-            "Formatting failed with exit code: {}",
-            exit_code
-        )))
+        Err(crate::core::error::CldevError::command_failed(
+            cmd_str, exit_code,
+        ))
     }
 }
 
@@ -105,7 +125,6 @@ pub fn format_code(paths: &[String], check: bool, output: &OutputHandler) -> Res
 ///
 /// # Returns
 /// Result indicating success or error
-#[allow(dead_code)]
 pub fn format_code_advanced(
     paths: &[String],
     check: bool,
@@ -189,12 +208,7 @@ pub fn format_code_advanced(
     output.debug(&format!("Executing command: {:?}", cmd));
 
     // Run the command with output streaming
-    let output_result = cmd.output().map_err(|e| {
-        crate::core::error::CldevError::Config(format!(
-            "Failed to execute format command '{}': {}",
-            command_parts[0], e
-        ))
-    })?;
+    let output_result = output_with_timeout(&mut cmd, configured_timeout())?;
 
     // Show stdout
     if !output_result.stdout.is_empty() {
@@ -289,10 +303,9 @@ pub fn format_code_advanced(
             }
         }
 
-        Err(crate::core::error::CldevError::Config(format!(
-            "Formatting failed with exit code: {}",
-            exit_code
-        )))
+        Err(crate::core::error::CldevError::command_failed(
+            cmd_str, exit_code,
+        ))
     }
 }
 
@@ -311,7 +324,7 @@ mod tests {
         let original_dir = std::env::current_dir().unwrap();
         std::env::set_current_dir(temp_dir.path()).unwrap();
 
-        let result = format_code(&[], false, &output);
+        let result = format_code(&[], false, false, &output);
 
         // Restore original directory
         std::env::set_current_dir(original_dir).unwrap();
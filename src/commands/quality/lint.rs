@@ -3,10 +3,15 @@
 /// Linting command implementation
 ///
 /// Automatically detects project type and runs the appropriate linter
+use super::report::{QualityKind, QualityReport};
+use super::workspace::run_in_members;
 use crate::cli::output::OutputHandler;
+use crate::core::config::Config;
 use crate::core::error::Result;
+use crate::core::exec::{configured_timeout, output_with_timeout, status_with_timeout};
+use crate::core::git_utils::GitUtils;
 use crate::core::project_detector::ProjectDetector;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::process::Command;
 
 /// Run linter with project auto-detection
@@ -14,15 +19,26 @@ use std::process::Command;
 /// # Arguments
 /// * `paths` - Specific files or patterns to lint (empty for all files)
 /// * `fix` - Whether to auto-fix issues
+/// * `all` - Lint the whole project instead of only files changed in Git
+/// * `json` - Print a machine-readable summary to stdout
 /// * `output` - Output handler for user feedback
 ///
+/// Without `paths` or `all`, only files changed in the working tree are
+/// linted when the linter accepts file arguments.
+///
 /// # Returns
 /// Result indicating success or error
-pub fn run_lint(paths: &[String], fix: bool, output: &OutputHandler) -> Result<()> {
+pub fn run_lint(
+    paths: &[String],
+    fix: bool,
+    all: bool,
+    json: bool,
+    output: &OutputHandler,
+) -> Result<()> {
     output.info(&output.t("quality-lint-detecting"));
 
     // Detect project type
-    let detector = ProjectDetector::new(None)?;
+    let detector = ProjectDetector::new(Some(&Config::project_root()?))?;
     let project_type = detector.project_type();
 
     let mut vars = std::collections::HashMap::new();
@@ -30,8 +46,58 @@ pub fn run_lint(paths: &[String], fix: bool, output: &OutputHandler) -> Result<(
     output.success(&output.t_with_vars("quality-lint-detected", &vars));
 
     // Get lint command based on project type
-    let all = paths.is_empty();
-    let command_parts = detector.get_lint_command(fix, all)?;
+    let changed = if paths.is_empty() && !all {
+        GitUtils::discover(detector.root())
+            .ok()
+            .and_then(|git| changed_paths(&git, detector.root()))
+    } else {
+        None
+    };
+    if let Some(changed) = &changed {
+        // A clean tree (e.g. a CI checkout) lints the whole project
+        if changed.is_empty() {
+            output.info(&output.t("quality-lint-no-changes"));
+        } else {
+            output.info(&output.t_plural(
+                "quality-lint-changed",
+                changed.len(),
+                "count",
+                &changed.len().to_string(),
+            ));
+        }
+    }
+
+    // Lint every member of a workspace monorepo in parallel
+    let members = detector.workspace_members();
+    if paths.is_empty() && !json && !members.is_empty() {
+        let root = detector.root();
+        let members = match &changed {
+            // Members without changes have nothing to lint
+            Some(changed) if !changed.is_empty() => members
+                .into_iter()
+                .filter(|member| !changes_in_member(changed, root, member).is_empty())
+                .collect(),
+            _ => members,
+        };
+        return run_in_members(
+            "lint",
+            root,
+            &members,
+            |member| match &changed {
+                Some(changed) => member.get_lint_command_changed(
+                    &changes_in_member(changed, root, member.root()),
+                    fix,
+                ),
+                None => member.get_lint_command(fix, true),
+            },
+            output,
+        );
+    }
+
+    let command_parts = match &changed {
+        Some(changed) => detector.get_lint_command_changed(changed, fix)?,
+        None => detector.get_lint_command(fix, paths.is_empty())?,
+    };
 
     if command_parts.is_empty() {
         return Err(crate::core::error::CldevError::Config(
@@ -63,13 +129,12 @@ pub fn run_lint(paths: &[String], fix: bool, output: &OutputHandler) -> Result<(
 
     output.debug(&format!("Executing command: {:?}", cmd));
 
+    if json {
+        return QualityReport::capture(QualityKind::Lint, cmd)?.finish();
+    }
+
     // Run the command
-    let status = cmd.status().map_err(|e| {
-        crate::core::error::CldevError::Config(format!(
-            "Failed to execute lint command '{}': {}",
-            command_parts[0], e
-        ))
-    })?;
+    let status = status_with_timeout(&mut cmd, configured_timeout())?;
 
     if status.success() {
         output.success(&output.t("quality-lint-success"));
@@ -80,12 +145,44 @@ pub fn run_lint(paths: &[String], fix: bool, output: &OutputHandler) -> Result<(
         let mut vars = std::collections::HashMap::new();
         vars.insert("code", exit_code_str.as_str());
         output.error(&output.t_with_vars("quality-lint-failed", &vars));
-        Err(crate::core::error::CldevError::Config(
-            output.t_with_vars("quality-lint-failed", &vars),
+        Err(crate::core::error::CldevError::command_failed(
+            cmd_str, exit_code,
         ))
     }
 }
 
+/// Get the files changed in the working tree, relative to `root`
+///
+/// Includes untracked files and skips deletions and files outside `root`.
+/// Returns `None` if the repository status cannot be read.
+fn changed_paths(git: &GitUtils, root: &Path) -> Option<Vec<PathBuf>> {
+    let workdir = git.workdir().ok()?.canonicalize().ok()?;
+    let root = root.canonicalize().ok()?;
+
+    let changed = git
+        .changed_files()
+        .ok()?
+        .into_iter()
+        .map(|file| workdir.join(file))
+        .filter(|path| path.is_file())
+        .filter_map(|path| path.strip_prefix(&root).ok().map(Path::to_path_buf))
+        .collect();
+    Some(changed)
+}
+
+/// Get the changed files inside a workspace member, relative to the member
+///
+/// `changed` is relative to the workspace `root`.
+fn changes_in_member(changed: &[PathBuf], root: &Path, member: &Path) -> Vec<PathBuf> {
+    let Ok(member) = member.strip_prefix(root) else {
+        return Vec::new();
+    };
+    changed
+        .iter()
+        .filter_map(|path| path.strip_prefix(member).ok().map(Path::to_path_buf))
+        .collect()
+}
+
 /// Run linter with advanced options
 ///
 /// # Arguments
@@ -183,12 +280,7 @@ pub fn run_lint_advanced(
     output.debug(&format!("Executing command: {:?}", cmd));
 
     // Run the command with output streaming
-    let output_result = cmd.output().map_err(|e| {
-        crate::core::error::CldevError::Config(format!(
-            "Failed to execute lint command '{}': {}",
-            command_parts[0], e
-        ))
-    })?;
+    let output_result = output_with_timeout(&mut cmd, configured_timeout())?;
 
     // Show stdout
     if !output_result.stdout.is_empty() {
@@ -249,8 +341,8 @@ pub fn run_lint_advanced(
             crate::core::project_detector::ProjectType::Unknown => {}
         }
 
-        Err(crate::core::error::CldevError::Config(
-            output.t_with_vars("quality-lint-failed", &vars),
+        Err(crate::core::error::CldevError::command_failed(
+            cmd_str, exit_code,
         ))
     }
 }
@@ -270,13 +362,80 @@ mod tests {
         let original_dir = std::env::current_dir().unwrap();
         std::env::set_current_dir(temp_dir.path()).unwrap();
 
-        let result = run_lint(&[], false, &output);
+        let result = run_lint(&[], false, true, false, &output);
         assert!(result.is_err());
 
         // Restore original directory
         std::env::set_current_dir(original_dir).unwrap();
     }
 
+    #[test]
+    fn test_changed_paths_relative_to_root() {
+        use crate::core::git_utils::run_git_checked;
+
+        let temp_dir = TempDir::new().unwrap();
+        let repo = temp_dir.path();
+        run_git_checked(repo, &["init", "-q"], "git init").unwrap();
+        fs::create_dir_all(repo.join("web/src")).unwrap();
+        fs::write(repo.join("web/src/app.ts"), "export {}\n").unwrap();
+        fs::write(repo.join("web/README.md"), "# web\n").unwrap();
+        fs::write(repo.join("top.ts"), "export {}\n").unwrap();
+
+        let git = GitUtils::open(repo).unwrap();
+        let mut changed = changed_paths(&git, &repo.join("web")).unwrap();
+        changed.sort();
+
+        // Untracked files are listed individually; top.ts is outside the root
+        assert_eq!(
+            changed,
+            vec![PathBuf::from("README.md"), PathBuf::from("src/app.ts")]
+        );
+
+        run_git_checked(repo, &["add", "-A"], "git add").unwrap();
+        run_git_checked(
+            repo,
+            &[
+                "-c",
+                "user.name=Test",
+                "-c",
+                "user.email=test@example.com",
+                "-c",
+                "commit.gpgsign=false",
+                "commit",
+                "-q",
+                "-m",
+                "initial",
+            ],
+            "git commit",
+        )
+        .unwrap();
+
+        // Deleted files are skipped
+        fs::remove_file(repo.join("web/README.md")).unwrap();
+        fs::write(repo.join("web/src/app.ts"), "export const x = 1;\n").unwrap();
+        assert_eq!(
+            changed_paths(&git, &repo.join("web")).unwrap(),
+            vec![PathBuf::from("src/app.ts")]
+        );
+    }
+
+    #[test]
+    fn test_changes_in_member_are_relative_to_member() {
+        let root = Path::new("/repo");
+        let changed = vec![
+            PathBuf::from("packages/web/src/app.ts"),
+            PathBuf::from("packages/api/main.py"),
+            PathBuf::from("README.md"),
+        ];
+
+        assert_eq!(
+            changes_in_member(&changed, root, &root.join("packages/web")),
+            vec![PathBuf::from("src/app.ts")]
+        );
+        assert!(changes_in_member(&changed, root, &root.join("packages/cli")).is_empty());
+        assert!(changes_in_member(&changed, root, Path::new("/elsewhere")).is_empty());
+    }
+
     #[test]
     fn test_lint_rust_project() {
         let temp_dir = TempDir::new().unwrap();
@@ -4,10 +4,20 @@
 /// - Linting (lint.rs)
 /// - Code formatting (format.rs)
 /// - Testing (test.rs)
+/// - Coverage threshold parsing (coverage.rs)
+/// - Format, lint and test in one go (fix_all.rs)
+/// - JSON summaries for CI (report.rs)
+/// - Workspace-wide runs for monorepos (workspace.rs)
+mod coverage;
+mod fix_all;
 mod format;
 mod lint;
+mod report;
 mod test;
+mod workspace;
 
-pub use format::format_code;
-pub use lint::run_lint;
-pub use test::run_tests;
+pub use coverage::{below_threshold, parse_coverage};
+pub use fix_all::fix_all;
+pub use format::{format_code, format_code_advanced};
+pub use lint::{run_lint, run_lint_advanced};
+pub use test::{run_tests, run_tests_advanced};
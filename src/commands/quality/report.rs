@@ -0,0 +1,363 @@
+/// Machine-readable quality command reports
+///
+/// Used by `--json` on `quality lint`, `format`, and `test`. The tool's own output
+/// is captured and forwarded to stderr, and a single JSON summary is written to stdout.
+use super::coverage::below_threshold;
+use crate::core::error::{CldevError, Result};
+use crate::core::exec::{configured_timeout, output_with_timeout};
+use lazy_static::lazy_static;
+use regex::Regex;
+use serde::Serialize;
+use std::io::{self, Write};
+use std::path::Path;
+use std::process::Command;
+use std::time::Instant;
+
+lazy_static! {
+    /// rustc/clippy diagnostic header, e.g. `warning: unused variable: `x``
+    static ref DIAGNOSTIC_HEADER: Regex =
+        Regex::new(r"^(warning|error)(\[\w+\])?: (.+)$").unwrap();
+
+    /// rustc/clippy location line, e.g. `  --> src/main.rs:3:9`
+    static ref DIAGNOSTIC_LOCATION: Regex =
+        Regex::new(r"^\s*--> (.+?):(\d+):(\d+)$").unwrap();
+
+    /// Compact `file:line:col: message` format (ruff, flake8, go vet, eslint unix)
+    static ref COMPACT_FINDING: Regex =
+        Regex::new(r"^([^\s:][^:]*):(\d+):(\d+):?\s+(.+)$").unwrap();
+
+    /// Files reported by formatters in check mode (rustfmt, prettier, black/ruff)
+    static ref UNFORMATTED_FILE: Regex =
+        Regex::new(r"^(?:Diff in (.+?)(?::\d+:| at line \d+:)|\[warn\] (\S+)$|would reformat (.+)$)")
+            .unwrap();
+
+    /// Failed test counts (cargo test, pytest, jest, go test summaries)
+    static ref FAILED_TESTS: Regex = Regex::new(r"(\d+) failed").unwrap();
+}
+
+/// Package runners whose first argument is the actual tool (`npx eslint .`)
+const PACKAGE_RUNNERS: &[&str] = &["npx", "pnpx", "bunx", "uvx"];
+
+/// Kind of quality command being reported
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QualityKind {
+    Lint,
+    Format,
+    Test,
+}
+
+impl QualityKind {
+    /// Get the command name used in the report
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Lint => "lint",
+            Self::Format => "format",
+            Self::Test => "test",
+        }
+    }
+}
+
+/// A single finding parsed from tool output
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Finding {
+    pub file: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line: Option<u32>,
+    pub message: String,
+}
+
+/// Summary of a quality command run
+#[derive(Debug, Clone, Serialize)]
+pub struct QualityReport {
+    pub command: String,
+    pub tool: String,
+    pub exit_code: i32,
+    pub duration_ms: u64,
+    pub findings_count: usize,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub findings: Vec<Finding>,
+    /// Measured line coverage, when a threshold is enforced
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub coverage: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub coverage_threshold: Option<u8>,
+}
+
+impl QualityReport {
+    /// Run the tool, forward its output to stderr, and summarize the result
+    ///
+    /// The report names the tool (`ruff`), not the whole command line.
+    pub fn capture(kind: QualityKind, cmd: Command) -> Result<Self> {
+        Self::capture_with_output(kind, cmd).map(|(report, _)| report)
+    }
+
+    /// Like [`QualityReport::capture`], also returning the tool's combined output
+    pub fn capture_with_output(kind: QualityKind, mut cmd: Command) -> Result<(Self, String)> {
+        let tool = tool_name(&cmd);
+        let start = Instant::now();
+        let result = output_with_timeout(&mut cmd, configured_timeout())?;
+        let duration_ms = start.elapsed().as_millis() as u64;
+
+        let mut stderr = io::stderr();
+        stderr.write_all(&result.stdout).ok();
+        stderr.write_all(&result.stderr).ok();
+
+        let combined = format!(
+            "{}\n{}",
+            String::from_utf8_lossy(&result.stdout),
+            String::from_utf8_lossy(&result.stderr)
+        );
+
+        let report = Self::from_output(
+            kind,
+            &tool,
+            result.status.code().unwrap_or(-1),
+            duration_ms,
+            &combined,
+        );
+        Ok((report, combined))
+    }
+
+    /// Build a report from already captured tool output
+    pub fn from_output(
+        kind: QualityKind,
+        tool: &str,
+        exit_code: i32,
+        duration_ms: u64,
+        text: &str,
+    ) -> Self {
+        let (findings_count, findings) = match kind {
+            QualityKind::Lint => {
+                let findings = parse_lint_findings(text);
+                (findings.len(), findings)
+            }
+            QualityKind::Format => {
+                let findings = parse_unformatted_files(text);
+                (findings.len(), findings)
+            }
+            QualityKind::Test => (count_failed_tests(text), Vec::new()),
+        };
+
+        Self {
+            command: kind.name().to_string(),
+            tool: tool.to_string(),
+            exit_code,
+            duration_ms,
+            findings_count,
+            findings,
+            coverage: None,
+            coverage_threshold: None,
+        }
+    }
+
+    /// Serialize the report as pretty-printed JSON
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    /// Print the report to stdout and convert the exit code into a result
+    ///
+    /// A successful run still fails when its coverage is below the threshold.
+    pub fn finish(self) -> Result<()> {
+        println!("{}", self.to_json()?);
+
+        if self.exit_code != 0 {
+            return Err(CldevError::command(format!(
+                "{} failed with exit code: {}",
+                self.tool, self.exit_code
+            )));
+        }
+        match (self.coverage, self.coverage_threshold) {
+            (Some(coverage), Some(threshold)) if coverage < f32::from(threshold) => {
+                Err(below_threshold(coverage, threshold))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Name of the tool `cmd` runs, e.g. `ruff` for `ruff check .`
+///
+/// Package runners are looked through, so `npx eslint .` reports `eslint`.
+fn tool_name(cmd: &Command) -> String {
+    let mut words = std::iter::once(cmd.get_program())
+        .chain(cmd.get_args())
+        .map(|word| {
+            Path::new(word)
+                .file_name()
+                .unwrap_or(word)
+                .to_string_lossy()
+                .to_string()
+        });
+    let program = words.next().unwrap_or_default();
+
+    if PACKAGE_RUNNERS.contains(&program.as_str()) {
+        if let Some(tool) = words.find(|word| !word.starts_with('-')) {
+            return tool;
+        }
+    }
+    program
+}
+
+/// Parse rustc-style and compact `file:line:col: message` diagnostics
+fn parse_lint_findings(text: &str) -> Vec<Finding> {
+    let mut findings = Vec::new();
+    let mut pending: Option<String> = None;
+
+    for line in text.lines() {
+        if let Some(caps) = DIAGNOSTIC_HEADER.captures(line) {
+            pending = Some(format!("{}: {}", &caps[1], &caps[3]));
+        } else if let Some(caps) = DIAGNOSTIC_LOCATION.captures(line) {
+            if let Some(message) = pending.take() {
+                findings.push(Finding {
+                    file: caps[1].to_string(),
+                    line: caps[2].parse().ok(),
+                    message,
+                });
+            }
+        } else if let Some(caps) = COMPACT_FINDING.captures(line) {
+            findings.push(Finding {
+                file: caps[1].trim_start_matches("./").to_string(),
+                line: caps[2].parse().ok(),
+                message: caps[4].to_string(),
+            });
+        }
+    }
+
+    findings
+}
+
+/// Parse the files a formatter reported as needing changes
+fn parse_unformatted_files(text: &str) -> Vec<Finding> {
+    let mut findings: Vec<Finding> = Vec::new();
+
+    for line in text.lines() {
+        if let Some(caps) = UNFORMATTED_FILE.captures(line.trim()) {
+            let file = caps
+                .iter()
+                .skip(1)
+                .flatten()
+                .next()
+                .map(|m| m.as_str().to_string())
+                .unwrap_or_default();
+            if !findings.iter().any(|f| f.file == file) {
+                findings.push(Finding {
+                    file,
+                    line: None,
+                    message: "needs formatting".to_string(),
+                });
+            }
+        }
+    }
+
+    findings
+}
+
+/// Sum failed-test counts across all summary lines
+fn count_failed_tests(text: &str) -> usize {
+    FAILED_TESTS
+        .captures_iter(text)
+        .filter_map(|caps| caps[1].parse::<usize>().ok())
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_clippy_findings() {
+        let text = "warning: unused variable: `x`\n  --> src/main.rs:3:9\n   |\n\
+                    warning: `demo` (bin \"demo\") generated 1 warning\n";
+        let findings = parse_lint_findings(text);
+        assert_eq!(
+            findings,
+            vec![Finding {
+                file: "src/main.rs".to_string(),
+                line: Some(3),
+                message: "warning: unused variable: `x`".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_parse_compact_findings() {
+        let text = "app.py:10:1: E302 expected 2 blank lines\n./main.go:5:2: unreachable code\n";
+        let findings = parse_lint_findings(text);
+        assert_eq!(findings.len(), 2);
+        assert_eq!(findings[1].file, "main.go");
+        assert_eq!(findings[1].line, Some(5));
+    }
+
+    #[test]
+    fn test_parse_unformatted_files() {
+        let text = "Diff in /p/src/lib.rs:1:\nDiff in /p/src/lib.rs:9:\n[warn] src/app.ts\nwould reformat app.py\n";
+        let files: Vec<String> = parse_unformatted_files(text)
+            .into_iter()
+            .map(|f| f.file)
+            .collect();
+        assert_eq!(files, vec!["/p/src/lib.rs", "src/app.ts", "app.py"]);
+    }
+
+    #[test]
+    fn test_count_failed_tests() {
+        let text = "test result: FAILED. 3 passed; 2 failed; 0 ignored\n\
+                    test result: FAILED. 1 passed; 1 failed; 0 ignored\n";
+        assert_eq!(count_failed_tests(text), 3);
+        assert_eq!(count_failed_tests("test result: ok. 5 passed; 0 failed"), 0);
+    }
+
+    #[test]
+    fn test_tool_name() {
+        let mut ruff = Command::new("ruff");
+        ruff.args(["check", "."]);
+        assert_eq!(tool_name(&ruff), "ruff");
+
+        let mut cargo = Command::new("/usr/local/bin/cargo");
+        cargo.args(["clippy", "--all-targets"]);
+        assert_eq!(tool_name(&cargo), "cargo");
+
+        let mut eslint = Command::new("npx");
+        eslint.args(["--yes", "eslint", "."]);
+        assert_eq!(tool_name(&eslint), "eslint");
+    }
+
+    #[test]
+    fn test_finish_enforces_coverage_threshold() {
+        let mut report =
+            QualityReport::from_output(QualityKind::Test, "cargo", 0, 10, "test result: ok");
+        report.coverage = Some(61.5);
+        report.coverage_threshold = Some(80);
+
+        let json: serde_json::Value = serde_json::from_str(&report.to_json().unwrap()).unwrap();
+        assert_eq!(json["coverage_threshold"], 80);
+
+        let err = report.clone().finish().unwrap_err();
+        assert!(matches!(err, CldevError::Validation(_)));
+        assert_ne!(err.exit_code(), CldevError::config("").exit_code());
+
+        report.coverage = Some(85.0);
+        assert!(report.clone().finish().is_ok());
+
+        // Coverage that could not be measured is not a failure
+        report.coverage = None;
+        assert!(report.finish().is_ok());
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_capture_emits_valid_json() {
+        let mut cmd = Command::new("sh");
+        cmd.args(["-c", "echo 'app.py:1:1: F401 unused import'; exit 1"]);
+
+        let report = QualityReport::capture(QualityKind::Lint, cmd).unwrap();
+        let json: serde_json::Value = serde_json::from_str(&report.to_json().unwrap()).unwrap();
+
+        assert_eq!(json["command"], "lint");
+        assert_eq!(json["tool"], "sh");
+        assert_eq!(json["exit_code"], 1);
+        assert_eq!(json["findings_count"], 1);
+        assert_eq!(json["findings"][0]["file"], "app.py");
+        assert!(json["duration_ms"].is_u64());
+    }
+}
@@ -1,18 +1,36 @@
 /// Testing command implementation
 ///
 /// Automatically detects project type and runs the appropriate test framework
+use super::report::{QualityKind, QualityReport};
+use super::{below_threshold, parse_coverage};
 use crate::cli::output::OutputHandler;
+use crate::core::config::Config;
 use crate::core::error::Result;
-use crate::core::project_detector::ProjectDetector;
+use crate::core::exec::{configured_timeout, output_with_timeout, status_with_timeout};
+use crate::core::project_detector::{ProjectDetector, ProjectType};
+use notify::Event;
+use std::collections::HashMap;
 use std::path::Path;
 use std::process::Command;
+use std::sync::mpsc::{self, Receiver};
+use std::time::{Duration, Instant};
+
+/// Quiet period after a file change before the tests are re-run
+const WATCH_DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// Directories whose changes never trigger a re-run (build output, dependencies)
+const WATCH_IGNORED_DIRS: &[&str] = &["target", "node_modules", ".git", "dist", "build"];
 
 /// Run tests with project auto-detection
 ///
+/// In watch mode, project types whose test runner has no native watch flag
+/// are re-run by cldev itself whenever a source file changes, until Ctrl-C.
+///
 /// # Arguments
 /// * `pattern` - Test pattern to filter tests
 /// * `coverage` - Whether to generate coverage report
 /// * `watch` - Whether to run in watch mode
+/// * `json` - Print a machine-readable summary to stdout
 /// * `output` - Output handler for user feedback
 ///
 /// # Returns
@@ -21,18 +39,21 @@ pub fn run_tests(
     pattern: Option<&str>,
     coverage: bool,
     watch: bool,
+    json: bool,
     output: &OutputHandler,
 ) -> Result<()> {
     output.info(&output.t("quality-test-detecting"));
 
     // Detect project type
-    let detector = ProjectDetector::new(None)?;
+    let detector = ProjectDetector::new(Some(&Config::project_root()?))?;
     let project_type = detector.project_type();
 
     output.success(&output.t_format("quality-test-detected", "type", project_type.name()));
 
+    let native_watch = watch && detector.has_native_watch();
+
     // Get test command based on project type
-    let command_parts = detector.get_test_command(pattern, coverage, watch)?;
+    let command_parts = detector.get_test_command(pattern, coverage, native_watch)?;
 
     if command_parts.is_empty() {
         return Err(crate::core::error::CldevError::Config(
@@ -40,72 +61,290 @@ pub fn run_tests(
         ));
     }
 
-    // Build command info message
-    let cmd_str = command_parts.join(" ");
-    output.info(&output.t_format("quality-test-running", "command", &cmd_str));
+    // Coverage thresholds come from the project and stack configuration
+    let threshold = if coverage {
+        Config::load_hierarchical(Some(detector.root().to_path_buf()))?
+            .effective_coverage_threshold()
+    } else {
+        None
+    };
 
-    // Execute test command
-    let mut cmd = Command::new(&command_parts[0]);
-    cmd.current_dir(detector.root());
+    if watch && !native_watch {
+        return watch_tests(&detector, &command_parts, coverage, threshold, output);
+    }
 
-    // Add command arguments
-    for arg in &command_parts[1..] {
-        cmd.arg(arg);
+    let cmd_str = command_parts.join(" ");
+    if json {
+        output.info(&output.t_format("quality-test-running", "command", &cmd_str));
+        let cmd = build_command(&command_parts, detector.root());
+        output.debug(&format!("Executing command: {:?}", cmd));
+        let (mut report, text) = QualityReport::capture_with_output(QualityKind::Test, cmd)?;
+        if let Some(threshold) = threshold {
+            report.coverage = parse_coverage(project_type, &text);
+            report.coverage_threshold = Some(threshold);
+        }
+        return report.finish();
     }
 
-    output.debug(&format!("Executing command: {:?}", cmd));
+    match execute_tests(
+        &command_parts,
+        detector.root(),
+        coverage,
+        threshold,
+        project_type,
+        run_timeout(native_watch),
+        output,
+    )? {
+        TestRun::Passed => Ok(()),
+        TestRun::Failed(exit_code) => Err(crate::core::error::CldevError::command_failed(
+            command_parts.join(" "),
+            exit_code,
+        )),
+        TestRun::BelowThreshold {
+            coverage,
+            threshold,
+        } => Err(below_threshold(coverage, threshold)),
+    }
+}
 
-    // Run the command
-    let status = cmd.status().map_err(|e| {
-        crate::core::error::CldevError::Config(format!(
-            "Failed to execute test command '{}': {}",
-            command_parts[0], e
-        ))
+/// Re-run the test command on source file changes until interrupted
+fn watch_tests(
+    detector: &ProjectDetector,
+    command_parts: &[String],
+    coverage: bool,
+    threshold: Option<u8>,
+    output: &OutputHandler,
+) -> Result<()> {
+    use notify::{RecursiveMode, Watcher};
+
+    let root = detector.root();
+    let (tx, rx) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(tx).map_err(|e| {
+        crate::core::error::CldevError::Config(format!("Failed to start file watcher: {}", e))
+    })?;
+    watcher.watch(root, RecursiveMode::Recursive).map_err(|e| {
+        crate::core::error::CldevError::Config(format!("Failed to watch {}: {}", root.display(), e))
     })?;
 
-    if status.success() {
-        output.success(&output.t("quality-test-success"));
+    let project_type = detector.project_type();
+    watch_loop(&rx, project_type.extensions(), WATCH_DEBOUNCE, || {
+        execute_tests(
+            command_parts,
+            root,
+            coverage,
+            threshold,
+            project_type,
+            run_timeout(false),
+            output,
+        )?;
+        output.info(&output.t_format("quality-test-watching", "dir", &root.display().to_string()));
+        Ok(())
+    })
+}
 
-        if coverage {
-            output.info(&output.t("quality-test-coverage-generated"));
-            match project_type {
-                crate::core::project_detector::ProjectType::NodeJs => {
-                    output.list_item("Check coverage/ directory for detailed report");
-                }
-                crate::core::project_detector::ProjectType::Rust => {
-                    output.list_item("Check tarpaulin-report.html or coverage/ directory");
-                }
-                crate::core::project_detector::ProjectType::Go => {
-                    output.list_item("Check coverage.out file");
-                    output.list_item("View with: go tool cover -html=coverage.out");
-                }
-                crate::core::project_detector::ProjectType::Python => {
-                    output.list_item("Check htmlcov/ directory for detailed report");
-                }
-                crate::core::project_detector::ProjectType::Ruby
-                | crate::core::project_detector::ProjectType::Java
-                | crate::core::project_detector::ProjectType::Php
-                | crate::core::project_detector::ProjectType::DotNet
-                | crate::core::project_detector::ProjectType::Elixir
-                | crate::core::project_detector::ProjectType::Kotlin
-                | crate::core::project_detector::ProjectType::Swift
-                | crate::core::project_detector::ProjectType::Scala => {
-                    output.list_item("Check your project's coverage output directory");
-                }
-                crate::core::project_detector::ProjectType::Unknown => {}
-            }
+/// Run the tests once, then again after every debounced batch of relevant changes
+///
+/// A change is relevant when it touches a file with one of `extensions`
+/// outside [`WATCH_IGNORED_DIRS`]. Changes arriving within `debounce` of each
+/// other are coalesced into a single run. The loop ends when the event
+/// channel closes or the runner fails.
+fn watch_loop<F>(
+    events: &Receiver<notify::Result<Event>>,
+    extensions: &[&str],
+    debounce: Duration,
+    mut run: F,
+) -> Result<()>
+where
+    F: FnMut() -> Result<()>,
+{
+    run()?;
+
+    while let Ok(event) = events.recv() {
+        if !is_relevant_change(&event, extensions) {
+            continue;
         }
 
-        Ok(())
+        // Swallow the burst of events an editor save produces
+        while events.recv_timeout(debounce).is_ok() {}
+
+        run()?;
+    }
+
+    Ok(())
+}
+
+/// Check whether a watcher event should trigger a test run
+fn is_relevant_change(event: &notify::Result<Event>, extensions: &[&str]) -> bool {
+    let Ok(event) = event else {
+        return false;
+    };
+    if event.kind.is_access() {
+        return false;
+    }
+
+    event.paths.iter().any(|path| {
+        let ignored = path.components().any(|c| {
+            c.as_os_str()
+                .to_str()
+                .is_some_and(|name| WATCH_IGNORED_DIRS.contains(&name))
+        });
+        let matches = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .is_some_and(|ext| extensions.contains(&ext));
+        !ignored && matches
+    })
+}
+
+/// Build the test command to run in the project root
+fn build_command(command_parts: &[String], root: &Path) -> Command {
+    let mut cmd = Command::new(&command_parts[0]);
+    cmd.current_dir(root);
+    cmd.args(&command_parts[1..]);
+    cmd
+}
+
+/// Timeout for a test run
+///
+/// A native watch-mode runner keeps going until the user stops it, so only
+/// one-shot runs are bounded by `performance.timeout_seconds`.
+fn run_timeout(watch: bool) -> Option<Duration> {
+    (!watch).then(configured_timeout)
+}
+
+/// Outcome of a single test run
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum TestRun {
+    Passed,
+    /// The test command exited with this code
+    Failed(i32),
+    /// The tests passed but line coverage is below the effective threshold
+    BelowThreshold {
+        coverage: f32,
+        threshold: u8,
+    },
+}
+
+/// Run the test command once and report the outcome
+///
+/// With `coverage`, the tool output is captured so the coverage percentage
+/// can be checked against `threshold`. The command is killed after `timeout`
+/// when one is given.
+fn execute_tests(
+    command_parts: &[String],
+    root: &Path,
+    coverage: bool,
+    threshold: Option<u8>,
+    project_type: ProjectType,
+    timeout: Option<Duration>,
+    output: &OutputHandler,
+) -> Result<TestRun> {
+    let cmd_str = command_parts.join(" ");
+    output.info(&output.t_format("quality-test-running", "command", &cmd_str));
+
+    let mut cmd = build_command(command_parts, root);
+    output.debug(&format!("Executing command: {:?}", cmd));
+    let started = Instant::now();
+
+    // Coverage runs are captured so the summary can be parsed afterwards
+    let (status, captured) = if coverage {
+        let result = match timeout {
+            Some(timeout) => output_with_timeout(&mut cmd, timeout)?,
+            None => cmd.output()?,
+        };
+        let stdout = String::from_utf8_lossy(&result.stdout);
+        let stderr = String::from_utf8_lossy(&result.stderr);
+        if !stdout.is_empty() {
+            output.println_raw(stdout.trim_end());
+        }
+        if !stderr.is_empty() {
+            eprintln!("{}", stderr.trim_end());
+        }
+        // Some tools (e.g. tarpaulin) log their summary to stderr
+        (result.status, format!("{}\n{}", stdout, stderr))
     } else {
+        let status = match timeout {
+            Some(timeout) => status_with_timeout(&mut cmd, timeout)?,
+            None => cmd.status()?,
+        };
+        (status, String::new())
+    };
+    output.debug_at(
+        2,
+        &format!(
+            "{} exited with {} after {:.1}s",
+            cmd_str,
+            status,
+            started.elapsed().as_secs_f64()
+        ),
+    );
+
+    if !status.success() {
         let exit_code = status.code().unwrap_or(-1);
         output.error(&output.t_format("quality-test-failed", "code", &exit_code.to_string()));
+        return Ok(TestRun::Failed(exit_code));
+    }
 
-        Err(crate::core::error::CldevError::Config(format!(
-            "Tests failed with exit code: {}",
-            exit_code
-        )))
+    output.success(&output.t("quality-test-success"));
+
+    if coverage {
+        output.info(&output.t("quality-test-coverage-generated"));
+        match project_type {
+            ProjectType::NodeJs => {
+                output.list_item("Check coverage/ directory for detailed report");
+            }
+            ProjectType::Rust => {
+                output.list_item("Check tarpaulin-report.html or coverage/ directory");
+            }
+            ProjectType::Go => {
+                output.list_item("Check coverage.out file");
+                output.list_item("View with: go tool cover -html=coverage.out");
+            }
+            ProjectType::Python => {
+                output.list_item("Check htmlcov/ directory for detailed report");
+            }
+            ProjectType::Ruby
+            | ProjectType::Java
+            | ProjectType::Php
+            | ProjectType::DotNet
+            | ProjectType::Elixir
+            | ProjectType::Kotlin
+            | ProjectType::Swift
+            | ProjectType::Scala => {
+                output.list_item("Check your project's coverage output directory");
+            }
+            ProjectType::Unknown => {}
+        }
     }
+
+    let Some(threshold) = threshold else {
+        return Ok(TestRun::Passed);
+    };
+    let Some(measured) = parse_coverage(project_type, &captured) else {
+        output.warning(&output.t_format(
+            "quality-test-coverage-unknown",
+            "threshold",
+            &threshold.to_string(),
+        ));
+        return Ok(TestRun::Passed);
+    };
+
+    let mut vars = HashMap::new();
+    let measured_str = format!("{:.2}", measured);
+    let threshold_str = threshold.to_string();
+    vars.insert("coverage", measured_str.as_str());
+    vars.insert("threshold", threshold_str.as_str());
+
+    if measured < f32::from(threshold) {
+        output.error(&output.t_with_vars("quality-test-coverage-below", &vars));
+        return Ok(TestRun::BelowThreshold {
+            coverage: measured,
+            threshold,
+        });
+    }
+
+    output.success(&output.t_with_vars("quality-test-coverage-met", &vars));
+    Ok(TestRun::Passed)
 }
 
 /// Run tests with advanced options
@@ -119,7 +358,6 @@ pub fn run_tests(
 ///
 /// # Returns
 /// Result indicating success or error
-#[allow(dead_code)]
 pub fn run_tests_advanced(
     pattern: Option<&str>,
     coverage: bool,
@@ -248,12 +486,10 @@ pub fn run_tests_advanced(
     output.debug(&format!("Executing command: {:?}", cmd));
 
     // Run the command with output streaming
-    let output_result = cmd.output().map_err(|e| {
-        crate::core::error::CldevError::Config(format!(
-            "Failed to execute test command '{}': {}",
-            command_parts[0], e
-        ))
-    })?;
+    let output_result = match run_timeout(watch) {
+        Some(timeout) => output_with_timeout(&mut cmd, timeout)?,
+        None => cmd.output()?,
+    };
 
     // Show stdout (respects quiet mode)
     if !output_result.stdout.is_empty() {
@@ -397,10 +633,9 @@ pub fn run_tests_advanced(
             crate::core::project_detector::ProjectType::Unknown => {}
         }
 
-        Err(crate::core::error::CldevError::Config(format!(
-            "Tests failed with exit code: {}",
-            exit_code
-        )))
+        Err(crate::core::error::CldevError::command_failed(
+            cmd_str, exit_code,
+        ))
     }
 }
 
@@ -419,13 +654,98 @@ mod tests {
         let original_dir = std::env::current_dir().unwrap();
         std::env::set_current_dir(temp_dir.path()).unwrap();
 
-        let result = run_tests(None, false, false, &output);
+        let result = run_tests(None, false, false, false, &output);
         assert!(result.is_err());
 
         // Restore original directory
         std::env::set_current_dir(original_dir).unwrap();
     }
 
+    fn change(path: &str) -> notify::Result<Event> {
+        Ok(
+            Event::new(notify::EventKind::Modify(notify::event::ModifyKind::Any))
+                .add_path(std::path::PathBuf::from(path)),
+        )
+    }
+
+    #[test]
+    fn test_watch_loop_reruns_on_source_change() {
+        let (tx, rx) = mpsc::channel();
+        tx.send(change("/project/src/lib.rs")).unwrap();
+        tx.send(change("/project/README.md")).unwrap();
+        tx.send(change("/project/target/debug/build/out.rs"))
+            .unwrap();
+        drop(tx);
+
+        let mut runs = 0;
+        watch_loop(&rx, &["rs"], Duration::from_millis(10), || {
+            runs += 1;
+            Ok(())
+        })
+        .unwrap();
+
+        // One initial run, one re-run for lib.rs; the rest are ignored
+        assert_eq!(runs, 2);
+    }
+
+    #[test]
+    fn test_watch_loop_debounces_bursts() {
+        let (tx, rx) = mpsc::channel();
+        for _ in 0..5 {
+            tx.send(change("/project/main.go")).unwrap();
+        }
+        drop(tx);
+
+        let mut runs = 0;
+        watch_loop(&rx, &["go"], Duration::from_millis(10), || {
+            runs += 1;
+            Ok(())
+        })
+        .unwrap();
+
+        assert_eq!(runs, 2);
+    }
+
+    #[test]
+    fn test_watch_runs_have_no_timeout() {
+        assert_eq!(run_timeout(true), None);
+        assert!(run_timeout(false).is_some());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_execute_tests_without_timeout_outlives_short_limit() {
+        let temp_dir = TempDir::new().unwrap();
+        let output = OutputHandler::default();
+        let command = ["sleep".to_string(), "1".to_string()];
+
+        let bounded = execute_tests(
+            &command,
+            temp_dir.path(),
+            false,
+            None,
+            ProjectType::Unknown,
+            Some(Duration::from_millis(200)),
+            &output,
+        );
+        assert!(matches!(
+            bounded,
+            Err(crate::core::error::CldevError::Timeout { .. })
+        ));
+
+        let unbounded = execute_tests(
+            &command,
+            temp_dir.path(),
+            false,
+            None,
+            ProjectType::Unknown,
+            run_timeout(true),
+            &output,
+        )
+        .unwrap();
+        assert_eq!(unbounded, TestRun::Passed);
+    }
+
     #[test]
     fn test_test_rust_project() {
         let temp_dir = TempDir::new().unwrap();
@@ -0,0 +1,205 @@
+/// Quality tools across monorepo workspace members
+///
+/// When the project root is a workspace, `quality lint` and `quality format`
+/// run the detected tool in every member concurrently, bounded by
+/// `performance.max_jobs`, and report a single summary.
+use crate::cli::output::OutputHandler;
+use crate::core::config::Config;
+use crate::core::error::{CldevError, Result};
+use crate::core::exec::{configured_timeout, output_with_timeout};
+use crate::core::project_detector::ProjectDetector;
+use rayon::prelude::*;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Outcome of running a tool in one workspace member
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MemberRun {
+    pub member: PathBuf,
+    pub exit_code: i32,
+    /// Captured stdout and stderr, or the error that prevented the run
+    pub output: String,
+}
+
+/// Run a quality tool in every workspace member and summarize the results
+///
+/// `command_for` picks the command from each member's own project detection,
+/// so mixed-language workspaces get the right tool per member.
+pub fn run_in_members<C>(
+    tool: &str,
+    root: &Path,
+    members: &[PathBuf],
+    command_for: C,
+    output: &OutputHandler,
+) -> Result<()>
+where
+    C: Fn(&ProjectDetector) -> Result<Vec<String>> + Sync,
+{
+    let jobs = Config::load(None)
+        .map(|c| c.performance.max_jobs)
+        .unwrap_or(1);
+
+    let count = members.len().to_string();
+    let mut vars = HashMap::new();
+    vars.insert("tool", tool);
+    vars.insert("count", count.as_str());
+    output.info(&output.t_with_vars("quality-workspace-running", &vars));
+
+    let runs = run_members(members, jobs, |member| {
+        run_member_command(member, &command_for)
+    })?;
+    report_members(tool, root, &runs, output)
+}
+
+/// Run `run` in each member on at most `jobs` threads, keeping member order
+///
+/// An error from `run` is recorded as a failed run rather than aborting the
+/// other members.
+pub fn run_members<F>(members: &[PathBuf], jobs: usize, run: F) -> Result<Vec<MemberRun>>
+where
+    F: Fn(&Path) -> Result<(i32, String)> + Sync,
+{
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(jobs.max(1))
+        .build()
+        .map_err(|e| CldevError::command(format!("Failed to start worker threads: {}", e)))?;
+
+    Ok(pool.install(|| {
+        members
+            .par_iter()
+            .map(|member| {
+                let (exit_code, output) = run(member).unwrap_or_else(|e| (-1, e.to_string()));
+                MemberRun {
+                    member: member.clone(),
+                    exit_code,
+                    output,
+                }
+            })
+            .collect()
+    }))
+}
+
+/// Detect a member's project type and run the selected command in it
+fn run_member_command<C>(member: &Path, command_for: &C) -> Result<(i32, String)>
+where
+    C: Fn(&ProjectDetector) -> Result<Vec<String>>,
+{
+    let detector = ProjectDetector::new(Some(member))?;
+    let parts = command_for(&detector)?;
+    let (program, args) = parts.split_first().ok_or_else(|| {
+        CldevError::config(format!(
+            "No command for workspace member {}",
+            member.display()
+        ))
+    })?;
+
+    let result = output_with_timeout(
+        Command::new(program).args(args).current_dir(member),
+        configured_timeout(),
+    )?;
+
+    Ok((
+        result.status.code().unwrap_or(-1),
+        format!(
+            "{}{}",
+            String::from_utf8_lossy(&result.stdout),
+            String::from_utf8_lossy(&result.stderr)
+        ),
+    ))
+}
+
+/// Print each member's result and fail with a summary naming the failed members
+pub fn report_members(
+    tool: &str,
+    root: &Path,
+    runs: &[MemberRun],
+    output: &OutputHandler,
+) -> Result<()> {
+    let mut failed = Vec::new();
+
+    for run in runs {
+        let name = run
+            .member
+            .strip_prefix(root)
+            .unwrap_or(&run.member)
+            .display()
+            .to_string();
+
+        if run.exit_code == 0 {
+            output.success(&output.t_format("quality-workspace-member-ok", "member", &name));
+        } else {
+            let code = run.exit_code.to_string();
+            let mut vars = HashMap::new();
+            vars.insert("member", name.as_str());
+            vars.insert("code", code.as_str());
+            output.error(&output.t_with_vars("quality-workspace-member-failed", &vars));
+            failed.push(format!("{} (exit code {})", name, run.exit_code));
+        }
+
+        if !run.output.trim().is_empty() {
+            output.println_raw(run.output.trim_end());
+        }
+    }
+
+    if failed.is_empty() {
+        return Ok(());
+    }
+
+    Err(CldevError::command(format!(
+        "{} failed in {} of {} workspace members: {}",
+        tool,
+        failed.len(),
+        runs.len(),
+        failed.join(", ")
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::output::test_support::capture;
+    use std::sync::Mutex;
+
+    #[test]
+    fn test_run_members_surfaces_failed_member() {
+        let root = PathBuf::from("/repo");
+        let members = vec![root.join("crates/api"), root.join("crates/core")];
+        let invoked = Mutex::new(Vec::new());
+
+        let runs = run_members(&members, 2, |member| {
+            invoked.lock().unwrap().push(member.to_path_buf());
+            if member.ends_with("core") {
+                Ok((1, "error: unused variable\n".to_string()))
+            } else {
+                Ok((0, String::new()))
+            }
+        })
+        .unwrap();
+
+        let mut invoked = invoked.into_inner().unwrap();
+        invoked.sort();
+        assert_eq!(invoked, members);
+        assert_eq!(runs[0].member, members[0]);
+        assert_eq!(runs[0].exit_code, 0);
+        assert_eq!(runs[1].exit_code, 1);
+
+        let mut output = OutputHandler::default();
+        let (stdout, _) = capture(&mut output);
+        let err = report_members("lint", &root, &runs, &output).unwrap_err();
+        let message = err.to_string();
+        assert!(message.contains("1 of 2 workspace members"));
+        assert!(message.contains("crates/core (exit code 1)"));
+        assert!(!message.contains("crates/api"));
+        assert!(stdout.text().contains("error: unused variable"));
+    }
+
+    #[test]
+    fn test_run_members_records_runner_errors() {
+        let members = vec![PathBuf::from("/repo/a")];
+        let runs = run_members(&members, 1, |_| Err(CldevError::config("no linter"))).unwrap();
+
+        assert_eq!(runs[0].exit_code, -1);
+        assert!(runs[0].output.contains("no linter"));
+    }
+}
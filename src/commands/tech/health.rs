@@ -0,0 +1,143 @@
+//! Readiness polling for dev servers started by `tech start`
+
+use crate::core::{CldevError, Result};
+use std::io::{Read, Write};
+use std::net::{TcpStream, ToSocketAddrs};
+use std::thread;
+use std::time::{Duration, Instant};
+use url::Url;
+
+/// Delay between readiness attempts
+const POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Limit for connecting to and reading from the server in one attempt
+const ATTEMPT_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Poll `url` until the server answers, returning how long it took
+///
+/// For `http` URLs the server is ready once a `GET` returns a success or
+/// redirect status (200–399); dev servers often redirect their root page.
+/// For other schemes, such as `https`, an accepted TCP connection counts as
+/// ready. Fails once `timeout` has elapsed without the server being ready.
+pub fn wait_for_http(url: &str, timeout: Duration) -> Result<Duration> {
+    let parsed = Url::parse(url)
+        .map_err(|e| CldevError::validation(format!("Invalid health URL '{}': {}", url, e)))?;
+    let host = parsed
+        .host_str()
+        .ok_or_else(|| CldevError::validation(format!("Health URL '{}' has no host", url)))?
+        .to_string();
+    let port = parsed
+        .port_or_known_default()
+        .ok_or_else(|| CldevError::validation(format!("Health URL '{}' has no port", url)))?;
+
+    let start = Instant::now();
+    loop {
+        if is_ready(&parsed, &host, port) {
+            return Ok(start.elapsed());
+        }
+        if start.elapsed() >= timeout {
+            return Err(CldevError::command(format!(
+                "{} did not become ready within {}s",
+                url,
+                timeout.as_secs()
+            )));
+        }
+        thread::sleep(POLL_INTERVAL.min(timeout.saturating_sub(start.elapsed())));
+    }
+}
+
+/// Make one readiness attempt
+fn is_ready(url: &Url, host: &str, port: u16) -> bool {
+    let Some(mut stream) = connect(host, port) else {
+        return false;
+    };
+    if url.scheme() != "http" {
+        return true;
+    }
+
+    let path = match url.query() {
+        Some(query) => format!("{}?{}", url.path(), query),
+        None => url.path().to_string(),
+    };
+    let request = format!(
+        "GET {} HTTP/1.1\r\nHost: {}:{}\r\nConnection: close\r\n\r\n",
+        path, host, port
+    );
+    if stream.write_all(request.as_bytes()).is_err() {
+        return false;
+    }
+
+    // Only the status line is needed: "HTTP/1.1 200 OK"
+    let mut head = [0u8; 64];
+    let read = stream.read(&mut head).unwrap_or(0);
+    String::from_utf8_lossy(&head[..read])
+        .split_whitespace()
+        .nth(1)
+        .and_then(|code| code.parse::<u16>().ok())
+        .is_some_and(|code| (200..400).contains(&code))
+}
+
+fn connect(host: &str, port: u16) -> Option<TcpStream> {
+    let addrs = (host, port).to_socket_addrs().ok()?;
+    addrs
+        .into_iter()
+        .find_map(|addr| TcpStream::connect_timeout(&addr, ATTEMPT_TIMEOUT).ok())
+        .inspect(|stream| {
+            let _ = stream.set_read_timeout(Some(ATTEMPT_TIMEOUT));
+            let _ = stream.set_write_timeout(Some(ATTEMPT_TIMEOUT));
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::TcpListener;
+
+    #[test]
+    fn test_wait_for_http_detects_ready_server() {
+        let listener = TcpListener::bind(("127.0.0.1", 0)).unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let server = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = [0u8; 512];
+            let read = stream.read(&mut request).unwrap();
+            stream
+                .write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 0\r\n\r\n")
+                .unwrap();
+            String::from_utf8_lossy(&request[..read]).to_string()
+        });
+
+        let url = format!("http://127.0.0.1:{}/health", port);
+        let elapsed = wait_for_http(&url, Duration::from_secs(5)).unwrap();
+
+        assert!(elapsed < Duration::from_secs(5));
+        assert!(server
+            .join()
+            .unwrap()
+            .starts_with("GET /health HTTP/1.1\r\n"));
+    }
+
+    #[test]
+    fn test_wait_for_http_times_out_when_nothing_listens() {
+        let port = TcpListener::bind(("127.0.0.1", 0))
+            .unwrap()
+            .local_addr()
+            .unwrap()
+            .port();
+
+        let start = Instant::now();
+        let url = format!("http://127.0.0.1:{}", port);
+        let err = wait_for_http(&url, Duration::from_millis(500)).unwrap_err();
+
+        assert!(err.to_string().contains("did not become ready"), "{}", err);
+        assert!(start.elapsed() < Duration::from_secs(3));
+    }
+
+    #[test]
+    fn test_wait_for_http_rejects_invalid_url() {
+        assert!(matches!(
+            wait_for_http("not a url", Duration::from_millis(10)),
+            Err(CldevError::Validation(_))
+        ));
+    }
+}
@@ -0,0 +1,201 @@
+use crate::core::stack_config::{StackConfig, TechStack};
+use crate::core::Result;
+use colored::Colorize;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// A tech stack available to `tech list`
+#[derive(Debug, Clone)]
+pub struct StackEntry {
+    pub stack: TechStack,
+    pub config_path: PathBuf,
+    pub has_config: bool,
+    pub config: StackConfig,
+    /// Why the stack config could not be loaded; defaults are shown instead
+    pub error: Option<String>,
+}
+
+impl StackEntry {
+    /// Get the stack kind label ("built-in" or "custom")
+    pub fn kind(&self) -> &'static str {
+        if self.stack.is_custom() {
+            "custom"
+        } else {
+            "built-in"
+        }
+    }
+}
+
+/// Handle tech stack list command
+pub fn handle_list(detailed: bool) -> Result<()> {
+    println!("{}", "📚 Available Tech Stacks".cyan().bold());
+
+    let stacks_dir = StackConfig::stacks_dir()?;
+    let entries = collect_stacks(&stacks_dir)?;
+
+    display_stacks(&entries, detailed);
+
+    println!(
+        "\n{} Stack configs directory: {}",
+        "ℹ️".cyan(),
+        stacks_dir.display()
+    );
+
+    Ok(())
+}
+
+/// Collect the built-in stacks followed by custom stacks found in `stacks_dir`
+///
+/// A stack config that cannot be read or parsed does not abort the listing;
+/// its entry falls back to the defaults and records the error.
+pub fn collect_stacks(stacks_dir: &Path) -> Result<Vec<StackEntry>> {
+    let mut stacks = TechStack::all();
+
+    if stacks_dir.is_dir() {
+        let mut custom: Vec<TechStack> = fs::read_dir(stacks_dir)?
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| path.extension().and_then(|e| e.to_str()) == Some("toml"))
+            .filter_map(|path| {
+                let stem = path.file_stem()?.to_str()?;
+                TechStack::parse(stem).ok()
+            })
+            .filter(TechStack::is_custom)
+            .collect();
+        custom.sort_by(|a, b| a.as_str().cmp(b.as_str()));
+        stacks.extend(custom);
+    }
+
+    stacks
+        .into_iter()
+        .map(|stack| {
+            let config_path = stacks_dir.join(format!("{}.toml", stack.as_str()));
+            let has_config = config_path.exists();
+            let (config, error) = match StackConfig::load_from(&config_path, &stack) {
+                Ok(config) => (config, None),
+                Err(e) => (StackConfig::default_for_stack(&stack), Some(e.to_string())),
+            };
+
+            Ok(StackEntry {
+                stack,
+                config_path,
+                has_config,
+                config,
+                error,
+            })
+        })
+        .collect()
+}
+
+/// Display the stack table
+fn display_stacks(entries: &[StackEntry], detailed: bool) {
+    use comfy_table::{modifiers::UTF8_ROUND_CORNERS, presets::UTF8_FULL, Table};
+
+    let mut header = vec!["Name", "Type", "Description", "Config"];
+    if detailed {
+        header.extend(["Package Manager", "Test Runner"]);
+    }
+
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .apply_modifier(UTF8_ROUND_CORNERS)
+        .set_header(header);
+
+    for entry in entries {
+        let config = if entry.error.is_some() {
+            format!("⚠️ {} (invalid)", entry.config_path.display())
+        } else if entry.has_config {
+            entry.config_path.display().to_string()
+        } else {
+            "(defaults)".to_string()
+        };
+
+        let mut row = vec![
+            entry.stack.as_str().to_string(),
+            entry.kind().to_string(),
+            entry.config.stack.description.clone(),
+            config,
+        ];
+        if detailed {
+            let tools = &entry.config.tools;
+            row.push(tools.package_manager.clone().unwrap_or_else(|| "-".into()));
+            row.push(tools.test_runner.clone().unwrap_or_else(|| "-".into()));
+        }
+
+        table.add_row(row);
+    }
+
+    println!("\n{}", table);
+
+    for error in entries.iter().filter_map(|e| e.error.as_deref()) {
+        println!("{} {}", "⚠️".yellow(), error);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_collect_stacks_includes_custom() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(
+            temp_dir.path().join("my-stack.toml"),
+            "[stack]\nname = \"my-stack\"\ndescription = \"In-house stack\"\n\n[tools]\ntest_runner = \"bats\"\n",
+        )
+        .unwrap();
+        fs::write(temp_dir.path().join("notes.txt"), "ignored").unwrap();
+
+        let entries = collect_stacks(temp_dir.path()).unwrap();
+        assert_eq!(entries.len(), TechStack::all().len() + 1);
+
+        let custom = entries
+            .iter()
+            .find(|e| e.stack.as_str() == "my-stack")
+            .unwrap();
+        assert_eq!(custom.kind(), "custom");
+        assert!(custom.has_config);
+        assert_eq!(custom.config.stack.description, "In-house stack");
+        assert_eq!(custom.config.tools.test_runner.as_deref(), Some("bats"));
+
+        let rust = entries
+            .iter()
+            .find(|e| e.stack == TechStack::RustCli)
+            .unwrap();
+        assert_eq!(rust.kind(), "built-in");
+        assert!(!rust.has_config);
+    }
+
+    #[test]
+    fn test_collect_stacks_missing_dir() {
+        let temp_dir = TempDir::new().unwrap();
+        let entries = collect_stacks(&temp_dir.path().join("missing")).unwrap();
+        assert_eq!(entries.len(), TechStack::all().len());
+    }
+
+    #[test]
+    fn test_collect_stacks_keeps_malformed_config() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(temp_dir.path().join("broken-stack.toml"), "[stack\n").unwrap();
+
+        let entries = collect_stacks(temp_dir.path()).unwrap();
+        assert_eq!(entries.len(), TechStack::all().len() + 1);
+
+        let broken = entries
+            .iter()
+            .find(|e| e.stack.as_str() == "broken-stack")
+            .unwrap();
+        assert!(broken.has_config);
+        assert!(broken
+            .error
+            .as_deref()
+            .unwrap()
+            .contains("Failed to parse stack config"));
+        assert!(entries
+            .iter()
+            .filter(|e| e.stack != broken.stack)
+            .all(|e| e.error.is_none()));
+    }
+}
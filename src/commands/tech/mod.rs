@@ -1,4 +1,8 @@
 /// Tech stack specific commands module
+pub mod health;
+pub mod list;
 pub mod start;
 
+pub use health::wait_for_http;
+pub use list::handle_list;
 pub use start::handle_start;
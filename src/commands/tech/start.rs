@@ -1,15 +1,104 @@
+//! Start the development server for a tech stack
+//!
+//! The server command comes from the `dev` entry of the stack or project
+//! `[commands]` table when one is configured, and is otherwise detected from
+//! the project files. It runs in the foreground with its output streamed
+//! until it exits or is stopped with Ctrl+C, while its health URL is polled
+//! in the background to report when it is ready.
+
+use super::wait_for_http;
 use crate::cli::args::{Environment, TechStack};
-use crate::core::{CldevError, ProjectDetector, Result};
+use crate::core::config::HierarchicalConfig;
+use crate::core::exec::{configured_timeout, ensure_success, run_with_timeout};
+use crate::core::{CldevError, Config, ProjectDetector, Result};
 use colored::Colorize;
 use indicatif::{ProgressBar, ProgressStyle};
-use std::process::{Command, Stdio};
+use std::io::ErrorKind;
+use std::net::TcpListener;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::thread;
+
+/// Ports tried after a busy one when suggesting an alternative
+const PORT_SUGGESTION_RANGE: u16 = 100;
+
+/// Label of a server run from the configured `dev` command
+const CONFIGURED_SOURCE: &str = "configured dev command";
+
+/// Dev server command resolved for a stack, port and environment
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DevServer {
+    /// What was detected, e.g. "Vite" or "configured dev command"
+    pub source: &'static str,
+    pub program: String,
+    pub args: Vec<String>,
+    /// Environment variables set for the server, including `PORT`
+    pub envs: Vec<(String, String)>,
+    pub port: u16,
+    /// URL polled to tell when the server is ready
+    pub health_url: String,
+    /// Directory the server runs in (the project root)
+    pub dir: PathBuf,
+}
+
+impl DevServer {
+    fn new(source: &'static str, program: &str, args: &[&str], port: u16) -> Self {
+        Self {
+            source,
+            program: program.to_string(),
+            args: args.iter().map(|arg| arg.to_string()).collect(),
+            envs: vec![("PORT".to_string(), port.to_string())],
+            port,
+            health_url: format!("http://localhost:{}", port),
+            dir: PathBuf::from("."),
+        }
+    }
+
+    fn env(mut self, key: &str, value: &str) -> Self {
+        self.envs.push((key.to_string(), value.to_string()));
+        self
+    }
+
+    /// Program and arguments joined with spaces, for display
+    pub fn command_line(&self) -> String {
+        std::iter::once(self.program.as_str())
+            .chain(self.args.iter().map(String::as_str))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
 
 /// Handle tech stack start command
-pub fn handle_start(stack: TechStack, port: Option<u16>, env: Environment) -> Result<()> {
+///
+/// `health_url` replaces the default readiness URL, `http://localhost:<port>`.
+pub fn handle_start(
+    stack: TechStack,
+    port: Option<u16>,
+    env: Environment,
+    health_url: Option<&str>,
+) -> Result<()> {
+    let root = Config::project_root()?;
+    start(stack, port, env, health_url, &root, run_dev_server)
+}
+
+/// Resolve the dev server for the project at `root` and hand it to `runner`
+fn start<F>(
+    stack: TechStack,
+    port: Option<u16>,
+    env: Environment,
+    health_url: Option<&str>,
+    root: &Path,
+    runner: F,
+) -> Result<()>
+where
+    F: FnOnce(&DevServer) -> Result<()>,
+{
     println!("{}", "🚀 Starting development environment...".cyan().bold());
 
     // Auto-detect project type if possible
-    let detected_type = ProjectDetector::new(None).ok().map(|d| d.project_type());
+    let detected_type = ProjectDetector::new(Some(root))
+        .ok()
+        .map(|d| d.project_type());
     println!(
         "{} Tech stack: {}",
         "ℹ️".cyan(),
@@ -24,36 +113,59 @@ pub fn handle_start(stack: TechStack, port: Option<u16>, env: Environment) -> Re
         );
     }
 
-    // Environment setup
-    let env_name = match env {
-        Environment::Development => "development",
-        Environment::Staging => "staging",
-        Environment::Production => "production",
-    };
-    println!("{} Environment: {}", "ℹ️".cyan(), env_name.yellow());
+    println!("{} Environment: {}", "ℹ️".cyan(), env.name().yellow());
 
-    // Port configuration
-    let default_port = get_default_port(&stack);
-    let actual_port = port.unwrap_or(default_port);
-    println!("{} Port: {}", "ℹ️".cyan(), actual_port.to_string().cyan());
+    let config = Config::load_hierarchical(Some(root.to_path_buf()))?;
+    let mut server = resolve_dev_server(stack, port, env, root, &config)?;
+    if let Some(url) = health_url {
+        server.health_url = url.to_string();
+    }
+    println!("{} Port: {}", "ℹ️".cyan(), server.port.to_string().cyan());
+    ensure_port_available(server.port)?;
 
-    // Pre-start checks
-    println!("\n{}", "🔍 Running pre-start checks...".cyan());
-    run_prestart_checks(&stack)?;
+    // Pre-start checks are for detected servers; a configured command is trusted
+    if server.source != CONFIGURED_SOURCE {
+        println!("\n{}", "🔍 Running pre-start checks...".cyan());
+        run_prestart_checks(&stack, root)?;
+    }
 
-    // Start the development environment
     println!("\n{}", "🎬 Starting services...".cyan().bold());
-    start_development_server(&stack, actual_port, env)?;
-
     println!(
-        "\n{}",
-        "✅ Development environment started successfully!"
-            .green()
-            .bold()
+        "  {} {}: {}",
+        "→".cyan(),
+        server.source,
+        server.command_line().dimmed()
     );
-    display_access_info(&stack, actual_port);
+    display_access_info(&stack, server.port);
 
-    Ok(())
+    runner(&server)
+}
+
+/// Resolve the dev server command, port and environment
+///
+/// The port is `--port`, then the project or stack `dev_port`, then the
+/// stack default. A `dev` command from the merged `[commands]` tables is run
+/// through `sh -c`; otherwise the server is detected from the project files.
+/// Variables from `[environments.<env>].env_vars` are passed to the server.
+fn resolve_dev_server(
+    stack: TechStack,
+    port: Option<u16>,
+    env: Environment,
+    root: &Path,
+    config: &HierarchicalConfig,
+) -> Result<DevServer> {
+    let port = port
+        .or_else(|| config.effective_dev_port())
+        .unwrap_or_else(|| get_default_port(&stack));
+
+    let mut server = match config.merged_commands().get("dev") {
+        Some(command) => DevServer::new(CONFIGURED_SOURCE, "sh", &["-c", command], port)
+            .env("NODE_ENV", env.name()),
+        None => detect_dev_server(stack, port, env, root)?,
+    };
+    server.envs.extend(config.environment(env.name()).env_vars);
+    server.dir = root.to_path_buf();
+    Ok(server)
 }
 
 /// Get default port for tech stack
@@ -66,8 +178,8 @@ fn get_default_port(stack: &TechStack) -> u16 {
     }
 }
 
-/// Run pre-start checks
-fn run_prestart_checks(stack: &TechStack) -> Result<()> {
+/// Run pre-start checks against the project at `root`
+fn run_prestart_checks(stack: &TechStack, root: &Path) -> Result<()> {
     let pb = ProgressBar::new_spinner();
     pb.set_style(
         ProgressStyle::default_spinner()
@@ -82,7 +194,7 @@ fn run_prestart_checks(stack: &TechStack) -> Result<()> {
             println!("  {} Node.js available", "✓".green());
 
             pb.set_message("Checking dependencies...");
-            check_dependencies()?;
+            check_dependencies(root)?;
             println!("  {} Dependencies installed", "✓".green());
         }
         TechStack::Api => {
@@ -92,7 +204,7 @@ fn run_prestart_checks(stack: &TechStack) -> Result<()> {
         }
         TechStack::Mobile => {
             pb.set_message("Checking mobile development tools...");
-            check_mobile_tools()?;
+            check_mobile_tools(root)?;
             println!("  {} Mobile tools available", "✓".green());
         }
         TechStack::DataScience => {
@@ -108,9 +220,7 @@ fn run_prestart_checks(stack: &TechStack) -> Result<()> {
 
 /// Check Node.js installation
 fn check_node_installation() -> Result<()> {
-    let output = Command::new("node")
-        .arg("--version")
-        .output()
+    let output = run_with_timeout("node", &["--version"], configured_timeout())
         .map_err(|_| CldevError::command("Node.js not found. Please install Node.js"))?;
 
     if !output.status.success() {
@@ -120,17 +230,15 @@ fn check_node_installation() -> Result<()> {
     Ok(())
 }
 
-/// Check if dependencies are installed
-fn check_dependencies() -> Result<()> {
-    if std::path::Path::new("package.json").exists()
-        && !std::path::Path::new("node_modules").exists()
-    {
+/// Check if dependencies are installed, installing them with npm if not
+fn check_dependencies(root: &Path) -> Result<()> {
+    if root.join("package.json").exists() && !root.join("node_modules").exists() {
         println!("  {} Installing dependencies...", "→".cyan());
-        let status = Command::new("npm").arg("install").status()?;
-
-        if !status.success() {
-            return Err(CldevError::command("Failed to install dependencies"));
-        }
+        // A first install can take far longer than any command timeout
+        let mut cmd = Command::new("npm");
+        cmd.arg("install").current_dir(root);
+        let status = cmd.status()?;
+        ensure_success(&cmd, status)?;
     }
 
     Ok(())
@@ -146,8 +254,9 @@ fn check_api_runtime() -> Result<()> {
         ("cargo", "--version"),
     ];
 
+    let timeout = configured_timeout();
     for (cmd, arg) in runtimes {
-        if Command::new(cmd).arg(arg).output().is_ok() {
+        if run_with_timeout(cmd, &[arg], timeout).is_ok() {
             return Ok(());
         }
     }
@@ -158,12 +267,11 @@ fn check_api_runtime() -> Result<()> {
 }
 
 /// Check mobile development tools
-fn check_mobile_tools() -> Result<()> {
+fn check_mobile_tools(root: &Path) -> Result<()> {
     // Check for React Native or Flutter
-    let has_react_native =
-        std::path::Path::new("package.json").exists() && std::path::Path::new("app.json").exists();
+    let has_react_native = root.join("package.json").exists() && root.join("app.json").exists();
 
-    let has_flutter = std::path::Path::new("pubspec.yaml").exists();
+    let has_flutter = root.join("pubspec.yaml").exists();
 
     if !has_react_native && !has_flutter {
         return Err(CldevError::command(
@@ -172,9 +280,7 @@ fn check_mobile_tools() -> Result<()> {
     }
 
     if has_flutter {
-        Command::new("flutter")
-            .arg("--version")
-            .output()
+        run_with_timeout("flutter", &["--version"], configured_timeout())
             .map_err(|_| CldevError::command("Flutter not found. Please install Flutter SDK"))?;
     }
 
@@ -183,9 +289,7 @@ fn check_mobile_tools() -> Result<()> {
 
 /// Check Jupyter installation
 fn check_jupyter_installation() -> Result<()> {
-    let output = Command::new("jupyter")
-        .arg("--version")
-        .output()
+    let output = run_with_timeout("jupyter", &["--version"], configured_timeout())
         .map_err(|_| CldevError::command("Jupyter not found. Install with: pip install jupyter"))?;
 
     if !output.status.success() {
@@ -195,163 +299,144 @@ fn check_jupyter_installation() -> Result<()> {
     Ok(())
 }
 
-/// Start development server based on tech stack
-fn start_development_server(stack: &TechStack, port: u16, env: Environment) -> Result<()> {
-    match stack {
-        TechStack::Web => start_web_dev_server(port, env),
-        TechStack::Api => start_api_dev_server(port, env),
-        TechStack::Mobile => start_mobile_dev_server(port),
-        TechStack::DataScience => start_jupyter_server(port),
-    }
-}
-
-/// Start web development server
-fn start_web_dev_server(port: u16, env: Environment) -> Result<()> {
-    println!(
-        "  {} Starting web dev server on port {}...",
-        "→".cyan(),
-        port
-    );
-
-    let env_name = match env {
-        Environment::Development => "development",
-        Environment::Staging => "staging",
-        Environment::Production => "production",
-    };
-
-    // Check for common web frameworks
-    if std::path::Path::new("next.config.js").exists()
-        || std::path::Path::new("next.config.ts").exists()
-    {
-        // Next.js
-        println!("  {} Detected Next.js project", "→".cyan());
-        Command::new("npm")
-            .args(["run", "dev", "--", "-p", &port.to_string()])
-            .env("NODE_ENV", env_name)
-            .spawn()?;
-    } else if std::path::Path::new("vite.config.js").exists()
-        || std::path::Path::new("vite.config.ts").exists()
-    {
-        // Vite
-        println!("  {} Detected Vite project", "→".cyan());
-        Command::new("npm")
-            .args(["run", "dev", "--", "--port", &port.to_string()])
-            .env("NODE_ENV", env_name)
-            .spawn()?;
-    } else if std::path::Path::new("package.json").exists() {
-        // Generic npm project
-        Command::new("npm")
-            .args(["run", "dev"])
-            .env("PORT", port.to_string())
-            .env("NODE_ENV", env_name)
-            .spawn()?;
-    } else {
-        return Err(CldevError::command("No web framework detected"));
-    }
-
-    println!("  {} Web server starting...", "✓".green());
-    Ok(())
-}
-
-/// Start API development server
-fn start_api_dev_server(port: u16, env: Environment) -> Result<()> {
-    println!("  {} Starting API server on port {}...", "→".cyan(), port);
-
-    let env_name = match env {
-        Environment::Development => "development",
-        Environment::Staging => "staging",
-        Environment::Production => "production",
-    };
-
-    // Try different API frameworks
-    if std::path::Path::new("main.py").exists() || std::path::Path::new("app.py").exists() {
-        // Python (FastAPI/Flask)
-        if std::path::Path::new("requirements.txt").exists() {
-            let content = std::fs::read_to_string("requirements.txt")?;
-            if content.contains("fastapi") {
-                println!("  {} Detected FastAPI project", "→".cyan());
-                Command::new("uvicorn")
-                    .args(["main:app", "--reload", "--port", &port.to_string()])
+/// Detect the dev server for the project files at `root`
+fn detect_dev_server(
+    stack: TechStack,
+    port: u16,
+    env: Environment,
+    root: &Path,
+) -> Result<DevServer> {
+    let env_name = env.name();
+    let port_arg = port.to_string();
+    let exists = |file: &str| root.join(file).exists();
+
+    let server = match stack {
+        TechStack::Web => if exists("next.config.js") || exists("next.config.ts") {
+            DevServer::new(
+                "Next.js",
+                "npm",
+                &["run", "dev", "--", "-p", &port_arg],
+                port,
+            )
+        } else if exists("vite.config.js") || exists("vite.config.ts") {
+            DevServer::new(
+                "Vite",
+                "npm",
+                &["run", "dev", "--", "--port", &port_arg],
+                port,
+            )
+        } else if exists("package.json") {
+            DevServer::new("npm", "npm", &["run", "dev"], port)
+        } else {
+            return Err(CldevError::command("No web framework detected"));
+        }
+        .env("NODE_ENV", env_name),
+        TechStack::Api => {
+            if exists("main.py") || exists("app.py") {
+                let requirements =
+                    std::fs::read_to_string(root.join("requirements.txt")).unwrap_or_default();
+                if requirements.contains("fastapi") {
+                    DevServer::new(
+                        "FastAPI",
+                        "uvicorn",
+                        &["main:app", "--reload", "--port", &port_arg],
+                        port,
+                    )
                     .env("ENVIRONMENT", env_name)
-                    .spawn()?;
-            } else if content.contains("flask") {
-                println!("  {} Detected Flask project", "→".cyan());
-                Command::new("flask")
-                    .args(["run", "--port", &port.to_string()])
-                    .env("FLASK_ENV", env_name)
-                    .spawn()?;
+                } else if requirements.contains("flask") {
+                    DevServer::new("Flask", "flask", &["run", "--port", &port_arg], port)
+                        .env("FLASK_ENV", env_name)
+                } else {
+                    return Err(CldevError::command(
+                        "No Python API framework detected in requirements.txt (expected fastapi or flask)",
+                    ));
+                }
+            } else if exists("package.json") {
+                DevServer::new("Node.js", "npm", &["run", "dev"], port).env("NODE_ENV", env_name)
+            } else if exists("Cargo.toml") {
+                DevServer::new("Rust", "cargo", &["run"], port).env("RUST_ENV", env_name)
+            } else if exists("go.mod") {
+                DevServer::new("Go", "go", &["run", "."], port)
+            } else {
+                return Err(CldevError::command("No API project detected"));
             }
         }
-    } else if std::path::Path::new("package.json").exists() {
-        // Node.js API
-        Command::new("npm")
-            .args(["run", "dev"])
-            .env("PORT", port.to_string())
-            .env("NODE_ENV", env_name)
-            .spawn()?;
-    } else if std::path::Path::new("Cargo.toml").exists() {
-        // Rust API
-        println!("  {} Detected Rust project", "→".cyan());
-        Command::new("cargo")
-            .args(["run"])
-            .env("PORT", port.to_string())
-            .env("RUST_ENV", env_name)
-            .spawn()?;
-    } else if std::path::Path::new("go.mod").exists() {
-        // Go API
-        println!("  {} Detected Go project", "→".cyan());
-        Command::new("go")
-            .args(["run", "."])
-            .env("PORT", port.to_string())
-            .spawn()?;
-    }
+        TechStack::Mobile => {
+            if exists("pubspec.yaml") {
+                DevServer::new("Flutter", "flutter", &["run"], port)
+            } else if exists("app.json") {
+                DevServer::new(
+                    "React Native",
+                    "npx",
+                    &["react-native", "start", "--port", &port_arg],
+                    port,
+                )
+            } else {
+                return Err(CldevError::command("No mobile project detected"));
+            }
+        }
+        TechStack::DataScience => DevServer::new(
+            "Jupyter",
+            "jupyter",
+            &["lab", "--port", &port_arg, "--no-browser"],
+            port,
+        ),
+    };
 
-    println!("  {} API server starting...", "✓".green());
-    Ok(())
+    Ok(server)
 }
 
-/// Start mobile development server
-fn start_mobile_dev_server(port: u16) -> Result<()> {
-    println!(
-        "  {} Starting mobile dev server on port {}...",
-        "→".cyan(),
-        port
-    );
-
-    if std::path::Path::new("pubspec.yaml").exists() {
-        // Flutter
-        println!("  {} Starting Flutter development...", "→".cyan());
-        println!("  {} Run: flutter run", "→".cyan());
-    } else if std::path::Path::new("app.json").exists() {
-        // React Native
-        println!("  {} Starting React Native Metro bundler...", "→".cyan());
-        Command::new("npx")
-            .args(["react-native", "start", "--port", &port.to_string()])
-            .stdout(Stdio::inherit())
-            .stderr(Stdio::inherit())
-            .spawn()?;
+/// Fail with a suggested free port when `port` is already taken on localhost
+fn ensure_port_available(port: u16) -> Result<()> {
+    match TcpListener::bind(("127.0.0.1", port)) {
+        Ok(_) => Ok(()),
+        Err(e) if e.kind() == ErrorKind::AddrInUse => {
+            let suggestion = (port.saturating_add(1)..=port.saturating_add(PORT_SUGGESTION_RANGE))
+                .find(|&candidate| TcpListener::bind(("127.0.0.1", candidate)).is_ok());
+            Err(CldevError::validation(match suggestion {
+                Some(free) => format!(
+                    "Port {} is already in use. Stop the process using it or run with --port {}",
+                    port, free
+                ),
+                None => format!(
+                    "Port {} is already in use. Stop the process using it or choose another --port",
+                    port
+                ),
+            }))
+        }
+        // Other bind errors (e.g. privileged ports) are left for the server to report
+        Err(_) => Ok(()),
     }
-
-    println!("  {} Mobile dev server starting...", "✓".green());
-    Ok(())
 }
 
-/// Start Jupyter server
-fn start_jupyter_server(port: u16) -> Result<()> {
-    println!(
-        "  {} Starting Jupyter server on port {}...",
-        "→".cyan(),
-        port
-    );
-
-    Command::new("jupyter")
-        .args(["lab", "--port", &port.to_string(), "--no-browser"])
-        .stdout(Stdio::inherit())
-        .stderr(Stdio::inherit())
-        .spawn()?;
-
-    println!("  {} Jupyter server starting...", "✓".green());
-    Ok(())
+/// Run the dev server in the foreground, streaming its output until it exits
+///
+/// Readiness is reported from a background thread polling the health URL
+/// for up to `performance.timeout_seconds`, so a server that never answers
+/// only produces a warning.
+fn run_dev_server(server: &DevServer) -> Result<()> {
+    let mut cmd = Command::new(&server.program);
+    cmd.args(&server.args)
+        .envs(server.envs.iter().map(|(k, v)| (k, v)))
+        .current_dir(&server.dir);
+    let mut child = cmd
+        .spawn()
+        .map_err(|e| CldevError::command(format!("Failed to start '{}': {}", server.program, e)))?;
+
+    let health_url = server.health_url.clone();
+    let timeout = configured_timeout();
+    thread::spawn(move || match wait_for_http(&health_url, timeout) {
+        Ok(elapsed) => println!(
+            "  {} Server ready at {} ({:.1}s)",
+            "✓".green(),
+            health_url,
+            elapsed.as_secs_f64()
+        ),
+        Err(e) => println!("  {} {}", "⚠️".yellow(), e),
+    });
+
+    let status = child.wait()?;
+    ensure_success(&cmd, status)
 }
 
 /// Display access information
@@ -380,6 +465,9 @@ fn display_access_info(stack: &TechStack, port: u16) {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::cell::RefCell;
+    use std::fs;
+    use tempfile::TempDir;
 
     #[test]
     fn test_default_ports() {
@@ -388,4 +476,162 @@ mod tests {
         assert_eq!(get_default_port(&TechStack::Mobile), 8081);
         assert_eq!(get_default_port(&TechStack::DataScience), 8888);
     }
+
+    /// Free port on localhost, released before returning
+    fn free_port() -> u16 {
+        TcpListener::bind(("127.0.0.1", 0))
+            .unwrap()
+            .local_addr()
+            .unwrap()
+            .port()
+    }
+
+    #[test]
+    fn test_start_runs_configured_dev_command_on_project_port() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        let port = free_port();
+        fs::create_dir(root.join(".cldev")).unwrap();
+        fs::write(
+            root.join(".cldev/config.toml"),
+            format!(
+                "[dev]\nport = {}\n\n[commands]\ndev = \"npm run serve\"\n\n\
+                 [environments.staging.env_vars]\nAPI_URL = \"https://staging.example.com\"\n",
+                port
+            ),
+        )
+        .unwrap();
+
+        let started = RefCell::new(None);
+        start(
+            TechStack::Web,
+            None,
+            Environment::Staging,
+            None,
+            root,
+            |server| {
+                started.replace(Some(server.clone()));
+                Ok(())
+            },
+        )
+        .unwrap();
+
+        let server = started.into_inner().unwrap();
+        assert_eq!(server.command_line(), "sh -c npm run serve");
+        assert_eq!(server.port, port);
+        assert!(server
+            .envs
+            .contains(&("PORT".to_string(), port.to_string())));
+        assert!(server
+            .envs
+            .contains(&("NODE_ENV".to_string(), "staging".to_string())));
+        assert!(server.envs.contains(&(
+            "API_URL".to_string(),
+            "https://staging.example.com".to_string()
+        )));
+    }
+
+    #[test]
+    fn test_detected_server_gets_port_flag() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        fs::write(root.join("package.json"), "{}").unwrap();
+        fs::write(root.join("vite.config.ts"), "").unwrap();
+        let config = Config::load_hierarchical(Some(root.to_path_buf())).unwrap();
+
+        let server = resolve_dev_server(
+            TechStack::Web,
+            Some(5173),
+            Environment::Development,
+            root,
+            &config,
+        )
+        .unwrap();
+
+        assert_eq!(server.source, "Vite");
+        assert_eq!(server.command_line(), "npm run dev -- --port 5173");
+        assert_eq!(server.dir, root);
+        assert!(server
+            .envs
+            .contains(&("NODE_ENV".to_string(), "development".to_string())));
+    }
+
+    #[test]
+    fn test_health_url_flag_overrides_default() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        fs::create_dir(root.join(".cldev")).unwrap();
+        fs::write(
+            root.join(".cldev/config.toml"),
+            "[commands]\ndev = \"./serve\"\n",
+        )
+        .unwrap();
+        let port = free_port();
+
+        let started = RefCell::new(None);
+        start(
+            TechStack::Api,
+            Some(port),
+            Environment::Development,
+            Some("http://localhost:9000/healthz"),
+            root,
+            |server| {
+                started.replace(Some(server.health_url.clone()));
+                Ok(())
+            },
+        )
+        .unwrap();
+
+        assert_eq!(
+            started.into_inner().unwrap(),
+            "http://localhost:9000/healthz"
+        );
+    }
+
+    #[test]
+    fn test_prestart_checks_read_project_root() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        fs::write(root.join("package.json"), "{}").unwrap();
+        fs::write(root.join("app.json"), "{}").unwrap();
+        fs::create_dir(root.join("node_modules")).unwrap();
+
+        // The working directory has none of these files
+        assert!(check_mobile_tools(root).is_ok());
+        assert!(check_dependencies(root).is_ok());
+    }
+
+    #[test]
+    fn test_port_in_use_is_reported_with_suggestion() {
+        let listener = TcpListener::bind(("127.0.0.1", 0)).unwrap();
+        let port = listener.local_addr().unwrap().port();
+
+        let err = ensure_port_available(port).unwrap_err().to_string();
+
+        assert!(
+            err.contains(&format!("Port {} is already in use", port)),
+            "{}",
+            err
+        );
+        assert!(err.contains("--port"), "{}", err);
+    }
+
+    #[test]
+    fn test_runner_is_not_called_when_port_is_busy() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(temp_dir.path().join("package.json"), "{}").unwrap();
+        let listener = TcpListener::bind(("127.0.0.1", 0)).unwrap();
+        let port = listener.local_addr().unwrap().port();
+
+        let result = start(
+            TechStack::Web,
+            Some(port),
+            Environment::Development,
+            None,
+            temp_dir.path(),
+            |_| panic!("runner must not be called"),
+        );
+
+        assert!(matches!(result, Err(CldevError::Validation(_))));
+    }
 }
@@ -0,0 +1,262 @@
+//! GitHub issue sync for `todo sync`
+//!
+//! Pending todos tagged `#issue` are opened as GitHub issues and linked by
+//! number. Linked todos are completed once their issue is closed.
+
+use super::manage::{TaskStatus, TodoItem};
+use crate::core::error::{CldevError, Result};
+use crate::core::git_utils::{check_gh_cli, GitUtils, RemoteType};
+use crate::core::security::output_checked;
+use crate::core::Config;
+
+/// Tag that marks a todo to be tracked as a GitHub issue
+pub const ISSUE_TAG: &str = "issue";
+
+/// State of a GitHub issue
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IssueState {
+    Open,
+    Closed,
+}
+
+/// Issue operations used by the sync
+pub trait IssueTracker {
+    /// Create an issue and return its number
+    fn create_issue(&self, title: &str, body: &str) -> Result<u64>;
+
+    /// Look up whether an issue is open or closed
+    fn issue_state(&self, number: u64) -> Result<IssueState>;
+}
+
+/// [`IssueTracker`] backed by the GitHub CLI (`gh`)
+pub struct GhCli;
+
+impl GhCli {
+    fn run(args: &[&str]) -> Result<String> {
+        let output = output_checked("gh", args)
+            .map_err(|e| CldevError::command(format!("Failed to run gh: {}", e)))?;
+
+        if !output.status.success() {
+            return Err(CldevError::command(format!(
+                "gh {} failed: {}",
+                args.first().copied().unwrap_or_default(),
+                String::from_utf8_lossy(&output.stderr).trim()
+            )));
+        }
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    }
+}
+
+impl IssueTracker for GhCli {
+    fn create_issue(&self, title: &str, body: &str) -> Result<u64> {
+        let stdout = Self::run(&["issue", "create", "--title", title, "--body", body])?;
+        parse_issue_number(&stdout).ok_or_else(|| {
+            CldevError::command(format!(
+                "Could not read the issue number from gh output: {}",
+                stdout.trim()
+            ))
+        })
+    }
+
+    fn issue_state(&self, number: u64) -> Result<IssueState> {
+        let stdout = Self::run(&["issue", "view", &number.to_string(), "--json", "state"])?;
+        let json: serde_json::Value = serde_json::from_str(&stdout)?;
+
+        match json["state"].as_str() {
+            Some("CLOSED") => Ok(IssueState::Closed),
+            Some(_) => Ok(IssueState::Open),
+            None => Err(CldevError::command(format!(
+                "Unexpected gh issue view output: {}",
+                stdout.trim()
+            ))),
+        }
+    }
+}
+
+/// Counts from a GitHub issue sync
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct SyncReport {
+    /// Issues created for `#issue` todos
+    pub created: usize,
+    /// Todos completed because their issue was closed
+    pub closed: usize,
+    /// Linked todos whose issue is still open
+    pub open: usize,
+    /// Why a todo's issue could not be created or looked up, one entry per todo
+    pub failures: Vec<String>,
+}
+
+/// Whether `todo sync` should sync with GitHub issues
+///
+/// Requires `[git].github_cli`, a GitHub `origin` remote, and a working `gh`.
+pub fn github_sync_enabled() -> bool {
+    let enabled = Config::load(None)
+        .map(|c| c.git.github_cli)
+        .unwrap_or(false);
+
+    enabled
+        && GitUtils::open_current()
+            .and_then(|git| git.detect_remote_type("origin"))
+            .is_ok_and(|remote| remote == RemoteType::GitHub)
+        && check_gh_cli().unwrap_or(false)
+}
+
+/// Sync pending todos with GitHub issues
+///
+/// Creates an issue for each pending `#issue` todo that has none yet and
+/// completes linked todos whose issue is closed. A todo that fails is
+/// reported in [`SyncReport::failures`] and does not stop the sync.
+pub fn sync_github(todos: &mut [TodoItem], tracker: &dyn IssueTracker) -> Result<SyncReport> {
+    let mut report = SyncReport::default();
+
+    for todo in todos.iter_mut() {
+        if todo.status == TaskStatus::Completed {
+            continue;
+        }
+
+        match todo.issue {
+            Some(number) => match tracker.issue_state(number) {
+                Ok(IssueState::Closed) => {
+                    todo.status = TaskStatus::Completed;
+                    todo.completed_at = Some(chrono::Local::now().format("%Y-%m-%d").to_string());
+                    report.closed += 1;
+                }
+                Ok(IssueState::Open) => report.open += 1,
+                Err(e) => report
+                    .failures
+                    .push(format!("Failed to check issue #{}: {}", number, e)),
+            },
+            None if todo.tags.iter().any(|t| t == ISSUE_TAG) => {
+                match tracker.create_issue(&todo.description, &issue_body(todo)) {
+                    Ok(number) => {
+                        todo.issue = Some(number);
+                        report.created += 1;
+                    }
+                    Err(e) => report.failures.push(format!(
+                        "Failed to create issue for '{}': {}",
+                        todo.description, e
+                    )),
+                }
+            }
+            None => {}
+        }
+    }
+
+    Ok(report)
+}
+
+/// Issue body for a todo
+fn issue_body(todo: &TodoItem) -> String {
+    let mut body = String::from("Created from the cldev todo list.");
+    if let Some(due) = &todo.due_date {
+        body.push_str(&format!("\n\nDue: {}", due));
+    }
+    body
+}
+
+/// Parse the issue number from `gh issue create` output (the issue URL)
+fn parse_issue_number(stdout: &str) -> Option<u64> {
+    stdout
+        .lines()
+        .rev()
+        .find_map(|line| line.trim().rsplit_once("/issues/"))
+        .and_then(|(_, number)| number.parse().ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::todo::manage::Priority;
+    use std::cell::RefCell;
+    use std::collections::HashMap;
+
+    /// Tracker that records created issues and serves fixed issue states
+    struct MockTracker {
+        states: HashMap<u64, IssueState>,
+        next_number: u64,
+        created: RefCell<Vec<String>>,
+    }
+
+    impl IssueTracker for MockTracker {
+        fn create_issue(&self, title: &str, _body: &str) -> Result<u64> {
+            let mut created = self.created.borrow_mut();
+            created.push(title.to_string());
+            Ok(self.next_number + created.len() as u64 - 1)
+        }
+
+        fn issue_state(&self, number: u64) -> Result<IssueState> {
+            self.states
+                .get(&number)
+                .copied()
+                .ok_or_else(|| CldevError::command(format!("issue {} not found", number)))
+        }
+    }
+
+    fn todo(description: &str, tags: &[&str], issue: Option<u64>) -> TodoItem {
+        TodoItem {
+            description: description.to_string(),
+            status: TaskStatus::Pending,
+            priority: Priority::Medium,
+            due_date: None,
+            tags: tags.iter().map(|t| t.to_string()).collect(),
+            created_at: "2025-01-01".to_string(),
+            completed_at: None,
+            issue,
+            source: None,
+            line_number: 0,
+        }
+    }
+
+    #[test]
+    fn test_sync_github_report_counts() {
+        let tracker = MockTracker {
+            states: HashMap::from([(10, IssueState::Closed), (11, IssueState::Open)]),
+            next_number: 20,
+            created: RefCell::new(Vec::new()),
+        };
+
+        let mut done = todo("Already done", &["issue"], None);
+        done.status = TaskStatus::Completed;
+
+        let mut todos = vec![
+            todo("Track rate limiting", &["issue", "api"], None),
+            todo("Fixed upstream", &["issue"], Some(10)),
+            todo("Still open", &["issue"], Some(11)),
+            todo("Missing issue", &["issue"], Some(99)),
+            todo("Local only", &["refactor"], None),
+            done,
+        ];
+
+        let report = sync_github(&mut todos, &tracker).unwrap();
+        assert_eq!(
+            report,
+            SyncReport {
+                created: 1,
+                closed: 1,
+                open: 1,
+                failures: vec![
+                    "Failed to check issue #99: Command execution error: issue 99 not found"
+                        .to_string()
+                ],
+            }
+        );
+
+        assert_eq!(*tracker.created.borrow(), ["Track rate limiting"]);
+        assert_eq!(todos[0].issue, Some(20));
+        assert_eq!(todos[1].status, TaskStatus::Completed);
+        assert!(todos[1].completed_at.is_some());
+        assert_eq!(todos[2].status, TaskStatus::Pending);
+        assert_eq!(todos[4].issue, None);
+    }
+
+    #[test]
+    fn test_parse_issue_number() {
+        assert_eq!(
+            parse_issue_number(
+                "Creating issue in user/repo\n\nhttps://github.com/user/repo/issues/42\n"
+            ),
+            Some(42)
+        );
+        assert_eq!(parse_issue_number("no url"), None);
+    }
+}
@@ -0,0 +1,169 @@
+//! Import `TODO`/`FIXME`/`HACK` comments from source into the todo list
+//!
+//! Each imported todo records a content hash of its comment, so re-running
+//! the import skips comments that were already imported even if they moved
+//! to another line.
+
+use super::manage::{Priority, TodoList};
+use crate::commands::analysis::{scan_technical_debt, source_files};
+use crate::core::Result;
+use colored::Colorize;
+use std::collections::HashSet;
+use std::path::Path;
+
+/// Handle todo import command
+pub fn handle_import() -> Result<()> {
+    println!("{}", "📥 Importing TODO comments...".cyan().bold());
+
+    let imported = import_from_source(&std::env::current_dir()?)?;
+    if imported == 0 {
+        println!("{}", "\n✅ No new TODO comments found".green());
+    } else {
+        println!(
+            "{}",
+            format!("\n✅ Imported {} todo(s) from source", imported).green()
+        );
+    }
+
+    Ok(())
+}
+
+/// Import debt comments from source files below `root` into the todo list
+///
+/// Returns the number of todos added.
+pub fn import_from_source(root: &Path) -> Result<usize> {
+    let mut todo_list = TodoList::load()?;
+    let imported = import_into(&mut todo_list, root);
+    if imported > 0 {
+        todo_list.save()?;
+    }
+    Ok(imported)
+}
+
+/// Add todos for comments below `root` that the list does not contain yet
+fn import_into(todo_list: &mut TodoList, root: &Path) -> usize {
+    let mut known: HashSet<String> = todo_list
+        .todos
+        .iter()
+        .filter_map(|t| t.source.clone())
+        .collect();
+    let mut imported = 0;
+
+    for path in source_files(root) {
+        let Ok(content) = std::fs::read_to_string(&path) else {
+            continue;
+        };
+        let file = path
+            .strip_prefix(root)
+            .unwrap_or(&path)
+            .to_string_lossy()
+            .replace('\\', "/");
+
+        for item in scan_technical_debt(&file, &content) {
+            let marker = item.description.split_whitespace().next().unwrap_or("TODO");
+            let hash = content_hash(&format!("{}\0{}\0{}", file, marker, item.comment));
+            if !known.insert(hash.clone()) {
+                continue;
+            }
+
+            let priority = match item.priority.as_str() {
+                "high" => Priority::High,
+                "medium" => Priority::Medium,
+                _ => Priority::Low,
+            };
+            let text = if item.comment.is_empty() {
+                marker.to_string()
+            } else {
+                item.comment.clone()
+            };
+
+            todo_list.add_todo(
+                format!("{} ({}:{})", text, file, item.line),
+                priority,
+                None,
+                vec![marker.to_lowercase()],
+            );
+            if let Some(todo) = todo_list.todos.last_mut() {
+                todo.source = Some(hash);
+            }
+            imported += 1;
+        }
+    }
+
+    imported
+}
+
+/// Stable 64-bit FNV-1a hash, hex-encoded
+///
+/// Hashes are persisted in the todo file, so they must not change between
+/// builds the way `std`'s `DefaultHasher` may.
+fn content_hash(text: &str) -> String {
+    const OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0100_0000_01b3;
+
+    let hash = text.bytes().fold(OFFSET, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(PRIME)
+    });
+    format!("{:016x}", hash)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_import_from_source_deduplicates() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        fs::create_dir_all(root.join("src")).unwrap();
+        fs::create_dir_all(root.join("target")).unwrap();
+        fs::write(
+            root.join("src/main.rs"),
+            "fn main() {\n    // TODO: handle errors\n    run();\n}\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("src/lib.rs"),
+            "// FIXME(sam): parser drops trailing newline\npub fn parse() {}\n",
+        )
+        .unwrap();
+        fs::write(root.join("target/gen.rs"), "// TODO: generated\n").unwrap();
+
+        let todo_file = root.join("todo.md");
+        let mut todo_list = TodoList::load_from(todo_file.clone()).unwrap();
+        assert_eq!(import_into(&mut todo_list, root), 2);
+        todo_list.save().unwrap();
+
+        let descriptions: Vec<&str> = todo_list
+            .todos
+            .iter()
+            .map(|t| t.description.as_str())
+            .collect();
+        assert_eq!(
+            descriptions,
+            [
+                "parser drops trailing newline (src/lib.rs:1)",
+                "handle errors (src/main.rs:2)",
+            ]
+        );
+        assert_eq!(todo_list.todos[0].priority, Priority::High);
+
+        // Re-running against the saved list imports nothing, even after lines move
+        fs::write(
+            root.join("src/main.rs"),
+            "fn main() {\n    setup();\n    // TODO: handle errors\n    run();\n}\n",
+        )
+        .unwrap();
+        let mut reloaded = TodoList::load_from(todo_file).unwrap();
+        assert_eq!(import_into(&mut reloaded, root), 0);
+        assert_eq!(reloaded.todos.len(), 2);
+    }
+
+    #[test]
+    fn test_content_hash_is_stable() {
+        assert_eq!(content_hash(""), "cbf29ce484222325");
+        assert_eq!(content_hash("a"), "af63dc4c8601ec8c");
+    }
+}
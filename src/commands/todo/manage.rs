@@ -1,3 +1,6 @@
+use super::github::{github_sync_enabled, sync_github, GhCli};
+use crate::cli::args::TodoPriority;
+use crate::core::config::home_dir;
 use crate::core::{CldevError, Result};
 use colored::Colorize;
 use dialoguer::{theme::ColorfulTheme, Input, Select};
@@ -75,6 +78,17 @@ impl Priority {
     }
 }
 
+impl From<TodoPriority> for Priority {
+    fn from(priority: TodoPriority) -> Self {
+        match priority {
+            TodoPriority::Low => Priority::Low,
+            TodoPriority::Medium => Priority::Medium,
+            TodoPriority::High => Priority::High,
+            TodoPriority::Critical => Priority::Critical,
+        }
+    }
+}
+
 /// Todo item parsed from Markdown
 #[derive(Debug, Clone)]
 pub struct TodoItem {
@@ -85,6 +99,10 @@ pub struct TodoItem {
     pub tags: Vec<String>,
     pub created_at: String,
     pub completed_at: Option<String>,
+    /// Linked GitHub issue number, set by `todo sync`
+    pub issue: Option<u64>,
+    /// Content hash of the source comment, set by `todo import`
+    pub source: Option<String>,
     #[allow(dead_code)]
     pub line_number: usize,
 }
@@ -98,8 +116,11 @@ pub struct TodoList {
 impl TodoList {
     /// Load todo list from Markdown file
     pub fn load() -> Result<Self> {
-        let path = Self::get_todo_file_path()?;
+        Self::load_from(Self::get_todo_file_path()?)
+    }
 
+    /// Load todo list from a specific Markdown file
+    pub fn load_from(path: PathBuf) -> Result<Self> {
         if !path.exists() {
             return Ok(Self {
                 todos: Vec::new(),
@@ -127,6 +148,8 @@ impl TodoList {
         let due_re = Regex::new(r"\|\s*Due:\s*([0-9-]+)").unwrap();
         let created_re = Regex::new(r"\|\s*Created:\s*([0-9-]+)").unwrap();
         let completed_re = Regex::new(r"\|\s*Completed:\s*([0-9-]+)").unwrap();
+        let issue_re = Regex::new(r"\|\s*Issue:\s*([0-9]+)").unwrap();
+        let source_re = Regex::new(r"\|\s*Source:\s*([0-9a-f]+)").unwrap();
 
         for (line_no, line) in content.lines().enumerate() {
             // Parse checkbox line
@@ -161,6 +184,18 @@ impl TodoList {
                     .and_then(|cap| cap.get(1))
                     .map(|m| m.as_str().to_string());
 
+                // Extract linked issue (optional)
+                let issue = issue_re
+                    .captures(text)
+                    .and_then(|cap| cap.get(1))
+                    .and_then(|m| m.as_str().parse().ok());
+
+                // Extract source comment hash (optional)
+                let source = source_re
+                    .captures(text)
+                    .and_then(|cap| cap.get(1))
+                    .map(|m| m.as_str().to_string());
+
                 // Extract tags (#hashtag format)
                 let tags: Vec<String> = tag_re
                     .captures_iter(text)
@@ -181,6 +216,8 @@ impl TodoList {
                     tags,
                     created_at,
                     completed_at,
+                    issue,
+                    source,
                     line_number: line_no + 1,
                 });
             }
@@ -239,6 +276,14 @@ impl TodoList {
                 metadata.push(format!("Completed: {}", completed));
             }
 
+            if let Some(issue) = item.issue {
+                metadata.push(format!("Issue: {}", issue));
+            }
+
+            if let Some(source) = &item.source {
+                metadata.push(format!("Source: {}", source));
+            }
+
             let metadata_str = metadata.join(" | ");
 
             // Build tags string
@@ -284,10 +329,7 @@ impl TodoList {
         }
 
         // Fall back to global
-        let home =
-            dirs::home_dir().ok_or_else(|| CldevError::config("Failed to get home directory"))?;
-
-        let global_dir = home.join(".claude").join("todos");
+        let global_dir = home_dir()?.join(".claude").join("todos");
         if !global_dir.exists() {
             fs::create_dir_all(&global_dir)?;
         }
@@ -296,17 +338,25 @@ impl TodoList {
     }
 
     /// Add a new todo item
-    pub fn add_todo(&mut self, description: String, priority: Priority, tags: Vec<String>) {
+    pub fn add_todo(
+        &mut self,
+        description: String,
+        priority: Priority,
+        due_date: Option<String>,
+        tags: Vec<String>,
+    ) {
         let created_at = chrono::Local::now().format("%Y-%m-%d").to_string();
 
         let todo = TodoItem {
             description,
             status: TaskStatus::Pending,
             priority,
-            due_date: None,
+            due_date,
             tags,
             created_at,
             completed_at: None,
+            issue: None,
+            source: None,
             line_number: 0,
         };
 
@@ -347,6 +397,19 @@ impl TodoList {
             .collect()
     }
 
+    /// Get pending todos ordered by priority (highest first), then due date
+    ///
+    /// Todos without a due date come last within their priority.
+    pub fn sorted_pending(&self) -> Vec<&TodoItem> {
+        let mut pending = self.get_pending();
+        pending.sort_by(|a, b| {
+            priority_rank(&b.priority)
+                .cmp(&priority_rank(&a.priority))
+                .then_with(|| due_date_rank(&a.due_date).cmp(&due_date_rank(&b.due_date)))
+        });
+        pending
+    }
+
     /// Sync with git commits to auto-complete todos
     pub fn sync_with_git(&mut self) -> Result<usize> {
         use std::process::Command;
@@ -394,9 +457,16 @@ impl TodoList {
 }
 
 /// Add a new todo
-pub fn add_todo(description: Option<String>) -> Result<()> {
+///
+/// `priority` skips the priority prompt. `due` must be a `YYYY-MM-DD` date.
+pub fn add_todo(
+    description: Option<String>,
+    priority: Option<Priority>,
+    due: Option<String>,
+) -> Result<()> {
     println!("{}", "➕ Add New Todo".cyan().bold());
 
+    let due_date = due.as_deref().map(parse_due_date).transpose()?;
     let mut todo_list = TodoList::load()?;
 
     // Get description
@@ -408,19 +478,24 @@ pub fn add_todo(description: Option<String>) -> Result<()> {
     };
 
     // Get priority
-    let priorities = vec!["📝 Low", "📌 Medium", "⚠️ High", "🔥 Critical"];
-    let priority_idx = Select::with_theme(&ColorfulTheme::default())
-        .with_prompt("Priority")
-        .items(&priorities)
-        .default(1)
-        .interact()?;
-
-    let priority = match priority_idx {
-        0 => Priority::Low,
-        1 => Priority::Medium,
-        2 => Priority::High,
-        3 => Priority::Critical,
-        _ => Priority::Medium,
+    let priority = match priority {
+        Some(priority) => priority,
+        None => {
+            let priorities = vec!["📝 Low", "📌 Medium", "⚠️ High", "🔥 Critical"];
+            let priority_idx = Select::with_theme(&ColorfulTheme::default())
+                .with_prompt("Priority")
+                .items(&priorities)
+                .default(1)
+                .interact()?;
+
+            match priority_idx {
+                0 => Priority::Low,
+                1 => Priority::Medium,
+                2 => Priority::High,
+                3 => Priority::Critical,
+                _ => Priority::Medium,
+            }
+        }
     };
 
     // Get tags (optional)
@@ -436,11 +511,14 @@ pub fn add_todo(description: Option<String>) -> Result<()> {
         .collect();
 
     // Add todo
-    todo_list.add_todo(desc.clone(), priority, tags);
+    todo_list.add_todo(desc.clone(), priority, due_date.clone(), tags);
     todo_list.save()?;
 
     println!("{}", "\n✅ Todo added successfully!".green());
     println!("{} Description: {}", "ℹ️".cyan(), desc);
+    if let Some(due) = &due_date {
+        println!("{} Due: {}", "ℹ️".cyan(), due);
+    }
     println!(
         "{} File: {}",
         "ℹ️".cyan(),
@@ -455,7 +533,7 @@ pub fn list_todos() -> Result<()> {
     println!("{}", "📋 Todo List".cyan().bold());
 
     let todo_list = TodoList::load()?;
-    let pending = todo_list.get_pending();
+    let pending = todo_list.sorted_pending();
 
     if pending.is_empty() {
         println!("{}", "\n✅ No pending todos!".green());
@@ -512,7 +590,11 @@ pub fn list_todos() -> Result<()> {
 
             println!("  {}. {}{}", i + 1, todo.description, tags_str);
 
-            println!("     {}", format!("created: {}", todo.created_at).dimmed());
+            let dates = match &todo.due_date {
+                Some(due) => format!("due: {} | created: {}", due, todo.created_at),
+                None => format!("created: {}", todo.created_at),
+            };
+            println!("     {}", dates.dimmed());
         }
     }
 
@@ -583,6 +665,11 @@ pub fn sync_todos() -> Result<()> {
     let mut todo_list = TodoList::load()?;
 
     let completed_count = todo_list.sync_with_git()?;
+    let github = if github_sync_enabled() {
+        Some(sync_github(&mut todo_list.todos, &GhCli)?)
+    } else {
+        None
+    };
     todo_list.save()?;
 
     println!("\n{} Sync completed!", "✅".green());
@@ -592,6 +679,26 @@ pub fn sync_todos() -> Result<()> {
         completed_count
     );
 
+    if let Some(report) = github {
+        println!(
+            "{} GitHub issues: {} created, {} closed, {} still open",
+            "ℹ️".cyan(),
+            report.created,
+            report.closed,
+            report.open
+        );
+        if !report.failures.is_empty() {
+            println!(
+                "{} {} todo(s) could not be synced with GitHub",
+                "⚠️".yellow(),
+                report.failures.len()
+            );
+            for failure in &report.failures {
+                println!("  {} {}", "•".dimmed(), failure);
+            }
+        }
+    }
+
     Ok(())
 }
 
@@ -727,6 +834,15 @@ fn get_due_date_urgency(due_date: &str) -> DueUrgency {
     }
 }
 
+/// Validate a due date given on the command line (`YYYY-MM-DD`)
+fn parse_due_date(due: &str) -> Result<String> {
+    chrono::NaiveDate::parse_from_str(due.trim(), "%Y-%m-%d")
+        .map(|date| date.format("%Y-%m-%d").to_string())
+        .map_err(|_| {
+            CldevError::validation(format!("Invalid due date '{}': expected YYYY-MM-DD", due))
+        })
+}
+
 /// Rank due date for sorting (lower = more urgent)
 fn due_date_rank(due_date: &Option<String>) -> i64 {
     use chrono::{Local, NaiveDate};
@@ -764,7 +880,7 @@ pub fn interactive_mode() -> Result<()> {
 
         match choice {
             0 => list_todos()?,
-            1 => add_todo(None)?,
+            1 => add_todo(None, None, None)?,
             2 => complete_todo()?,
             3 => sync_todos()?,
             4 => {
@@ -829,6 +945,7 @@ mod tests {
         todo_list.add_todo(
             "Test todo".to_string(),
             Priority::High,
+            None,
             vec!["test".to_string()],
         );
 
@@ -848,11 +965,102 @@ mod tests {
             file_path: PathBuf::from("test.md"),
         };
 
-        todo_list.add_todo("Test todo".to_string(), Priority::Medium, vec![]);
+        todo_list.add_todo("Test todo".to_string(), Priority::Medium, None, vec![]);
 
         todo_list.complete_todo(0).unwrap();
 
         assert_eq!(todo_list.todos[0].status, TaskStatus::Completed);
         assert!(todo_list.todos[0].completed_at.is_some());
     }
+
+    #[test]
+    fn test_add_todo_with_priority_and_due_date() {
+        let mut todo_list = TodoList {
+            todos: Vec::new(),
+            file_path: PathBuf::from("test.md"),
+        };
+
+        todo_list.add_todo(
+            "Ship release".to_string(),
+            TodoPriority::High.into(),
+            Some(parse_due_date("2030-03-01").unwrap()),
+            vec![],
+        );
+
+        let markdown = todo_list.to_markdown();
+        assert!(markdown.contains("- [ ] Ship release | Priority: high | Due: 2030-03-01 |"));
+
+        let parsed = TodoList::parse_markdown(&markdown);
+        assert_eq!(parsed[0].priority, Priority::High);
+        assert_eq!(parsed[0].due_date.as_deref(), Some("2030-03-01"));
+    }
+
+    #[test]
+    fn test_sorted_pending_by_priority_then_due_date() {
+        let mut todo_list = TodoList {
+            todos: Vec::new(),
+            file_path: PathBuf::from("test.md"),
+        };
+
+        todo_list.add_todo("low".to_string(), Priority::Low, None, vec![]);
+        todo_list.add_todo(
+            "high later".to_string(),
+            Priority::High,
+            Some("2030-06-01".to_string()),
+            vec![],
+        );
+        todo_list.add_todo("high undated".to_string(), Priority::High, None, vec![]);
+        todo_list.add_todo(
+            "high sooner".to_string(),
+            Priority::High,
+            Some("2030-01-01".to_string()),
+            vec![],
+        );
+        todo_list.add_todo("critical".to_string(), Priority::Critical, None, vec![]);
+
+        let order: Vec<&str> = todo_list
+            .sorted_pending()
+            .iter()
+            .map(|t| t.description.as_str())
+            .collect();
+        assert_eq!(
+            order,
+            [
+                "critical",
+                "high sooner",
+                "high later",
+                "high undated",
+                "low"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_issue_link_round_trip() {
+        let mut todo_list = TodoList {
+            todos: Vec::new(),
+            file_path: PathBuf::from("test.md"),
+        };
+        todo_list.add_todo(
+            "Track bug".to_string(),
+            Priority::High,
+            None,
+            vec!["issue".to_string()],
+        );
+        todo_list.todos[0].issue = Some(42);
+
+        let parsed = TodoList::parse_markdown(&todo_list.to_markdown());
+        assert_eq!(parsed[0].issue, Some(42));
+        assert_eq!(parsed[0].tags, vec!["issue"]);
+    }
+
+    #[test]
+    fn test_parse_due_date_rejects_malformed_dates() {
+        assert_eq!(parse_due_date("2030-02-28").unwrap(), "2030-02-28");
+
+        for bad in ["tomorrow", "2030/02/28", "2030-02-30", ""] {
+            let err = parse_due_date(bad).unwrap_err();
+            assert!(err.to_string().contains("expected YYYY-MM-DD"), "{}", bad);
+        }
+    }
 }
@@ -1,2 +1,9 @@
 /// Todo management commands module
+pub mod github;
+pub mod import;
 pub mod manage;
+
+#[allow(unused_imports)]
+pub use github::{sync_github, SyncReport};
+#[allow(unused_imports)]
+pub use import::import_from_source;
@@ -3,17 +3,20 @@
 //! Analyzes session context and determines whether to create a learning record automatically.
 //! Uses composite scoring based on errors, duration, files, commands, and todos.
 
-use crate::core::{sanitize_text, LearningRecordV3, RecordStatus, SessionContext};
+use crate::core::{
+    sanitize_text_with_allowlist, CaptureConfig, LearningRecordV3, RecordStatus, SessionContext,
+};
 use chrono::Local;
+use regex::Regex;
 
 /// Recommendation level for auto-capture
 #[derive(Debug, Clone, PartialEq)]
 pub enum RecordLevel {
-    /// Full record with user editing (score >= 0.7)
+    /// Full record with user editing (score >= `full_score`, default 0.7)
     Full,
-    /// Background indexing only (score 0.3-0.7)
+    /// Background indexing only (score >= `background_score`, default 0.3)
     Background,
-    /// Skip recording (score < 0.3)
+    /// Skip recording
     Skip,
 }
 
@@ -25,46 +28,52 @@ pub struct RecordRecommendation {
     pub reason: String,
 }
 
+/// Score for a value against tier thresholds
+///
+/// Returns the score of the highest tier whose threshold `value` reaches,
+/// or 0.0 if it reaches none.
+fn tier_score(value: u64, tiers: &[u64], scores: &[f64]) -> f64 {
+    tiers
+        .iter()
+        .zip(scores)
+        .rev()
+        .find(|(&threshold, _)| value >= threshold)
+        .map_or(0.0, |(_, &score)| score)
+}
+
 /// Analyze session and recommend recording level
-pub fn analyze_session(ctx: &SessionContext) -> RecordRecommendation {
+///
+/// Thresholds come from `[lr.capture]`; [`CaptureConfig::default`] matches
+/// the built-in behavior.
+pub fn analyze_session(ctx: &SessionContext, thresholds: &CaptureConfig) -> RecordRecommendation {
     let mut score = 0.0;
     let mut reasons = Vec::new();
 
     // 1. Error count (weight: 0.3)
     let error_count = ctx.unresolved_errors_count();
-    let error_score = match error_count {
-        0 => 0.0,
-        1..=2 => 0.15,
-        3..=5 => 0.25,
-        _ => 0.3,
-    };
-    score += error_score;
+    score += tier_score(
+        error_count as u64,
+        &thresholds.error_tiers,
+        &[0.15, 0.25, 0.3],
+    );
     if error_count > 0 {
         reasons.push(format!("{} errors", error_count));
     }
 
     // 2. Duration (weight: 0.25)
     let duration = ctx.duration_minutes();
-    let duration_score = match duration {
-        0..=5 => 0.0,
-        6..=15 => 0.1,
-        16..=30 => 0.2,
-        _ => 0.25,
-    };
-    score += duration_score;
+    score += tier_score(
+        duration.max(0) as u64,
+        &thresholds.duration_tiers,
+        &[0.1, 0.2, 0.25],
+    );
     if duration > 10 {
         reasons.push(format!("{}min work", duration));
     }
 
     // 3. File changes (weight: 0.2)
     let file_count = ctx.unique_files_modified();
-    let file_score = match file_count {
-        0..=2 => 0.0,
-        3..=5 => 0.1,
-        6..=10 => 0.15,
-        _ => 0.2,
-    };
-    score += file_score;
+    score += tier_score(file_count as u64, &thresholds.file_tiers, &[0.1, 0.15, 0.2]);
     if file_count > 3 {
         reasons.push(format!("{} files", file_count));
     }
@@ -95,9 +104,9 @@ pub fn analyze_session(ctx: &SessionContext) -> RecordRecommendation {
     }
 
     // Determine level
-    let level = if score >= 0.7 {
+    let level = if score >= thresholds.full_score {
         RecordLevel::Full
-    } else if score >= 0.3 {
+    } else if score >= thresholds.background_score {
         RecordLevel::Background
     } else {
         RecordLevel::Skip
@@ -117,9 +126,12 @@ pub fn analyze_session(ctx: &SessionContext) -> RecordRecommendation {
 }
 
 /// Generate markdown for Level 2 (semi-auto) record
+///
+/// Secrets in error messages not matching `allowlist` are redacted.
 pub fn generate_level2_markdown(
     ctx: &SessionContext,
     rec: &RecordRecommendation,
+    allowlist: &[Regex],
 ) -> LearningRecordV3 {
     let timestamp = Local::now();
     let id = format!(
@@ -139,7 +151,7 @@ pub fn generate_level2_markdown(
                 format!(
                     "- **{}**: {}",
                     e.error_type,
-                    sanitize_text(&e.message).sanitized
+                    sanitize_text_with_allowlist(&e.message, allowlist).sanitized
                 )
             })
             .collect::<Vec<_>>()
@@ -265,7 +277,7 @@ mod tests {
     #[test]
     fn test_analyze_minimal_session() {
         let ctx = create_test_context();
-        let rec = analyze_session(&ctx);
+        let rec = analyze_session(&ctx, &CaptureConfig::default());
         assert_eq!(rec.level, RecordLevel::Skip);
         assert!(rec.score < 0.3);
     }
@@ -282,7 +294,7 @@ mod tests {
                 resolved: false,
             });
         }
-        let rec = analyze_session(&ctx);
+        let rec = analyze_session(&ctx, &CaptureConfig::default());
         assert!(rec.score >= 0.25);
         assert!(rec.reason.contains("errors"));
     }
@@ -310,11 +322,45 @@ mod tests {
                 timestamp: Local::now(),
             });
         }
-        let rec = analyze_session(&ctx);
+        let rec = analyze_session(&ctx, &CaptureConfig::default());
         assert!(rec.score >= 0.3 && rec.score < 0.7);
         assert_eq!(rec.level, RecordLevel::Background);
     }
 
+    #[test]
+    fn test_lower_duration_threshold_raises_level() {
+        let mut ctx = create_test_context();
+        ctx.start_time = Local::now() - chrono::Duration::minutes(3);
+        ctx.add_error(ErrorCapture {
+            timestamp: Local::now(),
+            error_type: "TestError".to_string(),
+            message: "Short session error".to_string(),
+            context: None,
+            resolved: false,
+        });
+
+        let default_rec = analyze_session(&ctx, &CaptureConfig::default());
+        assert_eq!(default_rec.level, RecordLevel::Skip);
+
+        let thresholds = CaptureConfig {
+            duration_tiers: vec![1, 2, 3],
+            ..CaptureConfig::default()
+        };
+        let rec = analyze_session(&ctx, &thresholds);
+        assert!(rec.score > default_rec.score);
+        assert_eq!(rec.level, RecordLevel::Background);
+    }
+
+    #[test]
+    fn test_tier_score() {
+        let scores = [0.1, 0.2, 0.25];
+        assert_eq!(tier_score(5, &[6, 16, 31], &scores), 0.0);
+        assert_eq!(tier_score(6, &[6, 16, 31], &scores), 0.1);
+        assert_eq!(tier_score(30, &[6, 16, 31], &scores), 0.2);
+        assert_eq!(tier_score(100, &[6, 16, 31], &scores), 0.25);
+        assert_eq!(tier_score(100, &[], &scores), 0.0);
+    }
+
     #[test]
     fn test_generate_level2_markdown() {
         let mut ctx = create_test_context();
@@ -332,7 +378,7 @@ mod tests {
             reason: "3 errors, 45min work".to_string(),
         };
 
-        let record = generate_level2_markdown(&ctx, &rec);
+        let record = generate_level2_markdown(&ctx, &rec, &[]);
         assert!(record.auto_generated);
         assert_eq!(record.confidence, Some(0.85));
         assert!(record.markdown_body.contains("CompileError"));
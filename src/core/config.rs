@@ -20,12 +20,16 @@
 #![allow(dead_code)]
 
 use crate::core::error::{CldevError, Result};
-use crate::core::project_config::ProjectConfig;
+use crate::core::git_utils::GitUtils;
+use crate::core::project_config::{ProjectConfig, ProjectEnvironment};
+use crate::core::security::SecurePath;
 use crate::core::stack_config::{StackConfig, TechStack};
+use regex::Regex;
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::sync::{Arc, OnceLock};
+use std::time::Duration;
 
 /// Semantic version for configuration file format
 ///
@@ -33,7 +37,7 @@ use std::sync::{Arc, OnceLock};
 /// - Major: Breaking changes, migration required
 /// - Minor: Backward compatible additions
 /// - Patch: Bug fixes only
-pub const CONFIG_VERSION: &str = "1.0.0";
+pub const CONFIG_VERSION: &str = "2.0.0";
 
 /// Validates a version string against the current CONFIG_VERSION
 ///
@@ -56,7 +60,7 @@ pub fn validate_version(version: &str) -> Result<()> {
     // Major version must match
     if current_parts[0] != file_parts[0] {
         return Err(CldevError::validation(format!(
-            "Configuration major version mismatch: expected {}, found {}. Migration required.",
+            "Configuration major version mismatch: expected {}, found {}. Migration required (run `cldev config migrate`).",
             current_parts[0], file_parts[0]
         )));
     }
@@ -78,6 +82,83 @@ pub fn validate_version(version: &str) -> Result<()> {
     Ok(())
 }
 
+/// A single schema migration step, upgrading a document from major version `N` to `N + 1`
+type MigrationStep = fn(&mut toml::Table) -> Result<()>;
+
+/// Registered migration steps, keyed by the source major version
+///
+/// Each step only rewrites the raw TOML table; the `version` field is updated
+/// by [`migrate_value`] once all steps have been applied.
+const MIGRATIONS: &[(u32, MigrationStep)] = &[(1, migrate_v1_to_v2)];
+
+/// v1 → v2: `performance.parallel_tasks` is renamed to `performance.max_jobs`
+fn migrate_v1_to_v2(table: &mut toml::Table) -> Result<()> {
+    if let Some(toml::Value::Table(performance)) = table.get_mut("performance") {
+        if let Some(value) = performance.remove("parallel_tasks") {
+            performance.entry("max_jobs").or_insert(value);
+        }
+    }
+    Ok(())
+}
+
+/// Apply schema migrations to a raw TOML document
+///
+/// Runs every registered step between the major versions of `from` and `to`
+/// in order, then stamps the document with `to` as its new `version`.
+///
+/// # Errors
+///
+/// Returns a validation error if either version is malformed, if `to` is older
+/// than `from`, if the root is not a table, or if no migration step is
+/// registered for one of the intermediate major versions.
+pub fn migrate_value(old: toml::Value, from: &str, to: &str) -> Result<toml::Value> {
+    let (from_major, from_minor, from_patch) = ConfigVersion::parse(from)?;
+    let (to_major, to_minor, to_patch) = ConfigVersion::parse(to)?;
+
+    if (to_major, to_minor, to_patch) < (from_major, from_minor, from_patch) {
+        return Err(CldevError::validation(format!(
+            "Cannot migrate configuration backwards from {} to {}",
+            from, to
+        )));
+    }
+
+    let toml::Value::Table(mut table) = old else {
+        return Err(CldevError::validation(
+            "Configuration root must be a TOML table",
+        ));
+    };
+
+    for major in from_major..to_major {
+        let step = MIGRATIONS
+            .iter()
+            .find(|(source, _)| *source == major)
+            .map(|(_, step)| step)
+            .ok_or_else(|| {
+                CldevError::validation(format!(
+                    "No migration path from configuration v{} to v{}",
+                    major,
+                    major + 1
+                ))
+            })?;
+        step(&mut table)?;
+    }
+
+    table.insert("version".to_string(), toml::Value::String(to.to_string()));
+
+    Ok(toml::Value::Table(table))
+}
+
+/// Migrate a raw TOML document from version `from` to version `to`
+///
+/// See [`migrate_value`] for the rules applied; the migrated document is then
+/// deserialized into a [`Config`].
+pub fn migrate(old: toml::Value, from: &str, to: &str) -> Result<Config> {
+    let migrated = migrate_value(old, from, to)?;
+    migrated.try_into().map_err(|e: toml::de::Error| {
+        CldevError::config(format!("Failed to parse migrated configuration: {}", e))
+    })
+}
+
 /// Main configuration structure with versioning support
 ///
 /// This structure is designed to be shared across modules using `Arc<Config>`
@@ -108,6 +189,10 @@ pub struct Config {
     #[serde(default)]
     pub lr: LearningRecordConfig,
 
+    /// Code analysis settings
+    #[serde(default)]
+    pub analysis: AnalysisConfig,
+
     /// UI preferences
     #[serde(default)]
     pub ui: UiConfig,
@@ -119,7 +204,9 @@ pub struct Config {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GeneralConfig {
-    /// Language preference (en or ja)
+    /// Language preference (auto, en, ja, or an installed catalog code)
+    ///
+    /// Applies when `--lang` is not given.
     #[serde(default = "default_language")]
     pub language: String,
 
@@ -138,6 +225,10 @@ pub struct GeneralConfig {
     /// Current project name (optional)
     #[serde(default)]
     pub project_name: Option<String>,
+
+    /// Send a desktop notification when a command finishes
+    #[serde(default)]
+    pub notify_on_complete: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -165,6 +256,10 @@ pub struct QualityConfig {
     #[serde(default)]
     pub auto_fix: bool,
 
+    /// Run lint and format checks before commit
+    #[serde(default)]
+    pub lint_before_commit: bool,
+
     /// Run tests before commit
     #[serde(default = "default_true")]
     pub run_tests_before_commit: bool,
@@ -198,6 +293,74 @@ pub struct LearningRecordConfig {
     /// Default tags for new sessions
     #[serde(default = "default_tags")]
     pub default_tags: Vec<String>,
+
+    /// Regex patterns whose matches are never redacted by the sanitizer
+    #[serde(default)]
+    pub sanitizer_allowlist: Vec<String>,
+
+    /// Thresholds used to decide whether a session warrants a record
+    #[serde(default)]
+    pub capture: CaptureConfig,
+
+    /// Weights for ranking learning record search results
+    #[serde(default)]
+    pub search: SearchWeights,
+}
+
+/// Hybrid search weights (`[lr.search]`)
+///
+/// Each signal is normalized to 0..1 before weighting.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SearchWeights {
+    /// Weight of the full-text (FTS5 bm25) rank
+    #[serde(default = "default_fts_weight")]
+    pub fts_weight: f64,
+
+    /// Weight of the TF-IDF similarity
+    #[serde(default = "default_tfidf_weight")]
+    pub tfidf_weight: f64,
+
+    /// Weight of how recent the record is
+    #[serde(default = "default_recency_weight")]
+    pub recency_weight: f64,
+}
+
+/// Auto-capture thresholds (`[lr.capture]`)
+///
+/// Each `*_tiers` list holds the minimum value needed to reach each scoring
+/// tier of that signal, lowest tier first. Up to three tiers are used.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CaptureConfig {
+    /// Unresolved error counts for each error tier
+    #[serde(default = "default_error_tiers")]
+    pub error_tiers: Vec<u64>,
+
+    /// Session durations in minutes for each duration tier
+    #[serde(default = "default_duration_tiers")]
+    pub duration_tiers: Vec<u64>,
+
+    /// Modified file counts for each file tier
+    #[serde(default = "default_file_tiers")]
+    pub file_tiers: Vec<u64>,
+
+    /// Score at or above which a full record is recommended
+    #[serde(default = "default_full_score")]
+    pub full_score: f64,
+
+    /// Score at or above which background indexing is recommended
+    #[serde(default = "default_background_score")]
+    pub background_score: f64,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AnalysisConfig {
+    /// Paths `analysis analyze` skips in addition to the built-in list
+    ///
+    /// Entries use `.gitignore` syntax: a bare name such as `vendor` skips
+    /// that directory at any depth, while `generated/**` is anchored at the
+    /// project root.
+    #[serde(default)]
+    pub ignore: Vec<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -217,13 +380,35 @@ pub struct UiConfig {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PerformanceConfig {
-    /// Number of parallel tasks
-    #[serde(default = "default_parallel_tasks")]
-    pub parallel_tasks: usize,
+    /// Number of parallel jobs (also the thread count for `analysis analyze`)
+    ///
+    /// Named `parallel_tasks` before v2; older files are migrated on load.
+    #[serde(default = "default_max_jobs")]
+    pub max_jobs: usize,
 
     /// Command timeout in seconds
     #[serde(default = "default_timeout")]
     pub timeout_seconds: u64,
+
+    /// Attempts for network-bound operations (deploys, `git push`) that fail transiently
+    #[serde(default = "default_retry_attempts")]
+    pub retry_attempts: u32,
+
+    /// Delay before the first retry in milliseconds, doubled after each attempt
+    #[serde(default = "default_retry_delay_ms")]
+    pub retry_delay_ms: u64,
+}
+
+impl PerformanceConfig {
+    /// Get the timeout for external commands, at least one second
+    pub fn timeout(&self) -> Duration {
+        Duration::from_secs(self.timeout_seconds.max(1))
+    }
+
+    /// Get the delay before the first retry
+    pub fn retry_delay(&self) -> Duration {
+        Duration::from_millis(self.retry_delay_ms)
+    }
 }
 
 // Default value functions
@@ -232,7 +417,25 @@ fn default_version() -> String {
 }
 
 fn default_language() -> String {
-    "ja".to_string()
+    "auto".to_string()
+}
+
+// Configuration file chosen with the global `--config` flag
+static CONFIG_PATH_OVERRIDE: OnceLock<PathBuf> = OnceLock::new();
+
+// Project root chosen with the global `--project-root` flag
+static PROJECT_ROOT_OVERRIDE: OnceLock<PathBuf> = OnceLock::new();
+
+/// Get the user's home directory
+///
+/// `HOME` takes precedence over the platform lookup so that tests and custom
+/// setups can redirect it.
+pub fn home_dir() -> Result<PathBuf> {
+    std::env::var("HOME")
+        .ok()
+        .map(PathBuf::from)
+        .or_else(dirs::home_dir)
+        .ok_or_else(|| CldevError::config("Failed to get home directory"))
 }
 
 // Cache for default claude directory
@@ -241,8 +444,8 @@ static DEFAULT_CLAUDE_DIR: OnceLock<PathBuf> = OnceLock::new();
 fn default_claude_dir() -> PathBuf {
     DEFAULT_CLAUDE_DIR
         .get_or_init(|| {
-            dirs::home_dir()
-                .unwrap_or_else(|| PathBuf::from("."))
+            home_dir()
+                .unwrap_or_else(|_| PathBuf::from("."))
                 .join(".claude")
         })
         .clone()
@@ -254,8 +457,8 @@ static DEFAULT_PROJECTS_DIR: OnceLock<PathBuf> = OnceLock::new();
 fn default_projects_dir() -> PathBuf {
     DEFAULT_PROJECTS_DIR
         .get_or_init(|| {
-            dirs::home_dir()
-                .unwrap_or_else(|| PathBuf::from("."))
+            home_dir()
+                .unwrap_or_else(|_| PathBuf::from("."))
                 .join("projects")
         })
         .clone()
@@ -286,7 +489,39 @@ fn default_tags() -> Vec<String> {
     vec!["development".to_string(), "claude-code".to_string()]
 }
 
-fn default_parallel_tasks() -> usize {
+fn default_error_tiers() -> Vec<u64> {
+    vec![1, 3, 6]
+}
+
+fn default_duration_tiers() -> Vec<u64> {
+    vec![6, 16, 31]
+}
+
+fn default_file_tiers() -> Vec<u64> {
+    vec![3, 6, 11]
+}
+
+fn default_full_score() -> f64 {
+    0.7
+}
+
+fn default_background_score() -> f64 {
+    0.3
+}
+
+fn default_fts_weight() -> f64 {
+    0.5
+}
+
+fn default_tfidf_weight() -> f64 {
+    0.3
+}
+
+fn default_recency_weight() -> f64 {
+    0.2
+}
+
+fn default_max_jobs() -> usize {
     4
 }
 
@@ -294,6 +529,14 @@ fn default_timeout() -> u64 {
     300
 }
 
+fn default_retry_attempts() -> u32 {
+    3
+}
+
+fn default_retry_delay_ms() -> u64 {
+    500
+}
+
 impl Default for GeneralConfig {
     fn default() -> Self {
         Self {
@@ -302,6 +545,7 @@ impl Default for GeneralConfig {
             projects_dir: default_projects_dir(),
             tech_stack: None,
             project_name: None,
+            notify_on_complete: false,
         }
     }
 }
@@ -321,6 +565,7 @@ impl Default for QualityConfig {
     fn default() -> Self {
         Self {
             auto_fix: false,
+            lint_before_commit: false,
             run_tests_before_commit: default_true(),
         }
     }
@@ -342,10 +587,52 @@ impl Default for LearningRecordConfig {
             sessions_dir: default_sessions_dir(),
             auto_save: default_true(),
             default_tags: default_tags(),
+            sanitizer_allowlist: Vec::new(),
+            capture: CaptureConfig::default(),
+            search: SearchWeights::default(),
         }
     }
 }
 
+impl Default for SearchWeights {
+    fn default() -> Self {
+        Self {
+            fts_weight: default_fts_weight(),
+            tfidf_weight: default_tfidf_weight(),
+            recency_weight: default_recency_weight(),
+        }
+    }
+}
+
+impl Default for CaptureConfig {
+    fn default() -> Self {
+        Self {
+            error_tiers: default_error_tiers(),
+            duration_tiers: default_duration_tiers(),
+            file_tiers: default_file_tiers(),
+            full_score: default_full_score(),
+            background_score: default_background_score(),
+        }
+    }
+}
+
+impl LearningRecordConfig {
+    /// Compile the sanitizer allowlist patterns
+    pub fn sanitizer_allowlist_patterns(&self) -> Result<Vec<Regex>> {
+        self.sanitizer_allowlist
+            .iter()
+            .map(|pattern| {
+                Regex::new(pattern).map_err(|e| {
+                    CldevError::config(format!(
+                        "Invalid lr.sanitizer_allowlist pattern \"{}\": {}",
+                        pattern, e
+                    ))
+                })
+            })
+            .collect()
+    }
+}
+
 impl Default for UiConfig {
     fn default() -> Self {
         Self {
@@ -359,8 +646,10 @@ impl Default for UiConfig {
 impl Default for PerformanceConfig {
     fn default() -> Self {
         Self {
-            parallel_tasks: default_parallel_tasks(),
+            max_jobs: default_max_jobs(),
             timeout_seconds: default_timeout(),
+            retry_attempts: default_retry_attempts(),
+            retry_delay_ms: default_retry_delay_ms(),
         }
     }
 }
@@ -374,6 +663,7 @@ impl Default for Config {
             quality: QualityConfig::default(),
             dev: DevConfig::default(),
             lr: LearningRecordConfig::default(),
+            analysis: AnalysisConfig::default(),
             ui: UiConfig::default(),
             performance: PerformanceConfig::default(),
         }
@@ -388,7 +678,13 @@ impl Config {
     /// - **Linux**: `~/.config/cldev/config.toml`
     /// - **Windows**: `%APPDATA%\cldev\config.toml`
     /// - **Fallback**: `~/.cldev/config.toml` (if config directory cannot be determined)
+    ///
+    /// A path set with [`Config::set_path_override`] takes precedence.
     pub fn default_path() -> Result<PathBuf> {
+        if let Some(path) = CONFIG_PATH_OVERRIDE.get() {
+            return Ok(path.clone());
+        }
+
         // Check HOME environment variable first (for testing and custom setups)
         if let Ok(home) = std::env::var("HOME") {
             let home_path = PathBuf::from(home);
@@ -419,9 +715,67 @@ impl Config {
             Ok(config_dir.join("cldev").join("config.toml"))
         } else {
             // Fallback to home directory
-            dirs::home_dir()
-                .map(|home| home.join(".cldev").join("config.toml"))
-                .ok_or_else(|| CldevError::config("Could not determine home directory"))
+            Ok(home_dir()?.join(".cldev").join("config.toml"))
+        }
+    }
+
+    /// Configuration file chosen with [`Config::set_path_override`], if any
+    pub fn path_override() -> Option<&'static Path> {
+        CONFIG_PATH_OVERRIDE.get().map(PathBuf::as_path)
+    }
+
+    /// Use `path` instead of the default configuration file for this process
+    ///
+    /// Every later [`Config::default_path`] call, and so every `load(None)`
+    /// and `load_hierarchical`, resolves to `path`. Its directory must exist,
+    /// and the file may not be a symlink leading out of that directory. The
+    /// override can only be set once.
+    pub fn set_path_override(path: &Path) -> Result<()> {
+        let dir = path
+            .parent()
+            .filter(|dir| !dir.as_os_str().is_empty())
+            .unwrap_or(Path::new("."));
+        let file_name = path.file_name().ok_or_else(|| {
+            CldevError::config(format!("Invalid config path: {}", path.display()))
+        })?;
+        let resolved = SecurePath::within(dir, Path::new(file_name))
+            .map_err(|e| CldevError::security(format!("Invalid config path: {}", e)))?;
+
+        CONFIG_PATH_OVERRIDE
+            .set(resolved)
+            .map_err(|_| CldevError::config("Config path override is already set"))
+    }
+
+    /// Resolve project configuration from `root` instead of the working directory
+    ///
+    /// Every later [`Config::load_hierarchical`] call reads the project layer
+    /// from `root`, whatever root its caller inferred. `root` must be an
+    /// existing directory. The override can only be set once.
+    pub fn set_project_root_override(root: &Path) -> Result<()> {
+        if !root.is_dir() {
+            return Err(CldevError::config(format!(
+                "Project root is not a directory: {}",
+                root.display()
+            )));
+        }
+        let root = root.canonicalize()?;
+
+        PROJECT_ROOT_OVERRIDE
+            .set(root)
+            .map_err(|_| CldevError::config("Project root override is already set"))
+    }
+
+    /// Root of the project the current command works on
+    ///
+    /// The `--project-root` directory when given, otherwise the repository
+    /// containing the working directory, otherwise the working directory.
+    pub fn project_root() -> Result<PathBuf> {
+        if let Some(root) = PROJECT_ROOT_OVERRIDE.get() {
+            return Ok(root.clone());
+        }
+        match GitUtils::repo_root() {
+            Ok(root) => Ok(root),
+            Err(_) => Ok(std::env::current_dir()?),
         }
     }
 
@@ -440,6 +794,9 @@ impl Config {
     /// - Validates file permissions (should be 600)
     /// - Validates version compatibility
     /// - Returns default config if file doesn't exist
+    ///
+    /// A file from an older major version is migrated in memory; the file
+    /// itself is only rewritten by `cldev config migrate`.
     pub fn load(path: Option<PathBuf>) -> Result<Arc<Self>> {
         let config_path = path.unwrap_or_else(|| {
             Self::default_path().unwrap_or_else(|_| PathBuf::from("~/.config/cldev/config.toml"))
@@ -463,18 +820,43 @@ impl Config {
             ))
         })?;
 
-        let config: Config = toml::from_str(&content).map_err(|e| {
+        Ok(Arc::new(Self::parse(&content, &config_path)?))
+    }
+
+    /// Parse the contents of the configuration file at `source`
+    ///
+    /// A document from an older major version is migrated in memory before it
+    /// is deserialized; `source` only names the file in error messages.
+    pub fn parse(content: &str, source: &Path) -> Result<Self> {
+        let parse_error = |e: toml::de::Error| {
             CldevError::config(format!(
                 "Failed to parse config file {}: {}",
-                config_path.display(),
+                source.display(),
                 e
             ))
-        })?;
+        };
+        let value: toml::Value = toml::from_str(content).map_err(parse_error)?;
+
+        // Bring older files up to the current schema before deserializing
+        let version = value
+            .get("version")
+            .and_then(|v| v.as_str())
+            .unwrap_or(CONFIG_VERSION)
+            .to_string();
+        let (file_major, _, _) = ConfigVersion::parse(&version)?;
+        let (current_major, _, _) = ConfigVersion::parse(CONFIG_VERSION)?;
+        let value = if file_major < current_major {
+            migrate_value(value, &version, CONFIG_VERSION)?
+        } else {
+            value
+        };
+
+        let config: Config = value.try_into().map_err(parse_error)?;
 
         // Validate version
         validate_version(&config.version)?;
 
-        Ok(Arc::new(config))
+        Ok(config)
     }
 
     /// Save configuration to file
@@ -526,11 +908,22 @@ impl Config {
     /// Check configuration file permissions (Unix only)
     ///
     /// Ensures the config file has restrictive permissions (600) to prevent
-    /// unauthorized access.
+    /// unauthorized access, and is not a symlink to a file outside its
+    /// directory (whose permissions the check would otherwise follow).
     #[cfg(unix)]
     fn check_permissions(path: &Path) -> Result<()> {
         use std::os::unix::fs::PermissionsExt;
 
+        let dir = path
+            .parent()
+            .filter(|dir| !dir.as_os_str().is_empty())
+            .unwrap_or(Path::new("."));
+        SecurePath::new(dir.to_path_buf())
+            .and_then(|secure_path| secure_path.is_safe_symlink(path))
+            .map_err(|e| {
+                CldevError::security(format!("{}. Replace the symlink with a regular file.", e))
+            })?;
+
         let metadata = fs::metadata(path).map_err(|e| {
             CldevError::io(format!(
                 "Failed to read metadata for {}: {}",
@@ -586,11 +979,14 @@ impl Config {
     ///
     /// 1. **Global**: OS-specific config directory (e.g., `~/Library/Application Support/cldev/config.toml` on macOS)
     /// 2. **Stack**: OS-specific config directory + `stacks/{stack_name}.toml`
-    /// 3. **Project**: `./.cldev/config.toml` (if project_root is provided)
+    /// 3. **Project**: `.cldev/config.toml` in the project root
     ///
     /// # Arguments
     ///
-    /// * `project_root` - Optional project root directory for project-level config
+    /// * `project_root` - Project directory for project-level config. When it
+    ///   has no `.cldev/config.toml`, or is None, the enclosing Git repository
+    ///   root is used instead so the config is found from any subdirectory.
+    ///   A root set with [`Config::set_project_root_override`] replaces it.
     ///
     /// # Returns
     ///
@@ -607,15 +1003,16 @@ impl Config {
             None
         };
 
-        // 3. Load project config if project_root is provided
-        let project = if let Some(ref root) = project_root {
-            if ProjectConfig::exists(root) {
-                Some(ProjectConfig::load(root)?)
-            } else {
-                None
-            }
-        } else {
-            None
+        // 3. Load project config from the project root or its repository root
+        let project_root = PROJECT_ROOT_OVERRIDE.get().cloned().or(project_root);
+        let project_root = match project_root {
+            Some(root) if ProjectConfig::exists(&root) => Some(root),
+            Some(root) => GitUtils::repo_root_from(&root).ok(),
+            None => GitUtils::repo_root().ok(),
+        };
+        let project = match project_root {
+            Some(root) if ProjectConfig::exists(&root) => Some(ProjectConfig::load(&root)?),
+            _ => None,
         };
 
         Ok(HierarchicalConfig {
@@ -647,6 +1044,294 @@ impl Config {
     pub fn set_project_name(&mut self, name: Option<String>) {
         self.general.project_name = name;
     }
+
+    /// Validate semantic constraints on configuration values
+    ///
+    /// Returns a list of human-readable problems; an empty list means the
+    /// configuration is usable. Only I/O failures are reported as errors.
+    ///
+    /// # Constraints
+    ///
+    /// - `performance.max_jobs` must be at least 1
+    /// - `performance.timeout_seconds` must be at least 1
+    /// - `performance.retry_attempts` must be at least 1
+    /// - `general.language` must be `auto`, `en`, `ja`, or the code of an
+    ///   installed external catalog
+    /// - `analysis.ignore` entries must be valid `.gitignore` patterns
+    /// - The parents of `claude_dir` and `projects_dir` must be writable
+    pub fn validate_semantics(&self) -> Result<Vec<String>> {
+        let mut issues = Vec::new();
+
+        if self.performance.max_jobs == 0 {
+            issues.push("performance.max_jobs must be at least 1 (found 0)".to_string());
+        }
+
+        if self.performance.timeout_seconds == 0 {
+            issues.push("performance.timeout_seconds must be at least 1 (found 0)".to_string());
+        }
+
+        if self.performance.retry_attempts == 0 {
+            issues.push("performance.retry_attempts must be at least 1 (found 0)".to_string());
+        }
+
+        let language = self.general.language.as_str();
+        if !matches!(language, "auto" | "en" | "ja")
+            && crate::core::i18n::external_catalog_path(language).is_none()
+        {
+            issues.push(format!(
+                "general.language must be \"auto\", \"en\", \"ja\", or an installed catalog (found \"{}\")",
+                language
+            ));
+        }
+
+        for pattern in &self.analysis.ignore {
+            let mut builder = ignore::gitignore::GitignoreBuilder::new("");
+            if let Err(e) = builder.add_line(None, pattern) {
+                issues.push(format!(
+                    "analysis.ignore: invalid pattern \"{}\": {}",
+                    pattern, e
+                ));
+            }
+        }
+
+        for pattern in &self.lr.sanitizer_allowlist {
+            if let Err(e) = Regex::new(pattern) {
+                issues.push(format!(
+                    "lr.sanitizer_allowlist: invalid pattern \"{}\": {}",
+                    pattern, e
+                ));
+            }
+        }
+
+        for (key, dir) in [
+            ("general.claude_dir", &self.general.claude_dir),
+            ("general.projects_dir", &self.general.projects_dir),
+        ] {
+            if let Some(parent) = dir.parent().filter(|p| !p.as_os_str().is_empty()) {
+                if !Self::is_writable_dir(parent)? {
+                    issues.push(format!(
+                        "{}: parent directory is not writable: {}",
+                        key,
+                        parent.display()
+                    ));
+                }
+            }
+        }
+
+        Ok(issues)
+    }
+
+    /// Reset out-of-range numeric values to their defaults
+    ///
+    /// Returns `true` if any value was changed.
+    pub fn clamp_numeric_values(&mut self) -> bool {
+        let mut changed = false;
+
+        if self.performance.max_jobs == 0 {
+            self.performance.max_jobs = default_max_jobs();
+            changed = true;
+        }
+
+        if self.performance.timeout_seconds == 0 {
+            self.performance.timeout_seconds = default_timeout();
+            changed = true;
+        }
+
+        if self.performance.retry_attempts == 0 {
+            self.performance.retry_attempts = default_retry_attempts();
+            changed = true;
+        }
+
+        changed
+    }
+
+    /// Check whether the nearest existing ancestor of `path` is a writable directory
+    fn is_writable_dir(path: &Path) -> Result<bool> {
+        let Some(existing) = path.ancestors().find(|p| p.exists()) else {
+            return Ok(false);
+        };
+
+        let metadata = fs::metadata(existing).map_err(|e| {
+            CldevError::io(format!(
+                "Failed to read metadata for {}: {}",
+                existing.display(),
+                e
+            ))
+        })?;
+
+        Ok(metadata.is_dir() && !metadata.permissions().readonly())
+    }
+
+    /// Look up a value by dotted path (e.g. `git.default_base_branch`)
+    ///
+    /// Returns `None` if the path does not exist or the value is unset.
+    pub fn get_path(&self, dotted: &str) -> Option<toml::Value> {
+        let root = toml::Value::try_from(self).ok()?;
+        dotted
+            .split('.')
+            .try_fold(&root, |value, key| value.get(key))
+            .cloned()
+    }
+
+    /// Set a value by dotted path (e.g. `performance.timeout_seconds`)
+    ///
+    /// The raw string is coerced to the type of the existing value
+    /// (bool, integer, float, string, or comma-separated string list).
+    /// Unset optional fields are assigned as strings.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if the path is unknown, points at a whole
+    /// section, or the value cannot be converted to the field's type.
+    pub fn set_path(&mut self, dotted: &str, value: &str) -> Result<()> {
+        let keys: Vec<&str> = dotted.split('.').collect();
+        if keys.iter().any(|k| k.is_empty()) {
+            return Err(CldevError::validation(format!(
+                "Invalid configuration key: {}",
+                dotted
+            )));
+        }
+
+        let mut root = toml::Value::try_from(&*self)
+            .map_err(|e| CldevError::config(format!("Failed to serialize configuration: {}", e)))?;
+
+        let (leaf, parents) = keys.split_last().expect("split always yields one key");
+        let mut table = root
+            .as_table_mut()
+            .expect("configuration always serializes to a table");
+        for key in parents {
+            table = table
+                .get_mut(*key)
+                .and_then(|v| v.as_table_mut())
+                .ok_or_else(|| unknown_key(dotted))?;
+        }
+
+        let new_value = match table.get(*leaf) {
+            Some(toml::Value::Table(_)) => {
+                return Err(CldevError::validation(format!(
+                    "'{}' is a section, not a value",
+                    dotted
+                )))
+            }
+            Some(existing) => coerce_value(existing, value, dotted)?,
+            None => toml::Value::String(value.to_string()),
+        };
+        table.insert(leaf.to_string(), new_value);
+
+        let updated: Config = root.try_into().map_err(|e: toml::de::Error| {
+            CldevError::validation(format!("Invalid value for '{}': {}", dotted, e.message()))
+        })?;
+
+        // Unknown keys are silently dropped by serde, so make sure the value stuck
+        if updated.get_path(dotted).is_none() {
+            return Err(unknown_key(dotted));
+        }
+
+        *self = updated;
+        Ok(())
+    }
+}
+
+fn unknown_key(dotted: &str) -> CldevError {
+    CldevError::validation(format!("Unknown configuration key: {}", dotted))
+}
+
+/// Convert a raw string into a TOML value of the same type as `existing`
+fn coerce_value(existing: &toml::Value, raw: &str, dotted: &str) -> Result<toml::Value> {
+    let mismatch = |expected: &str| {
+        CldevError::validation(format!(
+            "Invalid value for '{}': expected {}, got '{}'",
+            dotted, expected, raw
+        ))
+    };
+
+    match existing {
+        toml::Value::Boolean(_) => raw
+            .parse::<bool>()
+            .map(toml::Value::Boolean)
+            .map_err(|_| mismatch("a boolean")),
+        toml::Value::Integer(_) => raw
+            .parse::<i64>()
+            .map(toml::Value::Integer)
+            .map_err(|_| mismatch("an integer")),
+        toml::Value::Float(_) => raw
+            .parse::<f64>()
+            .map(toml::Value::Float)
+            .map_err(|_| mismatch("a number")),
+        toml::Value::Array(_) => Ok(toml::Value::Array(
+            raw.split(',')
+                .map(str::trim)
+                .filter(|s| !s.is_empty())
+                .map(|s| toml::Value::String(s.to_string()))
+                .collect(),
+        )),
+        _ => Ok(toml::Value::String(raw.to_string())),
+    }
+}
+
+/// Configuration layer that provides a setting
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigLayer {
+    /// Built-in default (no layer sets the value)
+    Default,
+    /// Global configuration file
+    Global,
+    /// Tech stack configuration
+    Stack,
+    /// Project configuration (./.cldev/config.toml)
+    Project,
+}
+
+impl ConfigLayer {
+    /// Get the layer name
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Default => "default",
+            Self::Global => "global",
+            Self::Stack => "stack",
+            Self::Project => "project",
+        }
+    }
+}
+
+/// Which layer provides a setting, and which lower-layer values it overrides
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LayerResolution {
+    /// Setting key (e.g. `dev_port` or `commands.build`)
+    pub key: String,
+
+    /// Layer that provides the effective value
+    pub layer: ConfigLayer,
+
+    /// Effective value (None if no layer sets it)
+    pub value: Option<String>,
+
+    /// Values from lower layers that are shadowed by the effective one
+    pub overridden: Vec<(ConfigLayer, String)>,
+}
+
+impl LayerResolution {
+    /// Resolve a key from candidate values listed in priority order (highest first)
+    fn from_candidates(key: &str, candidates: Vec<(ConfigLayer, Option<String>)>) -> Self {
+        let mut set = candidates
+            .into_iter()
+            .filter_map(|(layer, value)| value.map(|v| (layer, v)));
+
+        match set.next() {
+            Some((layer, value)) => Self {
+                key: key.to_string(),
+                layer,
+                value: Some(value),
+                overridden: set.collect(),
+            },
+            None => Self {
+                key: key.to_string(),
+                layer: ConfigLayer::Default,
+                value: None,
+                overridden: Vec::new(),
+            },
+        }
+    }
 }
 
 /// Hierarchical configuration with 3 layers
@@ -729,6 +1414,16 @@ impl HierarchicalConfig {
         commands
     }
 
+    /// Get the build and deploy overrides for an environment
+    ///
+    /// Priority: Project > Default (no overrides, so detected commands are used)
+    pub fn environment(&self, name: &str) -> ProjectEnvironment {
+        self.project
+            .as_ref()
+            .and_then(|p| p.environments.get(name).cloned())
+            .unwrap_or_default()
+    }
+
     /// Get effective coverage threshold
     ///
     /// Priority: Project > Stack > Default
@@ -742,6 +1437,81 @@ impl HierarchicalConfig {
                     .and_then(|s| s.quality.coverage_threshold)
             })
     }
+
+    /// Explain which layer provides the effective value for a key
+    ///
+    /// Supported keys: `tech_stack`, `project_name`, `dev_port`, `base_branch`,
+    /// `coverage_threshold`, and `commands.<name>`. Unknown keys resolve to
+    /// [`ConfigLayer::Default`] with no value.
+    pub fn explain(&self, key: &str) -> LayerResolution {
+        use ConfigLayer::{Global, Project, Stack};
+
+        let project = self.project.as_ref();
+        let stack = self.stack.as_ref();
+
+        let candidates = match key {
+            "tech_stack" => vec![
+                (
+                    Project,
+                    project
+                        .and_then(|p| p.get_tech_stack())
+                        .map(|s| s.as_str().to_string()),
+                ),
+                (
+                    Global,
+                    self.global.get_tech_stack().map(|s| s.as_str().to_string()),
+                ),
+            ],
+            "project_name" => vec![
+                (
+                    Project,
+                    project
+                        .map(|p| p.project.name.clone())
+                        .filter(|n| !n.is_empty()),
+                ),
+                (Global, self.global.get_project_name().map(String::from)),
+            ],
+            "dev_port" => vec![
+                (
+                    Project,
+                    project.and_then(|p| p.dev.port).map(|v| v.to_string()),
+                ),
+                (
+                    Stack,
+                    stack
+                        .and_then(|s| s.environment.dev_port)
+                        .map(|v| v.to_string()),
+                ),
+            ],
+            "base_branch" => vec![
+                (Project, project.and_then(|p| p.git.base_branch.clone())),
+                (Global, Some(self.global.git.default_base_branch.clone())),
+            ],
+            "coverage_threshold" => vec![
+                (
+                    Project,
+                    project
+                        .and_then(|p| p.quality.min_coverage)
+                        .map(|v| v.to_string()),
+                ),
+                (
+                    Stack,
+                    stack
+                        .and_then(|s| s.quality.coverage_threshold)
+                        .map(|v| v.to_string()),
+                ),
+            ],
+            _ => match key.strip_prefix("commands.") {
+                Some(name) => vec![
+                    (Project, project.and_then(|p| p.commands.get(name).cloned())),
+                    (Stack, stack.and_then(|s| s.commands.get(name).cloned())),
+                ],
+                None => Vec::new(),
+            },
+        };
+
+        LayerResolution::from_candidates(key, candidates)
+    }
 }
 
 /// Configuration version utilities
@@ -792,18 +1562,39 @@ mod tests {
     fn test_default_config() {
         let config = Config::default();
         assert_eq!(config.version, CONFIG_VERSION);
-        assert_eq!(config.general.language, "ja");
+        assert_eq!(config.general.language, "auto");
         assert_eq!(config.git.default_base_branch, "main");
         assert!(config.dev.auto_create_branch);
-        assert_eq!(config.performance.parallel_tasks, 4);
+        assert_eq!(config.performance.max_jobs, 4);
         assert_eq!(config.performance.timeout_seconds, 300);
     }
 
+    #[test]
+    fn test_load_hierarchical_finds_project_config_from_subdir() {
+        use crate::core::git_utils::run_git_checked;
+
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        run_git_checked(root, &["init", "-q"], "git init").unwrap();
+        fs::create_dir_all(root.join(".cldev")).unwrap();
+        fs::write(
+            root.join(".cldev/config.toml"),
+            "[git]\nbase_branch = \"develop\"\n",
+        )
+        .unwrap();
+        let nested = root.join("src/commands");
+        fs::create_dir_all(&nested).unwrap();
+
+        let config = Config::load_hierarchical(Some(nested)).unwrap();
+        assert_eq!(config.effective_base_branch(), "develop");
+    }
+
     #[test]
     fn test_config_serialization() {
         let config = Config::default();
         let toml_str = toml::to_string_pretty(&config).unwrap();
-        assert!(toml_str.contains("version = \"1.0.0\""));
+        assert!(toml_str.contains("version = \"2.0.0\""));
+        assert!(toml_str.contains("max_jobs = 4"));
         assert!(toml_str.contains("[general]"));
         assert!(toml_str.contains("[git]"));
     }
@@ -811,31 +1602,56 @@ mod tests {
     #[test]
     fn test_config_deserialization() {
         let toml_str = r#"
-            version = "1.0.0"
+            version = "2.0.0"
 
             [general]
             language = "en"
 
             [git]
             default_base_branch = "develop"
+
+            [lr]
+            sanitizer_allowlist = ["[0-9a-f]{40}"]
         "#;
 
         let config: Config = toml::from_str(toml_str).unwrap();
-        assert_eq!(config.version, "1.0.0");
+        assert_eq!(config.version, "2.0.0");
         assert_eq!(config.general.language, "en");
         assert_eq!(config.git.default_base_branch, "develop");
+        assert_eq!(config.lr.sanitizer_allowlist, vec!["[0-9a-f]{40}"]);
+        assert_eq!(config.lr.sanitizer_allowlist_patterns().unwrap().len(), 1);
+        assert_eq!(config.lr.capture, CaptureConfig::default());
+    }
+
+    #[test]
+    fn test_capture_config_deserialization() {
+        let toml_str = r#"
+            [lr.capture]
+            duration_tiers = [1, 2, 3]
+            full_score = 0.5
+        "#;
+
+        let config: Config = toml::from_str(toml_str).unwrap();
+        assert_eq!(config.lr.capture.duration_tiers, vec![1, 2, 3]);
+        assert_eq!(config.lr.capture.full_score, 0.5);
+        assert_eq!(config.lr.capture.error_tiers, vec![1, 3, 6]);
+        assert_eq!(config.lr.capture.background_score, 0.3);
+        assert_eq!(config.lr.search, SearchWeights::default());
     }
 
     #[test]
     fn test_version_validation() {
         // Same version - OK
-        assert!(validate_version("1.0.0").is_ok());
+        assert!(validate_version("2.0.0").is_ok());
 
         // Lower minor version - OK (backward compatible)
-        assert!(validate_version("1.0.0").is_ok());
+        assert!(validate_version("2.0.5").is_ok());
+
+        // Newer minor version - Error
+        assert!(validate_version("2.1.0").is_err());
 
         // Different major version - Error
-        assert!(validate_version("2.0.0").is_err());
+        assert!(validate_version("1.0.0").is_err());
 
         // Invalid format - Error
         assert!(validate_version("1.0").is_err());
@@ -876,7 +1692,31 @@ mod tests {
 
         let config = Config::load(Some(config_path)).unwrap();
         assert_eq!(config.version, CONFIG_VERSION);
-        assert_eq!(config.general.language, "ja");
+        assert_eq!(config.general.language, "auto");
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_symlinked_config_is_rejected() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let temp_dir = TempDir::new().unwrap();
+        let config_dir = temp_dir.path().join("cldev");
+        fs::create_dir(&config_dir).unwrap();
+
+        // The link target has safe permissions, but lives outside the config dir
+        let target = temp_dir.path().join("shared-config.toml");
+        Config::default().save(Some(target.clone())).unwrap();
+        assert_eq!(
+            fs::metadata(&target).unwrap().permissions().mode() & 0o777,
+            0o600
+        );
+
+        let link = config_dir.join("config.toml");
+        std::os::unix::fs::symlink(&target, &link).unwrap();
+
+        let err = Config::load(Some(link)).unwrap_err();
+        assert!(err.to_string().contains("Symlink"), "{}", err);
     }
 
     #[test]
@@ -897,6 +1737,296 @@ mod tests {
         assert_eq!(mode & 0o777, 0o600);
     }
 
+    #[test]
+    fn test_migrate_same_major_roundtrip() {
+        let mut config = Config::default();
+        config.general.language = "en".to_string();
+        config.performance.max_jobs = 8;
+
+        let value = toml::Value::try_from(&config).unwrap();
+        let migrated = migrate(value, CONFIG_VERSION, CONFIG_VERSION).unwrap();
+
+        assert_eq!(migrated.version, CONFIG_VERSION);
+        assert_eq!(migrated.general.language, "en");
+        assert_eq!(migrated.performance.max_jobs, 8);
+        assert_eq!(
+            toml::to_string_pretty(&migrated).unwrap(),
+            toml::to_string_pretty(&config).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_migrate_v1_to_v2_renames_parallel_tasks() {
+        let old: toml::Value = toml::from_str(
+            r#"
+            version = "1.0.0"
+
+            [performance]
+            parallel_tasks = 8
+            timeout_seconds = 600
+        "#,
+        )
+        .unwrap();
+
+        let value = migrate_value(old.clone(), "1.0.0", "2.0.0").unwrap();
+        assert_eq!(value["version"].as_str(), Some("2.0.0"));
+        assert_eq!(value["performance"]["max_jobs"].as_integer(), Some(8));
+        assert!(value["performance"].get("parallel_tasks").is_none());
+        assert_eq!(
+            value["performance"]["timeout_seconds"].as_integer(),
+            Some(600)
+        );
+
+        let config = migrate(old, "1.0.0", "2.0.0").unwrap();
+        assert_eq!(config.version, "2.0.0");
+        assert_eq!(config.performance.max_jobs, 8);
+        assert_eq!(config.performance.timeout_seconds, 600);
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_load_migrates_v1_file_in_memory() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("config.toml");
+        let content = "version = \"1.0.0\"\n\n[performance]\nparallel_tasks = 8\n";
+        fs::write(&config_path, content).unwrap();
+        fs::set_permissions(&config_path, fs::Permissions::from_mode(0o600)).unwrap();
+
+        let config = Config::load(Some(config_path.clone())).unwrap();
+        assert_eq!(config.version, CONFIG_VERSION);
+        assert_eq!(config.performance.max_jobs, 8);
+        assert_eq!(fs::read_to_string(&config_path).unwrap(), content);
+    }
+
+    #[test]
+    fn test_migrate_rejects_invalid_paths() {
+        let value = toml::Value::try_from(Config::default()).unwrap();
+
+        // Downgrades are not supported
+        assert!(migrate(value.clone(), "2.0.0", "1.0.0").is_err());
+        // No step is registered from v2 onwards
+        assert!(migrate(value.clone(), "2.0.0", "3.0.0").is_err());
+        // Malformed versions
+        assert!(migrate(value, "1.0", "2.0.0").is_err());
+    }
+
+    #[test]
+    fn test_get_path_nested() {
+        let config = Config::default();
+
+        assert_eq!(
+            config.get_path("git.default_base_branch"),
+            Some(toml::Value::String("main".to_string()))
+        );
+        assert_eq!(
+            config.get_path("performance.timeout_seconds"),
+            Some(toml::Value::Integer(300))
+        );
+        assert!(config.get_path("git").unwrap().is_table());
+        assert!(config.get_path("git.nonexistent").is_none());
+        assert!(config.get_path("general.tech_stack").is_none());
+    }
+
+    #[test]
+    fn test_set_path_coerces_types() {
+        let mut config = Config::default();
+
+        config
+            .set_path("performance.timeout_seconds", "600")
+            .unwrap();
+        config.set_path("ui.color", "false").unwrap();
+        config
+            .set_path("git.default_base_branch", "develop")
+            .unwrap();
+        config.set_path("lr.default_tags", "rust, cli").unwrap();
+        config.set_path("general.tech_stack", "rust").unwrap();
+
+        assert_eq!(config.performance.timeout_seconds, 600);
+        assert!(!config.ui.color);
+        assert_eq!(config.git.default_base_branch, "develop");
+        assert_eq!(config.lr.default_tags, vec!["rust", "cli"]);
+        assert_eq!(config.general.tech_stack.as_deref(), Some("rust"));
+    }
+
+    #[test]
+    fn test_set_path_type_mismatch() {
+        let mut config = Config::default();
+
+        assert!(config
+            .set_path("performance.timeout_seconds", "soon")
+            .is_err());
+        assert!(config.set_path("ui.color", "maybe").is_err());
+        assert!(config.set_path("performance.max_jobs", "-1").is_err());
+        assert_eq!(config.performance.timeout_seconds, 300);
+        assert!(config.ui.color);
+    }
+
+    #[test]
+    fn test_set_path_unknown_key() {
+        let mut config = Config::default();
+
+        assert!(config.set_path("git.nonexistent", "value").is_err());
+        assert!(config.set_path("nonexistent.key", "value").is_err());
+        assert!(config.set_path("git", "value").is_err());
+        assert!(config.set_path("git..auto_push", "true").is_err());
+    }
+
+    #[test]
+    fn test_validate_semantics_default_is_clean() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut config = Config::default();
+        config.general.claude_dir = temp_dir.path().join(".claude");
+        config.general.projects_dir = temp_dir.path().join("projects");
+
+        assert!(config.validate_semantics().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_validate_semantics_max_jobs() {
+        let mut config = Config::default();
+        config.performance.max_jobs = 0;
+
+        let issues = config.validate_semantics().unwrap();
+        assert!(issues.iter().any(|i| i.contains("max_jobs")));
+    }
+
+    #[test]
+    fn test_validate_semantics_timeout() {
+        let mut config = Config::default();
+        config.performance.timeout_seconds = 0;
+
+        let issues = config.validate_semantics().unwrap();
+        assert!(issues.iter().any(|i| i.contains("timeout_seconds")));
+    }
+
+    #[test]
+    fn test_validate_semantics_sanitizer_allowlist() {
+        let mut config = Config::default();
+        config.lr.sanitizer_allowlist = vec!["[0-9a-f]{40}".to_string(), "(unclosed".to_string()];
+
+        let issues = config.validate_semantics().unwrap();
+        assert_eq!(
+            issues
+                .iter()
+                .filter(|i| i.contains("lr.sanitizer_allowlist"))
+                .count(),
+            1
+        );
+        assert!(config.lr.sanitizer_allowlist_patterns().is_err());
+    }
+
+    #[test]
+    fn test_validate_semantics_language() {
+        let mut config = Config::default();
+        config.general.language = "zz-none".to_string();
+
+        let issues = config.validate_semantics().unwrap();
+        assert!(issues.iter().any(|i| i.contains("general.language")));
+
+        config.general.language = "auto".to_string();
+        let issues = config.validate_semantics().unwrap();
+        assert!(!issues.iter().any(|i| i.contains("general.language")));
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_validate_semantics_unwritable_parent() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let temp_dir = TempDir::new().unwrap();
+        let locked = temp_dir.path().join("locked");
+        fs::create_dir(&locked).unwrap();
+        fs::set_permissions(&locked, fs::Permissions::from_mode(0o500)).unwrap();
+
+        let mut config = Config::default();
+        config.general.claude_dir = locked.join(".claude");
+        config.general.projects_dir = temp_dir.path().join("projects");
+
+        let issues = config.validate_semantics().unwrap();
+        fs::set_permissions(&locked, fs::Permissions::from_mode(0o700)).unwrap();
+
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0].contains("general.claude_dir"));
+    }
+
+    #[test]
+    fn test_clamp_numeric_values() {
+        let mut config = Config::default();
+        assert!(!config.clamp_numeric_values());
+
+        config.performance.max_jobs = 0;
+        config.performance.timeout_seconds = 0;
+        assert!(config.clamp_numeric_values());
+        assert_eq!(config.performance.max_jobs, 4);
+        assert_eq!(config.performance.timeout_seconds, 300);
+    }
+
+    fn hierarchical_with_overrides() -> HierarchicalConfig {
+        let mut stack = StackConfig::default();
+        stack.environment.dev_port = Some(3000);
+        stack.quality.coverage_threshold = Some(70);
+        stack
+            .commands
+            .insert("build".to_string(), "npm run build".to_string());
+
+        let mut project = ProjectConfig::default();
+        project.dev.port = Some(4000);
+        project
+            .commands
+            .insert("build".to_string(), "pnpm build".to_string());
+
+        HierarchicalConfig {
+            global: Arc::new(Config::default()),
+            stack: Some(stack),
+            project: Some(project),
+        }
+    }
+
+    #[test]
+    fn test_explain_project_overrides_stack() {
+        let resolution = hierarchical_with_overrides().explain("dev_port");
+
+        assert_eq!(resolution.layer.name(), "project");
+        assert_eq!(resolution.value.as_deref(), Some("4000"));
+        assert_eq!(
+            resolution.overridden,
+            vec![(ConfigLayer::Stack, "3000".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_explain_falls_through_to_lower_layer() {
+        let config = hierarchical_with_overrides();
+
+        let coverage = config.explain("coverage_threshold");
+        assert_eq!(coverage.layer, ConfigLayer::Stack);
+        assert_eq!(coverage.value.as_deref(), Some("70"));
+        assert!(coverage.overridden.is_empty());
+
+        let branch = config.explain("base_branch");
+        assert_eq!(branch.layer, ConfigLayer::Global);
+        assert_eq!(branch.value, Some(config.effective_base_branch()));
+    }
+
+    #[test]
+    fn test_explain_commands_and_unknown_keys() {
+        let config = hierarchical_with_overrides();
+
+        let build = config.explain("commands.build");
+        assert_eq!(build.layer, ConfigLayer::Project);
+        assert_eq!(build.value.as_deref(), Some("pnpm build"));
+        assert_eq!(
+            build.overridden,
+            vec![(ConfigLayer::Stack, "npm run build".to_string())]
+        );
+
+        let unknown = config.explain("nonexistent");
+        assert_eq!(unknown.layer, ConfigLayer::Default);
+        assert!(unknown.value.is_none());
+    }
+
     #[test]
     fn test_arc_sharing() {
         let config = Config::load(None).unwrap();
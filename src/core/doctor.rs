@@ -0,0 +1,186 @@
+//! Check that the tools a project's stack needs are installed
+//!
+//! The tools come from the detected project type and build tool, so the
+//! checks match the commands `cldev` would run for the project.
+
+use crate::core::project_detector::{BuildTool, ProjectDetector, ProjectType};
+use std::ffi::OsString;
+use std::path::Path;
+
+/// Result of looking up one tool on `PATH`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ToolCheck {
+    /// Executable name looked up on `PATH`
+    pub tool: &'static str,
+    /// What the tool is used for
+    pub purpose: &'static str,
+    /// How to install the tool when it is missing
+    pub install_hint: &'static str,
+    /// Whether the executable was found
+    pub installed: bool,
+}
+
+/// `(tool, purpose, install hint)` for each tool a project needs
+type Requirement = (&'static str, &'static str, &'static str);
+
+/// Check the tools needed by the project found by `detector` against `PATH`
+pub fn check_tools(detector: &ProjectDetector) -> Vec<ToolCheck> {
+    check_tools_in(detector, std::env::var_os("PATH"))
+}
+
+/// Check the tools needed by the project against the given search path
+pub fn check_tools_in(detector: &ProjectDetector, path: Option<OsString>) -> Vec<ToolCheck> {
+    let cwd = detector.root();
+    required_tools(detector)
+        .into_iter()
+        .map(|(tool, purpose, install_hint)| ToolCheck {
+            tool,
+            purpose,
+            install_hint,
+            installed: is_installed(tool, path.as_ref(), cwd),
+        })
+        .collect()
+}
+
+fn is_installed(tool: &str, path: Option<&OsString>, cwd: &Path) -> bool {
+    which::which_in(tool, path, cwd).is_ok()
+}
+
+/// Tools needed for the detected project type and build tool
+fn required_tools(detector: &ProjectDetector) -> Vec<Requirement> {
+    let mut tools: Vec<Requirement> =
+        vec![("git", "version control", "https://git-scm.com/downloads")];
+
+    tools.extend(match detector.project_type() {
+        ProjectType::Rust => vec![
+            ("cargo", "build and test", "https://rustup.rs"),
+            ("cargo-clippy", "lint", "rustup component add clippy"),
+            ("rustfmt", "format", "rustup component add rustfmt"),
+        ],
+        ProjectType::NodeJs => vec![("node", "runtime", "https://nodejs.org")],
+        ProjectType::Go => vec![
+            ("go", "build, test and lint", "https://go.dev/dl/"),
+            ("gofmt", "format", "https://go.dev/dl/"),
+        ],
+        ProjectType::Python => vec![
+            ("python3", "runtime", "https://www.python.org/downloads/"),
+            ("pytest", "test", "pip install pytest"),
+            ("ruff", "lint and format", "pip install ruff"),
+        ],
+        ProjectType::Ruby => vec![("ruby", "runtime", "https://www.ruby-lang.org/en/downloads/")],
+        ProjectType::Java | ProjectType::Kotlin | ProjectType::Scala => {
+            vec![("java", "JVM runtime", "https://adoptium.net")]
+        }
+        ProjectType::Php => vec![("php", "runtime", "https://www.php.net/downloads")],
+        ProjectType::DotNet => vec![(
+            "dotnet",
+            "build and test",
+            "https://dotnet.microsoft.com/download",
+        )],
+        ProjectType::Elixir => vec![(
+            "mix",
+            "build and test",
+            "https://elixir-lang.org/install.html",
+        )],
+        ProjectType::Swift => vec![("swift", "build and test", "https://www.swift.org/install/")],
+        ProjectType::Unknown => Vec::new(),
+    });
+
+    tools.extend(match detector.build_tool() {
+        BuildTool::Npm => Some(("npm", "package manager", "https://nodejs.org")),
+        BuildTool::Yarn | BuildTool::YarnBerry => {
+            Some(("yarn", "package manager", "corepack enable"))
+        }
+        BuildTool::Pnpm => Some(("pnpm", "package manager", "corepack enable")),
+        BuildTool::Bun => Some(("bun", "runtime and package manager", "https://bun.sh")),
+        BuildTool::Poetry => Some(("poetry", "package manager", "pipx install poetry")),
+        BuildTool::Pipenv => Some(("pipenv", "package manager", "pipx install pipenv")),
+        BuildTool::Uv => Some(("uv", "package manager", "https://docs.astral.sh/uv/")),
+        BuildTool::Bundler => Some(("bundle", "package manager", "gem install bundler")),
+        BuildTool::Maven => Some((
+            "mvn",
+            "build and test",
+            "https://maven.apache.org/install.html",
+        )),
+        BuildTool::Composer => Some((
+            "composer",
+            "package manager",
+            "https://getcomposer.org/download/",
+        )),
+        BuildTool::Sbt => Some((
+            "sbt",
+            "build and test",
+            "https://www.scala-sbt.org/download/",
+        )),
+        // Gradle projects use the checked-in wrapper; the rest ship with the toolchain
+        _ => None,
+    });
+
+    if detector.project_type() == ProjectType::Python && detector.root().join("tox.ini").exists() {
+        tools.push(("tox", "test environments", "pip install tox"));
+    }
+
+    tools
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn fake_tool(dir: &Path, name: &str) {
+        let path = dir.join(name);
+        fs::write(&path, "#!/bin/sh\nexit 0\n").unwrap();
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            fs::set_permissions(&path, fs::Permissions::from_mode(0o755)).unwrap();
+        }
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_missing_tool_is_reported_absent() {
+        let project = TempDir::new().unwrap();
+        fs::write(
+            project.path().join("Cargo.toml"),
+            "[package]\nname = \"demo\"\n",
+        )
+        .unwrap();
+        let bin = TempDir::new().unwrap();
+        for tool in ["git", "cargo", "cargo-clippy"] {
+            fake_tool(bin.path(), tool);
+        }
+
+        let detector = ProjectDetector::new(Some(project.path())).unwrap();
+        let checks = check_tools_in(&detector, Some(bin.path().as_os_str().to_owned()));
+
+        let status: Vec<(&str, bool)> = checks.iter().map(|c| (c.tool, c.installed)).collect();
+        assert_eq!(
+            status,
+            vec![
+                ("git", true),
+                ("cargo", true),
+                ("cargo-clippy", true),
+                ("rustfmt", false)
+            ]
+        );
+        assert_eq!(checks[3].install_hint, "rustup component add rustfmt");
+    }
+
+    #[test]
+    fn test_node_project_checks_its_package_manager() {
+        let project = TempDir::new().unwrap();
+        fs::write(project.path().join("package.json"), r#"{"name": "web"}"#).unwrap();
+        fs::write(project.path().join("pnpm-lock.yaml"), "").unwrap();
+        let bin = TempDir::new().unwrap();
+
+        let detector = ProjectDetector::new(Some(project.path())).unwrap();
+        let checks = check_tools_in(&detector, Some(bin.path().as_os_str().to_owned()));
+
+        let tools: Vec<&str> = checks.iter().map(|c| c.tool).collect();
+        assert_eq!(tools, vec!["git", "node", "pnpm"]);
+        assert!(checks.iter().all(|c| !c.installed));
+    }
+}
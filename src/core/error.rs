@@ -27,9 +27,18 @@ pub enum CldevError {
     #[error("Validation error: {0}")]
     Validation(String),
 
+    #[error("Security error: {0}")]
+    Security(String),
+
     #[error("Command execution error: {0}")]
     Command(String),
 
+    #[error("Command failed with exit code {code}: {command}")]
+    CommandFailed { command: String, code: i32 },
+
+    #[error("Command timed out after {secs}s: {command}")]
+    Timeout { command: String, secs: u64 },
+
     #[error("Git error: {0}")]
     Git(String),
 
@@ -61,6 +70,22 @@ impl CldevError {
         CldevError::Command(msg.into())
     }
 
+    /// Create a new error for an external command that exited unsuccessfully
+    pub fn command_failed<S: Into<String>>(command: S, code: i32) -> Self {
+        CldevError::CommandFailed {
+            command: command.into(),
+            code,
+        }
+    }
+
+    /// Create a new error for an external command killed after `secs` seconds
+    pub fn timeout<S: Into<String>>(command: S, secs: u64) -> Self {
+        CldevError::Timeout {
+            command: command.into(),
+            secs,
+        }
+    }
+
     /// Create a new IO error with custom message
     pub fn io<S: Into<String>>(msg: S) -> Self {
         CldevError::Io(std::io::Error::other(msg.into()))
@@ -68,7 +93,7 @@ impl CldevError {
 
     /// Create a new security error
     pub fn security<S: Into<String>>(msg: S) -> Self {
-        CldevError::Config(format!("Security: {}", msg.into()))
+        CldevError::Security(msg.into())
     }
 
     /// Create a new Git error
@@ -77,6 +102,43 @@ impl CldevError {
     }
 }
 
+/// Exit code for configuration errors, including unreadable config files
+pub const EXIT_CONFIG: i32 = 2;
+
+/// Exit code for invalid input
+pub const EXIT_VALIDATION: i32 = 3;
+
+/// Exit code for security violations (insecure permissions, path traversal)
+pub const EXIT_SECURITY: i32 = 4;
+
+/// Exit code for filesystem and I/O errors
+pub const EXIT_IO: i32 = 5;
+
+/// Exit code when an external command is killed for exceeding its timeout
+/// (the same code as coreutils `timeout`)
+pub const EXIT_TIMEOUT: i32 = 124;
+
+impl CldevError {
+    /// Get the process exit code for the error
+    ///
+    /// A failed external command passes its own exit code through, so
+    /// `cldev quality test` exits like the test runner did. Errors without a
+    /// more specific code exit with 1.
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            CldevError::Config(_) | CldevError::TomlParse(_) | CldevError::TomlSerialize(_) => {
+                EXIT_CONFIG
+            }
+            CldevError::Validation(_) => EXIT_VALIDATION,
+            CldevError::Security(_) => EXIT_SECURITY,
+            CldevError::Io(_) => EXIT_IO,
+            CldevError::CommandFailed { code, .. } if (1..=255).contains(code) => *code,
+            CldevError::Timeout { .. } => EXIT_TIMEOUT,
+            _ => 1,
+        }
+    }
+}
+
 // Implement From for dialoguer::Error
 impl From<dialoguer::Error> for CldevError {
     fn from(err: dialoguer::Error) -> Self {
@@ -84,6 +146,13 @@ impl From<dialoguer::Error> for CldevError {
     }
 }
 
+// Implement From for std::fmt::Error (writing output into a buffer)
+impl From<std::fmt::Error> for CldevError {
+    fn from(err: std::fmt::Error) -> Self {
+        CldevError::Command(format!("Formatting error: {}", err))
+    }
+}
+
 // Implement From for serde_json::Error
 impl From<serde_json::Error> for CldevError {
     fn from(err: serde_json::Error) -> Self {
@@ -97,3 +166,61 @@ impl From<git2::Error> for CldevError {
         CldevError::Git(err.to_string())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_config_errors_exit_with_config_code() {
+        assert_eq!(CldevError::config("bad").exit_code(), EXIT_CONFIG);
+        let parse = toml::from_str::<toml::Value>("key = [").unwrap_err();
+        assert_eq!(CldevError::from(parse).exit_code(), EXIT_CONFIG);
+    }
+
+    #[test]
+    fn test_validation_error_exit_code() {
+        assert_eq!(CldevError::validation("bad").exit_code(), EXIT_VALIDATION);
+    }
+
+    #[test]
+    fn test_security_error_exit_code() {
+        let err = CldevError::security("path traversal");
+        assert_eq!(err.exit_code(), EXIT_SECURITY);
+        assert_eq!(err.to_string(), "Security error: path traversal");
+    }
+
+    #[test]
+    fn test_io_error_exit_code() {
+        assert_eq!(CldevError::io("disk full").exit_code(), EXIT_IO);
+    }
+
+    #[test]
+    fn test_command_failed_propagates_child_exit_code() {
+        assert_eq!(CldevError::command_failed("npm test", 7).exit_code(), 7);
+        // Killed by a signal (no code) or out of range for a process exit code
+        assert_eq!(CldevError::command_failed("npm test", -1).exit_code(), 1);
+        assert_eq!(CldevError::command_failed("npm test", 256).exit_code(), 1);
+    }
+
+    #[test]
+    fn test_timeout_exit_code() {
+        assert_eq!(
+            CldevError::timeout("npm test", 300).exit_code(),
+            EXIT_TIMEOUT
+        );
+    }
+
+    #[test]
+    fn test_other_errors_exit_with_one() {
+        for err in [
+            CldevError::editor("vim crashed"),
+            CldevError::command("no such tool"),
+            CldevError::git("detached HEAD"),
+            CldevError::Env("HOME".to_string()),
+            CldevError::Dialog("closed".to_string()),
+        ] {
+            assert_eq!(err.exit_code(), 1, "{}", err);
+        }
+    }
+}
@@ -0,0 +1,202 @@
+//! External command execution bounded by `performance.timeout_seconds`
+//!
+//! A hung tool (a test runner stuck on a lock, a prompt waiting on stdin) would
+//! otherwise block `cldev` forever. The helpers here poll the child and kill
+//! it once the timeout passes, returning [`CldevError::Timeout`].
+//!
+//! Only the direct child is killed; tools that fork workers of their own
+//! may leave those running.
+
+use crate::core::config::PerformanceConfig;
+use crate::core::error::{CldevError, Result};
+use std::ffi::OsStr;
+use std::io::Read;
+use std::process::{Child, Command, ExitStatus, Output, Stdio};
+use std::sync::OnceLock;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// How often a running child is checked for exit
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+// Timeout set once from the configuration loaded at startup
+static TIMEOUT: OnceLock<Duration> = OnceLock::new();
+
+/// Use `timeout` for external commands for the rest of this process
+///
+/// Called once with `performance.timeout_seconds` after the configuration
+/// is loaded; later calls are ignored.
+pub fn set_timeout(timeout: Duration) {
+    let _ = TIMEOUT.set(timeout);
+}
+
+/// Timeout for external commands
+///
+/// This is the timeout passed to [`set_timeout`], or the default one before
+/// the configuration is loaded.
+pub fn configured_timeout() -> Duration {
+    TIMEOUT
+        .get()
+        .copied()
+        .unwrap_or_else(|| PerformanceConfig::default().timeout())
+}
+
+/// Run `program` with `args`, capturing its output, and kill it after `timeout`
+pub fn run_with_timeout<S: AsRef<OsStr>>(
+    program: &str,
+    args: &[S],
+    timeout: Duration,
+) -> Result<Output> {
+    output_with_timeout(Command::new(program).args(args), timeout)
+}
+
+/// Like [`Command::output`], but kill the child after `timeout`
+pub fn output_with_timeout(cmd: &mut Command, timeout: Duration) -> Result<Output> {
+    piped_output_with_timeout(
+        cmd.stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped()),
+        timeout,
+    )
+}
+
+/// Run `cmd` with its stdio as configured, capturing the piped streams
+///
+/// Streams not set to [`Stdio::piped`] are left alone and come back empty,
+/// so e.g. stdout can stream live while stderr is captured.
+pub fn piped_output_with_timeout(cmd: &mut Command, timeout: Duration) -> Result<Output> {
+    let mut child = spawn(cmd)?;
+
+    // Drain the pipes while waiting so a chatty child cannot fill one and stall
+    let stdout = child.stdout.take().map(read_to_end);
+    let stderr = child.stderr.take().map(read_to_end);
+    let status = wait_with_timeout(&mut child, cmd, timeout)?;
+
+    Ok(Output {
+        status,
+        stdout: stdout.map(join_reader).unwrap_or_default(),
+        stderr: stderr.map(join_reader).unwrap_or_default(),
+    })
+}
+
+/// Like [`Command::status`], but kill the child after `timeout`
+///
+/// The child inherits stdin, stdout and stderr, so its output streams live.
+pub fn status_with_timeout(cmd: &mut Command, timeout: Duration) -> Result<ExitStatus> {
+    let mut child = spawn(cmd)?;
+    wait_with_timeout(&mut child, cmd, timeout)
+}
+
+/// Turn an unsuccessful `status` of `cmd` into [`CldevError::CommandFailed`]
+///
+/// A child killed by a signal has no exit code and is reported as `-1`.
+pub fn ensure_success(cmd: &Command, status: ExitStatus) -> Result<()> {
+    if status.success() {
+        Ok(())
+    } else {
+        Err(CldevError::command_failed(
+            display_command(cmd),
+            status.code().unwrap_or(-1),
+        ))
+    }
+}
+
+/// Program and arguments of `cmd` joined with spaces, for messages
+pub fn display_command(cmd: &Command) -> String {
+    std::iter::once(cmd.get_program())
+        .chain(cmd.get_args())
+        .map(|part| part.to_string_lossy())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn spawn(cmd: &mut Command) -> Result<Child> {
+    cmd.spawn().map_err(|e| {
+        CldevError::command(format!(
+            "Failed to execute '{}': {}",
+            cmd.get_program().to_string_lossy(),
+            e
+        ))
+    })
+}
+
+fn wait_with_timeout(child: &mut Child, cmd: &Command, timeout: Duration) -> Result<ExitStatus> {
+    let deadline = Instant::now() + timeout;
+    loop {
+        if let Some(status) = child.try_wait()? {
+            return Ok(status);
+        }
+
+        let now = Instant::now();
+        if now >= deadline {
+            let _ = child.kill();
+            let _ = child.wait();
+            return Err(CldevError::timeout(display_command(cmd), timeout.as_secs()));
+        }
+        thread::sleep(POLL_INTERVAL.min(deadline - now));
+    }
+}
+
+fn read_to_end<R: Read + Send + 'static>(mut pipe: R) -> thread::JoinHandle<Vec<u8>> {
+    thread::spawn(move || {
+        let mut buf = Vec::new();
+        let _ = pipe.read_to_end(&mut buf);
+        buf
+    })
+}
+
+fn join_reader(handle: thread::JoinHandle<Vec<u8>>) -> Vec<u8> {
+    handle.join().unwrap_or_default()
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_slow_command_times_out() {
+        let start = Instant::now();
+        let err = run_with_timeout("sleep", &["5"], Duration::from_millis(200)).unwrap_err();
+
+        assert!(
+            matches!(&err, CldevError::Timeout { command, secs: 0 } if command == "sleep 5"),
+            "{:?}",
+            err
+        );
+        assert_eq!(err.exit_code(), crate::core::error::EXIT_TIMEOUT);
+        assert!(start.elapsed() < Duration::from_secs(2));
+    }
+
+    #[test]
+    fn test_fast_command_output_is_captured() {
+        let output = run_with_timeout(
+            "sh",
+            &["-c", "echo out; echo err >&2; exit 3"],
+            Duration::from_secs(5),
+        )
+        .unwrap();
+
+        assert_eq!(output.status.code(), Some(3));
+        assert_eq!(output.stdout, b"out\n");
+        assert_eq!(output.stderr, b"err\n");
+    }
+
+    #[test]
+    fn test_failing_command_reports_exit_code() {
+        let mut cmd = Command::new("sh");
+        cmd.args(["-c", "exit 7"]);
+        let status = status_with_timeout(&mut cmd, Duration::from_secs(5)).unwrap();
+        let err = ensure_success(&cmd, status).unwrap_err();
+
+        assert!(
+            matches!(&err, CldevError::CommandFailed { command, code: 7 } if command == "sh -c exit 7"),
+            "{:?}",
+            err
+        );
+        assert_eq!(err.exit_code(), 7);
+        assert_eq!(
+            err.to_string(),
+            "Command failed with exit code 7: sh -c exit 7"
+        );
+    }
+}
@@ -9,9 +9,10 @@
 #![allow(dead_code)]
 
 use crate::core::error::{CldevError, Result};
-use git2::{Repository, StatusOptions};
-use std::path::Path;
-use std::process::Command;
+use git2::{Repository, Status, StatusOptions};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
 
 /// Remote hosting service type
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -41,6 +42,113 @@ impl RemoteType {
     }
 }
 
+/// How a file changed in a diff
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DiffStatus {
+    Added,
+    Modified,
+    Deleted,
+    Renamed { from: String },
+}
+
+/// A changed file with its status and line counts
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileDiff {
+    pub path: String,
+    pub added: usize,
+    pub removed: usize,
+    pub status: DiffStatus,
+    /// Binary files have no line counts (`-` in `--numstat`)
+    pub binary: bool,
+}
+
+impl FileDiff {
+    /// Total number of changed lines
+    pub fn changed(&self) -> usize {
+        self.added + self.removed
+    }
+
+    /// Combine the output of `git diff -M --numstat` and `git diff -M --name-status`
+    ///
+    /// Renames appear in numstat as `old => new` or `dir/{old => new}.rs` and
+    /// are reported under the new path.
+    pub fn parse(numstat: &str, name_status: &str) -> Vec<FileDiff> {
+        let statuses: HashMap<&str, DiffStatus> = name_status
+            .lines()
+            .filter_map(|line| {
+                let mut parts = line.split('\t');
+                let code = parts.next()?;
+                let first = parts.next()?;
+                let status = match code.chars().next()? {
+                    'A' => DiffStatus::Added,
+                    'D' => DiffStatus::Deleted,
+                    'R' => {
+                        return Some((
+                            parts.next()?,
+                            DiffStatus::Renamed {
+                                from: first.to_string(),
+                            },
+                        ))
+                    }
+                    _ => DiffStatus::Modified,
+                };
+                Some((first, status))
+            })
+            .collect();
+
+        numstat
+            .lines()
+            .filter_map(|line| {
+                let mut parts = line.splitn(3, '\t');
+                let added = parts.next()?;
+                let removed = parts.next()?;
+                let (from, path) = split_rename(parts.next()?.trim());
+                if path.is_empty() {
+                    return None;
+                }
+
+                let status = statuses.get(path.as_str()).cloned().unwrap_or(match from {
+                    Some(from) => DiffStatus::Renamed { from },
+                    None => DiffStatus::Modified,
+                });
+                Some(FileDiff {
+                    binary: added == "-",
+                    added: added.parse().unwrap_or(0),
+                    removed: removed.parse().unwrap_or(0),
+                    path,
+                    status,
+                })
+            })
+            .collect()
+    }
+}
+
+/// Split a numstat rename path into `(Some(old), new)`
+///
+/// Handles both `old => new` and the compact `prefix{old => new}suffix` form.
+fn split_rename(path: &str) -> (Option<String>, String) {
+    if !path.contains(" => ") {
+        return (None, path.to_string());
+    }
+
+    if let (Some(open), Some(close)) = (path.find('{'), path.rfind('}')) {
+        if open < close {
+            let (prefix, suffix) = (&path[..open], &path[close + 1..]);
+            if let Some((old, new)) = path[open + 1..close].split_once(" => ") {
+                // `src/{ => nested}/a.rs` leaves a doubled slash when one side is empty
+                let join =
+                    |middle: &str| format!("{}{}{}", prefix, middle, suffix).replace("//", "/");
+                return (Some(join(old)), join(new));
+            }
+        }
+    }
+
+    match path.split_once(" => ") {
+        Some((old, new)) => (Some(old.to_string()), new.to_string()),
+        None => (None, path.to_string()),
+    }
+}
+
 /// Git repository wrapper for utility operations
 pub struct GitUtils {
     repo: Repository,
@@ -54,6 +162,13 @@ impl GitUtils {
         Ok(Self { repo })
     }
 
+    /// Open the Git repository containing `path`, searching parent directories
+    pub fn discover<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let repo = Repository::discover(path.as_ref())
+            .map_err(|e| CldevError::Git(format!("Failed to open Git repository: {}", e)))?;
+        Ok(Self { repo })
+    }
+
     /// Open a Git repository at a specific path
     pub fn open<P: AsRef<Path>>(path: P) -> Result<Self> {
         let repo = Repository::open(path.as_ref())
@@ -61,6 +176,24 @@ impl GitUtils {
         Ok(Self { repo })
     }
 
+    /// Top-level directory of the repository containing the current directory
+    ///
+    /// Commands that look for project files should resolve paths from here
+    /// rather than assuming they run from the repository root.
+    pub fn repo_root() -> Result<PathBuf> {
+        Self::repo_root_from(&std::env::current_dir()?)
+    }
+
+    /// Top-level directory of the repository containing `dir`
+    pub fn repo_root_from(dir: &Path) -> Result<PathBuf> {
+        let toplevel = run_git_checked(
+            dir,
+            &["rev-parse", "--show-toplevel"],
+            "Not inside a Git repository",
+        )?;
+        Ok(PathBuf::from(toplevel.trim()))
+    }
+
     /// Get the remote URL for a given remote name
     pub fn get_remote_url(&self, remote_name: &str) -> Result<String> {
         let remote = self.repo.find_remote(remote_name).map_err(|e| {
@@ -122,9 +255,12 @@ impl GitUtils {
     }
 
     /// Get a list of changed files
+    ///
+    /// Files inside untracked directories are listed individually.
     pub fn changed_files(&self) -> Result<Vec<String>> {
         let mut opts = StatusOptions::new();
         opts.include_untracked(true);
+        opts.recurse_untracked_dirs(true);
         opts.include_ignored(false);
 
         let statuses = self
@@ -142,6 +278,34 @@ impl GitUtils {
         Ok(files)
     }
 
+    /// Get files staged in the index, excluding staged deletions
+    pub fn staged_files(&self) -> Result<Vec<String>> {
+        let mut opts = StatusOptions::new();
+        opts.include_untracked(false);
+        opts.include_ignored(false);
+
+        let statuses = self
+            .repo
+            .statuses(Some(&mut opts))
+            .map_err(|e| CldevError::Git(format!("Failed to get repository status: {}", e)))?;
+
+        let staged = Status::INDEX_NEW
+            | Status::INDEX_MODIFIED
+            | Status::INDEX_RENAMED
+            | Status::INDEX_TYPECHANGE;
+
+        let mut files = Vec::new();
+        for entry in statuses.iter() {
+            if entry.status().intersects(staged) {
+                if let Some(path) = entry.path() {
+                    files.push(path.to_string());
+                }
+            }
+        }
+
+        Ok(files)
+    }
+
     /// Get the count of unpushed commits
     pub fn unpushed_commits(&self, remote_name: &str) -> Result<usize> {
         let local_branch = self.current_branch()?;
@@ -232,6 +396,49 @@ impl GitUtils {
         Ok(())
     }
 
+    /// Parsed per-file diff of staged (`--cached`) or unstaged changes
+    ///
+    /// Renames are detected with `-M`; untracked files are not included.
+    pub fn diff_stats(&self, staged: bool) -> Result<Vec<FileDiff>> {
+        let workdir = self.workdir()?;
+        let diff = |format: &str| {
+            let mut args = vec!["diff", "-M", format];
+            if staged {
+                args.push("--cached");
+            }
+            run_git_checked(workdir, &args, "git diff failed")
+        };
+
+        Ok(FileDiff::parse(
+            &diff("--numstat")?,
+            &diff("--name-status")?,
+        ))
+    }
+
+    /// Most recent tag reachable from HEAD, or None if there are no tags
+    pub fn latest_tag(&self) -> Result<Option<String>> {
+        let output = run_git(self.workdir()?, &["describe", "--tags", "--abbrev=0"])?;
+        if !output.status.success() {
+            return Ok(None);
+        }
+        let tag = String::from_utf8_lossy(&output.stdout).trim().to_string();
+        Ok((!tag.is_empty()).then_some(tag))
+    }
+
+    /// Files changed in the working tree since `rev`, relative to the repository root
+    pub fn files_changed_since(&self, rev: &str) -> Result<Vec<String>> {
+        let output = run_git_checked(
+            self.workdir()?,
+            &["diff", "--name-only", rev, "--"],
+            &format!("Failed to diff against {}", rev),
+        )?;
+        Ok(output
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(str::to_string)
+            .collect())
+    }
+
     /// Suggest commit description based on changed files and git diff
     pub fn suggest_commit_description(&self) -> Result<String> {
         let files = self.changed_files()?;
@@ -318,6 +525,82 @@ impl GitUtils {
     }
 }
 
+/// Run git in a repository directory
+pub fn run_git(repo: &Path, args: &[&str]) -> Result<Output> {
+    Command::new("git")
+        .arg("-C")
+        .arg(repo)
+        .args(args)
+        .output()
+        .map_err(|e| CldevError::command(format!("Failed to execute git: {}", e)))
+}
+
+/// Run git and return stdout, failing with `context` and stderr on error
+pub fn run_git_checked(repo: &Path, args: &[&str], context: &str) -> Result<String> {
+    let output = run_git(repo, args)?;
+    if !output.status.success() {
+        return Err(CldevError::git(format!(
+            "{}: {}",
+            context,
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Commit history of a single file
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ChurnStats {
+    /// Number of commits that touched the file
+    pub commits: usize,
+    /// Commits that touched the file in the last [`RECENT_CHURN_DAYS`] days
+    pub recent_commits: usize,
+    /// Author date of the latest commit touching the file (`YYYY-MM-DD`)
+    pub last_modified: Option<String>,
+}
+
+/// Window for [`ChurnStats::recent_commits`]
+pub const RECENT_CHURN_DAYS: i64 = 90;
+
+/// Collect the commit history of `path` from the repository containing it
+///
+/// Equivalent to counting `git log --oneline -- <file>` and reading the
+/// dates from `git log --format=%ad`. Files with no history report zero
+/// commits; paths outside a repository are an error.
+pub fn file_churn(path: &Path) -> Result<ChurnStats> {
+    let dir = match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => Path::new("."),
+    };
+    let file = path
+        .file_name()
+        .ok_or_else(|| CldevError::validation(format!("Not a file: {}", path.display())))?
+        .to_string_lossy();
+
+    let log = run_git_checked(
+        dir,
+        &[
+            "log",
+            "--follow",
+            "--format=%ad",
+            "--date=short",
+            "--",
+            &file,
+        ],
+        "Failed to read file history",
+    )?;
+    let dates: Vec<&str> = log.lines().filter(|l| !l.is_empty()).collect();
+
+    let cutoff = (chrono::Local::now() - chrono::Duration::days(RECENT_CHURN_DAYS))
+        .format("%Y-%m-%d")
+        .to_string();
+    Ok(ChurnStats {
+        commits: dates.len(),
+        recent_commits: dates.iter().filter(|d| **d >= cutoff.as_str()).count(),
+        last_modified: dates.first().map(|d| d.to_string()),
+    })
+}
+
 /// Check if the GitHub CLI (gh) is installed and available
 pub fn check_gh_cli() -> Result<bool> {
     match which::which("gh") {
@@ -359,6 +642,152 @@ pub fn check_cli_for_remote(remote_type: RemoteType) -> Result<bool> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_split_rename() {
+        assert_eq!(
+            split_rename("src/main.rs"),
+            (None, "src/main.rs".to_string())
+        );
+        assert_eq!(
+            split_rename("old.txt => new.txt"),
+            (Some("old.txt".to_string()), "new.txt".to_string())
+        );
+        assert_eq!(
+            split_rename("src/{util => helpers}/mod.rs"),
+            (
+                Some("src/util/mod.rs".to_string()),
+                "src/helpers/mod.rs".to_string()
+            )
+        );
+        assert_eq!(
+            split_rename("src/{ => core}/lib.rs"),
+            (
+                Some("src/lib.rs".to_string()),
+                "src/core/lib.rs".to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn test_diff_stats_added_modified_renamed() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let repo = temp_dir.path();
+        let git = |args: &[&str]| run_git_checked(repo, args, "git").unwrap();
+        git(&["init", "-q"]);
+
+        let body: String = (0..20).map(|i| format!("line {}\n", i)).collect();
+        std::fs::write(repo.join("modified.txt"), "one\ntwo\n").unwrap();
+        std::fs::write(repo.join("old.txt"), &body).unwrap();
+        git(&["add", "-A"]);
+        git(&[
+            "-c",
+            "user.name=Test",
+            "-c",
+            "user.email=test@example.com",
+            "commit",
+            "-q",
+            "-m",
+            "initial",
+        ]);
+
+        std::fs::write(repo.join("added.txt"), "new\n").unwrap();
+        std::fs::write(repo.join("logo.bin"), [0u8, 159, 146, 150]).unwrap();
+        std::fs::write(repo.join("modified.txt"), "one\n2\nthree\n").unwrap();
+        git(&["mv", "old.txt", "new.txt"]);
+        git(&["add", "-A"]);
+        std::fs::write(repo.join("modified.txt"), "one\n").unwrap();
+
+        let git_utils = GitUtils::open(repo).unwrap();
+        let mut staged = git_utils.diff_stats(true).unwrap();
+        staged.sort_by(|a, b| a.path.cmp(&b.path));
+
+        let summary: Vec<(&str, usize, usize, &DiffStatus, bool)> = staged
+            .iter()
+            .map(|f| (f.path.as_str(), f.added, f.removed, &f.status, f.binary))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("added.txt", 1, 0, &DiffStatus::Added, false),
+                ("logo.bin", 0, 0, &DiffStatus::Added, true),
+                ("modified.txt", 2, 1, &DiffStatus::Modified, false),
+                (
+                    "new.txt",
+                    0,
+                    0,
+                    &DiffStatus::Renamed {
+                        from: "old.txt".to_string()
+                    },
+                    false
+                ),
+            ]
+        );
+
+        let unstaged = git_utils.diff_stats(false).unwrap();
+        assert_eq!(unstaged.len(), 1);
+        assert_eq!(unstaged[0].path, "modified.txt");
+        assert_eq!((unstaged[0].added, unstaged[0].removed), (0, 2));
+    }
+
+    #[test]
+    fn test_repo_root_from_nested_dir() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let repo = temp_dir.path().canonicalize().unwrap();
+        run_git_checked(&repo, &["init", "-q"], "git init").unwrap();
+        let nested = repo.join("crates/core/src");
+        std::fs::create_dir_all(&nested).unwrap();
+
+        let root = GitUtils::repo_root_from(&nested).unwrap();
+        assert_eq!(root.canonicalize().unwrap(), repo);
+    }
+
+    #[test]
+    fn test_file_churn_counts_commits() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let repo = temp_dir.path();
+        run_git_checked(repo, &["init", "-q"], "git init").unwrap();
+        std::fs::create_dir_all(repo.join("src")).unwrap();
+
+        let commit = |message: &str| {
+            run_git_checked(repo, &["add", "-A"], "git add").unwrap();
+            run_git_checked(
+                repo,
+                &[
+                    "-c",
+                    "user.name=Test",
+                    "-c",
+                    "user.email=test@example.com",
+                    "commit",
+                    "-q",
+                    "-m",
+                    message,
+                ],
+                "git commit",
+            )
+            .unwrap();
+        };
+
+        std::fs::write(repo.join("src/stable.rs"), "fn stable() {}\n").unwrap();
+        for i in 0..4 {
+            std::fs::write(repo.join("src/hot.rs"), format!("fn hot() {{ {} }}\n", i)).unwrap();
+            commit(&format!("change hot {}", i));
+        }
+
+        let hot = file_churn(&repo.join("src/hot.rs")).unwrap();
+        assert_eq!(hot.commits, 4);
+        assert_eq!(hot.recent_commits, 4);
+        assert_eq!(
+            hot.last_modified,
+            Some(chrono::Local::now().format("%Y-%m-%d").to_string())
+        );
+
+        assert_eq!(file_churn(&repo.join("src/stable.rs")).unwrap().commits, 1);
+        assert_eq!(
+            file_churn(&repo.join("src/new.rs")).unwrap(),
+            ChurnStats::default()
+        );
+    }
+
     #[test]
     fn test_detect_remote_type_github() {
         assert_eq!(
@@ -387,6 +816,21 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_staged_files_excludes_unstaged() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let repo = Repository::init(temp_dir.path()).unwrap();
+        std::fs::write(temp_dir.path().join("staged.rs"), "fn main() {}").unwrap();
+        std::fs::write(temp_dir.path().join("untracked.rs"), "fn main() {}").unwrap();
+
+        let mut index = repo.index().unwrap();
+        index.add_path(Path::new("staged.rs")).unwrap();
+        index.write().unwrap();
+
+        let git_utils = GitUtils::open(temp_dir.path()).unwrap();
+        assert_eq!(git_utils.staged_files().unwrap(), vec!["staged.rs"]);
+    }
+
     #[test]
     fn test_detect_remote_type_other() {
         assert_eq!(
@@ -9,11 +9,14 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::env;
 use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
 
+use super::config::home_dir;
 use super::error::{CldevError, Result};
 
 /// Supported languages
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 #[serde(rename_all = "lowercase")]
 pub enum Language {
     /// English
@@ -23,36 +26,80 @@ pub enum Language {
     /// Japanese
     #[serde(rename = "ja")]
     Japanese,
+    /// Language provided by an external catalog (`~/.config/cldev/i18n/{code}.toml`)
+    #[serde(skip)]
+    External(String),
 }
 
 impl Language {
-    /// Detect language from environment variables
+    /// Detect language from the locale environment variables
     pub fn detect() -> Self {
-        // Check LANG environment variable
-        if let Ok(lang) = env::var("LANG") {
-            let lang_lower = lang.to_lowercase();
-            if lang_lower.starts_with("ja") {
-                return Language::Japanese;
-            }
+        Self::detect_from(|var| env::var(var).ok())
+    }
+
+    /// Detect language from locale variables looked up with `lookup`
+    ///
+    /// The locale is the first non-empty of `LC_ALL`, `LC_MESSAGES` and
+    /// `LANG`, following POSIX precedence. Japanese locales select Japanese,
+    /// locales with an installed external catalog select that catalog, and
+    /// everything else falls back to English.
+    pub fn detect_from<F>(lookup: F) -> Self
+    where
+        F: Fn(&str) -> Option<String>,
+    {
+        let Some(locale) = ["LC_ALL", "LC_MESSAGES", "LANG"]
+            .into_iter()
+            .filter_map(lookup)
+            .find(|value| !value.is_empty())
+        else {
+            return Language::English;
+        };
+
+        if locale.to_lowercase().starts_with("ja") {
+            return Language::Japanese;
         }
 
-        // Check LC_ALL as fallback
-        if let Ok(lang) = env::var("LC_ALL") {
-            let lang_lower = lang.to_lowercase();
-            if lang_lower.starts_with("ja") {
-                return Language::Japanese;
-            }
+        // Use an external catalog matching the locale, if one is installed
+        Self::external_for_locale(&locale).unwrap_or(Language::English)
+    }
+
+    /// Map a locale such as `fr_FR.UTF-8` to an installed external catalog
+    fn external_for_locale(locale: &str) -> Option<Self> {
+        let code = locale
+            .split(['_', '.', '@'])
+            .next()
+            .unwrap_or_default()
+            .to_lowercase();
+        if Self::from_code(&code).is_some() || !is_valid_code(&code) {
+            return None;
         }
 
-        // Default to English
-        Language::English
+        external_catalog_path(&code).map(|_| Language::External(code))
     }
 
     /// Get language code as string
-    pub fn code(&self) -> &'static str {
+    pub fn code(&self) -> &str {
         match self {
             Language::English => "en",
             Language::Japanese => "ja",
+            Language::External(code) => code,
+        }
+    }
+
+    /// Catalog suffix of the plural form used for `count` (`one` or `other`)
+    ///
+    /// Japanese does not inflect for number, so it always uses `other`.
+    /// External languages follow the English rule.
+    pub fn plural_form(&self, count: usize) -> &'static str {
+        match self {
+            Language::Japanese => "other",
+            Language::English | Language::External(_) => {
+                if count == 1 {
+                    "one"
+                } else {
+                    "other"
+                }
+            }
         }
     }
 
@@ -66,12 +113,50 @@ impl Language {
     }
 }
 
+/// Whether `code` is safe to use as a catalog file name (e.g. `fr`, `pt-br`)
+pub fn is_valid_code(code: &str) -> bool {
+    !code.is_empty() && code.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+}
+
+/// Directory holding external catalogs (`~/.config/cldev/i18n`)
+pub fn external_catalog_dir() -> Option<PathBuf> {
+    home_dir()
+        .ok()
+        .map(|home| home.join(".config").join("cldev").join("i18n"))
+}
+
+/// Path of the installed external catalog for `code`, if any
+///
+/// Both `{code}.toml` and `{code}.json` are recognized.
+pub fn external_catalog_path(code: &str) -> Option<PathBuf> {
+    if !is_valid_code(code) {
+        return None;
+    }
+
+    let dir = external_catalog_dir()?;
+    ["toml", "json"]
+        .iter()
+        .map(|ext| dir.join(format!("{}.{}", code, ext)))
+        .find(|path| path.is_file())
+}
+
 impl fmt::Display for Language {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", self.code())
     }
 }
 
+/// Where a looked-up message comes from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MessageSource {
+    /// Translated in the requested language
+    Language,
+    /// Missing in the requested language; the English message is used
+    English,
+    /// Missing in every language; the key itself is used
+    Key,
+}
+
 /// Message catalog containing translations for all languages
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MessageCatalog(HashMap<String, HashMap<String, String>>);
@@ -84,6 +169,52 @@ impl MessageCatalog {
         Ok(MessageCatalog(messages))
     }
 
+    /// Load a single-language catalog from a TOML or JSON file
+    ///
+    /// The file is a flat table of `key = "message"` pairs; the language code
+    /// is taken from the file name (`fr.toml` provides `fr`).
+    pub fn load_from_file(path: &Path) -> Result<Self> {
+        let code = path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .filter(|code| is_valid_code(code))
+            .ok_or_else(|| {
+                CldevError::config(format!(
+                    "Catalog file name must be a language code: {}",
+                    path.display()
+                ))
+            })?;
+
+        let content = fs::read_to_string(path)?;
+        let messages: HashMap<String, String> = match path.extension().and_then(|ext| ext.to_str())
+        {
+            Some("toml") => toml::from_str(&content).map_err(|e| {
+                CldevError::config(format!("Failed to parse {}: {}", path.display(), e))
+            })?,
+            Some("json") => serde_json::from_str(&content).map_err(|e| {
+                CldevError::config(format!("Failed to parse {}: {}", path.display(), e))
+            })?,
+            _ => {
+                return Err(CldevError::config(format!(
+                    "Unsupported catalog format (expected .toml or .json): {}",
+                    path.display()
+                )))
+            }
+        };
+
+        Ok(MessageCatalog(HashMap::from([(
+            code.to_lowercase(),
+            messages,
+        )])))
+    }
+
+    /// Add the languages of another catalog, replacing existing messages
+    pub fn merge(&mut self, other: MessageCatalog) {
+        for (code, messages) in other.0 {
+            self.0.entry(code).or_default().extend(messages);
+        }
+    }
+
     /// Get default message catalog (embedded in binary)
     pub fn new_default() -> Self {
         // Embedded default messages
@@ -92,13 +223,49 @@ impl MessageCatalog {
     }
 
     /// Get message for a specific language
-    pub fn get(&self, key: &str, lang: Language) -> Option<&str> {
+    pub fn get(&self, key: &str, lang: &Language) -> Option<&str> {
         self.0
             .get(lang.code())
             .and_then(|msgs| msgs.get(key))
             .map(|s| s.as_str())
     }
 
+    /// Look up a message, falling back to English and then to the key itself
+    ///
+    /// Use [`MessageCatalog::source`] to find out which fallback applied.
+    pub fn lookup(&self, key: &str, lang: &Language) -> String {
+        self.get(key, lang)
+            .or_else(|| self.get(key, &Language::English))
+            .unwrap_or(key)
+            .to_string()
+    }
+
+    /// Where [`MessageCatalog::lookup`] takes the message for `key` from
+    pub fn source(&self, key: &str, lang: &Language) -> MessageSource {
+        if self.get(key, lang).is_some() {
+            MessageSource::Language
+        } else if self.get(key, &Language::English).is_some() {
+            MessageSource::English
+        } else {
+            MessageSource::Key
+        }
+    }
+
+    /// Keys defined in any language but missing for `lang`, sorted
+    pub fn missing_keys(&self, lang: &Language) -> Vec<String> {
+        let available = self.0.get(lang.code());
+        let mut missing: Vec<String> = self
+            .0
+            .values()
+            .flat_map(|msgs| msgs.keys())
+            .filter(|key| available.is_none_or(|msgs| !msgs.contains_key(*key)))
+            .cloned()
+            .collect();
+        missing.sort();
+        missing.dedup();
+        missing
+    }
+
     /// Check if a message key exists
     pub fn has_key(&self, key: &str) -> bool {
         self.0.values().any(|msgs| msgs.contains_key(key))
@@ -108,7 +275,9 @@ impl MessageCatalog {
     pub fn languages(&self) -> Vec<Language> {
         self.0
             .keys()
-            .filter_map(|code| Language::from_code(code))
+            .map(|code| {
+                Language::from_code(code).unwrap_or_else(|| Language::External(code.clone()))
+            })
             .collect()
     }
 }
@@ -117,62 +286,107 @@ impl MessageCatalog {
 pub struct I18n {
     catalog: MessageCatalog,
     current_language: Language,
+    /// Why the external catalog of the current language could not be loaded
+    catalog_error: Option<String>,
 }
 
 impl I18n {
     /// Create a new I18n instance with the default catalog
     pub fn new() -> Self {
-        Self {
-            catalog: MessageCatalog::new_default(),
-            current_language: Language::detect(),
-        }
+        Self::with_language(Language::detect())
     }
 
     /// Create I18n with a custom message catalog
     pub fn with_catalog(catalog: MessageCatalog) -> Self {
-        Self {
+        let mut i18n = Self {
             catalog,
-            current_language: Language::detect(),
-        }
+            current_language: Language::English,
+            catalog_error: None,
+        };
+        i18n.set_language(Language::detect());
+        i18n
     }
 
     /// Create I18n with a specific language
+    ///
+    /// External languages load their catalog from `~/.config/cldev/i18n`.
     pub fn with_language(language: Language) -> Self {
-        Self {
+        let mut i18n = Self {
             catalog: MessageCatalog::new_default(),
-            current_language: language,
-        }
+            current_language: Language::English,
+            catalog_error: None,
+        };
+        i18n.set_language(language);
+        i18n
     }
 
     /// Get the current language
-    pub fn language(&self) -> Language {
-        self.current_language
+    pub fn language(&self) -> &Language {
+        &self.current_language
     }
 
     /// Set the current language
+    ///
+    /// Switching to an external language loads its catalog. Messages it lacks,
+    /// or all messages if it cannot be loaded, fall back to English; the
+    /// reason a catalog could not be loaded is kept in
+    /// [`I18n::catalog_error`].
     pub fn set_language(&mut self, language: Language) {
+        self.catalog_error = None;
+        if let Language::External(code) = &language {
+            match external_catalog_path(code) {
+                Some(path) => {
+                    if let Err(e) = self.load_catalog_file(&path) {
+                        self.catalog_error = Some(e.to_string());
+                    }
+                }
+                None => self.catalog_error = Some(format!("no catalog installed for '{}'", code)),
+            }
+        }
         self.current_language = language;
     }
 
+    /// Why the external catalog of the current language could not be loaded
+    pub fn catalog_error(&self) -> Option<&str> {
+        self.catalog_error.as_deref()
+    }
+
+    /// Load an external catalog file and switch to its language
+    ///
+    /// Returns the keys the catalog does not translate; they fall back to
+    /// English.
+    pub fn load_catalog_file(&mut self, path: &Path) -> Result<Vec<String>> {
+        let catalog = MessageCatalog::load_from_file(path)?;
+        let Some(language) = catalog.languages().into_iter().next() else {
+            return Ok(Vec::new());
+        };
+
+        self.catalog.merge(catalog);
+        self.current_language = language;
+        self.catalog_error = None;
+
+        Ok(self.missing_keys())
+    }
+
     /// Get a message by key
     ///
     /// Returns the message in the current language, falling back to English
     /// if not found. If the key doesn't exist at all, returns the key itself.
     pub fn get(&self, key: &str) -> String {
-        // Try current language first
-        if let Some(msg) = self.catalog.get(key, self.current_language) {
-            return msg.to_string();
-        }
+        self.catalog.lookup(key, &self.current_language)
+    }
 
-        // Fallback to English
-        if self.current_language != Language::English {
-            if let Some(msg) = self.catalog.get(key, Language::English) {
-                return msg.to_string();
-            }
-        }
+    /// Where [`I18n::get`] takes the message for `key` from
+    pub fn source(&self, key: &str) -> MessageSource {
+        self.catalog.source(key, &self.current_language)
+    }
 
-        // If nothing found, return the key itself as fallback
-        key.to_string()
+    /// Keys that have no translation in the current language
+    ///
+    /// Used to audit catalog coverage; lookups of these keys fall back to
+    /// English.
+    pub fn missing_keys(&self) -> Vec<String> {
+        self.catalog.missing_keys(&self.current_language)
     }
 
     /// Get a message with variable substitution
@@ -209,6 +423,25 @@ impl I18n {
         self.get_with_vars(key, &vars)
     }
 
+    /// Format a message whose wording depends on a count
+    ///
+    /// Looks up `{key}_one` or `{key}_other` according to the current
+    /// language's plural rules, falling back to `key` when the catalog has no
+    /// plural variants, then substitutes `var_name` like [`I18n::format`].
+    pub fn t_plural(&self, key: &str, count: usize, var_name: &str, var_value: &str) -> String {
+        self.format(&self.plural_key(key, count), var_name, var_value)
+    }
+
+    /// The key [`I18n::t_plural`] looks up for `count`
+    pub fn plural_key(&self, key: &str, count: usize) -> String {
+        let variant = format!("{}_{}", key, self.current_language.plural_form(count));
+        if self.catalog.has_key(&variant) {
+            variant
+        } else {
+            key.to_string()
+        }
+    }
+
     /// Get all available languages in the catalog
     pub fn available_languages(&self) -> Vec<Language> {
         self.catalog.languages()
@@ -247,6 +480,44 @@ mod tests {
         assert!(lang == Language::English || lang == Language::Japanese);
     }
 
+    #[test]
+    fn test_language_detect_from_follows_posix_precedence() {
+        let env = |vars: &'static [(&'static str, &'static str)]| {
+            move |name: &str| {
+                vars.iter()
+                    .find(|(var, _)| *var == name)
+                    .map(|(_, value)| value.to_string())
+            }
+        };
+
+        assert_eq!(
+            Language::detect_from(env(&[("LANG", "ja_JP.UTF-8")])),
+            Language::Japanese
+        );
+        assert_eq!(
+            Language::detect_from(env(&[("LANG", "en_US.UTF-8")])),
+            Language::English
+        );
+        // LC_ALL overrides LC_MESSAGES, which overrides LANG
+        assert_eq!(
+            Language::detect_from(env(&[("LC_ALL", "en_US.UTF-8"), ("LANG", "ja_JP.UTF-8")])),
+            Language::English
+        );
+        assert_eq!(
+            Language::detect_from(env(&[
+                ("LC_MESSAGES", "ja_JP.UTF-8"),
+                ("LANG", "en_US.UTF-8")
+            ])),
+            Language::Japanese
+        );
+        // Empty variables are skipped
+        assert_eq!(
+            Language::detect_from(env(&[("LC_ALL", ""), ("LANG", "ja_JP.UTF-8")])),
+            Language::Japanese
+        );
+        assert_eq!(Language::detect_from(env(&[])), Language::English);
+    }
+
     #[test]
     fn test_language_code() {
         assert_eq!(Language::English.code(), "en");
@@ -265,9 +536,12 @@ mod tests {
     fn test_message_catalog_get() {
         let catalog = test_catalog();
 
-        assert_eq!(catalog.get("hello", Language::English), Some("Hello"));
-        assert_eq!(catalog.get("hello", Language::Japanese), Some("こんにちは"));
-        assert_eq!(catalog.get("nonexistent", Language::English), None);
+        assert_eq!(catalog.get("hello", &Language::English), Some("Hello"));
+        assert_eq!(
+            catalog.get("hello", &Language::Japanese),
+            Some("こんにちは")
+        );
+        assert_eq!(catalog.get("nonexistent", &Language::English), None);
     }
 
     #[test]
@@ -284,9 +558,70 @@ mod tests {
         let i18n = I18n {
             catalog: test_catalog(),
             current_language: Language::English,
+            catalog_error: None,
+        };
+
+        assert_eq!(i18n.get("hello"), "Hello");
+    }
+
+    #[test]
+    fn test_i18n_falls_back_to_english() {
+        let mut catalog = MessageCatalog::new_default();
+        let key = "command-success";
+        let english = catalog.get(key, &Language::English).unwrap().to_string();
+        catalog.0.get_mut("ja").unwrap().remove(key);
+
+        let i18n = I18n {
+            catalog,
+            current_language: Language::Japanese,
+            catalog_error: None,
+        };
+
+        assert_eq!(i18n.get(key), english);
+        assert_ne!(i18n.get(key), key);
+        assert_eq!(i18n.source(key), MessageSource::English);
+        assert_eq!(i18n.source("command-failed"), MessageSource::Language);
+        assert_eq!(i18n.missing_keys(), vec![key.to_string()]);
+        assert_eq!(i18n.get("no-such-key"), "no-such-key");
+        assert_eq!(i18n.source("no-such-key"), MessageSource::Key);
+    }
+
+    #[test]
+    fn test_load_catalog_file() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let toml_path = temp_dir.path().join("fr.toml");
+        fs::write(&toml_path, "greeting = \"Bonjour, {name} !\"\n").unwrap();
+        let json_path = temp_dir.path().join("de.json");
+        fs::write(&json_path, r#"{"hello": "Hallo"}"#).unwrap();
+
+        let mut i18n = I18n {
+            catalog: test_catalog(),
+            current_language: Language::English,
+            catalog_error: None,
         };
 
+        let missing = i18n.load_catalog_file(&toml_path).unwrap();
+        assert_eq!(i18n.language(), &Language::External("fr".to_string()));
+        assert_eq!(i18n.format("greeting", "name", "Alice"), "Bonjour, Alice !");
         assert_eq!(i18n.get("hello"), "Hello");
+        assert_eq!(missing, vec!["hello".to_string(), "multi-var".to_string()]);
+
+        i18n.load_catalog_file(&json_path).unwrap();
+        assert_eq!(i18n.get("hello"), "Hallo");
+
+        let bad_path = temp_dir.path().join("fr.yaml");
+        fs::write(&bad_path, "hello: Salut").unwrap();
+        assert!(MessageCatalog::load_from_file(&bad_path).is_err());
+    }
+
+    #[test]
+    fn test_default_catalog_has_no_missing_keys() {
+        for language in [Language::English, Language::Japanese] {
+            assert_eq!(
+                I18n::with_language(language).missing_keys(),
+                Vec::<String>::new()
+            );
+        }
     }
 
     #[test]
@@ -294,6 +629,7 @@ mod tests {
         let i18n = I18n {
             catalog: test_catalog(),
             current_language: Language::English,
+            catalog_error: None,
         };
 
         let mut vars = HashMap::new();
@@ -301,11 +637,42 @@ mod tests {
         assert_eq!(i18n.get_with_vars("greeting", &vars), "Hello, Alice!");
     }
 
+    #[test]
+    fn test_t_plural() {
+        let json = r#"{
+            "en": {
+                "files_one": "{n} file",
+                "files_other": "{n} files",
+                "plain": "{n} item(s)"
+            },
+            "ja": {
+                "files_other": "{n} 個のファイル"
+            }
+        }"#;
+        let mut i18n = I18n {
+            catalog: MessageCatalog::from_json(json).unwrap(),
+            current_language: Language::English,
+            catalog_error: None,
+        };
+
+        assert_eq!(i18n.t_plural("files", 1, "n", "1"), "1 file");
+        assert_eq!(i18n.t_plural("files", 5, "n", "5"), "5 files");
+        assert_eq!(i18n.t_plural("files", 0, "n", "0"), "0 files");
+        // Keys without plural variants are used as-is
+        assert_eq!(i18n.t_plural("plain", 1, "n", "1"), "1 item(s)");
+
+        // Japanese has a single form for every count
+        i18n.set_language(Language::Japanese);
+        assert_eq!(i18n.t_plural("files", 1, "n", "1"), "1 個のファイル");
+        assert_eq!(i18n.t_plural("files", 5, "n", "5"), "5 個のファイル");
+    }
+
     #[test]
     fn test_i18n_format() {
         let i18n = I18n {
             catalog: test_catalog(),
             current_language: Language::English,
+            catalog_error: None,
         };
 
         assert_eq!(i18n.format("greeting", "name", "Bob"), "Hello, Bob!");
@@ -316,6 +683,7 @@ mod tests {
         let i18n = I18n {
             catalog: test_catalog(),
             current_language: Language::English,
+            catalog_error: None,
         };
 
         let mut vars = HashMap::new();
@@ -341,6 +709,7 @@ mod tests {
         let i18n = I18n {
             catalog: test_catalog(),
             current_language: Language::English,
+            catalog_error: None,
         };
 
         // Non-existent key should return the key itself
@@ -352,6 +721,7 @@ mod tests {
         let mut i18n = I18n {
             catalog: test_catalog(),
             current_language: Language::English,
+            catalog_error: None,
         };
 
         assert_eq!(i18n.get("hello"), "Hello");
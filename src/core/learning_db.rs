@@ -1,7 +1,7 @@
 //! SQLite-based Learning Database
 //!
 //! Hybrid approach: Markdown files for human-readable storage + SQLite for fast queries
-//! - Full-text search (FTS5) for content queries
+//! - Full-text search (FTS5) over frontmatter fields and the markdown body
 //! - Indexed lookups for files, tags, errors
 //! - Hotspot scoring and prioritization
 //! - Staleness detection via mtime tracking
@@ -9,7 +9,7 @@
 use crate::core::learning_record_v2::{LearningRecordV2, Priority};
 use crate::core::similarity::calculate_similarity;
 use crate::core::tfidf::{TfidfIndex, TfidfResult};
-use crate::core::{CldevError, Result};
+use crate::core::{CldevError, Result, SearchWeights};
 use chrono::{DateTime, Local, TimeZone};
 use rusqlite::{params, Connection, OptionalExtension};
 use std::collections::{HashMap, HashSet};
@@ -17,6 +17,26 @@ use std::fs;
 use std::path::{Path, PathBuf};
 use std::time::SystemTime;
 
+/// Marker inserted before each matched term in search snippets
+pub const HIGHLIGHT_START: &str = "<mark>";
+
+/// Marker inserted after each matched term in search snippets
+pub const HIGHLIGHT_END: &str = "</mark>";
+
+/// Maximum number of tokens in a search snippet
+const SNIPPET_TOKENS: i64 = 12;
+
+/// Age in days at which a record's recency score reaches zero
+const RECENCY_WINDOW_DAYS: f64 = 365.0;
+
+/// Candidates fetched from each signal per requested hybrid search result
+const HYBRID_CANDIDATES_PER_RESULT: usize = 5;
+
+/// FTS5 bm25 column weights: id, title, description, tags, error_patterns, body
+///
+/// Frontmatter fields outrank matches that only appear in the markdown body.
+const FTS_RANK: &str = "bm25(sessions_fts, 0.0, 10.0, 5.0, 5.0, 3.0, 1.0)";
+
 /// Learning database with SQLite backend
 pub struct LearningDatabase {
     conn: Connection,
@@ -41,7 +61,6 @@ pub struct SessionMetadata {
     pub title: String,
     pub description: String,
     pub markdown_path: String,
-    #[allow(dead_code)]
     pub hotspot_score: f64,
     #[allow(dead_code)]
     pub created_at: String,
@@ -120,7 +139,7 @@ impl LearningDatabase {
                 description,
                 tags,
                 error_patterns,
-                content='',
+                body,
                 tokenize='porter unicode61'
             );
 
@@ -162,11 +181,59 @@ impl LearningDatabase {
             )
             .map_err(|e| CldevError::Config(format!("Failed to initialize schema: {}", e)))?;
 
+        self.upgrade_fts_schema()
+    }
+
+    /// Recreate an FTS index created before body indexing and re-index all records
+    ///
+    /// Older databases used a contentless FTS table without a `body` column.
+    fn upgrade_fts_schema(&mut self) -> Result<()> {
+        let has_body: bool = self
+            .conn
+            .query_row(
+                "SELECT 1 FROM pragma_table_info('sessions_fts') WHERE name = 'body'",
+                [],
+                |_| Ok(true),
+            )
+            .optional()
+            .map_err(|e| CldevError::Config(format!("Failed to inspect FTS schema: {}", e)))?
+            .unwrap_or(false);
+
+        if has_body {
+            return Ok(());
+        }
+
+        self.conn
+            .execute_batch(
+                r#"
+            DROP TABLE sessions_fts;
+            CREATE VIRTUAL TABLE sessions_fts USING fts5(
+                id UNINDEXED,
+                title,
+                description,
+                tags,
+                error_patterns,
+                body,
+                tokenize='porter unicode61'
+            );
+        "#,
+            )
+            .map_err(|e| CldevError::Config(format!("Failed to upgrade FTS schema: {}", e)))?;
+
+        self.build_from_markdown()?;
         Ok(())
     }
 
     /// Build index from all markdown files in the directory
     pub fn build_from_markdown(&mut self) -> Result<(usize, usize)> {
+        self.build_from_markdown_with_progress(|_, _| {})
+    }
+
+    /// Build the index, calling `on_progress(done, total)` after each markdown file
+    pub fn build_from_markdown_with_progress(
+        &mut self,
+        mut on_progress: impl FnMut(usize, usize),
+    ) -> Result<(usize, usize)> {
         let mut inserted = 0;
         let mut updated = 0;
 
@@ -180,6 +247,7 @@ impl LearningDatabase {
             return Ok((0, 0));
         }
 
+        let mut paths = Vec::new();
         for entry in fs::read_dir(&self.markdown_dir).map_err(|e| {
             CldevError::Config(format!(
                 "Failed to read markdown directory {:?}: {}",
@@ -191,10 +259,13 @@ impl LearningDatabase {
             })?;
 
             let path = entry.path();
-            if !path.is_file() || path.extension().and_then(|s| s.to_str()) != Some("md") {
-                continue;
+            if path.is_file() && path.extension().and_then(|s| s.to_str()) == Some("md") {
+                paths.push(path);
             }
+        }
 
+        let total = paths.len();
+        for (done, path) in paths.into_iter().enumerate() {
             let content = fs::read_to_string(&path).map_err(|e| {
                 CldevError::Config(format!("Failed to read markdown file {:?}: {}", path, e))
             })?;
@@ -210,11 +281,51 @@ impl LearningDatabase {
                     inserted += 1;
                 }
             }
+
+            on_progress(done + 1, total);
         }
 
+        self.load_tfidf_index()?;
+
         Ok((inserted, updated))
     }
 
+    /// Rebuild the in-memory TF-IDF index from every indexed session
+    ///
+    /// Reads the stored full-text columns without touching the markdown files
+    /// or writing to the database, so it also covers sessions whose markdown
+    /// lives outside `markdown_dir`, such as records migrated to V3.
+    pub fn load_tfidf_index(&mut self) -> Result<usize> {
+        let mut stmt = self
+            .conn
+            .prepare("SELECT id, title, description, tags, error_patterns, body FROM sessions_fts")
+            .map_err(|e| CldevError::Config(format!("Failed to prepare query: {}", e)))?;
+
+        let documents = stmt
+            .query_map([], |row| {
+                let id: String = row.get(0)?;
+                let text = format!(
+                    "{} {} {} {} {}",
+                    row.get::<_, String>(1)?,
+                    row.get::<_, String>(2)?,
+                    row.get::<_, String>(3)?,
+                    row.get::<_, String>(4)?,
+                    row.get::<_, String>(5)?
+                );
+                Ok((id, text))
+            })
+            .map_err(|e| CldevError::Config(format!("Failed to execute query: {}", e)))?
+            .collect::<std::result::Result<Vec<_>, _>>()
+            .map_err(|e| CldevError::Config(format!("Failed to collect results: {}", e)))?;
+
+        self.tfidf_index = TfidfIndex::new();
+        for (id, text) in &documents {
+            self.tfidf_index.add_document(id.as_str(), text);
+        }
+
+        Ok(documents.len())
+    }
+
     /// Parse markdown file with YAML frontmatter
     fn parse_markdown(content: &str) -> Result<Option<LearningRecordV2>> {
         // Extract YAML frontmatter between --- delimiters
@@ -249,6 +360,34 @@ impl LearningDatabase {
         Ok(mtime)
     }
 
+    /// Extract searchable text beyond the frontmatter summary fields
+    ///
+    /// Combines the solution, learnings, and the markdown body that follows the
+    /// YAML frontmatter.
+    fn extract_body_text(record: &LearningRecordV2, markdown: &str) -> String {
+        let mut parts: Vec<&str> = Vec::new();
+
+        if let Some(solution) = &record.solution {
+            parts.push(&solution.summary);
+            parts.extend(solution.root_cause.as_deref());
+            parts.extend(solution.steps.iter().map(String::as_str));
+            parts.extend(solution.verification.iter().map(String::as_str));
+        }
+        parts.extend(record.learnings.iter().map(|l| l.insight.as_str()));
+
+        let body = match markdown.strip_prefix("---\n") {
+            Some(rest) => rest.split_once("---\n").map_or("", |(_, body)| body),
+            None => markdown,
+        };
+        parts.push(body.trim());
+
+        parts
+            .into_iter()
+            .filter(|p| !p.is_empty())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
     /// Upsert a session record (insert or update if exists)
     pub fn upsert_session(
         &mut self,
@@ -256,6 +395,8 @@ impl LearningDatabase {
         markdown_path: String,
     ) -> Result<bool> {
         let mtime = Self::get_mtime(Path::new(&markdown_path))?;
+        let markdown = fs::read_to_string(&markdown_path).unwrap_or_default();
+        let body = Self::extract_body_text(record, &markdown);
 
         let session_id = &record.session_meta.id;
         let hotspot_score = self.calculate_hotspot_score(record);
@@ -381,13 +522,14 @@ impl LearningDatabase {
             .join(" ");
 
         tx.execute(
-            "INSERT INTO sessions_fts (id, title, description, tags, error_patterns) VALUES (?1, ?2, ?3, ?4, ?5)",
+            "INSERT INTO sessions_fts (id, title, description, tags, error_patterns, body) VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
             params![
                 session_id,
                 &record.problem.title,
                 &record.problem.description,
                 &tags_str,
                 &errors_str,
+                &body,
             ],
         )
         .map_err(|e| CldevError::Config(format!("Failed to insert FTS entry: {}", e)))?;
@@ -397,10 +539,10 @@ impl LearningDatabase {
             .map_err(|e| CldevError::Config(format!("Failed to commit transaction: {}", e)))?;
 
         // Update TF-IDF index
-        // Build searchable text from title, description, tags, and error patterns
+        // Build searchable text from title, description, tags, error patterns, and body
         let searchable_text = format!(
-            "{} {} {} {}",
-            record.problem.title, record.problem.description, tags_str, errors_str
+            "{} {} {} {} {}",
+            record.problem.title, record.problem.description, tags_str, errors_str, body
         );
 
         // Remove old entry if exists
@@ -443,23 +585,24 @@ impl LearningDatabase {
     pub fn query_by_keyword(&self, keyword: &str, limit: usize) -> Result<Vec<QueryResult>> {
         let mut stmt = self
             .conn
-            .prepare(
+            .prepare(&format!(
                 r#"
-            SELECT DISTINCT s.*, fts.rank
-            FROM sessions_fts fts
-            JOIN sessions s ON fts.id = s.id
+            SELECT s.*, {} AS score
+            FROM sessions_fts
+            JOIN sessions s ON sessions_fts.id = s.id
             WHERE sessions_fts MATCH ?1
-            ORDER BY fts.rank, s.hotspot_score DESC
+            ORDER BY score, s.hotspot_score DESC
             LIMIT ?2
             "#,
-            )
+                FTS_RANK
+            ))
             .map_err(|e| CldevError::Config(format!("Failed to prepare query: {}", e)))?;
 
         let results = stmt
             .query_map(params![keyword, limit], |row| {
                 Ok((
                     Self::row_to_session_metadata(row)?,
-                    row.get::<_, f64>(12)?, // rank
+                    -row.get::<_, f64>(13)?, // bm25 score (lower is better)
                 ))
             })
             .map_err(|e| CldevError::Config(format!("Failed to execute query: {}", e)))?
@@ -469,6 +612,62 @@ impl LearningDatabase {
         self.enhance_query_results(results)
     }
 
+    /// Query sessions by keyword, with a highlighted excerpt around each match
+    ///
+    /// Matched terms in the excerpt are wrapped in [`HIGHLIGHT_START`] and
+    /// [`HIGHLIGHT_END`]. Results are ranked like [`Self::query_by_keyword`].
+    pub fn query_by_keyword_with_snippets(
+        &self,
+        keyword: &str,
+        limit: usize,
+    ) -> Result<Vec<(QueryResult, String)>> {
+        let mut stmt = self
+            .conn
+            .prepare(&format!(
+                r#"
+            SELECT s.*, {} AS score,
+                snippet(sessions_fts, -1, ?3, ?4, '…', ?5) AS excerpt
+            FROM sessions_fts
+            JOIN sessions s ON sessions_fts.id = s.id
+            WHERE sessions_fts MATCH ?1
+            ORDER BY score, s.hotspot_score DESC
+            LIMIT ?2
+            "#,
+                FTS_RANK
+            ))
+            .map_err(|e| CldevError::Config(format!("Failed to prepare query: {}", e)))?;
+
+        let rows = stmt
+            .query_map(
+                params![
+                    keyword,
+                    limit,
+                    HIGHLIGHT_START,
+                    HIGHLIGHT_END,
+                    SNIPPET_TOKENS
+                ],
+                |row| {
+                    Ok((
+                        (
+                            Self::row_to_session_metadata(row)?,
+                            -row.get::<_, f64>(13)?, // bm25 score (lower is better)
+                        ),
+                        row.get::<_, String>(14)?,
+                    ))
+                },
+            )
+            .map_err(|e| CldevError::Config(format!("Failed to execute query: {}", e)))?
+            .collect::<std::result::Result<Vec<_>, _>>()
+            .map_err(|e| CldevError::Config(format!("Failed to collect results: {}", e)))?;
+
+        let (results, snippets): (Vec<_>, Vec<_>) = rows.into_iter().unzip();
+        Ok(self
+            .enhance_query_results(results)?
+            .into_iter()
+            .zip(snippets)
+            .collect())
+    }
+
     /// Query sessions by file path
     pub fn query_by_file(&self, file_path: &str, limit: usize) -> Result<Vec<QueryResult>> {
         let mut stmt = self
@@ -619,8 +818,41 @@ impl LearningDatabase {
         self.enhance_query_results(results)
     }
 
+    /// Get an indexed session by ID
+    pub fn get_session(&self, session_id: &str) -> Result<Option<SessionMetadata>> {
+        self.conn
+            .query_row(
+                "SELECT * FROM sessions WHERE id = ?1",
+                params![session_id],
+                Self::row_to_session_metadata,
+            )
+            .optional()
+            .map_err(|e| CldevError::Config(format!("Failed to fetch session: {}", e)))
+    }
+
+    /// Get every indexed session, highest hotspot score first
+    pub fn get_all_sessions(&self) -> Result<Vec<SessionMetadata>> {
+        let mut stmt = self
+            .conn
+            .prepare(
+                r#"
+            SELECT *
+            FROM sessions
+            ORDER BY hotspot_score DESC, timestamp DESC
+            "#,
+            )
+            .map_err(|e| CldevError::Config(format!("Failed to prepare query: {}", e)))?;
+
+        let sessions = stmt
+            .query_map([], Self::row_to_session_metadata)
+            .map_err(|e| CldevError::Config(format!("Failed to execute query: {}", e)))?
+            .collect::<std::result::Result<Vec<_>, _>>()
+            .map_err(|e| CldevError::Config(format!("Failed to collect results: {}", e)))?;
+
+        Ok(sessions)
+    }
+
     /// Check if the database is stale (markdown files modified after last index)
-    #[allow(dead_code)]
     pub fn is_stale(&self) -> Result<bool> {
         if !self.markdown_dir.exists() {
             return Ok(false);
@@ -800,6 +1032,58 @@ impl LearningDatabase {
         Ok(results)
     }
 
+    /// Search with full-text rank, TF-IDF similarity and recency combined
+    ///
+    /// FTS and TF-IDF scores are each normalized to 0..1 against the best
+    /// candidate, recency falls from 1 to 0 over a year, and the three are
+    /// combined with `weights`. Each session appears once; its
+    /// `relevance_score` is the combined score. The TF-IDF index is in
+    /// memory, so it only covers records indexed by this instance.
+    pub fn search_hybrid(
+        &self,
+        query: &str,
+        limit: usize,
+        weights: &SearchWeights,
+    ) -> Result<Vec<QueryResult>> {
+        let fts_query = fts_any_term_query(query);
+        if fts_query.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let candidates = limit.max(1) * HYBRID_CANDIDATES_PER_RESULT;
+        let fts = self.query_by_keyword(&fts_query, candidates)?;
+        let tfidf = self.search_with_tfidf(query, candidates)?;
+
+        let score_of = |results: &[QueryResult]| -> Vec<(String, f64)> {
+            results
+                .iter()
+                .map(|r| (r.session.id.clone(), r.relevance_score))
+                .collect()
+        };
+        let fts_scores = score_of(&fts);
+        let tfidf_scores = score_of(&tfidf);
+
+        let mut sessions: HashMap<String, QueryResult> = HashMap::new();
+        for result in fts.into_iter().chain(tfidf) {
+            sessions.entry(result.session.id.clone()).or_insert(result);
+        }
+        let recency: HashMap<String, f64> = sessions
+            .iter()
+            .map(|(id, r)| (id.clone(), recency_score(&r.session.timestamp)))
+            .collect();
+
+        Ok(fuse_scores(&fts_scores, &tfidf_scores, &recency, weights)
+            .into_iter()
+            .take(limit)
+            .filter_map(|(id, score)| {
+                sessions.remove(&id).map(|mut result| {
+                    result.relevance_score = score;
+                    result
+                })
+            })
+            .collect())
+    }
+
     /// Get TF-IDF index statistics
     ///
     /// Returns statistics about the TF-IDF index including document count,
@@ -834,9 +1118,6 @@ impl LearningDatabase {
         const TAG_WEIGHT: f64 = 0.2;
         const RECENCY_WEIGHT: f64 = 0.1;
 
-        // Calculate max days for recency normalization (365 days)
-        const MAX_DAYS: f64 = 365.0;
-
         // 1. File matching (40%)
         if let Some(file) = file_path {
             let pattern = format!("%{}%", file);
@@ -978,14 +1259,8 @@ impl LearningDatabase {
         }
 
         // 4. Calculate recency scores (10%) for all candidates
-        let now = Local::now();
         for score in candidates.values_mut() {
-            if let Ok(timestamp) = DateTime::parse_from_rfc3339(&score.timestamp) {
-                let timestamp_local = timestamp.with_timezone(&Local);
-                let age_days = (now - timestamp_local).num_days().max(0) as f64;
-                let recency = (1.0 - (age_days / MAX_DAYS).min(1.0)) * RECENCY_WEIGHT;
-                score.recency_score = recency;
-            }
+            score.recency_score = recency_score(&score.timestamp) * RECENCY_WEIGHT;
         }
 
         // Sort by total score and get session metadata
@@ -1139,6 +1414,44 @@ impl LearningDatabase {
 
         Ok(final_results)
     }
+
+    /// Find the sessions most similar to an indexed session
+    ///
+    /// Uses the session's title, description, tags, and error patterns as a
+    /// TF-IDF query. The markdown body is left out so that links written into
+    /// it do not feed back into later searches. The TF-IDF index is in-memory,
+    /// so it must be populated via `build_from_markdown` or `upsert_session`.
+    ///
+    /// # Parameters
+    ///
+    /// - `session_id`: ID of the session to compare against
+    /// - `limit`: Maximum number of results (the session itself is excluded)
+    pub fn most_similar(&self, session_id: &str, limit: usize) -> Result<Vec<QueryResult>> {
+        let query = self
+            .conn
+            .query_row(
+                "SELECT title, description, tags, error_patterns FROM sessions_fts WHERE id = ?1",
+                params![session_id],
+                |row| {
+                    Ok([
+                        row.get::<_, String>(0)?,
+                        row.get::<_, String>(1)?,
+                        row.get::<_, String>(2)?,
+                        row.get::<_, String>(3)?,
+                    ]
+                    .join(" "))
+                },
+            )
+            .optional()
+            .map_err(|e| CldevError::Config(format!("Failed to fetch session: {}", e)))?
+            .ok_or_else(|| CldevError::Config(format!("Session not found: {}", session_id)))?;
+
+        let mut results = self.search_with_tfidf(&query, limit + 1)?;
+        results.retain(|r| r.session.id != session_id);
+        results.truncate(limit);
+
+        Ok(results)
+    }
 }
 #[cfg(test)]
 mod tests {
@@ -1274,6 +1587,255 @@ mod tests {
         assert!(results[0].relevance_score >= 0.5);
     }
 
+    /// Write a record as markdown with the given body and index it
+    fn index_record(
+        db: &mut LearningDatabase,
+        markdown_dir: &Path,
+        record: &LearningRecordV2,
+        body: &str,
+    ) {
+        let path = markdown_dir.join(format!("{}.md", record.session_meta.id));
+        let yaml = serde_yaml::to_string(record).unwrap();
+        fs::write(&path, format!("---\n{}---\n\n{}", yaml, body)).unwrap();
+        db.upsert_session(record, path.to_string_lossy().to_string())
+            .unwrap();
+    }
+
+    #[test]
+    fn test_keyword_search_finds_body_text() {
+        use crate::core::learning_record_v2::*;
+
+        let temp_dir = TempDir::new().unwrap();
+        let markdown_dir = temp_dir.path().join("markdown");
+        fs::create_dir_all(&markdown_dir).unwrap();
+        let mut db =
+            LearningDatabase::new(temp_dir.path().join("test.db"), markdown_dir.clone()).unwrap();
+
+        let record = LearningRecordBuilder::new(
+            SessionType::Debug,
+            Priority::High,
+            "Login loop",
+            "Users are redirected forever",
+            Severity::Error,
+        )
+        .build();
+        index_record(
+            &mut db,
+            &markdown_dir,
+            &record,
+            "# Session Notes\n\nThe fix was to invalidate the memoized tokenstore.\n",
+        );
+
+        let results = db.query_by_keyword("tokenstore", 10).unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].session.id, record.session_meta.id);
+
+        // Re-indexing replaces the FTS row instead of duplicating it
+        index_record(&mut db, &markdown_dir, &record, "Rewritten notes\n");
+        assert!(db.query_by_keyword("tokenstore", 10).unwrap().is_empty());
+        assert_eq!(db.query_by_keyword("rewritten", 10).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_keyword_search_snippet_highlights_match() {
+        use crate::core::learning_record_v2::*;
+
+        let temp_dir = TempDir::new().unwrap();
+        let markdown_dir = temp_dir.path().join("markdown");
+        fs::create_dir_all(&markdown_dir).unwrap();
+        let mut db =
+            LearningDatabase::new(temp_dir.path().join("test.db"), markdown_dir.clone()).unwrap();
+
+        let record = LearningRecordBuilder::new(
+            SessionType::Debug,
+            Priority::High,
+            "Login loop",
+            "Users are redirected forever",
+            Severity::Error,
+        )
+        .build();
+        index_record(
+            &mut db,
+            &markdown_dir,
+            &record,
+            "# Session Notes\n\nThe fix was to invalidate the memoized tokenstore after logout.\n",
+        );
+
+        let results = db.query_by_keyword_with_snippets("tokenstore", 10).unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].0.session.id, record.session_meta.id);
+        assert!(results[0]
+            .1
+            .contains(&format!("{}tokenstore{}", HIGHLIGHT_START, HIGHLIGHT_END)));
+    }
+
+    #[test]
+    fn test_fuse_scores_prefers_both_signals() {
+        let fts = vec![("both".to_string(), 8.0), ("fts_only".to_string(), 8.0)];
+        let tfidf = vec![("both".to_string(), 0.4), ("tfidf_only".to_string(), 0.4)];
+        let recency = HashMap::new();
+
+        let fused = fuse_scores(&fts, &tfidf, &recency, &SearchWeights::default());
+        let ids: Vec<&str> = fused.iter().map(|(id, _)| id.as_str()).collect();
+        assert_eq!(ids, ["both", "fts_only", "tfidf_only"]);
+        assert!((fused[0].1 - 0.8).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_search_hybrid_dedupes_and_ranks() {
+        use crate::core::learning_record_v2::*;
+
+        let temp_dir = TempDir::new().unwrap();
+        let markdown_dir = temp_dir.path().join("markdown");
+        fs::create_dir_all(&markdown_dir).unwrap();
+        let mut db =
+            LearningDatabase::new(temp_dir.path().join("test.db"), markdown_dir.clone()).unwrap();
+
+        let mut strong = LearningRecordBuilder::new(
+            SessionType::Debug,
+            Priority::Low,
+            "Deadlock in worker pool",
+            "Deadlock when workers share a lock",
+            Severity::Error,
+        )
+        .build();
+        strong.session_meta.id = "debug_strong".to_string();
+        index_record(&mut db, &markdown_dir, &strong, "deadlock\n");
+
+        // Stemmed FTS match only: TF-IDF does not treat "deadlocked" as "deadlock"
+        let mut weak = LearningRecordBuilder::new(
+            SessionType::Debug,
+            Priority::Low,
+            "Slow build",
+            "Compilation takes minutes",
+            Severity::Warning,
+        )
+        .build();
+        weak.session_meta.id = "debug_weak".to_string();
+        index_record(
+            &mut db,
+            &markdown_dir,
+            &weak,
+            "The linker deadlocked once while building release artifacts.\n",
+        );
+
+        let results = db
+            .search_hybrid("deadlock", 10, &SearchWeights::default())
+            .unwrap();
+        let ids: Vec<&str> = results.iter().map(|r| r.session.id.as_str()).collect();
+        assert_eq!(ids, ["debug_strong", "debug_weak"]);
+        assert!(results[0].relevance_score > results[1].relevance_score);
+
+        assert!(db
+            .search_hybrid("  ", 10, &SearchWeights::default())
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_load_tfidf_index_matches_rebuild() {
+        use crate::core::learning_record_v2::*;
+
+        let temp_dir = TempDir::new().unwrap();
+        let markdown_dir = temp_dir.path().join("markdown");
+        fs::create_dir_all(&markdown_dir).unwrap();
+        let db_path = temp_dir.path().join("test.db");
+        let mut db = LearningDatabase::new(db_path.clone(), markdown_dir.clone()).unwrap();
+
+        for (id, title) in [
+            ("debug_pool", "Connection pool exhausted"),
+            ("debug_css", "Sidebar layout breaks"),
+        ] {
+            let mut record = LearningRecordBuilder::new(
+                SessionType::Debug,
+                Priority::Low,
+                title,
+                "Seen in production",
+                Severity::Error,
+            )
+            .build();
+            record.session_meta.id = id.to_string();
+            index_record(&mut db, &markdown_dir, &record, "notes\n");
+        }
+        drop(db);
+
+        // A fresh instance has an empty TF-IDF index until it is loaded
+        let mut db = LearningDatabase::new(db_path, markdown_dir).unwrap();
+        assert!(db.search_with_tfidf("pool", 10).unwrap().is_empty());
+        assert!(!db.is_stale().unwrap());
+
+        assert_eq!(db.load_tfidf_index().unwrap(), 2);
+        let results = db.search_with_tfidf("pool", 10).unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].session.id, "debug_pool");
+    }
+
+    #[test]
+    fn test_keyword_search_weights_frontmatter_above_body() {
+        use crate::core::learning_record_v2::*;
+
+        let temp_dir = TempDir::new().unwrap();
+        let markdown_dir = temp_dir.path().join("markdown");
+        fs::create_dir_all(&markdown_dir).unwrap();
+        let mut db =
+            LearningDatabase::new(temp_dir.path().join("test.db"), markdown_dir.clone()).unwrap();
+
+        let mut body_match = LearningRecordBuilder::new(
+            SessionType::Debug,
+            Priority::Low,
+            "Slow build",
+            "Compilation takes minutes",
+            Severity::Warning,
+        )
+        .build();
+        body_match.session_meta.id = "debug_body_match".to_string();
+        index_record(
+            &mut db,
+            &markdown_dir,
+            &body_match,
+            "Caused by a deadlock.\n",
+        );
+
+        let mut title_match = LearningRecordBuilder::new(
+            SessionType::Debug,
+            Priority::Low,
+            "Deadlock in worker pool",
+            "Workers stop responding",
+            Severity::Error,
+        )
+        .build();
+        title_match.session_meta.id = "debug_title_match".to_string();
+        index_record(&mut db, &markdown_dir, &title_match, "Notes\n");
+
+        let results = db.query_by_keyword("deadlock", 10).unwrap();
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].session.id, title_match.session_meta.id);
+    }
+
+    #[test]
+    fn test_upgrades_contentless_fts_schema() {
+        let temp_dir = TempDir::new().unwrap();
+        let db_path = temp_dir.path().join("test.db");
+        {
+            let conn = Connection::open(&db_path).unwrap();
+            conn.execute_batch(
+                "CREATE VIRTUAL TABLE sessions_fts USING fts5(id UNINDEXED, title, description, tags, error_patterns, content='');",
+            )
+            .unwrap();
+        }
+
+        let db = LearningDatabase::new(db_path, temp_dir.path().join("markdown")).unwrap();
+        let has_body: bool = db
+            .conn
+            .query_row(
+                "SELECT 1 FROM pragma_table_info('sessions_fts') WHERE name = 'body'",
+                [],
+                |_| Ok(true),
+            )
+            .unwrap();
+        assert!(has_body);
+    }
+
     #[test]
     fn test_error_similarity() {
         let query = "thread panicked at overflow";
@@ -1289,6 +1851,70 @@ mod tests {
     }
 }
 
+/// Recency of an RFC 3339 timestamp, from 1.0 (now) to 0.0 (a year or older)
+///
+/// Unparseable timestamps score 0.0.
+fn recency_score(timestamp: &str) -> f64 {
+    DateTime::parse_from_rfc3339(timestamp).map_or(0.0, |timestamp| {
+        let age_days = (Local::now() - timestamp.with_timezone(&Local))
+            .num_days()
+            .max(0) as f64;
+        1.0 - (age_days / RECENCY_WINDOW_DAYS).min(1.0)
+    })
+}
+
+/// Build an FTS5 query matching any of the words in `query`
+///
+/// Each word is quoted so punctuation in user input is not parsed as FTS
+/// syntax.
+fn fts_any_term_query(query: &str) -> String {
+    query
+        .split_whitespace()
+        .map(|word| format!("\"{}\"", word.replace('"', "\"\"")))
+        .collect::<Vec<_>>()
+        .join(" OR ")
+}
+
+/// Combine per-signal scores into one ranking, best first
+///
+/// FTS and TF-IDF scores are divided by the best score of their signal so
+/// both range over 0..1; a session missing from a signal scores 0 there.
+/// Ties are broken by session ID.
+fn fuse_scores(
+    fts: &[(String, f64)],
+    tfidf: &[(String, f64)],
+    recency: &HashMap<String, f64>,
+    weights: &SearchWeights,
+) -> Vec<(String, f64)> {
+    let normalize = |scores: &[(String, f64)]| -> HashMap<String, f64> {
+        let max = scores.iter().map(|(_, s)| *s).fold(0.0, f64::max);
+        scores
+            .iter()
+            .map(|(id, s)| (id.clone(), if max > 0.0 { s / max } else { 0.0 }))
+            .collect()
+    };
+    let fts = normalize(fts);
+    let tfidf = normalize(tfidf);
+
+    let ids: HashSet<&String> = fts.keys().chain(tfidf.keys()).collect();
+    let mut fused: Vec<(String, f64)> = ids
+        .into_iter()
+        .map(|id| {
+            let score = weights.fts_weight * fts.get(id).copied().unwrap_or(0.0)
+                + weights.tfidf_weight * tfidf.get(id).copied().unwrap_or(0.0)
+                + weights.recency_weight * recency.get(id).copied().unwrap_or(0.0);
+            (id.clone(), score)
+        })
+        .collect();
+
+    fused.sort_by(|a, b| {
+        b.1.partial_cmp(&a.1)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then_with(|| a.0.cmp(&b.0))
+    });
+    fused
+}
+
 /// Composite score for context-based suggestions
 #[derive(Debug, Clone)]
 #[allow(dead_code)]
@@ -3,6 +3,7 @@
 //! Provides keyword-based search and hotspot tracking.
 //! Backed by SQLite for persistence (via LearningDatabase).
 
+use crate::core::LearningSession;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -79,14 +80,7 @@ impl Default for LearningIndexV2 {
 impl LearningIndexV2 {
     /// Get index file path
     fn index_path() -> crate::core::Result<std::path::PathBuf> {
-        use crate::core::CldevError;
-        let home = std::env::var("HOME")
-            .ok()
-            .map(std::path::PathBuf::from)
-            .or_else(dirs::home_dir)
-            .ok_or_else(|| CldevError::config("Failed to get home directory"))?;
-
-        let index_dir = home.join(".claude").join("learning-records");
+        let index_dir = LearningSession::records_dir()?;
         if !index_dir.exists() {
             std::fs::create_dir_all(&index_dir)?;
         }
@@ -124,20 +118,10 @@ impl LearningIndexV2 {
 
     /// Rebuild index using LearningDatabase
     pub fn rebuild() -> crate::core::Result<()> {
-        use crate::core::{CldevError, LearningDatabase};
-        use std::path::PathBuf;
-
-        let home = std::env::var("HOME")
-            .ok()
-            .map(PathBuf::from)
-            .or_else(dirs::home_dir)
-            .ok_or_else(|| CldevError::config("Failed to get home directory"))?;
+        use crate::core::LearningDatabase;
 
-        let db_path = home
-            .join(".claude")
-            .join("learning-records")
-            .join("learning.db");
-        let markdown_dir = home.join(".claude").join("learning-records");
+        let markdown_dir = LearningSession::records_dir()?;
+        let db_path = markdown_dir.join("learning.db");
 
         let mut db = LearningDatabase::new(db_path, markdown_dir)?;
         let (inserted, updated) = db.build_from_markdown()?;
@@ -154,22 +138,11 @@ impl LearningIndexV2 {
     #[allow(dead_code)]
     pub fn find_by_keyword(&self, keyword: &str) -> Vec<String> {
         use crate::core::LearningDatabase;
-        use std::path::PathBuf;
-
-        let home = match std::env::var("HOME")
-            .ok()
-            .map(PathBuf::from)
-            .or_else(dirs::home_dir)
-        {
-            Some(h) => h,
-            None => return Vec::new(),
-        };
 
-        let db_path = home
-            .join(".claude")
-            .join("learning-records")
-            .join("learning.db");
-        let markdown_dir = home.join(".claude").join("learning-records");
+        let Ok(markdown_dir) = LearningSession::records_dir() else {
+            return Vec::new();
+        };
+        let db_path = markdown_dir.join("learning.db");
 
         let db = match LearningDatabase::new(db_path, markdown_dir) {
             Ok(db) => db,
@@ -186,22 +159,11 @@ impl LearningIndexV2 {
     #[allow(dead_code)]
     pub fn find_by_tag(&self, tag: &str) -> Vec<String> {
         use crate::core::LearningDatabase;
-        use std::path::PathBuf;
-
-        let home = match std::env::var("HOME")
-            .ok()
-            .map(PathBuf::from)
-            .or_else(dirs::home_dir)
-        {
-            Some(h) => h,
-            None => return Vec::new(),
-        };
 
-        let db_path = home
-            .join(".claude")
-            .join("learning-records")
-            .join("learning.db");
-        let markdown_dir = home.join(".claude").join("learning-records");
+        let Ok(markdown_dir) = LearningSession::records_dir() else {
+            return Vec::new();
+        };
+        let db_path = markdown_dir.join("learning.db");
 
         let db = match LearningDatabase::new(db_path, markdown_dir) {
             Ok(db) => db,
@@ -218,22 +180,11 @@ impl LearningIndexV2 {
     #[allow(dead_code)]
     pub fn get_hotspots(&self, min_issues: usize) -> Vec<(String, FileHotspot)> {
         use crate::core::LearningDatabase;
-        use std::path::PathBuf;
-
-        let home = match std::env::var("HOME")
-            .ok()
-            .map(PathBuf::from)
-            .or_else(dirs::home_dir)
-        {
-            Some(h) => h,
-            None => return Vec::new(),
-        };
 
-        let db_path = home
-            .join(".claude")
-            .join("learning-records")
-            .join("learning.db");
-        let markdown_dir = home.join(".claude").join("learning-records");
+        let Ok(markdown_dir) = LearningSession::records_dir() else {
+            return Vec::new();
+        };
+        let db_path = markdown_dir.join("learning.db");
 
         let db = match LearningDatabase::new(db_path, markdown_dir) {
             Ok(db) => db,
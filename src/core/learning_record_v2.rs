@@ -3,6 +3,7 @@
 //! Provides YAML-based learning records with rich metadata for tracking
 //! development sessions, problems, solutions, and learnings.
 
+use crate::core::LearningSession;
 use chrono::{DateTime, Local};
 use serde::{Deserialize, Serialize};
 
@@ -284,13 +285,7 @@ impl LearningRecordBuilder {
         let record = self.build();
 
         // Get records directory
-        let home = std::env::var("HOME")
-            .ok()
-            .map(PathBuf::from)
-            .or_else(dirs::home_dir)
-            .ok_or_else(|| CldevError::config("Failed to get home directory"))?;
-
-        let records_dir = home.join(".claude").join("learning-records");
+        let records_dir = LearningSession::records_dir()?;
         if !records_dir.exists() {
             fs::create_dir_all(&records_dir)?;
         }
@@ -323,16 +318,7 @@ impl LearningRecordV2 {
         use crate::core::CldevError;
         use std::path::PathBuf;
 
-        let home = std::env::var("HOME")
-            .ok()
-            .map(PathBuf::from)
-            .or_else(dirs::home_dir)
-            .ok_or_else(|| CldevError::config("Failed to get home directory"))?;
-
-        let path = home
-            .join(".claude")
-            .join("learning-records")
-            .join(format!("{}.md", id));
+        let path = LearningSession::records_dir()?.join(format!("{}.md", id));
 
         if !path.exists() {
             return Err(CldevError::config(format!(
@@ -353,13 +339,7 @@ impl LearningRecordV2 {
         use crate::core::CldevError;
         use std::path::PathBuf;
 
-        let home = std::env::var("HOME")
-            .ok()
-            .map(PathBuf::from)
-            .or_else(dirs::home_dir)
-            .ok_or_else(|| CldevError::config("Failed to get home directory"))?;
-
-        let dir = home.join(".claude").join("learning-records");
+        let dir = LearningSession::records_dir()?;
         if !dir.exists() {
             return Ok(Vec::new());
         }
@@ -380,7 +360,7 @@ impl LearningRecordV2 {
 
     /// Parse markdown file with YAML frontmatter
     #[allow(dead_code)]
-    fn parse_markdown(content: &str) -> crate::core::Result<Self> {
+    pub fn parse_markdown(content: &str) -> crate::core::Result<Self> {
         use crate::core::CldevError;
 
         // Extract YAML frontmatter between --- delimiters
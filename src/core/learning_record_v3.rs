@@ -3,9 +3,11 @@
 //! Minimal YAML frontmatter (10%) + Markdown body (90%)
 //! Designed for natural human writing with AI processing
 
-use crate::core::{CldevError, Result};
+use crate::core::config::home_dir;
+use crate::core::{CldevError, LearningRecordV2, Result};
 use chrono::{DateTime, Local};
 use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
 
 /// Learning Record V3 - Human-first format
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -45,12 +47,135 @@ impl LearningRecordV3 {
         }
     }
 
+    /// Convert a V2 record into the V3 format
+    ///
+    /// The frontmatter keeps the ID, timestamp, duration and tags, and the
+    /// status follows `resolved` (resolved → `Resolved`, otherwise
+    /// `Pending`). Everything else from the structured V2 fields is written
+    /// into the Markdown body.
+    pub fn from_v2(v2: LearningRecordV2) -> Self {
+        let lowercase = |value: &dyn std::fmt::Debug| format!("{:?}", value).to_lowercase();
+        let meta = &v2.session_meta;
+        let problem = &v2.problem;
+
+        let mut body = format!(
+            "# {}\n\n**Type**: {} | **Priority**: {} | **Severity**: {}\n\n## Problem\n\n{}\n",
+            problem.title,
+            meta.session_type.as_str(),
+            lowercase(&meta.priority),
+            lowercase(&problem.severity),
+            problem.description
+        );
+
+        if !problem.error_signatures.is_empty() {
+            body.push_str("\n### Error Signatures\n\n");
+            for signature in &problem.error_signatures {
+                body.push_str(&format!(
+                    "- `{}`: {}\n",
+                    signature.error_type, signature.pattern
+                ));
+                if let Some(hash) = &signature.stack_trace_hash {
+                    body.push_str(&format!("  - Stack trace hash: {}\n", hash));
+                }
+            }
+        }
+
+        if let Some(solution) = &v2.solution {
+            body.push_str(&format!("\n## Solution\n\n{}\n", solution.summary));
+            if let Some(root_cause) = &solution.root_cause {
+                body.push_str(&format!("\n**Root cause**: {}\n", root_cause));
+            }
+            if !solution.steps.is_empty() {
+                body.push_str("\n### Steps\n\n");
+                for (i, step) in solution.steps.iter().enumerate() {
+                    body.push_str(&format!("{}. {}\n", i + 1, step));
+                }
+            }
+            if !solution.verification.is_empty() {
+                body.push_str("\n### Verification\n\n");
+                for check in &solution.verification {
+                    body.push_str(&format!("- {}\n", check));
+                }
+            }
+        }
+
+        let context = &v2.context;
+        if !context.files_affected.is_empty() {
+            body.push_str("\n## Files\n\n");
+            for file in &context.files_affected {
+                body.push_str(&format!(
+                    "- `{}` ({}, hotspot {:.2})",
+                    file.path,
+                    lowercase(&file.role),
+                    file.hotspot_score
+                ));
+                if let Some(summary) = &file.changes_summary {
+                    body.push_str(&format!(": {}", summary));
+                }
+                body.push('\n');
+            }
+        }
+
+        if !context.dependencies.is_empty() {
+            body.push_str("\n## Dependencies\n\n");
+            for dependency in &context.dependencies {
+                let version = dependency.version.as_deref().unwrap_or("*");
+                body.push_str(&format!(
+                    "- {} {}: {}\n",
+                    dependency.name, version, dependency.relevance
+                ));
+            }
+        }
+
+        if let Some(environment) = &context.environment {
+            body.push_str(&format!("\n## Environment\n\n{}\n", environment));
+        }
+
+        if !v2.learnings.is_empty() {
+            body.push_str("\n## Learnings\n\n");
+            for learning in &v2.learnings {
+                body.push_str(&format!(
+                    "- {} ({}, {} reusability)",
+                    learning.insight,
+                    learning.category,
+                    lowercase(&learning.reusability)
+                ));
+                if !learning.applicable_to.is_empty() {
+                    body.push_str(&format!(
+                        ", applies to: {}",
+                        learning.applicable_to.join(", ")
+                    ));
+                }
+                body.push('\n');
+            }
+        }
+
+        Self {
+            id: meta.id.clone(),
+            created: meta.timestamp,
+            auto_generated: false,
+            confidence: None,
+            tags: context.tags.clone(),
+            status: if meta.resolved {
+                RecordStatus::Resolved
+            } else {
+                RecordStatus::Pending
+            },
+            duration_min: meta.duration_minutes,
+            markdown_body: body,
+        }
+    }
+
+    /// Get the V3 learning records directory path (`~/.cldev/learning-records`)
+    ///
+    /// The directory is not created.
+    pub fn records_dir() -> Result<PathBuf> {
+        Ok(home_dir()?.join(".cldev").join("learning-records"))
+    }
+
     /// List all V3 learning record IDs
     pub fn list_all() -> Result<Vec<String>> {
-        let home = dirs::home_dir()
-            .ok_or_else(|| CldevError::Config("Could not determine home directory".to_string()))?;
-
-        let lr_dir = home.join(".cldev").join("learning-records");
+        let lr_dir = Self::records_dir()?;
         if !lr_dir.exists() {
             return Ok(Vec::new());
         }
@@ -71,13 +196,7 @@ impl LearningRecordV3 {
 
     /// Load a V3 learning record by ID
     pub fn load(id: &str) -> Result<Self> {
-        let home = dirs::home_dir()
-            .ok_or_else(|| CldevError::Config("Could not determine home directory".to_string()))?;
-
-        let file_path = home
-            .join(".cldev")
-            .join("learning-records")
-            .join(format!("{}.md", id));
+        let file_path = Self::records_dir()?.join(format!("{}.md", id));
 
         if !file_path.exists() {
             return Err(CldevError::Config(format!(
@@ -152,6 +271,23 @@ impl LearningRecordV3 {
             markdown_body,
         })
     }
+
+    /// Text of the first `## {heading}` section in the body, if it is not empty
+    ///
+    /// The section ends at the next level-1 or level-2 heading.
+    pub fn section(&self, heading: &str) -> Option<String> {
+        let marker = format!("## {}", heading);
+        let text = self
+            .markdown_body
+            .lines()
+            .skip_while(|line| line.trim_end() != marker)
+            .skip(1)
+            .take_while(|line| !line.starts_with("# ") && !line.starts_with("## "))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let text = text.trim();
+        (!text.is_empty()).then(|| text.to_string())
+    }
 }
 
 /// YAML frontmatter structure
@@ -183,6 +319,20 @@ mod tests {
         assert!(!record.auto_generated);
     }
 
+    #[test]
+    fn test_section() {
+        let record = LearningRecordV3::new(
+            "section-test".to_string(),
+            "# Title\n\n## Problem\n\nIt broke.\n\n### Details\n\nOften.\n\n## Solution\n\n## Learnings\n".to_string(),
+        );
+        assert_eq!(
+            record.section("Problem").as_deref(),
+            Some("It broke.\n\n### Details\n\nOften.")
+        );
+        assert_eq!(record.section("Solution"), None);
+        assert_eq!(record.section("Missing"), None);
+    }
+
     #[test]
     fn test_to_markdown_file() {
         let mut record = LearningRecordV3::new(
@@ -216,6 +366,69 @@ mod tests {
         assert_eq!(original.status, parsed.status);
     }
 
+    #[test]
+    fn test_from_v2_roundtrip() {
+        use crate::core::learning_record_v2::*;
+
+        let v2 = LearningRecordBuilder::new(
+            SessionType::Fix,
+            Priority::High,
+            "Pool timeout",
+            "Requests hang waiting for a connection",
+            Severity::Error,
+        )
+        .tag("database")
+        .files(vec![FileAffected {
+            path: "src/db/pool.rs".to_string(),
+            role: FileRole::Primary,
+            changes_summary: Some("Raised max connections".to_string()),
+            hotspot_score: 0.8,
+        }])
+        .solution(Solution {
+            summary: "Increase the pool size".to_string(),
+            root_cause: Some("Pool capped at 5".to_string()),
+            steps: vec!["Profile connections".to_string()],
+            verification: vec!["Load test passes".to_string()],
+        })
+        .learning(Learning {
+            insight: "Size pools to peak concurrency".to_string(),
+            category: "performance".to_string(),
+            reusability: Reusability::High,
+            applicable_to: vec!["postgres".to_string()],
+        })
+        .resolved(40)
+        .build();
+        let id = v2.session_meta.id.clone();
+
+        let v3 = LearningRecordV3::from_v2(v2.clone());
+        let parsed = LearningRecordV3::from_markdown_file(&v3.to_markdown_file()).unwrap();
+
+        assert_eq!(parsed.id, id);
+        assert_eq!(parsed.status, RecordStatus::Resolved);
+        assert_eq!(parsed.tags, vec!["database"]);
+        assert_eq!(parsed.duration_min, Some(40));
+        for expected in [
+            "# Pool timeout",
+            "**Type**: fix | **Priority**: high | **Severity**: error",
+            "Requests hang waiting for a connection",
+            "Increase the pool size",
+            "**Root cause**: Pool capped at 5",
+            "1. Profile connections",
+            "- Load test passes",
+            "- `src/db/pool.rs` (primary, hotspot 0.80): Raised max connections",
+            "- Size pools to peak concurrency (performance, high reusability), applies to: postgres",
+        ] {
+            assert!(parsed.markdown_body.contains(expected), "missing {}", expected);
+        }
+
+        let mut unresolved = v2;
+        unresolved.session_meta.resolved = false;
+        assert_eq!(
+            LearningRecordV3::from_v2(unresolved).status,
+            RecordStatus::Pending
+        );
+    }
+
     #[test]
     fn test_parse_with_confidence() {
         let content = r#"---
@@ -269,8 +482,9 @@ Just a note.
     #[test]
     fn test_session_end_integration() {
         use crate::core::{
-            analyze_session, generate_level2_markdown, ErrorCapture, FileModification,
-            ModificationType, SessionContext, TodoAction, TodoSnapshot, TodoStatus,
+            analyze_session, generate_level2_markdown, CaptureConfig, ErrorCapture,
+            FileModification, ModificationType, SessionContext, TodoAction, TodoSnapshot,
+            TodoStatus,
         };
 
         let mut ctx = SessionContext::new("integration-test".to_string());
@@ -291,10 +505,10 @@ Just a note.
             timestamp: chrono::Local::now(),
         });
 
-        let recommendation = analyze_session(&ctx);
+        let recommendation = analyze_session(&ctx, &CaptureConfig::default());
         assert!(recommendation.score >= 0.15);
 
-        let record = generate_level2_markdown(&ctx, &recommendation);
+        let record = generate_level2_markdown(&ctx, &recommendation, &[]);
         assert!(record.auto_generated);
         assert!(record.markdown_body.contains("CompileError"));
     }
@@ -7,15 +7,19 @@
 
 pub mod auto_capture;
 pub mod config;
+pub mod doctor;
 pub mod error;
+pub mod exec;
 pub mod git_utils;
 pub mod i18n;
 pub mod learning_db;
 pub mod learning_index;
 pub mod learning_record_v2;
 pub mod learning_record_v3;
+pub mod notification;
 pub mod project_config;
 pub mod project_detector;
+pub mod retry;
 pub mod sanitizer;
 pub mod security;
 pub mod session_context;
@@ -27,9 +31,13 @@ pub mod tfidf;
 pub use auto_capture::{
     analyze_session, generate_level2_markdown, RecordLevel, RecordRecommendation,
 };
-pub use config::{Config, ConfigVersion, HierarchicalConfig};
+pub use config::{
+    AnalysisConfig, CaptureConfig, Config, ConfigLayer, ConfigVersion, HierarchicalConfig,
+    LayerResolution, SearchWeights,
+};
+pub use doctor::{check_tools, ToolCheck};
 pub use error::{CldevError, Result};
-pub use git_utils::GitUtils;
+pub use git_utils::{ChurnStats, DiffStatus, FileDiff, GitUtils};
 pub use learning_db::{Hotspot, LearningDatabase, QueryResult, SessionMetadata};
 pub use learning_index::{
     FileHotspot, LearningIndexV2, SessionRef, TimelineEntry, UnresolvedEntry,
@@ -39,12 +47,15 @@ pub use learning_record_v2::{
     LearningRecordV2, Priority, Problem, Reusability, SessionMeta, SessionType, Severity, Solution,
 };
 pub use learning_record_v3::{LearningRecordV3, RecordStatus};
-pub use project_config::ProjectConfig;
+pub use project_config::{ProjectConfig, ProjectEnvironment};
 pub use project_detector::{ProjectDetector, ProjectType};
-pub use sanitizer::{sanitize_text, SanitizationResult};
+pub use sanitizer::{
+    sanitize_text, sanitize_text_with_allowlist, sanitize_with_spans, RedactionKind, RedactionSpan,
+    SanitizationResult,
+};
 pub use session_context::{
-    CommandRecord, ErrorCapture, FileModification, ModificationType, SessionContext, TodoAction,
-    TodoSnapshot, TodoStatus, ToolUsage,
+    CommandRecord, ErrorCapture, FileModification, ModificationType, SessionContext, SessionStats,
+    TodoAction, TodoSnapshot, TodoStatus, ToolUsage,
 };
 pub use session_recorder::{LearningSession, LearningSessionBuilder};
 pub use stack_config::{StackConfig, TechStack};
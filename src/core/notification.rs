@@ -0,0 +1,172 @@
+//! Desktop notifications for completed commands
+//!
+//! Enabled with the global `--notify` flag or `[general].notify_on_complete`.
+//! Notifications are best-effort: they are skipped in non-interactive
+//! environments and delivery failures are ignored.
+
+use crate::core::error::{CldevError, Result};
+use crate::core::i18n::I18n;
+use std::io::{self, IsTerminal};
+use std::time::Duration;
+
+/// Delivers a notification to the user
+pub trait Notifier {
+    /// Show a notification with a short summary and a body
+    fn notify(&self, summary: &str, body: &str) -> Result<()>;
+}
+
+/// Notifier backed by the OS notification service
+#[derive(Debug, Default, Clone, Copy)]
+pub struct DesktopNotifier;
+
+impl Notifier for DesktopNotifier {
+    fn notify(&self, summary: &str, body: &str) -> Result<()> {
+        notify_rust::Notification::new()
+            .appname("cldev")
+            .summary(summary)
+            .body(body)
+            .show()
+            .map(|_| ())
+            .map_err(|e| CldevError::command(format!("Failed to send notification: {}", e)))
+    }
+}
+
+/// Check whether a completion notification should be sent
+///
+/// Returns false when notifications are disabled, when stderr is not a
+/// terminal, or when running under CI.
+pub fn should_notify(enabled: bool) -> bool {
+    enabled && io::stderr().is_terminal() && std::env::var_os("CI").is_none()
+}
+
+/// Notify that a command finished, including its status and duration
+pub fn notify_command_complete(
+    notifier: &dyn Notifier,
+    i18n: &I18n,
+    command: &str,
+    success: bool,
+    duration: Duration,
+) -> Result<()> {
+    let summary = i18n.format("notify-summary", "command", command);
+    let key = if success {
+        "notify-success"
+    } else {
+        "notify-failure"
+    };
+    let body = i18n.format(key, "duration", &format!("{:.1}s", duration.as_secs_f64()));
+
+    notifier.notify(&summary, &body)
+}
+
+/// Derive a short command label such as `git status` from a command's Debug output
+pub fn command_label(debug: &str) -> String {
+    let head = debug.split([' ', '{']).next().unwrap_or_default();
+
+    head.split('(')
+        .map(|part| part.trim_end_matches(')'))
+        .filter(|part| !part.is_empty())
+        .map(to_kebab_case)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Convert a CamelCase identifier to kebab-case
+fn to_kebab_case(ident: &str) -> String {
+    let mut out = String::with_capacity(ident.len() + 4);
+    for (i, c) in ident.chars().enumerate() {
+        if c.is_uppercase() {
+            if i > 0 {
+                out.push('-');
+            }
+            out.extend(c.to_lowercase());
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::i18n::Language;
+    use std::cell::RefCell;
+
+    #[derive(Default)]
+    struct MockNotifier {
+        sent: RefCell<Vec<(String, String)>>,
+    }
+
+    impl Notifier for MockNotifier {
+        fn notify(&self, summary: &str, body: &str) -> Result<()> {
+            self.sent
+                .borrow_mut()
+                .push((summary.to_string(), body.to_string()));
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_notify_success_status() {
+        let notifier = MockNotifier::default();
+        let i18n = I18n::with_language(Language::English);
+
+        notify_command_complete(
+            &notifier,
+            &i18n,
+            "git status",
+            true,
+            Duration::from_millis(1500),
+        )
+        .unwrap();
+
+        let sent = notifier.sent.borrow();
+        assert_eq!(sent.len(), 1);
+        assert!(sent[0].0.contains("git status"));
+        assert!(sent[0].1.contains("1.5s"));
+        assert_eq!(sent[0].1, i18n.format("notify-success", "duration", "1.5s"));
+    }
+
+    #[test]
+    fn test_notify_failure_status() {
+        let notifier = MockNotifier::default();
+        let i18n = I18n::with_language(Language::English);
+
+        notify_command_complete(
+            &notifier,
+            &i18n,
+            "quality test",
+            false,
+            Duration::from_secs(3),
+        )
+        .unwrap();
+
+        let sent = notifier.sent.borrow();
+        assert_eq!(sent[0].1, i18n.format("notify-failure", "duration", "3.0s"));
+    }
+
+    #[test]
+    fn test_should_notify_disabled() {
+        assert!(!should_notify(false));
+    }
+
+    #[test]
+    fn test_command_label() {
+        assert_eq!(
+            command_label("Git(Status { detailed: false })"),
+            "git status"
+        );
+        assert_eq!(
+            command_label("Config(Check { detailed: false, fix: true })"),
+            "config check"
+        );
+        assert_eq!(
+            command_label("Lr(FindSessions { query: \"x\" })"),
+            "lr find-sessions"
+        );
+        assert_eq!(
+            command_label("Completions { shell: Bash, install: false }"),
+            "completions"
+        );
+    }
+}
@@ -56,6 +56,10 @@ pub struct ProjectConfig {
     /// Project-specific paths
     #[serde(default)]
     pub paths: ProjectPaths,
+
+    /// Per-environment build and deploy overrides (`[environments.<name>]`)
+    #[serde(default)]
+    pub environments: HashMap<String, ProjectEnvironment>,
 }
 
 /// Project metadata
@@ -107,6 +111,22 @@ pub struct ProjectDevConfig {
     pub env_vars: HashMap<String, String>,
 }
 
+/// Build and deploy overrides for one environment (`ops build` / `ops deploy`)
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProjectEnvironment {
+    /// Shell command replacing the detected build command
+    #[serde(default)]
+    pub build_command: Option<String>,
+
+    /// Shell command replacing the detected deployment
+    #[serde(default)]
+    pub deploy_command: Option<String>,
+
+    /// Environment variables set for the build and deploy commands
+    #[serde(default)]
+    pub env_vars: HashMap<String, String>,
+}
+
 /// Project-specific quality settings
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[allow(dead_code)]
@@ -390,6 +410,9 @@ impl ProjectConfig {
                 self.quality.min_coverage = Some(80);
                 self.paths.dist = PathBuf::from("target");
             }
+            TechStack::Custom(_) => {
+                // Custom stacks carry their settings in stacks/{name}.toml
+            }
         }
     }
 
@@ -495,7 +518,13 @@ mod tests {
             TechStack::RustCli
         ));
 
-        config.project.tech_stack = Some("invalid".to_string());
+        config.project.tech_stack = Some("my-stack".to_string());
+        assert_eq!(
+            config.get_tech_stack(),
+            Some(TechStack::Custom("my-stack".to_string()))
+        );
+
+        config.project.tech_stack = Some("Not A Stack".to_string());
         assert!(config.get_tech_stack().is_none());
     }
 
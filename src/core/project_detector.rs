@@ -74,6 +74,10 @@ pub enum Framework {
     Rails,
     Sinatra,
     SpringBoot,
+    Laravel,
+    Symfony,
+    // Mobile frameworks
+    Android,
     // Rust frameworks
     Actix,
     Axum,
@@ -203,6 +207,9 @@ impl Framework {
             Framework::Rails => "Rails",
             Framework::Sinatra => "Sinatra",
             Framework::SpringBoot => "Spring Boot",
+            Framework::Laravel => "Laravel",
+            Framework::Symfony => "Symfony",
+            Framework::Android => "Android",
             Framework::Actix => "Actix-web",
             Framework::Axum => "Axum",
             Framework::Rocket => "Rocket",
@@ -333,24 +340,17 @@ impl ProjectDetector {
             return Ok(ProjectType::Go);
         }
 
-        // Java (Maven or Gradle)
-        if root.join("pom.xml").exists() || root.join("build.gradle").exists() {
-            // Distinguish between Java and Kotlin
-            if root.join("build.gradle.kts").exists() {
-                return Ok(ProjectType::Kotlin);
-            }
-            // Check for Kotlin files if using build.gradle
-            if Self::has_files_with_extension(root, &["kt", "kts"]) {
+        // Java or Kotlin (Maven, or Gradle with the Groovy or Kotlin DSL)
+        if root.join("pom.xml").exists()
+            || root.join("build.gradle").exists()
+            || root.join("build.gradle.kts").exists()
+        {
+            if Self::uses_kotlin(root) {
                 return Ok(ProjectType::Kotlin);
             }
             return Ok(ProjectType::Java);
         }
 
-        // Kotlin (build.gradle.kts is Kotlin-specific)
-        if root.join("build.gradle.kts").exists() {
-            return Ok(ProjectType::Kotlin);
-        }
-
         // Swift Package Manager
         if root.join("Package.swift").exists() {
             return Ok(ProjectType::Swift);
@@ -535,6 +535,27 @@ impl ProjectDetector {
                         frameworks.push(Framework::SpringBoot);
                     }
                 }
+                if Self::is_android(root) {
+                    frameworks.push(Framework::Android);
+                }
+            }
+            ProjectType::Php => {
+                // Check composer.json requirements for PHP frameworks
+                if let Ok(content) = fs::read_to_string(root.join("composer.json")) {
+                    if let Ok(json) = serde_json::from_str::<serde_json::Value>(&content) {
+                        let requires = |package: &str| {
+                            ["require", "require-dev"]
+                                .iter()
+                                .any(|key| json.get(key).and_then(|r| r.get(package)).is_some())
+                        };
+                        if requires("laravel/framework") {
+                            frameworks.push(Framework::Laravel);
+                        }
+                        if requires("symfony/framework-bundle") {
+                            frameworks.push(Framework::Symfony);
+                        }
+                    }
+                }
             }
             ProjectType::Rust => {
                 // Check Cargo.toml for Rust web frameworks
@@ -722,6 +743,46 @@ impl ProjectDetector {
         deps
     }
 
+    /// Whether a JVM project is written in Kotlin
+    ///
+    /// A Kotlin DSL build script (`build.gradle.kts`) alone does not make a
+    /// Kotlin project, since Java projects use it too. The Kotlin Gradle
+    /// plugin, a `src/main/kotlin` directory, or `.kt` files at the root do.
+    fn uses_kotlin(root: &Path) -> bool {
+        let applies_plugin = ["build.gradle.kts", "build.gradle"].iter().any(|script| {
+            fs::read_to_string(root.join(script)).is_ok_and(|content| {
+                content.contains("kotlin(") || content.contains("org.jetbrains.kotlin")
+            })
+        });
+
+        applies_plugin
+            || root.join("src/main/kotlin").is_dir()
+            || Self::has_files_with_extension(root, &["kt"])
+    }
+
+    /// Whether a Gradle project builds an Android app or library
+    ///
+    /// Detected from an `AndroidManifest.xml` in the usual source sets, or a
+    /// `com.android` plugin in the root or `app` build script.
+    fn is_android(root: &Path) -> bool {
+        let has_manifest = ["src/main", "app/src/main", "."]
+            .iter()
+            .any(|dir| root.join(dir).join("AndroidManifest.xml").exists());
+
+        has_manifest
+            || [
+                "build.gradle",
+                "build.gradle.kts",
+                "app/build.gradle",
+                "app/build.gradle.kts",
+            ]
+            .iter()
+            .any(|script| {
+                fs::read_to_string(root.join(script))
+                    .is_ok_and(|content| content.contains("com.android"))
+            })
+    }
+
     /// Check if directory has files with specific extensions
     fn has_files_with_extension(root: &Path, extensions: &[&str]) -> bool {
         if let Ok(entries) = fs::read_dir(root) {
@@ -767,6 +828,145 @@ impl ProjectDetector {
         &self.root
     }
 
+    /// Get the member directories of a workspace monorepo
+    ///
+    /// Member patterns are read from `[workspace].members` in Cargo.toml,
+    /// `workspaces` in package.json, and `packages` in pnpm-workspace.yaml.
+    /// Patterns may end in `/*` to match every subdirectory; exclusions
+    /// (`!pattern`) and other globs are skipped. Returns an empty list for
+    /// single-package projects.
+    pub fn workspace_members(&self) -> Vec<PathBuf> {
+        let mut patterns = Vec::new();
+
+        if let Ok(content) = fs::read_to_string(self.root.join("Cargo.toml")) {
+            if let Ok(manifest) = toml::from_str::<toml::Value>(&content) {
+                if let Some(members) = manifest
+                    .get("workspace")
+                    .and_then(|w| w.get("members"))
+                    .and_then(|m| m.as_array())
+                {
+                    patterns.extend(members.iter().filter_map(|m| m.as_str().map(String::from)));
+                }
+            }
+        }
+
+        if let Ok(content) = fs::read_to_string(self.root.join("package.json")) {
+            if let Ok(json) = serde_json::from_str::<serde_json::Value>(&content) {
+                // Either `["packages/*"]` or Yarn's `{ "packages": [...] }`
+                let workspaces = json.get("workspaces").and_then(|w| {
+                    w.as_array()
+                        .or_else(|| w.get("packages").and_then(|p| p.as_array()))
+                });
+                if let Some(workspaces) = workspaces {
+                    patterns.extend(
+                        workspaces
+                            .iter()
+                            .filter_map(|w| w.as_str().map(String::from)),
+                    );
+                }
+            }
+        }
+
+        if let Ok(content) = fs::read_to_string(self.root.join("pnpm-workspace.yaml")) {
+            if let Ok(yaml) = serde_yaml::from_str::<serde_yaml::Value>(&content) {
+                if let Some(packages) = yaml.get("packages").and_then(|p| p.as_sequence()) {
+                    patterns.extend(packages.iter().filter_map(|p| p.as_str().map(String::from)));
+                }
+            }
+        }
+
+        let mut members = Vec::new();
+        for pattern in &patterns {
+            for member in self.expand_member_pattern(pattern) {
+                if !members.contains(&member) {
+                    members.push(member);
+                }
+            }
+        }
+        members
+    }
+
+    /// Detect the sub-projects of a monorepo
+    ///
+    /// Every workspace member and every immediate child directory with its own
+    /// marker file (Cargo.toml, go.mod, package.json, ...) becomes a detector.
+    /// A child without a marker, such as `services/`, is treated as a grouping
+    /// directory and its children are checked instead. Hidden directories and
+    /// dependency or build output directories are skipped. Detectors are
+    /// returned in path order.
+    pub fn detect_subprojects(&self) -> Result<Vec<ProjectDetector>> {
+        let mut candidates = self.workspace_members();
+        for child in Self::child_dirs(&self.root) {
+            if Self::detect_project_type(&child)? == ProjectType::Unknown {
+                candidates.extend(Self::child_dirs(&child));
+            } else {
+                candidates.push(child);
+            }
+        }
+        candidates.sort();
+        candidates.dedup();
+
+        let mut subprojects = Vec::new();
+        for dir in candidates {
+            let detector = Self::new(Some(&dir))?;
+            if detector.project_type() != ProjectType::Unknown {
+                subprojects.push(detector);
+            }
+        }
+        Ok(subprojects)
+    }
+
+    /// Visible subdirectories of `dir` that may hold a project
+    fn child_dirs(dir: &Path) -> Vec<PathBuf> {
+        const SKIPPED: &[&str] = &["node_modules", "target", "vendor", "dist", "build"];
+
+        let Ok(entries) = fs::read_dir(dir) else {
+            return Vec::new();
+        };
+        entries
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| path.is_dir())
+            .filter(|path| {
+                path.file_name()
+                    .and_then(|name| name.to_str())
+                    .is_some_and(|name| !name.starts_with('.') && !SKIPPED.contains(&name))
+            })
+            .collect()
+    }
+
+    /// Expand a single workspace member pattern into existing directories
+    fn expand_member_pattern(&self, pattern: &str) -> Vec<PathBuf> {
+        let pattern = pattern.trim_start_matches("./").trim_end_matches('/');
+        if pattern.starts_with('!') {
+            return Vec::new();
+        }
+
+        match pattern.strip_suffix("/*") {
+            Some(parent) if !parent.contains('*') => {
+                let Ok(entries) = fs::read_dir(self.root.join(parent)) else {
+                    return Vec::new();
+                };
+                let mut dirs: Vec<PathBuf> = entries
+                    .flatten()
+                    .map(|entry| entry.path())
+                    .filter(|path| path.is_dir())
+                    .collect();
+                dirs.sort();
+                dirs
+            }
+            None if !pattern.contains('*') => {
+                let dir = self.root.join(pattern);
+                if dir.is_dir() {
+                    vec![dir]
+                } else {
+                    Vec::new()
+                }
+            }
+            _ => Vec::new(),
+        }
+    }
+
     /// Get lint command for the detected project type
     ///
     /// # Arguments
@@ -824,18 +1024,12 @@ impl ProjectDetector {
                 Ok(cmd)
             }
             ProjectType::Python => {
-                // Try to detect which linter is installed
-                if self.has_command("ruff") {
-                    let mut cmd = vec!["ruff".to_string(), "check".to_string()];
-                    if fix {
-                        cmd.push("--fix".to_string());
-                    }
-                    cmd.push(".".to_string());
-                    Ok(cmd)
-                } else if self.has_command("pylint") {
-                    Ok(vec!["pylint".to_string(), ".".to_string()])
-                } else if self.has_command("flake8") {
-                    Ok(vec!["flake8".to_string(), ".".to_string()])
+                if let Some(linter) = self.python_linter() {
+                    Ok(Self::python_lint_command(
+                        linter,
+                        fix,
+                        self.python_source_targets(),
+                    ))
                 } else {
                     Err(CldevError::Config(
                         "No Python linter found. Please install ruff, pylint, or flake8."
@@ -910,6 +1104,67 @@ impl ProjectDetector {
         }
     }
 
+    /// Get a lint command limited to changed files
+    ///
+    /// Linters that accept file arguments (ESLint, ruff, pylint, flake8,
+    /// RuboCop) are given the changed files matching the project's source
+    /// extensions. Other linters, or a change set with no source files, fall
+    /// back to the full [`get_lint_command`](Self::get_lint_command).
+    ///
+    /// # Arguments
+    /// * `changed` - Changed files, relative to the project root
+    /// * `fix` - Whether to auto-fix issues
+    pub fn get_lint_command_changed(&self, changed: &[PathBuf], fix: bool) -> Result<Vec<String>> {
+        self.lint_command_for_changes(changed, fix, self.python_linter())
+    }
+
+    /// [`get_lint_command_changed`](Self::get_lint_command_changed) with the
+    /// installed Python linter already looked up
+    fn lint_command_for_changes(
+        &self,
+        changed: &[PathBuf],
+        fix: bool,
+        python_linter: Option<&str>,
+    ) -> Result<Vec<String>> {
+        let files: Vec<String> = changed
+            .iter()
+            .filter(|path| {
+                path.extension()
+                    .and_then(|e| e.to_str())
+                    .is_some_and(|ext| self.project_type.extensions().contains(&ext))
+            })
+            .map(|path| path.to_string_lossy().to_string())
+            .collect();
+        if files.is_empty() {
+            return self.get_lint_command(fix, true);
+        }
+
+        // Python targets differ by layout, so build from the bare linter
+        if self.project_type == ProjectType::Python {
+            return match python_linter {
+                Some(linter) => Ok(Self::python_lint_command(linter, fix, files)),
+                None => self.get_lint_command(fix, true),
+            };
+        }
+
+        let mut cmd = self.get_lint_command(fix, false)?;
+        let accepts_paths = match self.project_type {
+            ProjectType::NodeJs => cmd.first().is_some_and(|c| c == "npx"),
+            ProjectType::Ruby => true,
+            _ => false,
+        };
+        if !accepts_paths {
+            return self.get_lint_command(fix, true);
+        }
+
+        // Replace the whole-project target with the changed files
+        if cmd.last().is_some_and(|arg| arg == ".") {
+            cmd.pop();
+        }
+        cmd.extend(files);
+        Ok(cmd)
+    }
+
     /// Get format command for the detected project type
     ///
     /// # Arguments
@@ -970,14 +1225,14 @@ impl ProjectDetector {
                     if check {
                         cmd.push("--check".to_string());
                     }
-                    cmd.push(".".to_string());
+                    cmd.extend(self.python_source_targets());
                     Ok(cmd)
                 } else if self.has_command("ruff") {
                     let mut cmd = vec!["ruff".to_string(), "format".to_string()];
                     if check {
                         cmd.push("--check".to_string());
                     }
-                    cmd.push(".".to_string());
+                    cmd.extend(self.python_source_targets());
                     Ok(cmd)
                 } else {
                     Err(CldevError::Config(
@@ -1104,16 +1359,10 @@ impl ProjectDetector {
                 Ok(cmd)
             }
             ProjectType::Rust => {
-                let mut cmd = vec!["cargo".to_string(), "test".to_string()];
-
-                if let Some(p) = pattern {
-                    cmd.push(p.to_string());
-                }
-
                 if coverage {
                     // Use cargo-tarpaulin if available
                     if self.has_command("cargo-tarpaulin") {
-                        cmd = vec!["cargo".to_string(), "tarpaulin".to_string()];
+                        return Ok(vec!["cargo".to_string(), "tarpaulin".to_string()]);
                     } else {
                         return Err(CldevError::Config(
                             "Coverage requested but cargo-tarpaulin not found. Install with: cargo install cargo-tarpaulin".to_string()
@@ -1121,7 +1370,11 @@ impl ProjectDetector {
                     }
                 }
 
-                Ok(cmd)
+                Ok(Self::rust_test_command(
+                    pattern,
+                    self.has_command("cargo-nextest"),
+                    std::env::var_os("CI").is_some(),
+                ))
             }
             ProjectType::Go => {
                 let mut cmd = vec!["go".to_string(), "test".to_string()];
@@ -1131,12 +1384,6 @@ impl ProjectDetector {
                     cmd.push("-coverprofile=coverage.out".to_string());
                 }
 
-                if watch {
-                    return Err(CldevError::Config(
-                        "Watch mode not natively supported in Go. Consider using tools like 'gow' or 'air'.".to_string()
-                    ));
-                }
-
                 if let Some(p) = pattern {
                     cmd.push("-run".to_string());
                     cmd.push(p.to_string());
@@ -1146,6 +1393,15 @@ impl ProjectDetector {
                 Ok(cmd)
             }
             ProjectType::Python => {
+                // tox runs the suite in the environments the project declares
+                if self.root.join("tox.ini").exists() {
+                    let mut cmd = vec!["tox".to_string()];
+                    if let Some(p) = pattern {
+                        cmd.extend(["--".to_string(), "-k".to_string(), p.to_string()]);
+                    }
+                    return Ok(cmd);
+                }
+
                 if !self.has_command("pytest") {
                     return Err(CldevError::Config(
                         "pytest not found. Please install pytest.".to_string(),
@@ -1155,10 +1411,18 @@ impl ProjectDetector {
                 let mut cmd = vec!["pytest".to_string()];
 
                 if coverage {
-                    cmd.push("--cov".to_string());
+                    if self.is_python_src_layout() {
+                        cmd.push("--cov=src".to_string());
+                    } else {
+                        cmd.push("--cov".to_string());
+                    }
                     cmd.push("--cov-report=html".to_string());
                 }
 
+                if self.is_python_src_layout() && self.root.join("tests").is_dir() {
+                    cmd.push("tests".to_string());
+                }
+
                 if watch {
                     cmd.push("--watch".to_string());
                 }
@@ -1189,6 +1453,21 @@ impl ProjectDetector {
 
                 Ok(cmd)
             }
+            ProjectType::Java | ProjectType::Kotlin
+                if self.frameworks.contains(&Framework::Android) =>
+            {
+                // Instrumented tests (with coverage) need a device or emulator;
+                // otherwise run the local unit tests of the debug variant
+                if coverage {
+                    return Ok(vec!["./gradlew".to_string(), "connectedCheck".to_string()]);
+                }
+
+                let mut cmd = vec!["./gradlew".to_string(), "testDebugUnitTest".to_string()];
+                if let Some(p) = pattern {
+                    cmd.push(format!("--tests={}", p));
+                }
+                Ok(cmd)
+            }
             ProjectType::Java => {
                 if self.root.join("pom.xml").exists() {
                     // Maven
@@ -1207,7 +1486,14 @@ impl ProjectDetector {
                 }
             }
             ProjectType::Php => {
-                let mut cmd = vec!["vendor/bin/phpunit".to_string()];
+                // Laravel wraps PHPUnit in `artisan test`; Symfony ships its own bridge
+                let mut cmd = if self.frameworks.contains(&Framework::Laravel) {
+                    vec!["php".to_string(), "artisan".to_string(), "test".to_string()]
+                } else if self.frameworks.contains(&Framework::Symfony) {
+                    vec!["php".to_string(), "bin/phpunit".to_string()]
+                } else {
+                    vec!["vendor/bin/phpunit".to_string()]
+                };
 
                 if coverage {
                     cmd.push("--coverage-html".to_string());
@@ -1298,6 +1584,88 @@ impl ProjectDetector {
         }
     }
 
+    /// Check whether the test runner can watch files itself
+    ///
+    /// `get_test_command` only honors its `watch` flag for these runners;
+    /// `quality test --watch` re-runs the others on file changes.
+    pub fn has_native_watch(&self) -> bool {
+        match self.project_type {
+            ProjectType::NodeJs => self.has_script("test:watch"),
+            ProjectType::Python => true,
+            _ => false,
+        }
+    }
+
+    /// Whether a Python project keeps its packages under `src/`
+    fn is_python_src_layout(&self) -> bool {
+        self.root.join("src").is_dir()
+    }
+
+    /// Paths linters and formatters should check
+    ///
+    /// `src` and `tests` for a src-layout project, otherwise the whole root.
+    fn python_source_targets(&self) -> Vec<String> {
+        if !self.is_python_src_layout() {
+            return vec![".".to_string()];
+        }
+        ["src", "tests"]
+            .into_iter()
+            .filter(|dir| self.root.join(dir).is_dir())
+            .map(String::from)
+            .collect()
+    }
+
+    /// The first installed Python linter, in order of preference
+    fn python_linter(&self) -> Option<&'static str> {
+        ["ruff", "pylint", "flake8"]
+            .into_iter()
+            .find(|linter| self.has_command(linter))
+    }
+
+    /// Lint command running `linter` (ruff, pylint or flake8) on `targets`
+    fn python_lint_command(
+        linter: &str,
+        fix: bool,
+        targets: impl IntoIterator<Item = String>,
+    ) -> Vec<String> {
+        let mut cmd = vec![linter.to_string()];
+        if linter == "ruff" {
+            cmd.push("check".to_string());
+            if fix {
+                cmd.push("--fix".to_string());
+            }
+        }
+        cmd.extend(targets);
+        cmd
+    }
+
+    /// Test command for a Rust project
+    ///
+    /// Uses `cargo nextest run` when `nextest` is installed, filtering by
+    /// `pattern` with a `test(...)` filter expression. Under CI every test is
+    /// run so all failures are reported. Falls back to `cargo test`.
+    fn rust_test_command(pattern: Option<&str>, nextest: bool, ci: bool) -> Vec<String> {
+        if !nextest {
+            let mut cmd = vec!["cargo".to_string(), "test".to_string()];
+            cmd.extend(pattern.map(str::to_string));
+            return cmd;
+        }
+
+        let mut cmd = vec![
+            "cargo".to_string(),
+            "nextest".to_string(),
+            "run".to_string(),
+        ];
+        if ci {
+            cmd.push("--no-fail-fast".to_string());
+        }
+        if let Some(p) = pattern {
+            cmd.push("-E".to_string());
+            cmd.push(format!("test({})", p));
+        }
+        cmd
+    }
+
     /// Check if package.json has a specific script
     fn has_script(&self, script_name: &str) -> bool {
         if self.project_type != ProjectType::NodeJs {
@@ -1395,6 +1763,64 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_detect_subprojects_in_monorepo() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        let api = root.join("services").join("api");
+        let web = root.join("web");
+        fs::create_dir_all(&api).unwrap();
+        fs::create_dir_all(&web).unwrap();
+        fs::create_dir_all(root.join("docs")).unwrap();
+        fs::create_dir_all(root.join("node_modules").join("left-pad")).unwrap();
+        create_test_project(&api, ProjectType::Go);
+        create_test_project(&web, ProjectType::NodeJs);
+        create_test_project(
+            &root.join("node_modules").join("left-pad"),
+            ProjectType::NodeJs,
+        );
+
+        let detector = ProjectDetector::new(Some(root)).unwrap();
+        assert_eq!(detector.project_type(), ProjectType::Unknown);
+
+        let subprojects = detector.detect_subprojects().unwrap();
+        let found: Vec<(&Path, ProjectType)> = subprojects
+            .iter()
+            .map(|sub| (sub.root(), sub.project_type()))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                (api.as_path(), ProjectType::Go),
+                (web.as_path(), ProjectType::NodeJs)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_detect_subprojects_includes_workspace_members() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        fs::write(
+            root.join("Cargo.toml"),
+            "[workspace]\nmembers = [\"crates/*\"]\n",
+        )
+        .unwrap();
+        for name in ["core", "cli"] {
+            let crate_dir = root.join("crates").join(name);
+            fs::create_dir_all(&crate_dir).unwrap();
+            create_test_project(&crate_dir, ProjectType::Rust);
+        }
+
+        let detector = ProjectDetector::new(Some(root)).unwrap();
+        let subprojects = detector.detect_subprojects().unwrap();
+        assert_eq!(subprojects.len(), 2);
+        assert!(subprojects
+            .iter()
+            .all(|sub| sub.project_type() == ProjectType::Rust));
+        assert!(subprojects[0].root().ends_with("crates/cli"));
+    }
+
     #[test]
     fn test_detect_nodejs_project() {
         let temp_dir = TempDir::new().unwrap();
@@ -1544,6 +1970,204 @@ mod tests {
         assert_eq!(detector.project_type(), ProjectType::Kotlin);
     }
 
+    #[test]
+    fn test_kotlin_dsl_java_project_is_java() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(
+            temp_dir.path().join("build.gradle.kts"),
+            "plugins { java }\n",
+        )
+        .unwrap();
+
+        let detector = ProjectDetector::new(Some(temp_dir.path())).unwrap();
+        assert_eq!(detector.project_type(), ProjectType::Java);
+        assert!(!detector.frameworks().contains(&Framework::Android));
+        assert_eq!(
+            detector.get_test_command(None, false, false).unwrap(),
+            vec!["./gradlew", "test"]
+        );
+    }
+
+    #[test]
+    fn test_android_kotlin_project() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        fs::write(
+            root.join("build.gradle.kts"),
+            "plugins {\n    id(\"com.android.application\")\n    kotlin(\"android\")\n}\n",
+        )
+        .unwrap();
+
+        let detector = ProjectDetector::new(Some(root)).unwrap();
+        assert_eq!(detector.project_type(), ProjectType::Kotlin);
+        assert!(detector.frameworks().contains(&Framework::Android));
+        assert_eq!(
+            detector
+                .get_test_command(Some("LoginTest"), false, false)
+                .unwrap(),
+            vec!["./gradlew", "testDebugUnitTest", "--tests=LoginTest"]
+        );
+        assert_eq!(
+            detector.get_test_command(None, true, false).unwrap(),
+            vec!["./gradlew", "connectedCheck"]
+        );
+    }
+
+    #[test]
+    fn test_android_groovy_project_from_manifest() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        fs::write(root.join("build.gradle"), "buildscript {}\n").unwrap();
+        fs::create_dir_all(root.join("app/src/main")).unwrap();
+        fs::write(
+            root.join("app/src/main/AndroidManifest.xml"),
+            "<manifest package=\"com.example.app\" />\n",
+        )
+        .unwrap();
+
+        let detector = ProjectDetector::new(Some(root)).unwrap();
+        assert_eq!(detector.project_type(), ProjectType::Java);
+        assert_eq!(detector.frameworks(), &[Framework::Android]);
+        assert_eq!(
+            detector.get_test_command(None, false, false).unwrap(),
+            vec!["./gradlew", "testDebugUnitTest"]
+        );
+    }
+
+    #[test]
+    fn test_rust_test_command_uses_nextest_when_available() {
+        assert_eq!(
+            ProjectDetector::rust_test_command(Some("parser"), true, false),
+            vec!["cargo", "nextest", "run", "-E", "test(parser)"]
+        );
+        assert_eq!(
+            ProjectDetector::rust_test_command(None, true, true),
+            vec!["cargo", "nextest", "run", "--no-fail-fast"]
+        );
+    }
+
+    #[test]
+    fn test_rust_test_command_falls_back_to_cargo_test() {
+        assert_eq!(
+            ProjectDetector::rust_test_command(Some("parser"), false, true),
+            vec!["cargo", "test", "parser"]
+        );
+
+        let temp_dir = TempDir::new().unwrap();
+        create_test_project(temp_dir.path(), ProjectType::Rust);
+        let detector = ProjectDetector::new(Some(temp_dir.path())).unwrap();
+        let cmd = detector.get_test_command(None, false, false).unwrap();
+        if which::which("cargo-nextest").is_ok() {
+            assert_eq!(cmd[..3], ["cargo", "nextest", "run"]);
+        } else {
+            assert_eq!(cmd, vec!["cargo", "test"]);
+        }
+    }
+
+    #[test]
+    fn test_laravel_project() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(
+            temp_dir.path().join("composer.json"),
+            r#"{"require": {"php": "^8.2", "laravel/framework": "^11.0"}}"#,
+        )
+        .unwrap();
+
+        let detector = ProjectDetector::new(Some(temp_dir.path())).unwrap();
+        assert_eq!(detector.project_type(), ProjectType::Php);
+        assert_eq!(detector.frameworks(), &[Framework::Laravel]);
+        assert_eq!(
+            detector
+                .get_test_command(Some("UserTest"), false, false)
+                .unwrap(),
+            vec!["php", "artisan", "test", "--filter", "UserTest"]
+        );
+    }
+
+    #[test]
+    fn test_symfony_project() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(
+            temp_dir.path().join("composer.json"),
+            r#"{"require": {"symfony/framework-bundle": "7.0.*"}, "require-dev": {"symfony/phpunit-bridge": "7.0.*"}}"#,
+        )
+        .unwrap();
+
+        let detector = ProjectDetector::new(Some(temp_dir.path())).unwrap();
+        assert_eq!(detector.frameworks(), &[Framework::Symfony]);
+        assert_eq!(
+            detector.get_test_command(None, false, false).unwrap(),
+            vec!["php", "bin/phpunit"]
+        );
+    }
+
+    #[test]
+    fn test_plain_php_project_uses_phpunit() {
+        let temp_dir = TempDir::new().unwrap();
+        create_test_project(temp_dir.path(), ProjectType::Php);
+
+        let detector = ProjectDetector::new(Some(temp_dir.path())).unwrap();
+        assert_eq!(detector.frameworks(), &[Framework::Unknown]);
+        assert_eq!(
+            detector.get_test_command(None, false, false).unwrap(),
+            vec!["vendor/bin/phpunit"]
+        );
+    }
+
+    #[test]
+    fn test_python_tox_project_uses_tox() {
+        let temp_dir = TempDir::new().unwrap();
+        create_test_project(temp_dir.path(), ProjectType::Python);
+        fs::write(
+            temp_dir.path().join("tox.ini"),
+            "[tox]\nenv_list = py311, py312\n",
+        )
+        .unwrap();
+
+        let detector = ProjectDetector::new(Some(temp_dir.path())).unwrap();
+        assert_eq!(
+            detector.get_test_command(None, false, false).unwrap(),
+            vec!["tox"]
+        );
+        assert_eq!(
+            detector
+                .get_test_command(Some("parser"), false, false)
+                .unwrap(),
+            vec!["tox", "--", "-k", "parser"]
+        );
+    }
+
+    #[test]
+    fn test_python_src_layout_targets_src_and_tests() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        create_test_project(root, ProjectType::Python);
+        fs::create_dir_all(root.join("src/mypkg")).unwrap();
+        fs::create_dir_all(root.join("tests")).unwrap();
+
+        let detector = ProjectDetector::new(Some(root)).unwrap();
+        assert_eq!(
+            ProjectDetector::python_lint_command("ruff", true, detector.python_source_targets()),
+            vec!["ruff", "check", "--fix", "src", "tests"]
+        );
+        assert_eq!(
+            ProjectDetector::python_lint_command("flake8", false, detector.python_source_targets()),
+            vec!["flake8", "src", "tests"]
+        );
+    }
+
+    #[test]
+    fn test_python_flat_layout_lints_root() {
+        let temp_dir = TempDir::new().unwrap();
+        create_test_project(temp_dir.path(), ProjectType::Python);
+
+        let detector = ProjectDetector::new(Some(temp_dir.path())).unwrap();
+        assert_eq!(
+            ProjectDetector::python_lint_command("ruff", false, detector.python_source_targets()),
+            vec!["ruff", "check", "."]
+        );
+    }
+
     #[test]
     fn test_project_type_names() {
         assert_eq!(ProjectType::NodeJs.name(), "Node.js");
@@ -1793,6 +2417,138 @@ members = ["crates/*"]
         assert_eq!(detector.monorepo_type(), MonorepoType::Cargo);
     }
 
+    #[test]
+    fn test_lint_command_changed_passes_paths() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(
+            temp_dir.path().join("Gemfile"),
+            "source 'https://rubygems.org'",
+        )
+        .unwrap();
+        let detector = ProjectDetector::new(Some(temp_dir.path())).unwrap();
+
+        let changed = vec![
+            PathBuf::from("app/models/user.rb"),
+            PathBuf::from("README.md"),
+            PathBuf::from("lib/tasks/db.rake"),
+        ];
+        assert_eq!(
+            detector.get_lint_command_changed(&changed, true).unwrap(),
+            vec![
+                "bundle",
+                "exec",
+                "rubocop",
+                "-A",
+                "app/models/user.rb",
+                "lib/tasks/db.rake"
+            ]
+        );
+
+        // Only non-source changes lint the whole project
+        assert_eq!(
+            detector
+                .get_lint_command_changed(&[PathBuf::from("README.md")], false)
+                .unwrap(),
+            detector.get_lint_command(false, true).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_lint_command_changed_python_src_layout() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        create_test_project(root, ProjectType::Python);
+        fs::create_dir_all(root.join("src/mypkg")).unwrap();
+        fs::create_dir_all(root.join("tests")).unwrap();
+        let detector = ProjectDetector::new(Some(root)).unwrap();
+
+        // The layout's `src tests` targets are replaced, not appended to
+        let changed = vec![
+            PathBuf::from("src/mypkg/core.py"),
+            PathBuf::from("README.md"),
+        ];
+        assert_eq!(
+            detector
+                .lint_command_for_changes(&changed, true, Some("ruff"))
+                .unwrap(),
+            vec!["ruff", "check", "--fix", "src/mypkg/core.py"]
+        );
+        assert_eq!(
+            detector
+                .lint_command_for_changes(&changed, false, Some("pylint"))
+                .unwrap(),
+            vec!["pylint", "src/mypkg/core.py"]
+        );
+    }
+
+    #[test]
+    fn test_lint_command_changed_eslint() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(
+            temp_dir.path().join("package.json"),
+            r#"{"name": "app", "devDependencies": {"eslint": "^9.0.0"}}"#,
+        )
+        .unwrap();
+        let detector = ProjectDetector::new(Some(temp_dir.path())).unwrap();
+
+        let changed = vec![PathBuf::from("src/index.ts")];
+        assert_eq!(
+            detector.get_lint_command_changed(&changed, false).unwrap(),
+            vec!["npx", "eslint", "src/index.ts"]
+        );
+    }
+
+    #[test]
+    fn test_lint_command_changed_falls_back_for_clippy() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(
+            temp_dir.path().join("Cargo.toml"),
+            "[package]\nname = \"test\"",
+        )
+        .unwrap();
+        let detector = ProjectDetector::new(Some(temp_dir.path())).unwrap();
+
+        assert_eq!(
+            detector
+                .get_lint_command_changed(&[PathBuf::from("src/main.rs")], false)
+                .unwrap(),
+            vec!["cargo", "clippy", "--all-targets", "--all-features"]
+        );
+        assert_eq!(
+            detector.get_lint_command_changed(&[], false).unwrap(),
+            detector.get_lint_command(false, true).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_workspace_members() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(
+            temp_dir.path().join("Cargo.toml"),
+            "[workspace]\nmembers = [\"crates/*\", \"tools/cli\", \"missing\"]\n",
+        )
+        .unwrap();
+        for dir in ["crates/core", "crates/api", "tools/cli"] {
+            fs::create_dir_all(temp_dir.path().join(dir)).unwrap();
+        }
+        fs::write(temp_dir.path().join("crates/README.md"), "").unwrap();
+
+        let detector = ProjectDetector::new(Some(temp_dir.path())).unwrap();
+        assert_eq!(
+            detector.workspace_members(),
+            vec![
+                temp_dir.path().join("crates/api"),
+                temp_dir.path().join("crates/core"),
+                temp_dir.path().join("tools/cli"),
+            ]
+        );
+
+        let single = TempDir::new().unwrap();
+        fs::write(single.path().join("package.json"), r#"{"name": "app"}"#).unwrap();
+        let detector = ProjectDetector::new(Some(single.path())).unwrap();
+        assert!(detector.workspace_members().is_empty());
+    }
+
     #[test]
     fn test_monorepo_detection_none() {
         let temp_dir = TempDir::new().unwrap();
@@ -0,0 +1,180 @@
+//! Retry with exponential backoff for transient failures
+//!
+//! Network-bound operations such as deploys and `git push` occasionally fail
+//! for reasons that go away on their own (timeouts, dropped connections,
+//! 5xx responses). [`with_backoff`] retries those, and only those: user
+//! errors such as a rejected push or a missing tool fail immediately.
+
+use crate::core::error::{CldevError, Result};
+use std::io::ErrorKind;
+use std::thread;
+use std::time::Duration;
+
+/// Error message fragments that indicate a transient network failure
+const TRANSIENT_PATTERNS: &[&str] = &[
+    "timed out",
+    "timeout",
+    "connection reset",
+    "connection refused",
+    "connection closed",
+    "could not resolve host",
+    "temporary failure in name resolution",
+    "network is unreachable",
+    "the remote end hung up unexpectedly",
+    "early eof",
+    "rate limit",
+    "502 bad gateway",
+    "503 service unavailable",
+    "504 gateway timeout",
+];
+
+/// Check whether an error is a transient failure worth retrying
+pub fn is_transient(error: &CldevError) -> bool {
+    match error {
+        CldevError::Io(e) => matches!(
+            e.kind(),
+            ErrorKind::TimedOut
+                | ErrorKind::ConnectionRefused
+                | ErrorKind::ConnectionReset
+                | ErrorKind::ConnectionAborted
+                | ErrorKind::NotConnected
+                | ErrorKind::BrokenPipe
+                | ErrorKind::Interrupted
+        ),
+        CldevError::Command(_) | CldevError::Git(_) | CldevError::CommandFailed { .. } => {
+            let message = error.to_string().to_lowercase();
+            TRANSIENT_PATTERNS.iter().any(|p| message.contains(p))
+        }
+        CldevError::Timeout { .. } => true,
+        _ => false,
+    }
+}
+
+/// Run `f` up to `attempts` times, doubling the delay after each transient failure
+///
+/// Errors that are not [transient](is_transient) are returned immediately,
+/// as is the error from the final attempt. `attempts` below 1 is treated as 1.
+/// Before each retry, `on_retry` is called with the number of the failed
+/// attempt, its error, and the delay before the next one, so the caller can
+/// tell the user.
+pub fn with_backoff<T, F, R>(
+    attempts: u32,
+    base_delay: Duration,
+    mut on_retry: R,
+    mut f: F,
+) -> Result<T>
+where
+    F: FnMut() -> Result<T>,
+    R: FnMut(u32, &CldevError, Duration),
+{
+    let attempts = attempts.max(1);
+    let mut delay = base_delay;
+    let mut attempt = 1;
+
+    loop {
+        match f() {
+            Err(e) if attempt < attempts && is_transient(&e) => {
+                on_retry(attempt, &e, delay);
+                thread::sleep(delay);
+                delay = delay.saturating_mul(2);
+                attempt += 1;
+            }
+            result => return result,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_retries_until_success() {
+        let mut calls = 0;
+        let mut notices = Vec::new();
+        let result = with_backoff(
+            3,
+            Duration::from_millis(1),
+            |attempt, e, delay| notices.push((attempt, e.to_string(), delay)),
+            || {
+                calls += 1;
+                if calls < 3 {
+                    Err(CldevError::command("Connection reset by peer"))
+                } else {
+                    Ok("deployed")
+                }
+            },
+        );
+
+        assert_eq!(result.unwrap(), "deployed");
+        assert_eq!(calls, 3);
+        assert_eq!(notices.len(), 2);
+        assert_eq!(notices[0].0, 1);
+        assert!(notices[0].1.contains("Connection reset"));
+        assert_eq!(notices[1].2, Duration::from_millis(2));
+    }
+
+    #[test]
+    fn test_gives_up_after_attempts() {
+        let mut calls = 0;
+        let result: Result<()> = with_backoff(
+            2,
+            Duration::from_millis(1),
+            |_, _, _| {},
+            || {
+                calls += 1;
+                Err(CldevError::git(
+                    "fatal: unable to access: Operation timed out",
+                ))
+            },
+        );
+
+        assert!(result.is_err());
+        assert_eq!(calls, 2);
+    }
+
+    #[test]
+    fn test_user_errors_are_not_retried() {
+        let mut calls = 0;
+        let result: Result<()> = with_backoff(
+            5,
+            Duration::from_millis(1),
+            |_, _, _| {},
+            || {
+                calls += 1;
+                Err(CldevError::git(
+                    "! [rejected] main -> main (non-fast-forward)",
+                ))
+            },
+        );
+
+        assert!(result.is_err());
+        assert_eq!(calls, 1);
+    }
+
+    #[test]
+    fn test_is_transient() {
+        assert!(is_transient(&CldevError::Io(std::io::Error::from(
+            ErrorKind::TimedOut
+        ))));
+        assert!(!is_transient(&CldevError::Io(std::io::Error::from(
+            ErrorKind::NotFound
+        ))));
+        assert!(is_transient(&CldevError::command(
+            "Vercel deployment failed: 503 Service Unavailable"
+        )));
+        assert!(is_transient(&CldevError::command_failed(
+            "git push: Could not resolve host: github.com",
+            128
+        )));
+        assert!(!is_transient(&CldevError::command_failed(
+            "cargo test",
+            101
+        )));
+        assert!(is_transient(&CldevError::Timeout {
+            command: "vercel --prod".to_string(),
+            secs: 300,
+        }));
+        assert!(!is_transient(&CldevError::validation("timed out")));
+    }
+}
@@ -18,7 +18,7 @@
 
 use std::fs;
 use std::path::{Path, PathBuf};
-use std::process::Command;
+use std::process::{Command, ExitStatus, Output};
 use thiserror::Error;
 
 /// Security-related errors
@@ -34,6 +34,9 @@ pub enum SecurityError {
     #[error("Command not allowed: {command}")]
     CommandNotAllowed { command: String },
 
+    #[error("Unsafe argument {arg:?}: {reason}")]
+    UnsafeArgument { arg: String, reason: String },
+
     #[error("Invalid file permissions: expected {expected}, got {actual}")]
     InvalidPermissions { expected: String, actual: String },
 
@@ -42,6 +45,9 @@ pub enum SecurityError {
 
     #[error("Path canonicalization failed: {0}")]
     CanonicalizationError(String),
+
+    #[error("Symlink {path} points outside its directory to {target}")]
+    SymlinkEscape { path: String, target: String },
 }
 
 /// Result type for security operations
@@ -60,6 +66,13 @@ const ALLOWED_COMMANDS: &[&str] = &[
     "gh", "glab",
 ];
 
+/// Characters a shell would interpret (separators, redirection, quoting,
+/// substitution and globbing)
+const SHELL_METACHARACTERS: &[char] = &[
+    ';', '&', '|', '`', '$', '<', '>', '(', ')', '{', '}', '[', ']', '\'', '"', '\\', '*', '?',
+    '!', '#', '~',
+];
+
 /// Expected file permission mode for configuration files (0o600 = rw-------)
 const CONFIG_FILE_PERMISSION: u32 = 0o600;
 
@@ -214,6 +227,92 @@ impl SecurePath {
         Ok(full_path)
     }
 
+    /// Resolves `candidate` and ensures it stays inside `base` (a root jail)
+    ///
+    /// Relative candidates are resolved against `base`. Both paths are
+    /// canonicalized, so `..` components, symlinks and absolute paths that
+    /// lead outside `base` are rejected. The candidate need not exist yet:
+    /// its deepest existing ancestor is canonicalized and the remaining
+    /// components are appended, which must not contain `..`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SecurityError::InvalidBaseDirectory` if `base` cannot be
+    /// canonicalized, `SecurityError::CanonicalizationError` if an existing
+    /// part of the candidate cannot be resolved (e.g. a dangling symlink),
+    /// and `SecurityError::PathTraversal` if the candidate escapes `base`.
+    pub fn within(base: &Path, candidate: &Path) -> SecurityResult<PathBuf> {
+        let base = base
+            .canonicalize()
+            .map_err(|e| SecurityError::InvalidBaseDirectory {
+                path: format!("{}: {}", base.display(), e),
+            })?;
+        let full_path = base.join(candidate);
+
+        // Walk up to the deepest ancestor that exists (symlinks count, even
+        // dangling ones, so they are resolved rather than written through)
+        let mut existing = full_path.as_path();
+        let mut missing = Vec::new();
+        while fs::symlink_metadata(existing).is_err() {
+            let name = existing
+                .file_name()
+                .ok_or_else(|| SecurityError::PathTraversal {
+                    path: full_path.display().to_string(),
+                })?;
+            missing.push(name);
+            existing = existing
+                .parent()
+                .ok_or_else(|| SecurityError::PathTraversal {
+                    path: full_path.display().to_string(),
+                })?;
+        }
+
+        let mut resolved = existing.canonicalize().map_err(|e| {
+            SecurityError::CanonicalizationError(format!("{}: {}", existing.display(), e))
+        })?;
+        resolved.extend(missing.iter().rev());
+
+        if !resolved.starts_with(&base) {
+            return Err(SecurityError::PathTraversal {
+                path: resolved.display().to_string(),
+            });
+        }
+
+        Ok(resolved)
+    }
+
+    /// Rejects `path` if it is a symlink resolving outside the base directory
+    ///
+    /// Permission checks follow symlinks, so a config or session file linked
+    /// to a world-readable file elsewhere would otherwise pass them. Regular
+    /// files and symlinks that stay inside the base directory are accepted.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SecurityError::SymlinkEscape` if the link points outside the
+    /// base directory, or `SecurityError::CanonicalizationError` if the link
+    /// cannot be resolved.
+    pub fn is_safe_symlink(&self, path: &Path) -> SecurityResult<()> {
+        let is_symlink = fs::symlink_metadata(path)
+            .map(|metadata| metadata.file_type().is_symlink())
+            .unwrap_or(false);
+        if !is_symlink {
+            return Ok(());
+        }
+
+        let target = path.canonicalize().map_err(|e| {
+            SecurityError::CanonicalizationError(format!("{}: {}", path.display(), e))
+        })?;
+        if !target.starts_with(&self.base_dir) {
+            return Err(SecurityError::SymlinkEscape {
+                path: path.display().to_string(),
+                target: target.display().to_string(),
+            });
+        }
+
+        Ok(())
+    }
+
     /// Returns the base directory
     pub fn base_dir(&self) -> &Path {
         &self.base_dir
@@ -265,6 +364,75 @@ pub fn safe_command(command: &str, args: &[&str]) -> SecurityResult<Command> {
     Ok(cmd)
 }
 
+/// Validate a user-provided fragment before using it as a command argument
+///
+/// Rejects empty values, control characters, shell metacharacters and a
+/// leading `-` (which the callee would parse as an option). Use it for
+/// token-like values such as branch names; free text such as titles is safe
+/// to pass through [`exec_checked`], which never involves a shell.
+///
+/// # Example
+///
+/// ```
+/// use cldev::core::security::safe_arg;
+///
+/// assert!(safe_arg("feature/login").is_ok());
+/// assert!(safe_arg("main; rm -rf /").is_err());
+/// ```
+pub fn safe_arg(arg: &str) -> SecurityResult<String> {
+    let reject = |reason: &str| {
+        Err(SecurityError::UnsafeArgument {
+            arg: arg.to_string(),
+            reason: reason.to_string(),
+        })
+    };
+
+    if arg.is_empty() {
+        return reject("argument is empty");
+    }
+    if arg.starts_with('-') {
+        return reject("argument would be parsed as an option");
+    }
+    if arg.chars().any(char::is_control) {
+        return reject("argument contains control characters");
+    }
+    if arg
+        .chars()
+        .any(|c| c.is_whitespace() || SHELL_METACHARACTERS.contains(&c))
+    {
+        return reject("argument contains shell metacharacters");
+    }
+
+    Ok(arg.to_string())
+}
+
+/// Run an allowed command with inherited stdio and wait for it
+///
+/// Arguments are passed to the process directly, never through a shell, so
+/// each one reaches the program as a single argument.
+///
+/// # Errors
+///
+/// Returns `SecurityError::CommandNotAllowed` if the command is not in the
+/// allowlist, or `SecurityError::IoError` if it cannot be started.
+pub fn exec_checked<S: AsRef<str>>(command: &str, args: &[S]) -> SecurityResult<ExitStatus> {
+    let mut cmd = safe_command(command, &[])?;
+    cmd.args(args.iter().map(AsRef::as_ref));
+
+    Ok(cmd.status()?)
+}
+
+/// Run an allowed command and capture its output
+///
+/// Like [`exec_checked`], but stdout and stderr are collected instead of
+/// inherited, so that callers can inspect why the command failed.
+pub fn output_checked<S: AsRef<str>>(command: &str, args: &[S]) -> SecurityResult<Output> {
+    let mut cmd = safe_command(command, &[])?;
+    cmd.args(args.iter().map(AsRef::as_ref));
+
+    Ok(cmd.output()?)
+}
+
 /// Checks if a configuration file has secure permissions
 ///
 /// # Security Features
@@ -409,6 +577,127 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_within_allows_new_paths_inside_jail() {
+        let temp_dir = TempDir::new().unwrap();
+        let base = temp_dir.path();
+
+        let resolved = SecurePath::within(base, Path::new("nested/dir/file.txt")).unwrap();
+        assert_eq!(
+            resolved,
+            base.canonicalize().unwrap().join("nested/dir/file.txt")
+        );
+
+        // `..` that stays inside the jail is fine once resolved
+        fs::create_dir(base.join("a")).unwrap();
+        assert!(SecurePath::within(base, Path::new("a/../b.txt")).is_ok());
+    }
+
+    #[test]
+    fn test_within_rejects_absolute_escape() {
+        let temp_dir = TempDir::new().unwrap();
+        let base = temp_dir.path().join("jail");
+        fs::create_dir(&base).unwrap();
+
+        let outside = temp_dir.path().join("outside.txt");
+        assert!(matches!(
+            SecurePath::within(&base, &outside),
+            Err(SecurityError::PathTraversal { .. })
+        ));
+        assert!(SecurePath::within(&base, Path::new("/etc/passwd")).is_err());
+        assert!(SecurePath::within(&base, Path::new("../outside.txt")).is_err());
+        assert!(SecurePath::within(&base, Path::new("missing/../../outside.txt")).is_err());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_within_rejects_symlink_escape() {
+        let temp_dir = TempDir::new().unwrap();
+        let base = temp_dir.path().join("jail");
+        let outside = temp_dir.path().join("outside");
+        fs::create_dir(&base).unwrap();
+        fs::create_dir(&outside).unwrap();
+
+        // A directory symlink pointing out of the jail
+        std::os::unix::fs::symlink(&outside, base.join("link")).unwrap();
+        assert!(matches!(
+            SecurePath::within(&base, Path::new("link/file.txt")),
+            Err(SecurityError::PathTraversal { .. })
+        ));
+
+        // A dangling file symlink would be written through, so it is rejected too
+        std::os::unix::fs::symlink(outside.join("new.txt"), base.join("dangling")).unwrap();
+        assert!(SecurePath::within(&base, Path::new("dangling")).is_err());
+    }
+
+    #[test]
+    fn test_safe_arg_rejects_injection() {
+        for arg in [
+            "; rm -rf /",
+            "main; rm -rf /",
+            "main && reboot",
+            "$(whoami)",
+            "`id`",
+            "a|b",
+            "line\nbreak",
+            "--upload-pack=evil",
+            "",
+        ] {
+            assert!(
+                matches!(safe_arg(arg), Err(SecurityError::UnsafeArgument { .. })),
+                "{:?} should be rejected",
+                arg
+            );
+        }
+    }
+
+    #[test]
+    fn test_safe_arg_accepts_plain_tokens() {
+        for arg in [
+            "main",
+            "feature/login-form",
+            "release-1.2.0",
+            "user@host",
+            "v2_x",
+        ] {
+            assert_eq!(safe_arg(arg).unwrap(), arg);
+        }
+    }
+
+    #[test]
+    fn test_exec_checked_rejects_unlisted_command() {
+        assert!(matches!(
+            exec_checked("sh", &["-c", "echo hi"]),
+            Err(SecurityError::CommandNotAllowed { .. })
+        ));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_is_safe_symlink() {
+        let temp_dir = TempDir::new().unwrap();
+        let base = temp_dir.path().join("config");
+        fs::create_dir(&base).unwrap();
+        let secure_path = SecurePath::new(base.clone()).unwrap();
+
+        // Regular files and links within the directory are fine
+        File::create(base.join("real.toml")).unwrap();
+        std::os::unix::fs::symlink(base.join("real.toml"), base.join("alias.toml")).unwrap();
+        assert!(secure_path.is_safe_symlink(&base.join("real.toml")).is_ok());
+        assert!(secure_path
+            .is_safe_symlink(&base.join("alias.toml"))
+            .is_ok());
+
+        // A link to a file outside the directory is rejected
+        let outside = temp_dir.path().join("shared.toml");
+        File::create(&outside).unwrap();
+        std::os::unix::fs::symlink(&outside, base.join("config.toml")).unwrap();
+        assert!(matches!(
+            secure_path.is_safe_symlink(&base.join("config.toml")),
+            Err(SecurityError::SymlinkEscape { .. })
+        ));
+    }
+
     #[test]
     fn test_validate_non_existent() {
         let temp_dir = TempDir::new().unwrap();
@@ -3,8 +3,14 @@
 //! Automatically tracks session activity including commands, errors, todos, and file changes.
 //! Used for auto-generating learning records with minimal user input.
 
+use crate::core::error::{CldevError, Result};
 use chrono::{DateTime, Local};
+use fs2::FileExt;
 use serde::{Deserialize, Serialize};
+use std::fs::{self, OpenOptions};
+use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
 
 /// Session context for tracking development activity
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -16,6 +22,42 @@ pub struct SessionContext {
     pub errors_encountered: Vec<ErrorCapture>,
     pub files_modified: Vec<FileModification>,
     pub tool_usage: Vec<ToolUsage>,
+    /// Maximum number of commands kept in `command_history`
+    #[serde(default = "default_max_history")]
+    pub max_history: usize,
+    /// Number of oldest commands evicted to stay within `max_history`
+    #[serde(default)]
+    pub truncated_commands: usize,
+}
+
+/// Default cap on the command history
+pub const DEFAULT_MAX_HISTORY: usize = 500;
+
+fn default_max_history() -> usize {
+    DEFAULT_MAX_HISTORY
+}
+
+/// Attempts to take the session lock before giving up
+const LOCK_ATTEMPTS: u32 = 12;
+
+/// Delay before the first lock retry; doubled on each attempt
+const LOCK_BACKOFF: Duration = Duration::from_millis(5);
+
+/// Upper bound on the delay between lock retries
+const LOCK_BACKOFF_MAX: Duration = Duration::from_millis(50);
+
+/// Sequence number keeping temporary session files of one process apart
+static TMP_SEQUENCE: AtomicUsize = AtomicUsize::new(0);
+
+/// Backoff delay plus up to the same amount of jitter
+///
+/// Jitter keeps writers that collided once from retrying in lockstep.
+fn jittered(delay: Duration) -> Duration {
+    let nanos = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.subsec_nanos() as u128)
+        .unwrap_or(0);
+    delay + Duration::from_nanos((nanos % delay.as_nanos().max(1)) as u64)
 }
 
 /// Command execution record
@@ -83,6 +125,21 @@ pub enum ModificationType {
     Renamed,
 }
 
+/// Summary of a session's command history
+#[derive(Debug, Clone, PartialEq)]
+pub struct SessionStats {
+    /// Number of commands run
+    pub total: usize,
+    /// Number of commands with a non-zero exit code
+    pub failures: usize,
+    /// Fraction of commands that succeeded (0.0 when no commands ran)
+    pub success_rate: f64,
+    /// Average execution time in milliseconds
+    pub avg_ms: u64,
+    /// Up to five slowest commands with their execution time, slowest first
+    pub slowest: Vec<(String, u64)>,
+}
+
 /// Tool usage tracking
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ToolUsage {
@@ -103,13 +160,31 @@ impl SessionContext {
             errors_encountered: Vec::new(),
             files_modified: Vec::new(),
             tool_usage: Vec::new(),
+            max_history: DEFAULT_MAX_HISTORY,
+            truncated_commands: 0,
         }
     }
 
+    /// Set the maximum number of commands kept in the history
+    #[allow(dead_code)]
+    pub fn with_max_history(mut self, max_history: usize) -> Self {
+        self.max_history = max_history;
+        self
+    }
+
     /// Add a command record
+    ///
+    /// The oldest commands are evicted once the history exceeds
+    /// `max_history`; `truncated_commands` counts how many were dropped.
     #[allow(dead_code)]
     pub fn add_command(&mut self, record: CommandRecord) {
         self.command_history.push(record);
+
+        let excess = self.command_history.len().saturating_sub(self.max_history);
+        if excess > 0 {
+            self.command_history.drain(..excess);
+            self.truncated_commands += excess;
+        }
     }
 
     /// Add a todo snapshot
@@ -174,6 +249,224 @@ impl SessionContext {
             .collect::<HashSet<_>>()
             .len()
     }
+
+    /// Write the session to `path` atomically
+    ///
+    /// The JSON is written to a temporary file next to `path` and renamed
+    /// over it, so readers never see a partially written session. The
+    /// temporary file is unique per process and call, so concurrent writers
+    /// never share one.
+    pub fn save_atomic(&self, path: &Path) -> Result<()> {
+        let json = serde_json::to_string_pretty(self)?;
+        let tmp = path.with_extension(format!(
+            "json.tmp.{}.{}",
+            std::process::id(),
+            TMP_SEQUENCE.fetch_add(1, Ordering::Relaxed)
+        ));
+
+        fs::write(&tmp, json)?;
+        fs::rename(&tmp, path).inspect_err(|_| {
+            let _ = fs::remove_file(&tmp);
+        })?;
+
+        Ok(())
+    }
+
+    /// Read-modify-write the session at `path` under an exclusive lock
+    ///
+    /// If the lock stays busy, the update is skipped and `Ok(false)` is
+    /// returned. See [`locked`](Self::locked).
+    pub fn update<F>(path: &Path, apply: F) -> Result<bool>
+    where
+        F: FnOnce(&mut SessionContext),
+    {
+        let updated = Self::locked(path, || {
+            let json = fs::read_to_string(path)?;
+            let mut ctx: SessionContext = serde_json::from_str(&json)?;
+            apply(&mut ctx);
+            ctx.save_atomic(path)
+        })?;
+        Ok(updated.is_some())
+    }
+
+    /// Run `f` while holding the exclusive lock on the session at `path`
+    ///
+    /// Concurrent `cldev` processes serialize on an advisory lock on a
+    /// `.lock` file next to the session, so no write is lost or interleaved.
+    /// If the lock stays busy after a few retries with backoff, `f` is not
+    /// run and `Ok(None)` is returned.
+    pub fn locked<T, F>(path: &Path, f: F) -> Result<Option<T>>
+    where
+        F: FnOnce() -> Result<T>,
+    {
+        let lock_file = OpenOptions::new()
+            .create(true)
+            .truncate(false)
+            .write(true)
+            .open(path.with_extension("lock"))?;
+
+        let mut delay = LOCK_BACKOFF;
+        for attempt in 1..=LOCK_ATTEMPTS {
+            if lock_file.try_lock_exclusive().is_ok() {
+                break;
+            }
+            if attempt == LOCK_ATTEMPTS {
+                return Ok(None);
+            }
+            std::thread::sleep(jittered(delay));
+            delay = (delay * 2).min(LOCK_BACKOFF_MAX);
+        }
+
+        let result = f();
+
+        let _ = FileExt::unlock(&lock_file);
+        result.map(Some)
+    }
+
+    /// Summarize the command history
+    pub fn stats(&self) -> SessionStats {
+        let total = self.command_history.len();
+        let failures = self.failed_commands_count();
+        let total_ms: u64 = self
+            .command_history
+            .iter()
+            .map(|c| c.execution_time_ms)
+            .sum();
+
+        let mut slowest: Vec<(String, u64)> = self
+            .command_history
+            .iter()
+            .map(|c| (c.command.clone(), c.execution_time_ms))
+            .collect();
+        // Stable sort keeps earlier commands first among equal durations
+        slowest.sort_by_key(|(_, ms)| std::cmp::Reverse(*ms));
+        slowest.truncate(5);
+
+        SessionStats {
+            total,
+            failures,
+            success_rate: if total == 0 {
+                0.0
+            } else {
+                (total - failures) as f64 / total as f64
+            },
+            avg_ms: if total == 0 {
+                0
+            } else {
+                total_ms / total as u64
+            },
+            slowest,
+        }
+    }
+
+    /// Render the session as a shareable Markdown report
+    ///
+    /// The report is not sanitized; redact secrets before writing it out.
+    pub fn to_markdown(&self) -> String {
+        let mut md = format!(
+            "# Session Report: {}\n\n\
+             - **Started**: {}\n\
+             - **Duration**: {} minutes\n\
+             - **Commands**: {} ({} failed)\n\
+             - **Errors**: {} ({} unresolved)\n\
+             - **Files modified**: {}\n",
+            self.session_id,
+            self.start_time.format("%Y-%m-%d %H:%M"),
+            self.duration_minutes(),
+            self.command_history.len(),
+            self.failed_commands_count(),
+            self.errors_encountered.len(),
+            self.unresolved_errors_count(),
+            self.unique_files_modified(),
+        );
+
+        md.push_str("\n## Commands\n\n");
+        if self.truncated_commands > 0 {
+            md.push_str(&format!(
+                "_{} older commands were truncated_\n\n",
+                self.truncated_commands
+            ));
+        }
+        if self.command_history.is_empty() {
+            md.push_str("_None_\n");
+        } else {
+            md.push_str("| Time | Command | Exit Code | Duration | Directory |\n");
+            md.push_str("|------|---------|-----------|----------|-----------|\n");
+            for cmd in &self.command_history {
+                md.push_str(&format!(
+                    "| {} | `{}` | {} | {:.2}s | {} |\n",
+                    cmd.timestamp.format("%H:%M:%S"),
+                    escape_cell(&cmd.command),
+                    cmd.exit_code,
+                    cmd.execution_time_ms as f64 / 1000.0,
+                    escape_cell(&cmd.working_dir),
+                ));
+            }
+        }
+
+        md.push_str("\n## Errors\n\n");
+        if self.errors_encountered.is_empty() {
+            md.push_str("_None_\n");
+        }
+        for error in &self.errors_encountered {
+            md.push_str(&format!(
+                "### {} ({})\n\n```\n{}\n```\n\n",
+                error.error_type,
+                if error.resolved {
+                    "resolved"
+                } else {
+                    "unresolved"
+                },
+                error.message.trim_end(),
+            ));
+            if let Some(context) = &error.context {
+                md.push_str(&format!("Context: {}\n\n", context));
+            }
+        }
+
+        md.push_str("\n## File Modifications\n\n");
+        if self.files_modified.is_empty() {
+            md.push_str("_None_\n");
+        } else {
+            md.push_str("| File | Change | Lines |\n");
+            md.push_str("|------|--------|-------|\n");
+            for file in &self.files_modified {
+                md.push_str(&format!(
+                    "| `{}` | {:?} | +{} -{} |\n",
+                    escape_cell(&file.file_path),
+                    file.modification_type,
+                    file.lines_added,
+                    file.lines_deleted,
+                ));
+            }
+        }
+
+        md.push_str("\n## Todos\n\n");
+        if self.todo_history.is_empty() {
+            md.push_str("_None_\n");
+        }
+        for todo in &self.todo_history {
+            let check = if matches!(todo.status, TodoStatus::Completed) {
+                "x"
+            } else {
+                " "
+            };
+            md.push_str(&format!(
+                "- [{}] {} ({:?}, {})\n",
+                check,
+                todo.content,
+                todo.action,
+                todo.timestamp.format("%H:%M"),
+            ));
+        }
+
+        md
+    }
+}
+
+/// Escape a value for use inside a Markdown table cell
+fn escape_cell(text: &str) -> String {
+    text.replace('|', "\\|").replace('\n', " ")
 }
 
 #[cfg(test)]
@@ -226,4 +519,181 @@ mod tests {
         });
         assert_eq!(ctx.unresolved_errors_count(), 1);
     }
+
+    fn record(command: &str, exit_code: i32, execution_time_ms: u64) -> CommandRecord {
+        CommandRecord {
+            command: command.to_string(),
+            exit_code,
+            execution_time_ms,
+            timestamp: Local::now(),
+            working_dir: "/test".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_stats() {
+        let mut ctx = SessionContext::new("test".to_string());
+        for (command, exit_code, ms) in [
+            ("git status", 0, 40),
+            ("cargo build", 0, 9000),
+            ("cargo test", 1, 12000),
+            ("cargo fmt", 0, 300),
+            ("cargo clippy", 1, 9000),
+            ("ls", 0, 10),
+            ("cargo doc", 0, 4000),
+            ("git diff", 0, 50),
+        ] {
+            ctx.add_command(record(command, exit_code, ms));
+        }
+
+        let stats = ctx.stats();
+        assert_eq!(stats.total, 8);
+        assert_eq!(stats.failures, 2);
+        assert!((stats.success_rate - 0.75).abs() < f64::EPSILON);
+        assert_eq!(stats.avg_ms, 34400 / 8);
+        assert_eq!(
+            stats.slowest,
+            vec![
+                ("cargo test".to_string(), 12000),
+                ("cargo build".to_string(), 9000),
+                ("cargo clippy".to_string(), 9000),
+                ("cargo doc".to_string(), 4000),
+                ("cargo fmt".to_string(), 300),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_history_is_capped() {
+        let mut ctx = SessionContext::new("test".to_string());
+        for i in 0..600 {
+            ctx.add_command(record(&format!("cmd {}", i), 0, i));
+        }
+
+        assert_eq!(ctx.command_history.len(), 500);
+        assert_eq!(ctx.truncated_commands, 100);
+        assert_eq!(ctx.command_history[0].command, "cmd 100");
+        assert_eq!(ctx.command_history[499].command, "cmd 599");
+        assert!(ctx
+            .to_markdown()
+            .contains("_100 older commands were truncated_"));
+
+        let mut small = SessionContext::new("test".to_string()).with_max_history(2);
+        for i in 0..3 {
+            small.add_command(record(&format!("cmd {}", i), 0, i));
+        }
+        assert_eq!(small.command_history.len(), 2);
+        assert_eq!(small.truncated_commands, 1);
+    }
+
+    #[test]
+    fn test_sessions_without_cap_fields_load_with_default() {
+        let mut value = serde_json::to_value(SessionContext::new("old".to_string())).unwrap();
+        let fields = value.as_object_mut().unwrap();
+        fields.remove("max_history");
+        fields.remove("truncated_commands");
+
+        let ctx: SessionContext = serde_json::from_value(value).unwrap();
+        assert_eq!(ctx.max_history, DEFAULT_MAX_HISTORY);
+        assert_eq!(ctx.truncated_commands, 0);
+    }
+
+    #[test]
+    fn test_concurrent_updates_keep_every_record() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("current-session.json");
+        SessionContext::new("test".to_string())
+            .save_atomic(&path)
+            .unwrap();
+
+        let handles: Vec<_> = (0..8)
+            .map(|i| {
+                let path = path.clone();
+                std::thread::spawn(move || {
+                    SessionContext::update(&path, |ctx| {
+                        ctx.add_command(record(&format!("cmd {}", i), 0, 10))
+                    })
+                    .unwrap()
+                })
+            })
+            .collect();
+        for handle in handles {
+            assert!(handle.join().unwrap());
+        }
+
+        let ctx: SessionContext =
+            serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+        let mut commands: Vec<_> = ctx.command_history.iter().map(|c| &c.command).collect();
+        commands.sort();
+        let expected: Vec<String> = (0..8).map(|i| format!("cmd {}", i)).collect();
+        assert_eq!(commands, expected.iter().collect::<Vec<_>>());
+
+        // No temporary files are left behind
+        let leftovers = fs::read_dir(temp_dir.path())
+            .unwrap()
+            .flatten()
+            .filter(|e| e.file_name().to_string_lossy().contains(".tmp."))
+            .count();
+        assert_eq!(leftovers, 0);
+    }
+
+    #[test]
+    fn test_unlocked_saves_in_one_process_do_not_collide() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("current-session.json");
+
+        let handles: Vec<_> = (0..8)
+            .map(|i| {
+                let path = path.clone();
+                std::thread::spawn(move || {
+                    for _ in 0..10 {
+                        SessionContext::new(format!("session-{}", i))
+                            .save_atomic(&path)
+                            .unwrap();
+                    }
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        let ctx: SessionContext =
+            serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+        assert!(ctx.session_id.starts_with("session-"));
+    }
+
+    #[test]
+    fn test_stats_empty_session() {
+        let stats = SessionContext::new("test".to_string()).stats();
+        assert_eq!(stats.total, 0);
+        assert_eq!(stats.success_rate, 0.0);
+        assert_eq!(stats.avg_ms, 0);
+        assert!(stats.slowest.is_empty());
+    }
+
+    #[test]
+    fn test_to_markdown_sections() {
+        let mut ctx = SessionContext::new("test".to_string());
+        ctx.add_command(CommandRecord {
+            command: "grep foo | wc -l".to_string(),
+            exit_code: 0,
+            execution_time_ms: 250,
+            timestamp: Local::now(),
+            working_dir: "/test".to_string(),
+        });
+        ctx.add_file_modification(FileModification {
+            file_path: "src/main.rs".to_string(),
+            modification_type: ModificationType::Modified,
+            lines_added: 3,
+            lines_deleted: 1,
+            timestamp: Local::now(),
+        });
+
+        let md = ctx.to_markdown();
+        assert!(md.starts_with("# Session Report: test\n"));
+        assert!(md.contains("| `grep foo \\| wc -l` | 0 | 0.25s | /test |"));
+        assert!(md.contains("| `src/main.rs` | Modified | +3 -1 |"));
+        assert!(md.contains("## Errors\n\n_None_\n"));
+    }
 }
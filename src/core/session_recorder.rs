@@ -1,10 +1,12 @@
 #![allow(dead_code)]
 
+use crate::core::config::home_dir;
+use crate::core::security::SecurePath;
 use crate::core::{CldevError, Result};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 /// Learning session metadata for tracking development patterns
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -131,15 +133,8 @@ impl LearningSession {
     }
 
     /// Get the default sessions directory path
-    fn sessions_dir() -> Result<PathBuf> {
-        // Prioritize HOME env var for testing, fallback to dirs::home_dir()
-        let home = std::env::var("HOME")
-            .ok()
-            .map(PathBuf::from)
-            .or_else(dirs::home_dir)
-            .ok_or_else(|| CldevError::config("Failed to get home directory"))?;
-
-        let sessions_path = home.join(".claude").join("learnings");
+    pub fn sessions_dir() -> Result<PathBuf> {
+        let sessions_path = home_dir()?.join(".claude").join("learnings");
 
         if !sessions_path.exists() {
             fs::create_dir_all(&sessions_path)?;
@@ -148,6 +143,13 @@ impl LearningSession {
         Ok(sessions_path)
     }
 
+    /// Get the learning records directory path (`~/.claude/learning-records`)
+    ///
+    /// Unlike [`sessions_dir`](Self::sessions_dir), the directory is not created.
+    pub fn records_dir() -> Result<PathBuf> {
+        Ok(home_dir()?.join(".claude").join("learning-records"))
+    }
+
     /// Convert session to Markdown format with YAML frontmatter
     fn to_markdown(&self) -> String {
         // YAML Frontmatter (minimal metadata for machine readability)
@@ -206,7 +208,20 @@ impl LearningSession {
 
     /// Save session to disk in Markdown format
     pub fn save(&self) -> Result<PathBuf> {
-        let sessions_dir = Self::sessions_dir()?;
+        self.save_to(&Self::sessions_dir()?)
+    }
+
+    /// Save session in Markdown format under a specific sessions directory
+    pub fn save_to(&self, sessions_dir: &Path) -> Result<PathBuf> {
+        self.save_to_with_suffix(sessions_dir, "")
+    }
+
+    /// Save session under `sessions_dir` with `suffix` appended to the file stem
+    ///
+    /// Lets a derived record (e.g. an auto-capture) sit next to the session
+    /// it was created alongside instead of overwriting it.
+    pub fn save_to_with_suffix(&self, sessions_dir: &Path, suffix: &str) -> Result<PathBuf> {
+        fs::create_dir_all(sessions_dir)?;
 
         // New naming convention: YYYY-MM-DD-{type}-{slug}.md
         let date = chrono::Local::now().format("%Y-%m-%d");
@@ -221,8 +236,11 @@ impl LearningSession {
             .trim_matches('-')
             .to_string();
 
-        let filename = format!("{}-{}-{}.md", date, self.session_type, slug);
-        let filepath = sessions_dir.join(filename);
+        let filename = format!("{}-{}-{}{}.md", date, self.session_type, slug, suffix);
+
+        // The session type is user input; keep the file inside the sessions dir
+        let filepath = SecurePath::within(sessions_dir, Path::new(&filename))
+            .map_err(|e| CldevError::security(format!("Invalid session path: {}", e)))?;
 
         // Save as Markdown
         let markdown = self.to_markdown();
@@ -658,6 +676,13 @@ impl LearningSessionBuilder {
         let path = session.save()?;
         Ok((session, path))
     }
+
+    /// Build and save the session under a specific sessions directory
+    pub fn save_to(self, sessions_dir: &Path) -> Result<(LearningSession, PathBuf)> {
+        let session = self.session;
+        let path = session.save_to(sessions_dir)?;
+        Ok((session, path))
+    }
 }
 
 #[cfg(test)]
@@ -12,6 +12,9 @@
 //! - `data-science`: Python/R, Jupyter, ML/Data analysis
 //! - `rust-cli`: Rust CLI development with modern tooling
 //!
+//! Any other kebab-case name (e.g. `my-stack`) is a custom stack loaded from
+//! `stacks/my-stack.toml`.
+//!
 //! # Configuration Hierarchy
 //!
 //! Stack configs sit between global and project configs:
@@ -19,15 +22,16 @@
 
 #![allow(dead_code)]
 
+use crate::core::config::home_dir;
 use crate::core::error::{CldevError, Result};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 /// Technical stack identifier
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(rename_all = "kebab-case")]
+#[serde(try_from = "String", into = "String")]
 #[allow(dead_code)]
 pub enum TechStack {
     /// Frontend web development (React, Vue, Angular, TypeScript)
@@ -40,21 +44,27 @@ pub enum TechStack {
     DataScience,
     /// Rust CLI development
     RustCli,
+    /// User-defined stack loaded from `stacks/{name}.toml`
+    Custom(String),
 }
 
 impl TechStack {
     /// Get the stack name as a string
-    pub fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             TechStack::FrontendWeb => "frontend-web",
             TechStack::BackendApi => "backend-api",
             TechStack::MobileApp => "mobile-app",
             TechStack::DataScience => "data-science",
             TechStack::RustCli => "rust-cli",
+            TechStack::Custom(name) => name,
         }
     }
 
     /// Parse stack name from string
+    ///
+    /// Names other than the built-in stacks become [`TechStack::Custom`], provided
+    /// they are kebab-case (lowercase letters, digits, and single hyphens).
     pub fn parse(s: &str) -> Result<Self> {
         match s {
             "frontend-web" => Ok(TechStack::FrontendWeb),
@@ -62,14 +72,31 @@ impl TechStack {
             "mobile-app" => Ok(TechStack::MobileApp),
             "data-science" => Ok(TechStack::DataScience),
             "rust-cli" => Ok(TechStack::RustCli),
+            _ if Self::is_kebab_case(s) => Ok(TechStack::Custom(s.to_string())),
             _ => Err(CldevError::validation(format!(
-                "Unknown tech stack: {}. Valid options: frontend-web, backend-api, mobile-app, data-science, rust-cli",
+                "Invalid tech stack name: {}. Use a built-in stack (frontend-web, backend-api, mobile-app, data-science, rust-cli) or a kebab-case custom name",
                 s
             ))),
         }
     }
 
-    /// Get all available tech stacks
+    /// Check whether a name is kebab-case (e.g. `my-stack`)
+    fn is_kebab_case(s: &str) -> bool {
+        !s.is_empty()
+            && s.split('-').all(|part| {
+                !part.is_empty()
+                    && part
+                        .chars()
+                        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit())
+            })
+    }
+
+    /// Check whether this is a user-defined stack
+    pub fn is_custom(&self) -> bool {
+        matches!(self, TechStack::Custom(_))
+    }
+
+    /// Get all built-in tech stacks
     pub fn all() -> Vec<Self> {
         vec![
             TechStack::FrontendWeb,
@@ -81,6 +108,20 @@ impl TechStack {
     }
 }
 
+impl TryFrom<String> for TechStack {
+    type Error = CldevError;
+
+    fn try_from(s: String) -> Result<Self> {
+        Self::parse(&s)
+    }
+}
+
+impl From<TechStack> for String {
+    fn from(stack: TechStack) -> Self {
+        stack.as_str().to_string()
+    }
+}
+
 /// Stack-specific configuration
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 #[allow(dead_code)]
@@ -270,9 +311,7 @@ impl StackConfig {
         if let Some(config_dir) = dirs::config_dir() {
             Ok(config_dir.join("cldev").join("stacks"))
         } else {
-            dirs::home_dir()
-                .map(|home| home.join(".cldev").join("stacks"))
-                .ok_or_else(|| CldevError::config("Could not determine home directory"))
+            Ok(home_dir()?.join(".cldev").join("stacks"))
         }
     }
 
@@ -299,15 +338,20 @@ impl StackConfig {
     ///
     /// Returns the loaded StackConfig, or default if file doesn't exist
     pub fn load(stack: &TechStack) -> Result<Self> {
-        let stack_path = Self::stack_path(stack)?;
+        Self::load_from(&Self::stack_path(stack)?, stack)
+    }
 
+    /// Load stack configuration from a specific file
+    ///
+    /// Returns the stack's defaults if the file doesn't exist
+    pub fn load_from(stack_path: &Path, stack: &TechStack) -> Result<Self> {
         // Return default if file doesn't exist
         if !stack_path.exists() {
             return Ok(Self::default_for_stack(stack));
         }
 
         // Read and parse TOML
-        let content = fs::read_to_string(&stack_path).map_err(|e| {
+        let content = fs::read_to_string(stack_path).map_err(|e| {
             CldevError::config(format!(
                 "Failed to read stack config {}: {}",
                 stack_path.display(),
@@ -363,7 +407,8 @@ impl StackConfig {
 
     /// Get default configuration for a specific tech stack
     ///
-    /// This provides sensible defaults when no stack config file exists
+    /// This provides sensible defaults when no stack config file exists.
+    /// Custom stacks have no built-in defaults.
     pub fn default_for_stack(stack: &TechStack) -> Self {
         match stack {
             TechStack::FrontendWeb => Self::default_frontend_web(),
@@ -371,6 +416,7 @@ impl StackConfig {
             TechStack::MobileApp => Self::default_mobile_app(),
             TechStack::DataScience => Self::default_data_science(),
             TechStack::RustCli => Self::default_rust_cli(),
+            TechStack::Custom(_) => Self::default(),
         }
     }
 
@@ -644,7 +690,46 @@ mod tests {
             TechStack::parse("backend-api").unwrap(),
             TechStack::BackendApi
         ));
-        assert!(TechStack::parse("invalid").is_err());
+        assert!(TechStack::parse("Invalid Stack").is_err());
+        assert!(TechStack::parse("../etc").is_err());
+        assert!(TechStack::parse("my--stack").is_err());
+        assert!(TechStack::parse("").is_err());
+    }
+
+    #[test]
+    fn test_custom_stack_roundtrip() {
+        let stack = TechStack::parse("my-stack").unwrap();
+        assert_eq!(stack, TechStack::Custom("my-stack".to_string()));
+        assert!(stack.is_custom());
+        assert_eq!(stack.as_str(), "my-stack");
+        assert_eq!(TechStack::parse(stack.as_str()).unwrap(), stack);
+
+        let json = serde_json::to_string(&stack).unwrap();
+        assert_eq!(json, "\"my-stack\"");
+        assert_eq!(serde_json::from_str::<TechStack>(&json).unwrap(), stack);
+        assert_eq!(
+            serde_json::to_string(&TechStack::RustCli).unwrap(),
+            "\"rust-cli\""
+        );
+    }
+
+    #[test]
+    fn test_load_custom_stack_file() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let stack = TechStack::parse("my-stack").unwrap();
+        let stack_path = temp_dir.path().join(format!("{}.toml", stack.as_str()));
+        fs::write(
+            &stack_path,
+            "[stack]\nname = \"my-stack\"\n\n[commands]\nbuild = \"make\"\n",
+        )
+        .unwrap();
+
+        let config = StackConfig::load_from(&stack_path, &stack).unwrap();
+        assert_eq!(config.stack.name, "my-stack");
+        assert_eq!(config.commands.get("build"), Some(&"make".to_string()));
+
+        let missing = StackConfig::load_from(&temp_dir.path().join("other.toml"), &stack).unwrap();
+        assert!(missing.commands.is_empty());
     }
 
     #[test]
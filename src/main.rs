@@ -6,16 +6,28 @@ use crate::core::error::Result;
 use clap::Parser;
 use cli::args::{Cli, Commands, ConfigCommands};
 use cli::output::OutputHandler;
+use cli::pager::Pager;
 
 fn main() {
-    if let Err(e) = run() {
-        let output = OutputHandler::default();
+    let cli = parse_cli();
+
+    // Initialize output handler with global flags and language, so that
+    // errors are reported in the requested format as well
+    let lang = cli.lang.clone().unwrap_or(cli::args::Language::Auto);
+    let mut output = OutputHandler::with_level(cli.output_level(), cli.no_color, lang.to_i18n());
+    output.set_format(cli.output_format);
+    output.set_verbosity(cli.verbosity);
+    output.set_dry_run(cli.dry_run);
+    output.set_quiet_on_success(cli.quiet_on_success);
+
+    if let Err(e) = run(&cli, &mut output) {
         output.error(&format!("Error: {}", e));
-        std::process::exit(1);
+        std::process::exit(e.exit_code());
     }
 }
 
-fn run() -> Result<()> {
+/// Parse the command line, exiting for help and oversized arguments
+fn parse_cli() -> Cli {
     // Pre-parse language flag before clap parsing for i18n help
     let lang = cli::args::extract_language_from_args();
     cli::help::init_help_i18n(lang);
@@ -43,11 +55,34 @@ fn run() -> Result<()> {
         }
     }
 
-    let cli = Cli::parse();
+    Cli::parse()
+}
+
+fn run(cli: &Cli, output: &mut OutputHandler) -> Result<()> {
+    if let Some(config_path) = &cli.config {
+        core::config::Config::set_path_override(config_path)?;
+    }
+    if let Some(project_root) = &cli.project_root {
+        core::config::Config::set_project_root_override(project_root)?;
+    }
 
-    // Initialize output handler with global flags and language
-    let mut output =
-        OutputHandler::with_language(cli.verbose, cli.quiet, cli.no_color, cli.lang.to_i18n());
+    // Load the configuration once for the settings that apply to every command
+    let config = core::config::Config::load(None).unwrap_or_else(|e| {
+        output.warning(&output.t_format("config-load-failed-defaults", "error", &e.to_string()));
+        std::sync::Arc::new(core::config::Config::default())
+    });
+    output.set_progress_bar(config.ui.progress_bar);
+    core::exec::set_timeout(config.performance.timeout());
+
+    // Without --lang, the configured language applies
+    if cli.lang.is_none() {
+        let language = cli::args::Language::parse(&config.general.language)
+            .unwrap_or(cli::args::Language::Auto)
+            .to_i18n();
+        if &language != output.i18n().language() {
+            output.set_language(language);
+        }
+    }
 
     // Track command execution in active session (if any)
     let command_name = format!("{:?}", cli.command);
@@ -55,24 +90,33 @@ fn run() -> Result<()> {
 
     // Route to appropriate command handler
     let result = match &cli.command {
-        Commands::Config(cmd) => handle_config_command(cmd, &mut output),
-        Commands::Dev(cmd) => handle_dev_command(cmd, &output),
-        Commands::Git(cmd) => handle_git_command(cmd, &output),
-        Commands::Quality(cmd) => handle_quality_command(cmd, &output),
-        Commands::Tech(cmd) => handle_tech_command(cmd, &output),
-        Commands::Ops(cmd) => handle_ops_command(cmd, &output),
-        Commands::Analysis(cmd) => handle_analysis_command(cmd, &output),
-        Commands::Lr(cmd) => handle_lr_command(cmd, &output),
-        Commands::Todo(cmd) => handle_todo_command(cmd, &output),
-        Commands::Session(cmd) => handle_session_command(cmd, &output),
+        Commands::Config(cmd) => handle_config_command(cmd, output),
+        Commands::Dev(cmd) => handle_dev_command(cmd, output),
+        Commands::Git(cmd) => handle_git_command(cmd, output),
+        Commands::Quality(cmd) => handle_quality_command(cmd, output),
+        Commands::Tech(cmd) => handle_tech_command(cmd, output),
+        Commands::Ops(cmd) => handle_ops_command(cmd, output),
+        Commands::Analysis(cmd) => handle_analysis_command(cmd, output),
+        Commands::Lr(cmd) => handle_lr_command(cmd, &Pager::new(cli.pager_mode()), output),
+        Commands::Todo(cmd) => handle_todo_command(cmd, output),
+        Commands::Session(cmd) => handle_session_command(cmd, output),
+        Commands::Doctor => commands::doctor::run_doctor(output),
         Commands::Completions { shell, install } => {
-            handle_completions_command(*shell, *install, &output)
+            handle_completions_command(*shell, *install, output)
         }
     };
 
     // Update session context if active
     let execution_time = start_time.elapsed();
     update_session_context(&command_name, &result, execution_time);
+    notify_completion(
+        cli.notify || config.general.notify_on_complete,
+        &command_name,
+        &result,
+        execution_time,
+        output,
+    );
+    output.finish_quiet_on_success(result.is_ok());
 
     result
 }
@@ -88,6 +132,11 @@ fn handle_config_command(cmd: &ConfigCommands, output: &mut OutputHandler) -> Re
             output.debug(&format!("Editing config target: {:?}", target));
             commands::config::edit_config(None, None, output)
         }
+        ConfigCommands::Get { key } => commands::config::handle_config_get(key, None, output),
+        ConfigCommands::Set { key, value } => {
+            commands::config::handle_config_set(key, value, None, output)
+        }
+        ConfigCommands::Diff => commands::config::handle_config_diff(None, output),
         ConfigCommands::List { detailed, filter } => {
             let filter_str = filter.as_ref().map(|f| format!("{:?}", f));
             commands::config::list_commands(filter_str, *detailed, output)
@@ -104,6 +153,12 @@ fn handle_config_command(cmd: &ConfigCommands, output: &mut OutputHandler) -> Re
             *retention_days,
             output,
         ),
+        ConfigCommands::Restore { from } => {
+            commands::config::handle_config_restore(from.as_deref(), output)
+        }
+        ConfigCommands::Migrate { backup } => {
+            commands::config::handle_config_migrate(None, *backup, output)
+        }
         ConfigCommands::UpdateDocs { doc_type, validate } => {
             commands::config::handle_update_docs(doc_type.as_ref(), *validate, output)
         }
@@ -187,7 +242,25 @@ fn handle_git_command(cmd: &cli::args::GitCommands, output: &OutputHandler) -> R
             message,
             no_verify,
             amend,
-        } => commands::git::create_commit(message.clone(), *no_verify, *amend, output),
+            skip_checks,
+            emoji,
+            no_emoji,
+        } => {
+            // None falls back to [ui].emoji
+            let emoji = match (*emoji, *no_emoji) {
+                (true, _) => Some(true),
+                (_, true) => Some(false),
+                _ => None,
+            };
+            commands::git::create_commit(
+                message.clone(),
+                *no_verify,
+                *amend,
+                *skip_checks,
+                emoji,
+                output,
+            )
+        }
         GitCommands::Branch { name, branch_type } => {
             commands::git::create_branch(name.clone(), *branch_type, output)
         }
@@ -197,34 +270,59 @@ fn handle_git_command(cmd: &cli::args::GitCommands, output: &OutputHandler) -> R
             detailed,
         } => commands::git::create_merge_request(target, title.clone(), *detailed, output),
         GitCommands::Status { detailed } => commands::git::show_status(*detailed, output),
+        GitCommands::Sync { autostash } => commands::git::sync_with_base(*autostash, output),
+        GitCommands::InstallHook {
+            kind,
+            command,
+            force,
+        } => commands::git::install_hook(*kind, command.as_deref(), *force, output),
     }
 }
 
-fn handle_quality_command(cmd: &cli::args::QualityCommands, output: &OutputHandler) -> Result<()> {
+fn handle_quality_command(
+    cmd: &cli::args::QualityCommands,
+    output: &mut OutputHandler,
+) -> Result<()> {
     use cli::args::QualityCommands;
 
     match cmd {
-        QualityCommands::Lint { fix, paths } => {
-            output.debug(&format!("Lint command - fix: {}, paths: {:?}", fix, paths));
-            commands::quality::run_lint(paths, *fix, output)
+        QualityCommands::Lint {
+            fix,
+            all,
+            json,
+            paths,
+        } => {
+            output.set_stderr_only(*json);
+            output.debug(&format!(
+                "Lint command - fix: {}, all: {}, paths: {:?}",
+                fix, all, paths
+            ));
+            commands::quality::run_lint(paths, *fix, *all, *json, output)
         }
-        QualityCommands::Format { check, paths } => {
+        QualityCommands::Format { check, json, paths } => {
+            output.set_stderr_only(*json);
             output.debug(&format!(
                 "Format command - check: {}, paths: {:?}",
                 check, paths
             ));
-            commands::quality::format_code(paths, *check, output)
+            commands::quality::format_code(paths, *check, *json, output)
         }
         QualityCommands::Test {
             pattern,
             coverage,
             watch,
+            json,
         } => {
+            output.set_stderr_only(*json);
             output.debug(&format!(
                 "Test command - pattern: {:?}, coverage: {}, watch: {}",
                 pattern, coverage, watch
             ));
-            commands::quality::run_tests(pattern.as_deref(), *coverage, *watch, output)
+            commands::quality::run_tests(pattern.as_deref(), *coverage, *watch, *json, output)
+        }
+        QualityCommands::FixAll { skip_tests } => {
+            output.debug(&format!("Fix-all command - skip_tests: {}", skip_tests));
+            commands::quality::fix_all(*skip_tests, output)
         }
     }
 }
@@ -233,14 +331,23 @@ fn handle_tech_command(cmd: &cli::args::TechCommands, output: &OutputHandler) ->
     use cli::args::TechCommands;
 
     match cmd {
-        TechCommands::Start { stack, port, env } => {
+        TechCommands::Start {
+            stack,
+            port,
+            env,
+            health_url,
+        } => {
             output.debug(&format!("Starting tech stack: {:?}", stack));
-            commands::tech::handle_start(*stack, *port, *env)
+            commands::tech::handle_start(*stack, *port, *env, health_url.as_deref())
+        }
+        TechCommands::List { detailed } => {
+            output.debug("Listing tech stacks");
+            commands::tech::handle_list(*detailed)
         }
     }
 }
 
-fn handle_ops_command(cmd: &cli::args::OpsCommands, output: &OutputHandler) -> Result<()> {
+fn handle_ops_command(cmd: &cli::args::OpsCommands, output: &mut OutputHandler) -> Result<()> {
     use cli::args::OpsCommands;
 
     match cmd {
@@ -250,27 +357,50 @@ fn handle_ops_command(cmd: &cli::args::OpsCommands, output: &OutputHandler) -> R
             clean,
         } => {
             output.debug(&format!("Building for environment: {:?}", env));
-            commands::ops::handle_build(*env, *analyze, *clean)
+            commands::ops::handle_build(*env, *analyze, *clean, output)
         }
         OpsCommands::Deploy { env, yes, dry_run } => {
+            if *dry_run {
+                output.set_dry_run(true);
+            }
             output.debug(&format!("Deploying to environment: {:?}", env));
-            commands::ops::handle_deploy(*env, *yes, *dry_run)
+            commands::ops::handle_deploy(*env, *yes, output)
         }
+        OpsCommands::Status => commands::ops::handle_status(),
     }
 }
 
 fn handle_analysis_command(
     cmd: &cli::args::AnalysisCommands,
-    output: &OutputHandler,
+    output: &mut OutputHandler,
 ) -> Result<()> {
-    use cli::args::AnalysisCommands;
+    use cli::args::{AnalysisCommands, AnalysisFormat};
 
     match cmd {
         AnalysisCommands::Analyze {
             target,
             format,
             detailed,
-        } => commands::analyze_project(*target, *format, *detailed, output),
+            dup_window,
+            langs,
+            no_gitignore,
+            since_tag,
+        } => {
+            // Keep stdout for the report itself when it is meant to be saved
+            output.set_stderr_only(!matches!(format, AnalysisFormat::Text));
+            commands::analyze_project(
+                *target,
+                *format,
+                *detailed,
+                *dup_window,
+                &commands::ScanScope {
+                    langs: langs.clone(),
+                    use_gitignore: !*no_gitignore,
+                    since_tag: *since_tag,
+                },
+                output,
+            )
+        }
         AnalysisCommands::Explain {
             target,
             examples,
@@ -288,17 +418,25 @@ fn handle_analysis_command(
             *performance_focus,
             output,
         ),
-        AnalysisCommands::Serena { mode, targets } => commands::run_serena(*mode, targets, output),
+        AnalysisCommands::Serena {
+            mode,
+            targets,
+            plan,
+        } => commands::run_serena(*mode, targets, *plan, output),
     }
 }
 
-fn handle_lr_command(cmd: &cli::args::LrCommands, output: &OutputHandler) -> Result<()> {
+fn handle_lr_command(
+    cmd: &cli::args::LrCommands,
+    pager: &Pager,
+    output: &OutputHandler,
+) -> Result<()> {
     use cli::args::LrCommands;
 
     match cmd {
         LrCommands::New { topic, edit } => {
             output.debug(&format!("Creating new learning record: {}", topic));
-            commands::lr::handle_new(topic.clone(), *edit)
+            commands::lr::handle_new(topic.clone(), *edit, output.is_dry_run())
         }
         LrCommands::Find {
             query,
@@ -306,7 +444,7 @@ fn handle_lr_command(cmd: &cli::args::LrCommands, output: &OutputHandler) -> Res
             limit,
         } => {
             output.debug(&format!("Searching learning records: {}", query));
-            commands::lr::handle_find(query.clone(), *field, *limit)
+            commands::lr::handle_find(query.clone(), *field, *limit, pager, output)
         }
         LrCommands::Stats { period, detailed } => {
             output.debug(&format!("Generating learning statistics: {:?}", period));
@@ -314,7 +452,7 @@ fn handle_lr_command(cmd: &cli::args::LrCommands, output: &OutputHandler) -> Res
         }
         LrCommands::Problems { priority, recent } => {
             output.debug("Analyzing problem patterns");
-            commands::lr::handle_problems(*priority, *recent)
+            commands::lr::handle_problems(*priority, *recent, pager)
         }
         LrCommands::CheckFile { file_path } => {
             output.debug(&format!("Checking file hotspot: {}", file_path));
@@ -332,6 +470,36 @@ fn handle_lr_command(cmd: &cli::args::LrCommands, output: &OutputHandler) -> Res
             output.debug(&format!("Finding similar sessions to: {}", session_id));
             commands::lr::handle_similar(session_id, *limit)
         }
+        LrCommands::Lint => {
+            output.debug("Linting learning records");
+            commands::lr::handle_lint()
+        }
+        LrCommands::Link { id, limit } => {
+            output.debug(&format!("Linking related records for: {}", id));
+            commands::lr::handle_link(id, *limit, output)
+        }
+        LrCommands::Export {
+            format,
+            output: path,
+        } => {
+            output.debug(&format!("Exporting learning records as {:?}", format));
+            commands::lr::handle_export(*format, path.clone())
+        }
+        LrCommands::Import { path } => {
+            output.debug(&format!(
+                "Importing learning records from: {}",
+                path.display()
+            ));
+            commands::lr::handle_import(path, output.is_dry_run())
+        }
+        LrCommands::Migrate => {
+            output.debug("Migrating learning records to V3");
+            commands::lr::handle_migrate(output.is_dry_run())
+        }
+        LrCommands::Hotspots { format, limit } => {
+            output.debug(&format!("Listing file hotspots as {:?}", format));
+            commands::lr::handle_hotspots(*format, *limit)
+        }
     }
 }
 
@@ -343,9 +511,13 @@ fn handle_todo_command(cmd: &cli::args::TodoCommands, output: &OutputHandler) ->
     };
 
     match cmd {
-        TodoCommands::Add { description } => {
+        TodoCommands::Add {
+            description,
+            priority,
+            due,
+        } => {
             output.debug("Adding todo");
-            add_todo_impl(description.clone())
+            add_todo_impl(description.clone(), priority.map(Into::into), due.clone())
         }
         TodoCommands::List => {
             output.debug("Listing todos");
@@ -363,6 +535,10 @@ fn handle_todo_command(cmd: &cli::args::TodoCommands, output: &OutputHandler) ->
             output.debug("Syncing todos with git");
             sync_todos()
         }
+        TodoCommands::Import => {
+            output.debug("Importing todos from source comments");
+            commands::todo::import::handle_import()
+        }
         TodoCommands::Interactive => {
             output.debug("Starting interactive mode");
             interactive_mode()
@@ -380,21 +556,64 @@ fn handle_completions_command(
     install: bool,
     output: &OutputHandler,
 ) -> Result<()> {
-    use cli::{generate_completions, print_installation_instructions};
+    use cli::{generate_completions, install_completions};
 
     output.debug(&format!("Generating completions for: {:?}", shell));
 
-    // Generate completions to stdout
-    generate_completions(shell);
+    if !install {
+        // Generate completions to stdout
+        return generate_completions(shell);
+    }
 
-    // Print installation instructions if requested
-    if install {
-        print_installation_instructions(shell, output);
+    let path = install_completions(shell)?;
+    let path_str = path.display().to_string();
+    output.success(&output.t_format("completions-installed", "path", &path_str));
+    match shell {
+        cli::args::Shell::Zsh => {
+            let dir = path
+                .parent()
+                .map(|p| p.display().to_string())
+                .unwrap_or_default();
+            output.info(&output.t_format("completions-hint-zsh", "dir", &dir))
+        }
+        cli::args::Shell::PowerShell => {
+            output.info(&output.t_format("completions-hint-powershell", "path", &path_str))
+        }
+        cli::args::Shell::Elvish => output.info(&output.t("completions-hint-elvish")),
+        cli::args::Shell::Nushell => {
+            output.info(&output.t_format("completions-hint-nushell", "path", &path_str))
+        }
+        cli::args::Shell::Bash | cli::args::Shell::Fish => {}
     }
 
     Ok(())
 }
 
+/// Send a desktop notification for the finished command if `enabled`
+fn notify_completion(
+    enabled: bool,
+    command_name: &str,
+    result: &Result<()>,
+    execution_time: std::time::Duration,
+    output: &OutputHandler,
+) {
+    use crate::core::notification::{
+        command_label, notify_command_complete, should_notify, DesktopNotifier,
+    };
+
+    if !should_notify(enabled) {
+        return;
+    }
+
+    let _ = notify_command_complete(
+        &DesktopNotifier,
+        output.i18n(),
+        &command_label(command_name),
+        result.is_ok(),
+        execution_time,
+    );
+}
+
 /// Update session context if an active session exists
 fn update_session_context(
     command_name: &str,
@@ -405,52 +624,40 @@ fn update_session_context(
     use std::path::PathBuf;
 
     // Get session path
-    let session_path = dirs::home_dir()
-        .unwrap_or_else(|| PathBuf::from("."))
-        .join(".cldev")
-        .join("current-session.json");
+    let session_path = cli::session::get_session_path();
 
     // Skip if no active session
     if !session_path.exists() {
         return;
     }
 
-    // Load session context
-    let Ok(json) = std::fs::read_to_string(&session_path) else {
-        return;
-    };
-    let Ok(mut ctx) = serde_json::from_str::<SessionContext>(&json) else {
-        return;
-    };
-
     // Add command record
-    let exit_code = if result.is_ok() { 0 } else { 1 };
+    let exit_code = result.as_ref().err().map_or(0, |e| e.exit_code());
     let working_dir = std::env::current_dir()
         .unwrap_or_else(|_| PathBuf::from("."))
         .display()
         .to_string();
 
-    ctx.add_command(CommandRecord {
-        command: command_name.to_string(),
-        exit_code,
-        execution_time_ms: execution_time.as_millis() as u64,
-        timestamp: chrono::Local::now(),
-        working_dir,
-    });
-
-    // Add error if command failed
-    if let Err(e) = result {
-        ctx.add_error(ErrorCapture {
+    // Locked read-modify-write so concurrent cldev processes don't drop
+    // each other's records; failures never affect the command itself
+    let _ = SessionContext::update(&session_path, |ctx| {
+        ctx.add_command(CommandRecord {
+            command: command_name.to_string(),
+            exit_code,
+            execution_time_ms: execution_time.as_millis() as u64,
             timestamp: chrono::Local::now(),
-            error_type: "CommandError".to_string(),
-            message: format!("{}", e),
-            context: Some(command_name.to_string()),
-            resolved: false,
+            working_dir,
         });
-    }
 
-    // Save updated context
-    if let Ok(json) = serde_json::to_string_pretty(&ctx) {
-        let _ = std::fs::write(&session_path, json);
-    }
+        // Add error if command failed
+        if let Err(e) = result {
+            ctx.add_error(ErrorCapture {
+                timestamp: chrono::Local::now(),
+                error_type: "CommandError".to_string(),
+                message: format!("{}", e),
+                context: Some(command_name.to_string()),
+                resolved: false,
+            });
+        }
+    });
 }
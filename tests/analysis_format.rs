@@ -0,0 +1,54 @@
+//! Integration tests for `cldev analysis analyze --format`
+
+use assert_cmd::Command;
+use std::fs;
+use tempfile::TempDir;
+
+fn analyze(project: &TempDir, format: &str) -> (String, String) {
+    let home = TempDir::new().unwrap();
+    let assert = Command::cargo_bin("cldev")
+        .unwrap()
+        .env("HOME", home.path())
+        .args(["--lang", "en", "--no-color", "--project-root"])
+        .arg(project.path())
+        .args(["analysis", "analyze", "--format", format])
+        .assert()
+        .success();
+    let output = assert.get_output();
+    (
+        String::from_utf8(output.stdout.clone()).unwrap(),
+        String::from_utf8(output.stderr.clone()).unwrap(),
+    )
+}
+
+fn sample_project() -> TempDir {
+    let project = TempDir::new().unwrap();
+    fs::create_dir(project.path().join("src")).unwrap();
+    fs::write(
+        project.path().join("src/main.rs"),
+        "fn main() {\n    if true { println!(\"hi\"); }\n}\n",
+    )
+    .unwrap();
+    project
+}
+
+#[test]
+fn test_html_report_is_the_only_stdout() {
+    let project = sample_project();
+    let (stdout, stderr) = analyze(&project, "html");
+
+    assert!(stdout.starts_with("<!DOCTYPE html>"), "{stdout}");
+    assert!(stdout.trim_end().ends_with("</html>"), "{stdout}");
+    assert!(stderr.contains("Starting"), "{stderr}");
+}
+
+#[test]
+fn test_markdown_report_is_the_only_stdout() {
+    let project = sample_project();
+    let (stdout, stderr) = analyze(&project, "markdown");
+
+    assert!(stdout.starts_with("# "), "{stdout}");
+    assert!(stdout.contains("## Summary"), "{stdout}");
+    assert!(!stdout.contains("Starting"), "{stdout}");
+    assert!(stderr.contains("Starting"), "{stderr}");
+}
@@ -0,0 +1,92 @@
+//! Integration tests for the global `--config` flag
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use std::fs;
+use tempfile::TempDir;
+
+fn cldev(home: &TempDir) -> Command {
+    let mut cmd = Command::cargo_bin("cldev").unwrap();
+    cmd.env("HOME", home.path())
+        .args(["--lang", "en", "--no-color"]);
+    cmd
+}
+
+#[test]
+fn test_config_flag_overrides_default_location() {
+    let home = TempDir::new().unwrap();
+    let config_path = home.path().join("alt.toml");
+    fs::write(
+        &config_path,
+        "version = \"2.0.0\"\n\n[git]\ndefault_base_branch = \"develop\"\n",
+    )
+    .unwrap();
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(&config_path, fs::Permissions::from_mode(0o600)).unwrap();
+    }
+
+    cldev(&home)
+        .args(["config", "get", "git.default_base_branch"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("main"));
+
+    cldev(&home)
+        .arg("--config")
+        .arg(&config_path)
+        .args(["config", "get", "git.default_base_branch"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("develop"));
+}
+
+#[test]
+fn test_config_flag_rejects_missing_directory() {
+    let home = TempDir::new().unwrap();
+
+    cldev(&home)
+        .arg("--config")
+        .arg(home.path().join("missing/config.toml"))
+        .args(["config", "get", "git.default_base_branch"])
+        .assert()
+        .code(4)
+        .stderr(predicate::str::contains("Invalid config path"));
+}
+
+#[test]
+fn test_config_check_accepts_installed_catalog_language() {
+    let home = TempDir::new().unwrap();
+    let catalog_dir = home.path().join(".config/cldev/i18n");
+    fs::create_dir_all(&catalog_dir).unwrap();
+    fs::write(catalog_dir.join("fr.toml"), "greeting = \"Bonjour\"\n").unwrap();
+
+    let config_path = home.path().join("alt.toml");
+    fs::write(
+        &config_path,
+        "version = \"2.0.0\"\n\n[general]\nlanguage = \"fr\"\n",
+    )
+    .unwrap();
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(&config_path, fs::Permissions::from_mode(0o600)).unwrap();
+    }
+
+    cldev(&home)
+        .arg("--config")
+        .arg(&config_path)
+        .args(["config", "check", "--detailed"])
+        .assert()
+        .stdout(predicate::str::contains("general.language").not())
+        .stderr(predicate::str::contains("general.language").not());
+
+    fs::remove_file(catalog_dir.join("fr.toml")).unwrap();
+    cldev(&home)
+        .arg("--config")
+        .arg(&config_path)
+        .args(["config", "check", "--detailed"])
+        .assert()
+        .stdout(predicate::str::contains("general.language"));
+}
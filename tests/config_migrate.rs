@@ -0,0 +1,78 @@
+//! Integration tests for `cldev config migrate`
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use std::fs;
+use std::path::Path;
+use tempfile::TempDir;
+
+fn cldev(home: &TempDir) -> Command {
+    let mut cmd = Command::cargo_bin("cldev").unwrap();
+    cmd.env("HOME", home.path())
+        .args(["--lang", "en", "--no-color"]);
+    cmd
+}
+
+fn write_config(path: &Path, content: &str) {
+    fs::write(path, content).unwrap();
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(path, fs::Permissions::from_mode(0o600)).unwrap();
+    }
+}
+
+#[test]
+fn test_migrate_v1_file_roundtrip() {
+    let home = TempDir::new().unwrap();
+    let config_path = home.path().join("config.toml");
+    write_config(
+        &config_path,
+        "version = \"1.0.0\"\n\n[performance]\nparallel_tasks = 8\ntimeout_seconds = 600\n",
+    );
+
+    // A v1 file is read through an in-memory migration and left untouched
+    let original = fs::read_to_string(&config_path).unwrap();
+    cldev(&home)
+        .arg("--config")
+        .arg(&config_path)
+        .args(["config", "get", "performance.max_jobs"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("8"));
+    assert_eq!(fs::read_to_string(&config_path).unwrap(), original);
+
+    cldev(&home)
+        .arg("--config")
+        .arg(&config_path)
+        .args(["config", "migrate", "--backup"])
+        .assert()
+        .success();
+
+    let migrated: toml::Value = toml::from_str(&fs::read_to_string(&config_path).unwrap()).unwrap();
+    assert_eq!(migrated["version"].as_str(), Some("2.0.0"));
+    assert_eq!(migrated["performance"]["max_jobs"].as_integer(), Some(8));
+    assert!(migrated["performance"].get("parallel_tasks").is_none());
+    assert_eq!(
+        migrated["performance"]["timeout_seconds"].as_integer(),
+        Some(600)
+    );
+
+    cldev(&home)
+        .arg("--config")
+        .arg(&config_path)
+        .args(["config", "get", "performance.max_jobs"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("8"));
+
+    // Migrating again is a no-op
+    let before = fs::read_to_string(&config_path).unwrap();
+    cldev(&home)
+        .arg("--config")
+        .arg(&config_path)
+        .args(["config", "migrate"])
+        .assert()
+        .success();
+    assert_eq!(fs::read_to_string(&config_path).unwrap(), before);
+}
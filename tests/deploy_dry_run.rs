@@ -0,0 +1,39 @@
+//! Integration tests for `cldev ops deploy` under `--dry-run`
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use std::fs;
+use tempfile::TempDir;
+
+#[test]
+fn test_dry_run_prints_predeployment_checks_without_running_them() {
+    let home = TempDir::new().unwrap();
+    let project = TempDir::new().unwrap();
+    fs::write(
+        project.path().join("package.json"),
+        r#"{"name": "demo", "scripts": {"test": "touch tests-ran"}}"#,
+    )
+    .unwrap();
+
+    for flag in ["--dry-run", "-d"] {
+        Command::cargo_bin("cldev")
+            .unwrap()
+            .current_dir(project.path())
+            .env("HOME", home.path())
+            .args([
+                "--lang",
+                "en",
+                "--no-color",
+                "ops",
+                "deploy",
+                "staging",
+                flag,
+            ])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("npm run test"))
+            .stdout(predicate::str::contains("npm audit --audit-level=moderate"));
+
+        assert!(!project.path().join("tests-ran").exists());
+    }
+}
@@ -0,0 +1,123 @@
+//! Integration tests for the process exit codes of external command failures
+
+#![cfg(unix)]
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use std::fs;
+use std::os::unix::fs::PermissionsExt;
+use std::path::Path;
+use tempfile::TempDir;
+
+/// Write a project whose development build runs `build_command`
+fn project_with_build(build_command: &str) -> TempDir {
+    let project = TempDir::new().unwrap();
+    fs::create_dir(project.path().join(".cldev")).unwrap();
+    fs::write(
+        project.path().join(".cldev/config.toml"),
+        format!(
+            "[environments.development]\nbuild_command = \"{}\"\n",
+            build_command
+        ),
+    )
+    .unwrap();
+    project
+}
+
+fn ops_build(project: &Path, home: &Path) -> assert_cmd::assert::Assert {
+    Command::cargo_bin("cldev")
+        .unwrap()
+        .current_dir(project)
+        .env("HOME", home)
+        .args([
+            "--lang",
+            "en",
+            "--no-color",
+            "ops",
+            "build",
+            "--env",
+            "development",
+        ])
+        .assert()
+}
+
+#[test]
+fn test_failing_command_exits_with_its_own_code() {
+    let home = TempDir::new().unwrap();
+    let project = project_with_build("exit 7");
+
+    ops_build(project.path(), home.path())
+        .code(7)
+        .stderr(predicate::str::contains(
+            "Command failed with exit code 7: sh -c exit 7",
+        ));
+}
+
+#[test]
+fn test_failing_command_reports_json_error_under_output_json() {
+    let home = TempDir::new().unwrap();
+    let project = project_with_build("exit 7");
+
+    let assert = Command::cargo_bin("cldev")
+        .unwrap()
+        .current_dir(project.path())
+        .env("HOME", home.path())
+        .args([
+            "--lang",
+            "en",
+            "--output",
+            "json",
+            "ops",
+            "build",
+            "--env",
+            "development",
+        ])
+        .assert()
+        .code(7);
+
+    let stderr = String::from_utf8(assert.get_output().stderr.clone()).unwrap();
+    let error = stderr
+        .lines()
+        .filter_map(|line| serde_json::from_str::<serde_json::Value>(line).ok())
+        .find(|record| record["level"] == "error")
+        .expect("error record on stderr");
+    assert!(error["message"]
+        .as_str()
+        .unwrap()
+        .contains("Command failed with exit code 7"));
+}
+
+#[test]
+fn test_hung_command_exits_with_timeout_code() {
+    let home = TempDir::new().unwrap();
+    let config_path = home.path().join("config.toml");
+    fs::write(
+        &config_path,
+        "version = \"2.0.0\"\n\n[performance]\ntimeout_seconds = 1\n",
+    )
+    .unwrap();
+    fs::set_permissions(&config_path, fs::Permissions::from_mode(0o600)).unwrap();
+    let project = project_with_build("sleep 3");
+
+    Command::cargo_bin("cldev")
+        .unwrap()
+        .current_dir(project.path())
+        .env("HOME", home.path())
+        .arg("--config")
+        .arg(&config_path)
+        .args([
+            "--lang",
+            "en",
+            "--no-color",
+            "ops",
+            "build",
+            "--env",
+            "development",
+        ])
+        .timeout(std::time::Duration::from_secs(10))
+        .assert()
+        .code(124)
+        .stderr(predicate::str::contains(
+            "Command timed out after 1s: sh -c sleep 3",
+        ));
+}
@@ -0,0 +1,57 @@
+//! Integration tests for selecting an external message catalog
+
+#![cfg(unix)]
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use std::fs;
+use std::os::unix::fs::PermissionsExt;
+use tempfile::TempDir;
+
+/// Install a French catalog translating the doctor headline into `home`
+fn home_with_french_catalog() -> TempDir {
+    let home = TempDir::new().unwrap();
+    let dir = home.path().join(".config/cldev/i18n");
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(
+        dir.join("fr.toml"),
+        "doctor-project = \"Type de projet : {type}\"\n",
+    )
+    .unwrap();
+    home
+}
+
+#[test]
+fn test_lang_flag_selects_external_catalog() {
+    let home = home_with_french_catalog();
+
+    Command::cargo_bin("cldev")
+        .unwrap()
+        .current_dir(home.path())
+        .env("HOME", home.path())
+        .args(["--lang", "fr", "--no-color", "doctor"])
+        .assert()
+        .stdout(predicate::str::contains("Type de projet : Unknown"));
+}
+
+#[test]
+fn test_configured_language_selects_external_catalog() {
+    let home = home_with_french_catalog();
+    let config_path = home.path().join("config.toml");
+    fs::write(
+        &config_path,
+        "version = \"2.0.0\"\n\n[general]\nlanguage = \"fr\"\n",
+    )
+    .unwrap();
+    fs::set_permissions(&config_path, fs::Permissions::from_mode(0o600)).unwrap();
+
+    Command::cargo_bin("cldev")
+        .unwrap()
+        .current_dir(home.path())
+        .env("HOME", home.path())
+        .arg("--config")
+        .arg(&config_path)
+        .args(["--no-color", "doctor"])
+        .assert()
+        .stdout(predicate::str::contains("Type de projet : Unknown"));
+}
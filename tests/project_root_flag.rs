@@ -0,0 +1,67 @@
+//! Integration tests for the global `--project-root` flag
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use std::fs;
+use tempfile::TempDir;
+
+#[test]
+fn test_project_root_flag_loads_project_config_from_root() {
+    let home = TempDir::new().unwrap();
+    let project = TempDir::new().unwrap();
+    let elsewhere = TempDir::new().unwrap();
+    fs::create_dir(project.path().join(".cldev")).unwrap();
+    fs::write(
+        project.path().join(".cldev/config.toml"),
+        "[git]\nbase_branch = \"release\"\n",
+    )
+    .unwrap();
+
+    Command::cargo_bin("cldev")
+        .unwrap()
+        .current_dir(elsewhere.path())
+        .env("HOME", home.path())
+        .args(["--lang", "en", "--no-color", "--project-root"])
+        .arg(project.path())
+        .args(["config", "diff"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("release"));
+}
+
+#[test]
+fn test_project_root_flag_rejects_missing_directory() {
+    let home = TempDir::new().unwrap();
+
+    Command::cargo_bin("cldev")
+        .unwrap()
+        .env("HOME", home.path())
+        .args(["--lang", "en", "--no-color", "--project-root"])
+        .arg(home.path().join("missing"))
+        .args(["config", "diff"])
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains("Project root is not a directory"));
+}
+
+#[test]
+fn test_project_root_flag_detects_project_in_root() {
+    let home = TempDir::new().unwrap();
+    let project = TempDir::new().unwrap();
+    let elsewhere = TempDir::new().unwrap();
+    fs::write(
+        project.path().join("Cargo.toml"),
+        "[package]\nname = \"demo\"\nversion = \"0.1.0\"\n",
+    )
+    .unwrap();
+
+    Command::cargo_bin("cldev")
+        .unwrap()
+        .current_dir(elsewhere.path())
+        .env("HOME", home.path())
+        .args(["--lang", "en", "--no-color", "--project-root"])
+        .arg(project.path())
+        .arg("doctor")
+        .assert()
+        .stdout(predicate::str::contains("Detected project type: Rust"));
+}
@@ -0,0 +1,44 @@
+//! Integration tests for `cldev quality ... --json`
+
+use assert_cmd::Command;
+use std::fs;
+use std::path::PathBuf;
+use tempfile::TempDir;
+
+#[test]
+fn test_quality_lint_json_names_the_tool() {
+    let home = TempDir::new().unwrap();
+    let project = TempDir::new().unwrap();
+    fs::create_dir(project.path().join("src")).unwrap();
+    fs::write(
+        project.path().join("Cargo.toml"),
+        "[package]\nname = \"demo\"\nversion = \"0.1.0\"\nedition = \"2021\"\n\n[workspace]\n",
+    )
+    .unwrap();
+    fs::write(project.path().join("src/main.rs"), "fn main() {}\n").unwrap();
+
+    // Keep the toolchain reachable while HOME points at the temporary directory
+    let real_home = PathBuf::from(std::env::var_os("HOME").unwrap_or_default());
+    let rustup_home =
+        std::env::var_os("RUSTUP_HOME").map_or_else(|| real_home.join(".rustup"), PathBuf::from);
+    let cargo_home =
+        std::env::var_os("CARGO_HOME").map_or_else(|| real_home.join(".cargo"), PathBuf::from);
+
+    let assert = Command::cargo_bin("cldev")
+        .unwrap()
+        .current_dir(project.path())
+        .env("HOME", home.path())
+        .env("RUSTUP_HOME", rustup_home)
+        .env("CARGO_HOME", cargo_home)
+        .env("CARGO_TARGET_DIR", project.path().join("target"))
+        .args(["--lang", "en", "--no-color", "quality", "lint", "--json"])
+        .assert()
+        .success();
+
+    let stdout = String::from_utf8(assert.get_output().stdout.clone()).unwrap();
+    let report: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    assert_eq!(report["command"], "lint");
+    assert_eq!(report["tool"], "cargo");
+    assert_eq!(report["exit_code"], 0);
+    assert!(report["findings_count"].is_u64());
+}